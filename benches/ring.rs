@@ -0,0 +1,76 @@
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use lingo::prepared::PreparedRing;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+// Signing and verification of SAG and bLSAG signatures over ed25519 and secp256k1, for rings of
+// 4 to 4096 members. Rings and keys come from a fixed seed, so runs compare like with like.
+//
+//     cargo bench --bench ring -- secp256k1/verify
+
+const RING_SIZES: [usize; 6] = [4, 16, 64, 256, 1024, 4096];
+const MESSAGE: &[u8] = b"lingo benchmark message";
+
+fn bench_curve<C: CurveGroup>(c: &mut Criterion, curve: &str) {
+    let mut group = c.benchmark_group(curve);
+    // a 4096-member bLSAG signature takes seconds; ten samples keep the large rings bearable
+    group.sample_size(10);
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for size in RING_SIZES {
+        let private_key = SecretKey::<C>::generate(&mut rng);
+        let index = size / 2;
+        let ring = Ring::new_with_rng(size, &private_key, index, &mut rng).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("sign", size), &ring, |b, ring| {
+            b.iter(|| {
+                ring.sign_with_rng(MESSAGE, &private_key, index, &mut rng)
+                    .unwrap()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("sign_linkable", size), &ring, |b, ring| {
+            b.iter(|| {
+                ring.sign_linkable_with_rng(MESSAGE, &private_key, index, &mut rng)
+                    .unwrap()
+            })
+        });
+
+        let signature = ring
+            .sign_with_rng(MESSAGE, &private_key, index, &mut rng)
+            .unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("verify", size),
+            &signature,
+            |b, signature| b.iter(|| signature.verify(MESSAGE).unwrap()),
+        );
+        let signature = ring
+            .sign_linkable_with_rng(MESSAGE, &private_key, index, &mut rng)
+            .unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("verify_linkable", size),
+            &signature,
+            |b, signature| b.iter(|| signature.verify(MESSAGE).unwrap()),
+        );
+        let prepared = PreparedRing::new(ring.clone()).unwrap();
+        let detached = signature.detach();
+        group.bench_with_input(
+            BenchmarkId::new("verify_linkable_prepared", size),
+            &detached,
+            |b, detached| b.iter(|| detached.verify_with_prepared(&prepared, MESSAGE).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+fn ed25519(c: &mut Criterion) {
+    bench_curve::<ark_ed25519::EdwardsProjective>(c, "ed25519");
+}
+
+fn secp256k1(c: &mut Criterion) {
+    bench_curve::<ark_secp256k1::Projective>(c, "secp256k1");
+}
+
+criterion_group!(benches, ed25519, secp256k1);
+criterion_main!(benches);
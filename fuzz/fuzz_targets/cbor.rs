@@ -0,0 +1,21 @@
+#![no_main]
+
+// Arbitrary bytes into the CBOR encoding, with the same checks as the wire target.
+
+use libfuzzer_sys::fuzz_target;
+use lingo::wire::{DecodedSignature, WireCurve};
+
+fn check<C: WireCurve>(data: &[u8]) {
+    let Ok(signature) = DecodedSignature::<C>::from_cbor(data) else {
+        return;
+    };
+    let _ = signature.verify(b"lingo fuzz");
+    let decoded = DecodedSignature::<C>::from_cbor(&signature.signature().to_cbor())
+        .expect("a decoded signature re-encodes to valid bytes");
+    assert!(decoded == signature);
+}
+
+fuzz_target!(|data: &[u8]| {
+    check::<ark_secp256k1::Projective>(data);
+    check::<ark_ed25519::EdwardsProjective>(data);
+});
@@ -0,0 +1,35 @@
+#![no_main]
+
+// Arbitrary text into every text decoder: armored, hex and base45 signatures, hex key images,
+// and rings from hex lists and PEM bundles. None of them may panic, and decoded signatures must
+// survive verification.
+
+use libfuzzer_sys::fuzz_target;
+use lingo::key_image::KeyImage;
+use lingo::pem::SpkiCurve;
+use lingo::ring::Ring;
+use lingo::wire::{DecodedSignature, WireCurve};
+
+fn check<C: WireCurve + SpkiCurve>(text: &str) {
+    for signature in [
+        DecodedSignature::<C>::from_armor(text),
+        DecodedSignature::<C>::from_hex(text),
+        DecodedSignature::<C>::from_compact_base45(text),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        let _ = signature.verify(b"lingo fuzz");
+    }
+    let _ = KeyImage::<C>::from_hex(text);
+    let _ = Ring::<C>::from_hex_list(text);
+    let _ = Ring::<C>::from_pem_bundle(text);
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = core::str::from_utf8(data) else {
+        return;
+    };
+    check::<ark_secp256k1::Projective>(text);
+    check::<ark_ed25519::EdwardsProjective>(text);
+});
@@ -0,0 +1,76 @@
+#![no_main]
+
+// Signatures built field by field from arbitrary bytes, over a fixed ring, straight into the
+// verifier: scalars need not be canonical, the key image is any point its hex decoder accepts,
+// and the hash id is arbitrary. Nothing may panic, and since the ring's secret keys are never
+// used to sign, nothing may verify.
+
+use ark_ff::BigInt;
+use libfuzzer_sys::fuzz_target;
+use lingo::key_image::KeyImage;
+use lingo::ring::{Ring, RingSignature};
+use lingo::secp256k1;
+use std::sync::OnceLock;
+
+type Curve = ark_secp256k1::Projective;
+
+const RING_SIZE: usize = 4;
+const MESSAGE: &[u8] = b"lingo fuzz";
+
+// the public keys of secret keys 1 to 4
+fn ring() -> &'static Ring<Curve> {
+    static RING: OnceLock<Ring<Curve>> = OnceLock::new();
+    RING.get_or_init(|| {
+        let keys: Vec<[u8; 33]> = (1u8..=RING_SIZE as u8)
+            .map(|secret| {
+                let mut bytes = [0u8; 32];
+                bytes[31] = secret;
+                secp256k1::encode(
+                    lingo::keypair::KeyPair::<Curve>::try_from(&bytes)
+                        .unwrap()
+                        .public_key(),
+                )
+            })
+            .collect();
+        Ring::from_sec1_keys(&keys).unwrap()
+    })
+}
+
+fn scalar(bytes: &[u8]) -> BigInt<4> {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        let mut word = [0u8; 8];
+        word[..chunk.len()].copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(word);
+    }
+    BigInt::new(limbs)
+}
+
+fuzz_target!(|data: &[u8]| {
+    let [flags, hash_id, rest @ ..] = data else {
+        return;
+    };
+    let mut chunks = rest.chunks(32);
+    let challenge = scalar(chunks.next().unwrap_or_default());
+    let ring_sig_vals = (0..RING_SIZE)
+        .map(|_| scalar(chunks.next().unwrap_or_default()))
+        .collect();
+    let image = (flags & 1 == 1)
+        .then(|| {
+            let hex: String = chunks
+                .flatten()
+                .take(33)
+                .map(|byte| format!("{byte:02x}"))
+                .collect();
+            KeyImage::<Curve>::from_hex(&hex).ok()
+        })
+        .flatten();
+    let signature = RingSignature {
+        ring: ring(),
+        challenge,
+        ring_sig_vals,
+        image,
+        hash_id: *hash_id,
+    };
+    assert!(signature.verify(MESSAGE).is_err());
+});
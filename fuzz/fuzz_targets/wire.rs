@@ -0,0 +1,22 @@
+#![no_main]
+
+// Arbitrary bytes into the versioned wire format. Anything that decodes must survive
+// verification without panicking and re-encode to bytes that decode to the same signature.
+
+use libfuzzer_sys::fuzz_target;
+use lingo::wire::{DecodedSignature, WireCurve};
+
+fn check<C: WireCurve>(data: &[u8]) {
+    let Ok(signature) = DecodedSignature::<C>::from_bytes(data) else {
+        return;
+    };
+    let _ = signature.verify(b"lingo fuzz");
+    let decoded = DecodedSignature::<C>::from_bytes(&signature.to_bytes())
+        .expect("a decoded signature re-encodes to valid bytes");
+    assert!(decoded == signature);
+}
+
+fuzz_target!(|data: &[u8]| {
+    check::<ark_secp256k1::Projective>(data);
+    check::<ark_ed25519::EdwardsProjective>(data);
+});
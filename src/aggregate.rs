@@ -0,0 +1,91 @@
+use crate::error::{RingError, VerifyError};
+use crate::hash;
+use crate::ring::{Ring, RingSignature, ScalarBigInt};
+use crate::secret::SecretKey;
+use alloc::vec::Vec;
+use ark_ec::CurveGroup;
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::Digest;
+
+const AGGREGATE_DOMAIN: &[u8] = b"lingo-aggregate-v1";
+
+// One ring signature covers the whole ordered batch: the messages are folded into a single
+// length-prefixed digest that is signed in their place, so the proof stays `n + 1` scalars no
+// matter how many messages it covers, against `N (n + 1)` for independent signatures.
+
+/// Signs every message in `messages` at once with a single SAG signature.
+#[cfg(feature = "std")]
+pub fn sign<'a, C: CurveGroup>(
+    ring: &'a Ring<C>,
+    messages: &[&[u8]],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+    sign_with_rng(
+        ring,
+        messages,
+        private_key,
+        secret_index,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Like [`sign`], drawing the nonces from `rng`.
+pub fn sign_with_rng<'a, C: CurveGroup, R: RngCore + CryptoRng>(
+    ring: &'a Ring<C>,
+    messages: &[&[u8]],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    rng: &mut R,
+) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+    ring.sign_with_rng(&digest::<C>(ring, messages), private_key, secret_index, rng)
+}
+
+/// Signs every message in `messages` at once with a single linkable bLSAG signature.
+#[cfg(feature = "std")]
+pub fn sign_linkable<'a, C: CurveGroup>(
+    ring: &'a Ring<C>,
+    messages: &[&[u8]],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+    sign_linkable_with_rng(
+        ring,
+        messages,
+        private_key,
+        secret_index,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Like [`sign_linkable`], drawing the nonces from `rng`.
+pub fn sign_linkable_with_rng<'a, C: CurveGroup, R: RngCore + CryptoRng>(
+    ring: &'a Ring<C>,
+    messages: &[&[u8]],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    rng: &mut R,
+) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+    ring.sign_linkable_with_rng(&digest::<C>(ring, messages), private_key, secret_index, rng)
+}
+
+/// Verifies an aggregated signature against the same messages, in the same order, that were
+/// signed.
+pub fn verify<C: CurveGroup>(
+    signature: &RingSignature<'_, ScalarBigInt<C>, C>,
+    messages: &[&[u8]],
+) -> Result<(), VerifyError> {
+    signature.verify(&digest::<C>(signature.ring(), messages))
+}
+
+fn digest<C: CurveGroup>(ring: &Ring<C>, messages: &[&[u8]]) -> Vec<u8> {
+    let mut hasher = hash::challenge_hasher::<C>(AGGREGATE_DOMAIN, ring.keys(), &[]);
+    hasher.update((messages.len() as u64).to_le_bytes());
+    for message in messages {
+        hasher.update((message.len() as u64).to_le_bytes());
+        hasher.update(message);
+    }
+    hasher.finalize().to_vec()
+}
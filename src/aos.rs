@@ -0,0 +1,211 @@
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::ring::{CurvePoint, Ring, Scalar};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+const AOS_DOMAIN: &[u8] = b"lingo-aos-v1";
+
+/// A ring member in an AOS ring. Members only ever exchange byte strings with the signing loop,
+/// so keys from different groups can sit in the same ring: each member reduces the shared
+/// challenge into its own scalar field.
+pub trait AosPublicKey {
+    fn encode(&self) -> Vec<u8>;
+
+    fn random_response(&self, rng: &mut dyn RngCore) -> Vec<u8>;
+
+    /// Recomputes the member's commitment `r G + c K`, or `None` if `response` is not a
+    /// canonical scalar for this member's group.
+    fn commit(&self, challenge: &[u8], response: &[u8]) -> Option<Vec<u8>>;
+}
+
+/// Discrete-log public key `K = k G` over any arkworks curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DlogPublicKey<C: CurveGroup>(pub CurvePoint<C>);
+
+impl<C: CurveGroup> AosPublicKey for DlogPublicKey<C> {
+    fn encode(&self) -> Vec<u8> {
+        encode(&self.0)
+    }
+
+    fn random_response(&self, rng: &mut dyn RngCore) -> Vec<u8> {
+        encode(&Scalar::<C>::rand(rng))
+    }
+
+    fn commit(&self, challenge: &[u8], response: &[u8]) -> Option<Vec<u8>> {
+        if self.0.is_zero() {
+            return None;
+        }
+        let response = Scalar::<C>::deserialize_compressed(response).ok()?;
+        let challenge = Scalar::<C>::from_le_bytes_mod_order(challenge);
+        let point = generator::mul::<C>(&response) + self.0 * challenge;
+        Some(encode(&point.into_affine()))
+    }
+}
+
+#[derive(Default)]
+pub struct AosRing {
+    members: Vec<Box<dyn AosPublicKey>>,
+}
+
+impl AosRing {
+    pub fn new(members: Vec<Box<dyn AosPublicKey>>) -> AosRing {
+        AosRing { members }
+    }
+
+    pub fn push(&mut self, member: impl AosPublicKey + 'static) {
+        self.members.push(Box::new(member));
+    }
+
+    pub fn extend_from_ring<C: CurveGroup>(&mut self, ring: &Ring<C>) {
+        for key in ring.keys() {
+            self.push(DlogPublicKey::<C>(*key));
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn members(&self) -> &[Box<dyn AosPublicKey>] {
+        &self.members
+    }
+
+    /// Produces an AOS signature for the member at `secret_index`, which must be a
+    /// [`DlogPublicKey`] over `C` opened by `private_key`, failing with the [`RingError`]
+    /// [`Ring::sign`] would give otherwise. The other members may be of any type.
+    #[cfg(feature = "std")]
+    pub fn sign<C: CurveGroup>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<AosSignature<'_>, RingError> {
+        self.sign_with_rng(message, private_key, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`AosRing::sign`], drawing the nonces and decoy responses from `rng`.
+    pub fn sign_with_rng<C: CurveGroup, R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<AosSignature<'_>, RingError> {
+        let n = self.size();
+        if private_key.expose_secret().is_zero() {
+            return Err(RingError::ZeroPrivateKey);
+        }
+        if secret_index >= n {
+            return Err(RingError::IndexOutOfBounds {
+                index: secret_index,
+                size: n,
+            });
+        }
+        let secret = SecretScalar::<C>::from_private_key(private_key);
+        let public_key = ct::generator_mul::<C>(&secret).into_affine();
+        if encode(&public_key) != self.members[secret_index].encode() {
+            return Err(RingError::KeyMismatch);
+        }
+
+        let prefix = self.challenge_hasher(message);
+        let mut challenges = vec![Vec::new(); n];
+        let mut responses = vec![Vec::new(); n];
+
+        let alpha = SecretScalar::<C>::random(rng);
+        let commitment = encode(&ct::generator_mul::<C>(&alpha).into_affine());
+        challenges[(secret_index + 1) % n] = challenge(&prefix, &commitment);
+
+        for offset in 1..n {
+            let i = (secret_index + offset) % n;
+            let member = &self.members[i];
+            responses[i] = member.random_response(rng);
+            let commitment = member
+                .commit(&challenges[i], &responses[i])
+                .expect("freshly sampled responses are canonical");
+            challenges[(i + 1) % n] = challenge(&prefix, &commitment);
+        }
+
+        let c = Scalar::<C>::from_le_bytes_mod_order(&challenges[secret_index]);
+        responses[secret_index] = encode(&alpha.response(&c, &secret));
+
+        Ok(AosSignature {
+            ring: self,
+            challenge: challenges.swap_remove(0),
+            responses,
+        })
+    }
+
+    fn challenge_hasher(&self, message: &[u8]) -> Sha512 {
+        let mut hasher = Sha512::new();
+        hasher.update((AOS_DOMAIN.len() as u64).to_le_bytes());
+        hasher.update(AOS_DOMAIN);
+        hasher.update((self.members.len() as u64).to_le_bytes());
+        for member in &self.members {
+            let encoded = member.encode();
+            hasher.update((encoded.len() as u64).to_le_bytes());
+            hasher.update(&encoded);
+        }
+        hasher.update((message.len() as u64).to_le_bytes());
+        hasher.update(message);
+        hasher
+    }
+}
+
+pub struct AosSignature<'a> {
+    pub ring: &'a AosRing,
+    pub challenge: Vec<u8>,
+    pub responses: Vec<Vec<u8>>,
+}
+
+impl<'a> AosSignature<'a> {
+    pub fn ring(&self) -> &AosRing {
+        self.ring
+    }
+
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        let members = self.ring.members();
+        if members.is_empty() {
+            return Err(VerifyError::RingTooSmall);
+        }
+        if members.len() != self.responses.len() {
+            return Err(VerifyError::LengthMismatch);
+        }
+
+        let prefix = self.ring.challenge_hasher(message);
+        let mut current = self.challenge.clone();
+        for (member, response) in members.iter().zip(&self.responses) {
+            let commitment = member
+                .commit(&current, response)
+                .ok_or(VerifyError::NonCanonicalScalar)?;
+            current = challenge(&prefix, &commitment);
+        }
+
+        error::check_challenge(&current, &self.challenge)
+    }
+}
+
+fn challenge(prefix: &Sha512, commitment: &[u8]) -> Vec<u8> {
+    let mut hasher = prefix.clone();
+    hasher.update((commitment.len() as u64).to_le_bytes());
+    hasher.update(commitment);
+    hasher.finalize().to_vec()
+}
+
+fn encode<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut bytes)
+        .expect("serializing into a Vec cannot fail");
+    bytes
+}
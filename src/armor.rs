@@ -0,0 +1,129 @@
+use crate::base64;
+use crate::error::DecodeError;
+use crate::ring::{RingSignature, ScalarBigInt};
+use crate::wire::{DecodedSignature, WireCurve};
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+// PGP-style ASCII armor (RFC 4880 §6.2) around the `wire` bytes, for pasting signatures into
+// email, forums and commit messages:
+//
+//   -----BEGIN LINGO RING SIGNATURE-----
+//   Curve: secp256k1
+//   Scheme: blsag
+//
+//   base64 of the wire bytes, 64 characters per line
+//   =CRC-24 of the wire bytes, base64
+//   -----END LINGO RING SIGNATURE-----
+//
+// The headers are informational and any others are ignored, but a `Curve` header must name the
+// curve being decoded. Text around the block, blank lines and trailing whitespace (including
+// CRLF line ends) are tolerated; the checksum line is required.
+
+const BEGIN: &str = "-----BEGIN LINGO RING SIGNATURE-----";
+const END: &str = "-----END LINGO RING SIGNATURE-----";
+const CURVE: &str = "Curve";
+const SCHEME: &str = "Scheme";
+const LINE_WIDTH: usize = 64;
+
+const CRC24_INIT: u32 = 0xb704ce;
+const CRC24_POLY: u32 = 0x1864cfb;
+
+impl<'a, C: WireCurve> RingSignature<'a, ScalarBigInt<C>, C> {
+    /// [`RingSignature::to_bytes`] in ASCII armor, read back with
+    /// [`DecodedSignature::from_armor`]. Panics if a scalar is not canonical.
+    pub fn to_armor(&self) -> String {
+        let bytes = self.to_bytes();
+        let scheme = if self.image.is_some() { "blsag" } else { "sag" };
+        let mut armor = format!(
+            "{BEGIN}\n{CURVE}: {}\n{SCHEME}: {scheme}\n\n",
+            C::CURVE_NAME
+        );
+        let body = base64::encode(&bytes, base64::STANDARD, true);
+        for line in body.as_bytes().chunks(LINE_WIDTH) {
+            armor.push_str(core::str::from_utf8(line).expect("base64 is ASCII"));
+            armor.push('\n');
+        }
+        let checksum = crc24(&bytes).to_be_bytes();
+        armor.push('=');
+        armor.push_str(&base64::encode(&checksum[1..], base64::STANDARD, true));
+        armor.push('\n');
+        armor.push_str(END);
+        armor.push('\n');
+        armor
+    }
+}
+
+impl<C: WireCurve> DecodedSignature<C> {
+    pub fn to_armor(&self) -> String {
+        self.signature().to_armor()
+    }
+
+    /// Parses the first armored signature in `text`, as written by [`RingSignature::to_armor`].
+    pub fn from_armor(text: &str) -> Result<DecodedSignature<C>, DecodeError> {
+        let mut lines = text.lines().map(str::trim_end);
+        lines
+            .by_ref()
+            .find(|line| *line == BEGIN)
+            .ok_or(DecodeError::InvalidArmor)?;
+
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            let (key, value) = line.split_once(": ").ok_or(DecodeError::InvalidArmor)?;
+            if key == CURVE && value != C::CURVE_NAME {
+                return Err(DecodeError::WrongCurveName(value.to_string()));
+            }
+        }
+
+        let mut body = Vec::new();
+        let mut checksum = None;
+        let mut ended = false;
+        for line in lines.by_ref() {
+            if line == END {
+                ended = true;
+                break;
+            }
+            match line.strip_prefix('=') {
+                Some(crc) if checksum.is_none() => checksum = Some(crc),
+                Some(_) => return Err(DecodeError::InvalidArmor),
+                None if checksum.is_some() => return Err(DecodeError::InvalidArmor),
+                None => body.extend(line.bytes()),
+            }
+        }
+        if !ended {
+            return Err(DecodeError::InvalidArmor);
+        }
+        let checksum = base64::decode(
+            checksum.ok_or(DecodeError::InvalidArmor)?.as_bytes(),
+            base64::STANDARD,
+            true,
+        )
+        .filter(|crc| crc.len() == 3)
+        .ok_or(DecodeError::InvalidArmor)?;
+        let bytes =
+            base64::decode(&body, base64::STANDARD, true).ok_or(DecodeError::InvalidArmor)?;
+        if crc24(&bytes).to_be_bytes()[1..] != checksum[..] {
+            return Err(DecodeError::BadChecksum);
+        }
+        DecodedSignature::from_bytes(&bytes)
+    }
+}
+
+// the OpenPGP CRC-24, RFC 4880 §6.1
+fn crc24(bytes: &[u8]) -> u32 {
+    let mut crc = CRC24_INIT;
+    for byte in bytes {
+        crc ^= (*byte as u32) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x1000000 != 0 {
+                crc ^= CRC24_POLY;
+            }
+        }
+    }
+    crc & 0xffffff
+}
@@ -0,0 +1,80 @@
+// Base64 (RFC 4648) for PEM bodies and the URL-safe text encoding of signatures. PEM uses the
+// standard alphabet with padding; the URL-safe form drops the padding, as JWTs do.
+
+use crate::error::DecodeError;
+use crate::ring::{RingSignature, ScalarBigInt};
+use crate::wire::{DecodedSignature, WireCurve};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub(crate) const STANDARD: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+pub(crate) const URL_SAFE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+pub(crate) fn encode(bytes: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | (*byte as u32) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(alphabet[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else if pad {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes `symbols`, which must be padded to a multiple of four if `pad` is set and must
+/// carry no padding otherwise.
+pub(crate) fn decode(symbols: &[u8], alphabet: &[u8; 64], pad: bool) -> Option<Vec<u8>> {
+    let padding = symbols
+        .iter()
+        .rev()
+        .take_while(|byte| **byte == b'=')
+        .count();
+    let body = &symbols[..symbols.len() - padding];
+    if pad {
+        if !symbols.len().is_multiple_of(4) || padding > 2 {
+            return None;
+        }
+    } else if padding > 0 || body.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(body.len() / 4 * 3 + 2);
+    for chunk in body.chunks(4) {
+        let mut group = 0u32;
+        for symbol in chunk {
+            let value = alphabet.iter().position(|c| c == symbol)?;
+            group = group << 6 | value as u32;
+        }
+        group <<= 6 * (4 - chunk.len());
+        bytes.extend(&group.to_be_bytes()[1..chunk.len()]);
+    }
+    Some(bytes)
+}
+
+impl<'a, C: WireCurve> RingSignature<'a, ScalarBigInt<C>, C> {
+    /// [`RingSignature::to_bytes`] as unpadded URL-safe base64, fit for HTTP headers and
+    /// token segments.
+    pub fn to_base64(&self) -> String {
+        encode(&self.to_bytes(), URL_SAFE, false)
+    }
+}
+
+impl<C: WireCurve> DecodedSignature<C> {
+    pub fn to_base64(&self) -> String {
+        encode(&self.to_bytes(), URL_SAFE, false)
+    }
+
+    /// Parses the output of [`RingSignature::to_base64`]. Padding is rejected.
+    pub fn from_base64(text: &str) -> Result<DecodedSignature<C>, DecodeError> {
+        let bytes = decode(text.as_bytes(), URL_SAFE, false).ok_or(DecodeError::InvalidBase64)?;
+        DecodedSignature::from_bytes(&bytes)
+    }
+}
@@ -0,0 +1,85 @@
+// Writes the interop test vectors: one file per curve and scheme, named `<curve>-<scheme>.json`,
+// in the layout described in `lingo::test_vectors`. Every file is derived from a fixed seed, so
+// running this again produces the same bytes and other implementations can pin them.
+//
+//   cargo run --features interop --bin lingo-vectors -- [DIR]
+//
+// DIR defaults to `vectors`. Curves left out of the build by their feature are skipped.
+
+use lingo::test_vectors::{VectorCase, VectorFile};
+use lingo::wire::WireCurve;
+use std::path::Path;
+use std::{env, fs, io, process};
+
+const SEED_DOMAIN: &str = "lingo-interop-vectors-v1";
+
+const CASES: &[VectorCase<'static>] = &[
+    VectorCase {
+        message: b"",
+        ring_size: 1,
+        secret_index: 0,
+    },
+    VectorCase {
+        message: b"lingo",
+        ring_size: 2,
+        secret_index: 1,
+    },
+    VectorCase {
+        message: b"The quick brown fox jumps over the lazy dog",
+        ring_size: 3,
+        secret_index: 0,
+    },
+    VectorCase {
+        message: &[0xff; 64],
+        ring_size: 5,
+        secret_index: 2,
+    },
+    VectorCase {
+        message: b"a ring of eleven members",
+        ring_size: 11,
+        secret_index: 10,
+    },
+];
+
+fn main() {
+    let dir = env::args().nth(1).unwrap_or_else(|| "vectors".to_string());
+    if let Err(error) = write_all(Path::new(&dir)) {
+        eprintln!("lingo-vectors: {dir}: {error}");
+        process::exit(1);
+    }
+}
+
+fn write_all(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    write::<ark_secp256k1::Projective>(dir)?;
+    write::<ark_ed25519::EdwardsProjective>(dir)?;
+    #[cfg(feature = "p256")]
+    write::<lingo::curves::p256::Projective>(dir)?;
+    #[cfg(feature = "bls12-381")]
+    write::<lingo::curves::bls12_381::G1Projective>(dir)?;
+    #[cfg(feature = "bn254")]
+    write::<lingo::curves::bn254::G1Projective>(dir)?;
+    #[cfg(feature = "pallas")]
+    write::<lingo::curves::pallas::Projective>(dir)?;
+    #[cfg(feature = "vesta")]
+    write::<lingo::curves::vesta::Projective>(dir)?;
+    #[cfg(feature = "jubjub")]
+    write::<lingo::curves::jubjub::EdwardsProjective>(dir)?;
+    Ok(())
+}
+
+// both schemes on one curve
+fn write<C: WireCurve>(dir: &Path) -> io::Result<()> {
+    for linkable in [false, true] {
+        let scheme = if linkable { "blsag" } else { "sag" };
+        let seed = format!("{SEED_DOMAIN}/{}/{scheme}", C::CURVE_NAME);
+        let file = VectorFile::generate::<C>(linkable, CASES, seed.as_bytes());
+        // a file that does not check against this build is a bug, not something to publish
+        file.check::<C>()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let path = dir.join(format!("{}-{scheme}.json", C::CURVE_NAME));
+        fs::write(&path, file.to_json() + "\n")?;
+        println!("{}: {} vectors", path.display(), file.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,278 @@
+use crate::ct;
+use crate::error::{self, RingError, SessionError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::ring::{CurvePoint, Ring, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, UniformRand};
+use ark_serialize::Valid;
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+
+const BLIND_DOMAIN: &[u8] = b"lingo-blind-v1";
+
+// Blind signing uses the per-member-challenge (CDS) form of a ring signature: the signer
+// commits to every member, the user re-randomises each commitment with `alpha_j G + beta_j K_j`
+// and hands back a challenge shifted by `sum beta_j`, so neither the message nor the final
+// signature can be matched to the signing session.
+
+/// Signer-side state for one blind signing session.
+pub struct BlindSigner<C: CurveGroup> {
+    // the signer's position, by which `challenges` and `responses` are rotated
+    shift: usize,
+    secret: SecretScalar<C>,
+    nonce: SecretScalar<C>,
+    challenges: Vec<Scalar<C>>,
+    responses: Vec<Scalar<C>>,
+}
+
+/// First message, signer to user: one commitment per ring member.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlindCommitment<C: CurveGroup> {
+    pub commitments: Vec<CurvePoint<C>>,
+}
+
+/// Second message, user to signer: the blinded challenge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlindRequest<C: CurveGroup> {
+    pub challenge: ScalarBigInt<C>,
+}
+
+/// Third message, signer to user: per-member challenges and responses, still blinded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlindResponse<C: CurveGroup> {
+    pub challenges: Vec<ScalarBigInt<C>>,
+    pub responses: Vec<ScalarBigInt<C>>,
+}
+
+/// User-side blinding factors, consumed by [`unblind`].
+pub struct Blinding<'a, C: CurveGroup> {
+    ring: &'a Ring<C>,
+    alphas: Vec<Scalar<C>>,
+    betas: Vec<Scalar<C>>,
+}
+
+impl<C: CurveGroup> BlindSigner<C> {
+    #[cfg(feature = "std")]
+    pub fn commit(
+        ring: &Ring<C>,
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<(BlindSigner<C>, BlindCommitment<C>), RingError> {
+        BlindSigner::commit_with_rng(ring, private_key, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`BlindSigner::commit`], drawing the nonce and simulated members from `rng`.
+    pub fn commit_with_rng<R: RngCore + CryptoRng>(
+        ring: &Ring<C>,
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<(BlindSigner<C>, BlindCommitment<C>), RingError> {
+        let n = ring.size();
+        let secret = ring.signer_secret(private_key, secret_index)?;
+
+        let nonce = SecretScalar::<C>::random(rng);
+        let mut challenges = vec![Scalar::<C>::from(0u64); n];
+        let mut responses = vec![Scalar::<C>::from(0u64); n];
+        // the simulated members are filled in over the ring rotated so the signer is at
+        // position 0, as in `Ring::sign`, and the session keeps them in that order until
+        // `respond` rotates them back
+        let keys = ct::rotate_left(ring.keys(), secret_index);
+        let mut commitments = vec![ct::generator_mul::<C>(&nonce)];
+        for k in 1..n {
+            challenges[k] = Scalar::<C>::rand(rng);
+            responses[k] = Scalar::<C>::rand(rng);
+            commitments.push(generator::mul::<C>(&responses[k]) + keys[k] * challenges[k]);
+        }
+        let commitments = ct::rotate_right(&C::normalize_batch(&commitments), secret_index);
+
+        Ok((
+            BlindSigner {
+                shift: secret_index,
+                secret,
+                nonce,
+                challenges,
+                responses,
+            },
+            BlindCommitment { commitments },
+        ))
+    }
+
+    /// Answers the user's blinded challenge. Consumes the signer state so a nonce is never
+    /// used for two challenges, even one refused as non-canonical.
+    pub fn respond(mut self, request: &BlindRequest<C>) -> Result<BlindResponse<C>, SessionError> {
+        let challenge =
+            Scalar::<C>::from_bigint(request.challenge).ok_or(SessionError::NonCanonicalScalar)?;
+        let simulated: Scalar<C> = self.challenges.iter().sum();
+        self.challenges[0] = challenge - simulated;
+        self.responses[0] = self.nonce.response(&self.challenges[0], &self.secret);
+        let challenges = ct::rotate_right(&self.challenges, self.shift);
+        let responses = ct::rotate_right(&self.responses, self.shift);
+        Ok(BlindResponse {
+            challenges: challenges.iter().map(|c| c.into_bigint()).collect(),
+            responses: responses.iter().map(|s| s.into_bigint()).collect(),
+        })
+    }
+}
+
+impl<C: CurveGroup> BlindRequest<C> {
+    /// Blinds `message` against the signer's commitments, returning the request to send and
+    /// the factors needed to [`unblind`] the response. Fails if there is not one commitment per
+    /// member, or if any of them is the identity or outside the prime-order subgroup.
+    #[cfg(feature = "std")]
+    pub fn new<'a>(
+        ring: &'a Ring<C>,
+        message: &[u8],
+        commitment: &BlindCommitment<C>,
+    ) -> Result<(Blinding<'a, C>, BlindRequest<C>), SessionError> {
+        BlindRequest::new_with_rng(ring, message, commitment, &mut rand::thread_rng())
+    }
+
+    /// Like [`BlindRequest::new`], drawing the blinding factors from `rng`.
+    pub fn new_with_rng<'a, R: RngCore + CryptoRng>(
+        ring: &'a Ring<C>,
+        message: &[u8],
+        commitment: &BlindCommitment<C>,
+        rng: &mut R,
+    ) -> Result<(Blinding<'a, C>, BlindRequest<C>), SessionError> {
+        let n = ring.size();
+        if commitment.commitments.len() != n {
+            return Err(SessionError::LengthMismatch);
+        }
+        let invalid: Vec<usize> = commitment
+            .commitments
+            .iter()
+            .enumerate()
+            .filter(|(_, point)| point.is_zero() || point.check().is_err())
+            .map(|(i, _)| i)
+            .collect();
+        if !invalid.is_empty() {
+            return Err(SessionError::InvalidPoints(invalid));
+        }
+
+        let alphas: Vec<Scalar<C>> = (0..n).map(|_| Scalar::<C>::rand(rng)).collect();
+        let betas: Vec<Scalar<C>> = (0..n).map(|_| Scalar::<C>::rand(rng)).collect();
+        let blinded: Vec<C> = commitment
+            .commitments
+            .iter()
+            .zip(ring.keys())
+            .zip(alphas.iter().zip(&betas))
+            .map(|((r, key), (alpha, beta))| ct::generator_mul::<C>(alpha) + *key * beta + *r)
+            .collect();
+
+        let total = challenge::<C>(ring, message, &C::normalize_batch(&blinded));
+        let shift: Scalar<C> = betas.iter().sum();
+        Ok((
+            Blinding {
+                ring,
+                alphas,
+                betas,
+            },
+            BlindRequest {
+                challenge: (total - shift).into_bigint(),
+            },
+        ))
+    }
+}
+
+/// Removes the blinding from the signer's response. Returns `None` if the unblinded signature
+/// does not verify over `message`, which means the signer misbehaved.
+pub fn unblind<'a, C: CurveGroup>(
+    blinding: Blinding<'a, C>,
+    message: &[u8],
+    response: &BlindResponse<C>,
+) -> Option<BlindRingSignature<'a, ScalarBigInt<C>, C>> {
+    let n = blinding.ring.size();
+    if response.challenges.len() != n || response.responses.len() != n {
+        return None;
+    }
+
+    let mut challenges = Vec::with_capacity(n);
+    let mut responses = Vec::with_capacity(n);
+    for j in 0..n {
+        let c = Scalar::<C>::from_bigint(response.challenges[j])?;
+        let s = Scalar::<C>::from_bigint(response.responses[j])?;
+        challenges.push((c + blinding.betas[j]).into_bigint());
+        responses.push((s + blinding.alphas[j]).into_bigint());
+    }
+
+    let signature = BlindRingSignature {
+        ring: blinding.ring,
+        challenges,
+        ring_sig_vals: responses,
+    };
+    signature.verify(message).is_ok().then_some(signature)
+}
+
+#[derive(PartialEq, Eq)]
+pub struct BlindRingSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: &'a Ring<C>,
+    pub challenges: Vec<B>,
+    pub ring_sig_vals: Vec<B>,
+}
+
+impl<'a, B, C> BlindRingSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn ring(&self) -> &Ring<C> {
+        self.ring
+    }
+}
+
+impl<'a, C> BlindRingSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        let keys = self.ring.keys();
+        let n = keys.len();
+        if n == 0 {
+            return Err(VerifyError::RingTooSmall);
+        }
+        if self.challenges.len() != n || self.ring_sig_vals.len() != n {
+            return Err(VerifyError::LengthMismatch);
+        }
+        if keys.iter().any(|key| key.is_zero()) {
+            return Err(VerifyError::InvalidPoint);
+        }
+
+        let mut total = Scalar::<C>::from(0u64);
+        let mut commitments = Vec::with_capacity(n);
+        for ((key, c), s) in keys.iter().zip(&self.challenges).zip(&self.ring_sig_vals) {
+            let (Some(c), Some(s)) = (Scalar::<C>::from_bigint(*c), Scalar::<C>::from_bigint(*s))
+            else {
+                return Err(VerifyError::NonCanonicalScalar);
+            };
+            total += c;
+            commitments.push(generator::mul::<C>(&s) + *key * c);
+        }
+
+        error::check_challenge(
+            challenge::<C>(self.ring, message, &C::normalize_batch(&commitments)),
+            total,
+        )
+    }
+}
+
+fn challenge<C: CurveGroup>(
+    ring: &Ring<C>,
+    message: &[u8],
+    commitments: &[CurvePoint<C>],
+) -> Scalar<C> {
+    let mut hasher = hash::challenge_hasher::<C>(BLIND_DOMAIN, ring.keys(), message);
+    for commitment in commitments {
+        hash::append_point::<C>(&mut hasher, commitment);
+    }
+    hash::hash_to_scalar(hasher)
+}
@@ -0,0 +1,209 @@
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::ring::{CurvePoint, Ring, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, UniformRand};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+const BORROMEAN_DOMAIN: &[u8] = b"lingo-borromean-v1";
+
+/// Produces a Borromean ring signature: one signer per ring, with every ring's challenge chain
+/// starting from a single shared challenge `e_0`. `private_keys[t]` must open
+/// `rings[t].keys()[secret_indices[t]]`; otherwise, or if there are no rings, signing fails with
+/// the [`RingError`] naming the problem.
+#[cfg(feature = "std")]
+pub fn sign<'a, C>(
+    rings: &'a [Ring<C>],
+    message: &[u8],
+    private_keys: &[SecretKey<C>],
+    secret_indices: &[usize],
+) -> Result<BorromeanSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+{
+    sign_with_rng(
+        rings,
+        message,
+        private_keys,
+        secret_indices,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Like [`sign`], drawing the nonces and decoy responses from `rng`.
+pub fn sign_with_rng<'a, C, R>(
+    rings: &'a [Ring<C>],
+    message: &[u8],
+    private_keys: &[SecretKey<C>],
+    secret_indices: &[usize],
+    rng: &mut R,
+) -> Result<BorromeanSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+    R: RngCore + CryptoRng,
+{
+    if rings.is_empty() {
+        return Err(RingError::EmptyRing);
+    }
+    if private_keys.len() != rings.len() || secret_indices.len() != rings.len() {
+        return Err(RingError::KeyMismatch);
+    }
+
+    let secrets: Vec<SecretScalar<C>> = rings
+        .iter()
+        .zip(private_keys)
+        .zip(secret_indices)
+        .map(|((ring, private_key), &index)| ring.signer_secret(private_key, index))
+        .collect::<Result<_, _>>()?;
+
+    let prefix = message_hasher::<C>(rings, message);
+    let mut responses: Vec<Vec<Scalar<C>>> = rings
+        .iter()
+        .map(|ring| vec![Scalar::<C>::from(0u64); ring.size()])
+        .collect();
+
+    // walk every ring from just after its signer up to its last member
+    let mut nonces = Vec::with_capacity(rings.len());
+    let mut tails = Vec::with_capacity(rings.len());
+    for (t, (ring, &index)) in rings.iter().zip(secret_indices).enumerate() {
+        let nonce = Scalar::<C>::rand(rng);
+        let mut point = ct::generator_mul::<C>(&nonce);
+        let members = responses[t].iter_mut().zip(ring.keys()).enumerate();
+        for (i, (response, key)) in members.skip(index + 1) {
+            let challenge = link_challenge::<C>(&prefix, &point, t, i - 1);
+            *response = Scalar::<C>::rand(rng);
+            point = generator::mul::<C>(response) - *key * challenge;
+        }
+        nonces.push(nonce);
+        tails.push(point);
+    }
+
+    let shared = shared_challenge::<C>(&prefix, &tails);
+
+    // close every ring from the shared challenge back round to its signer
+    for (t, (ring, &index)) in rings.iter().zip(secret_indices).enumerate() {
+        let mut challenge = shared;
+        let members = responses[t].iter_mut().zip(ring.keys()).enumerate();
+        for (i, (response, key)) in members.take(index) {
+            *response = Scalar::<C>::rand(rng);
+            let point = generator::mul::<C>(response) - *key * challenge;
+            challenge = link_challenge::<C>(&prefix, &point, t, i);
+        }
+        responses[t][index] = nonces[t] + *secrets[t] * challenge;
+    }
+
+    Ok(BorromeanSignature {
+        rings,
+        challenge: shared.into_bigint(),
+        ring_sig_vals: responses
+            .iter()
+            .map(|row| row.iter().map(|s| s.into_bigint()).collect())
+            .collect(),
+    })
+}
+
+#[derive(PartialEq, Eq)]
+pub struct BorromeanSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub rings: &'a [Ring<C>],
+    pub challenge: B,
+    pub ring_sig_vals: Vec<Vec<B>>,
+}
+
+impl<'a, B, C> BorromeanSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn rings(&self) -> &[Ring<C>] {
+        self.rings
+    }
+}
+
+impl<'a, C> BorromeanSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        if self.rings.is_empty() || self.rings.iter().any(|ring| ring.size() == 0) {
+            return Err(VerifyError::RingTooSmall);
+        }
+        if self.rings.len() != self.ring_sig_vals.len() {
+            return Err(VerifyError::LengthMismatch);
+        }
+        for (ring, row) in self.rings.iter().zip(&self.ring_sig_vals) {
+            if ring.size() != row.len() {
+                return Err(VerifyError::LengthMismatch);
+            }
+            if ring.keys().iter().any(|key| key.is_zero()) {
+                return Err(VerifyError::InvalidPoint);
+            }
+        }
+
+        let shared =
+            Scalar::<C>::from_bigint(self.challenge).ok_or(VerifyError::NonCanonicalScalar)?;
+
+        let prefix = message_hasher::<C>(self.rings, message);
+        let mut tails = Vec::with_capacity(self.rings.len());
+        for (t, (ring, row)) in self.rings.iter().zip(&self.ring_sig_vals).enumerate() {
+            let mut challenge = shared;
+            let mut point = C::zero();
+            for (i, (key, response)) in ring.keys().iter().zip(row).enumerate() {
+                let response =
+                    Scalar::<C>::from_bigint(*response).ok_or(VerifyError::NonCanonicalScalar)?;
+                if i > 0 {
+                    challenge = link_challenge::<C>(&prefix, &point, t, i - 1);
+                }
+                point = generator::mul::<C>(&response) - *key * challenge;
+            }
+            tails.push(point);
+        }
+
+        error::check_challenge(shared_challenge::<C>(&prefix, &tails), shared)
+    }
+}
+
+fn message_hasher<C: CurveGroup>(rings: &[Ring<C>], message: &[u8]) -> Sha512 {
+    let keys: Vec<CurvePoint<C>> = rings
+        .iter()
+        .flat_map(|ring| ring.keys().iter().copied())
+        .collect();
+    let mut hasher = hash::challenge_hasher::<C>(BORROMEAN_DOMAIN, &keys, message);
+    // ring boundaries, so the same keys split differently produce a different statement
+    for ring in rings {
+        hasher.update((ring.size() as u64).to_le_bytes());
+    }
+    hasher
+}
+
+fn link_challenge<C: CurveGroup>(
+    prefix: &Sha512,
+    point: &C,
+    ring: usize,
+    index: usize,
+) -> Scalar<C> {
+    let mut hasher = prefix.clone();
+    hash::append_point::<C>(&mut hasher, &point.into_affine());
+    hasher.update((ring as u64).to_le_bytes());
+    hasher.update((index as u64).to_le_bytes());
+    hash::hash_to_scalar(hasher)
+}
+
+fn shared_challenge<C: CurveGroup>(prefix: &Sha512, tails: &[C]) -> Scalar<C> {
+    let mut hasher = prefix.clone();
+    for tail in C::normalize_batch(tails) {
+        hash::append_point::<C>(&mut hasher, &tail);
+    }
+    hash::hash_to_scalar(hasher)
+}
@@ -0,0 +1,163 @@
+use crate::challenge::{self, ChallengeHash};
+use crate::key_image::KeyImage;
+use crate::ring::{CurvePoint, Ring, RingSignature, Scalar, ScalarBigInt};
+use crate::serialize;
+use crate::wire::DecodedSignature;
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+// borsh support, behind the `borsh` feature. Points and scalars are written as their compressed
+// arkworks encodings, which have a fixed width per curve, so they sit in borsh layouts like byte
+// arrays; sequences carry borsh's u32 length prefix and the key image borsh's option tag. A
+// `RingSignature` is written without its ring and read back through
+// `RingSignature::deserialize_borsh_with_ring`; `DecodedSignature` carries its ring and
+// round-trips on its own. The layout has no room for a challenge hash, so only SHA-512
+// signatures can be written.
+
+fn write<T: CanonicalSerialize, W: Write>(value: &T, writer: &mut W) -> Result<()> {
+    value
+        .serialize_compressed(writer)
+        .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+}
+
+// checked for curve and subgroup membership and for a canonical encoding
+fn read<T, R>(reader: &mut R) -> Result<T>
+where
+    T: CanonicalDeserialize + CanonicalSerialize + Default,
+    R: Read,
+{
+    serialize::read_canonical(reader, Compress::Yes, Validate::Yes)
+        .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, reason)
+}
+
+fn write_length<W: Write>(length: usize, writer: &mut W) -> Result<()> {
+    u32::try_from(length)
+        .map_err(|_| invalid("sequence too long"))?
+        .serialize(writer)
+}
+
+fn read_keys<C: CurveGroup, R: Read>(reader: &mut R) -> Result<Vec<CurvePoint<C>>> {
+    let size = u32::deserialize_reader(reader)?;
+    // grown as keys arrive rather than trusting the length prefix with an allocation
+    let mut keys = Vec::new();
+    for _ in 0..size {
+        keys.push(read(reader)?);
+    }
+    Ok(keys)
+}
+
+/// A borsh `Vec` of compressed public keys.
+impl<C: CurveGroup> BorshSerialize for Ring<C> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_length(self.size(), writer)?;
+        for key in self.keys() {
+            write(key, writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Applies the same checks as [`Ring::from_fixed_pubkeys`].
+impl<C: CurveGroup> BorshDeserialize for Ring<C> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Ring<C>> {
+        Ring::from_fixed_pubkeys(read_keys::<C, R>(reader)?)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+}
+
+impl<C: CurveGroup> BorshSerialize for KeyImage<C> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write(self.point(), writer)
+    }
+}
+
+/// Rejects the identity and points outside the prime-order subgroup.
+impl<C: CurveGroup> BorshDeserialize for KeyImage<C> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<KeyImage<C>> {
+        let image = KeyImage::from_point(read::<CurvePoint<C>, R>(reader)?);
+        if !image.is_valid() {
+            return Err(invalid("invalid key image"));
+        }
+        Ok(image)
+    }
+}
+
+/// The challenge, a borsh `Vec` of responses and an `Option` key image. The ring is not
+/// included. Non-canonical scalars and signatures made with a challenge hash other than SHA-512
+/// cannot be written.
+impl<'a, C: CurveGroup> BorshSerialize for RingSignature<'a, ScalarBigInt<C>, C> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        if self.hash_id != challenge::Sha512::HASH_ID {
+            return Err(invalid("challenge hash other than sha512"));
+        }
+        let scalar = |value: &ScalarBigInt<C>| {
+            Scalar::<C>::from_bigint(*value).ok_or_else(|| invalid("non-canonical scalar"))
+        };
+        write(&scalar(&self.challenge)?, writer)?;
+        write_length(self.ring_sig_vals.len(), writer)?;
+        for value in &self.ring_sig_vals {
+            write(&scalar(value)?, writer)?;
+        }
+        self.image.serialize(writer)
+    }
+}
+
+impl<'a, C: CurveGroup> RingSignature<'a, ScalarBigInt<C>, C> {
+    /// Reads a signature written by its [`BorshSerialize`] impl, attaching it to `ring`. The
+    /// number of responses must match the ring size.
+    pub fn deserialize_borsh_with_ring<R: Read>(
+        ring: &'a Ring<C>,
+        reader: &mut R,
+    ) -> Result<RingSignature<'a, ScalarBigInt<C>, C>> {
+        let challenge = read::<Scalar<C>, R>(reader)?.into_bigint();
+        if u32::deserialize_reader(reader)? as usize != ring.size() {
+            return Err(invalid("one response per ring member"));
+        }
+        let ring_sig_vals = (0..ring.size())
+            .map(|_| read::<Scalar<C>, R>(reader).map(|r| r.into_bigint()))
+            .collect::<Result<Vec<_>>>()?;
+        let image = Option::<KeyImage<C>>::deserialize_reader(reader)?;
+        Ok(RingSignature {
+            ring,
+            challenge,
+            ring_sig_vals,
+            image,
+            hash_id: challenge::Sha512::HASH_ID,
+        })
+    }
+}
+
+/// The ring followed by the signature.
+impl<C: CurveGroup> BorshSerialize for DecodedSignature<C> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.ring().serialize(writer)?;
+        self.signature().serialize(writer)
+    }
+}
+
+impl<C: CurveGroup> BorshDeserialize for DecodedSignature<C> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<DecodedSignature<C>> {
+        let ring = Ring::<C>::deserialize_reader(reader)?;
+        let RingSignature {
+            challenge,
+            ring_sig_vals,
+            image,
+            hash_id,
+            ..
+        } = RingSignature::deserialize_borsh_with_ring(&ring, reader)?;
+        Ok(DecodedSignature {
+            ring,
+            challenge,
+            ring_sig_vals,
+            image,
+            hash_id,
+        })
+    }
+}
@@ -0,0 +1,145 @@
+use crate::ct;
+use crate::error::RingError;
+use crate::generator;
+use crate::hash;
+use crate::ring::{CurvePoint, Ring, Scalar};
+use crate::secret::SecretKey;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use ark_ec::CurveGroup;
+use ark_ff::{UniformRand, Zero};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, Rng, RngCore};
+
+// `Ring::new`, `Ring::from_pubkeys` and `Ring::from_fixed_pubkeys` each leave part of a ring's
+// layout to the caller: where the signer goes, how many keys sit either side of it, what index to
+// sign with afterwards. The builder takes keys in any mix, remembers where the signer's key went
+// through every shuffle, and hands that position back with the ring.
+
+/// Assembles a ring one piece at a time: known keys with [`RingBuilder::add_key`], random ones
+/// with [`RingBuilder::add_decoys`], the signer's own with [`RingBuilder::with_signer`], in any
+/// order. [`RingBuilder::build`] checks the result and returns it with the signer's index.
+///
+/// ```
+/// use ark_std::rand::{SeedableRng, rngs::StdRng};
+/// use lingo::builder::RingBuilder;
+/// use lingo::secret::SecretKey;
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// let private_key = SecretKey::<ark_secp256k1::Projective>::generate(&mut rng);
+/// let (ring, index) = RingBuilder::new()
+///     .add_decoys(3, &mut rng)
+///     .with_signer(&private_key)
+///     .add_decoys(4, &mut rng)
+///     .shuffle_with_rng(&mut rng)
+///     .build()
+///     .unwrap();
+/// let signature = ring
+///     .sign_with_rng(b"message", &private_key, index, &mut rng)
+///     .unwrap();
+/// assert!(signature.verify(b"message").is_ok());
+/// ```
+#[derive(Clone)]
+pub struct RingBuilder<C: CurveGroup> {
+    keys: Vec<CurvePoint<C>>,
+    signer: Option<usize>,
+    // the first problem with the signer, reported by `build`
+    error: Option<RingError>,
+}
+
+impl<C: CurveGroup> Default for RingBuilder<C> {
+    fn default() -> Self {
+        RingBuilder {
+            keys: Vec::new(),
+            signer: None,
+            error: None,
+        }
+    }
+}
+
+impl<C: CurveGroup> RingBuilder<C> {
+    pub fn new() -> RingBuilder<C> {
+        RingBuilder::default()
+    }
+
+    /// Appends a member's public key.
+    pub fn add_key(mut self, public_key: CurvePoint<C>) -> RingBuilder<C> {
+        self.keys.push(public_key);
+        self
+    }
+
+    /// Appends `count` random public keys drawn from `rng`.
+    pub fn add_decoys<R: RngCore + CryptoRng>(
+        mut self,
+        count: usize,
+        rng: &mut R,
+    ) -> RingBuilder<C> {
+        self.keys
+            .extend((0..count).map(|_| generator::mul::<C>(&Scalar::<C>::rand(rng)).into_affine()));
+        self
+    }
+
+    /// Appends the signer's public key and remembers its position.
+    pub fn with_signer(mut self, private_key: &SecretKey<C>) -> RingBuilder<C> {
+        if self.error.is_none() {
+            if private_key.expose_secret().is_zero() {
+                self.error = Some(RingError::ZeroPrivateKey);
+            } else if self.signer.is_some() {
+                self.error = Some(RingError::MultipleSigners);
+            }
+        }
+        self.signer = Some(self.keys.len());
+        self.keys
+            .push(ct::generator_mul::<C>(private_key.expose_secret()).into_affine());
+        self
+    }
+
+    /// Shuffles the keys added so far. Keys added afterwards go on the end, so shuffle last.
+    #[cfg(feature = "std")]
+    pub fn shuffle(self) -> RingBuilder<C> {
+        self.shuffle_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Like [`RingBuilder::shuffle`], drawing the permutation from `rng`.
+    pub fn shuffle_with_rng<R: RngCore + CryptoRng>(mut self, rng: &mut R) -> RingBuilder<C> {
+        // Fisher–Yates, following the signer's key through each swap without branching on it
+        for i in (1..self.keys.len()).rev() {
+            let j = rng.gen_range(0..=i);
+            self.keys.swap(i, j);
+            self.signer = self.signer.map(|signer| ct::follow_swap(signer, i, j));
+        }
+        self
+    }
+
+    /// The ring and the signer's index in it. Fails on a zero private key, a missing or repeated
+    /// signer, any key [`Ring::from_fixed_pubkeys`] would reject, or a key added twice, listing
+    /// the indices of the repeats.
+    pub fn build(self) -> Result<(Ring<C>, usize), RingError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let index = self.signer.ok_or(RingError::NoSigner)?;
+
+        let mut seen = BTreeSet::new();
+        let duplicates: Vec<usize> = self
+            .keys
+            .iter()
+            .enumerate()
+            .filter(|(_, key)| !seen.insert(hash::point_bytes::<C>(key)))
+            .map(|(i, _)| i)
+            .collect();
+        let ring = Ring::from_fixed_pubkeys(self.keys)?;
+        if !duplicates.is_empty() {
+            return Err(RingError::DuplicatePublicKeys(duplicates));
+        }
+        Ok((ring, index))
+    }
+}
+
+impl<C: CurveGroup> Ring<C> {
+    /// An empty [`RingBuilder`].
+    pub fn builder() -> RingBuilder<C> {
+        RingBuilder::new()
+    }
+}
@@ -0,0 +1,243 @@
+use crate::challenge::{self, ChallengeHash};
+use crate::error::DecodeError;
+use crate::ring::{CurvePoint, DetachedSignature, Ring, RingSignature, ScalarBigInt};
+use crate::wire::{self, DecodedSignature, WireCurve};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+// A CBOR encoding of signatures shaped like a COSE_Sign1 message (RFC 9052) with a detached
+// payload, so existing CBOR stacks can take it apart:
+//
+//   18([
+//     << { 1: "lingo-sag" / "lingo-blsag", "crv": curve name, ? "hash": uint } >>,  protected
+//     { "ring": [+ bstr] },                                            unprotected
+//     nil,                                                             detached payload
+//     bstr                                                             signature
+//   ])
+//
+// The signature is the challenge, the responses and the key image in their compressed arkworks
+// encodings, as in the `wire` format. "hash" is the `ChallengeHash::HASH_ID` of the challenge
+// hash, left out for SHA-512. Unlike COSE the message is signed directly rather than
+// through a Sig_structure, so these are not verifiable by COSE libraries. Encoding is
+// deterministic (RFC 8949 §4.2) and decoding only accepts that exact encoding.
+
+const COSE_SIGN1_TAG: u64 = 18;
+const ALG: u64 = 1;
+const ALG_SAG: &str = "lingo-sag";
+const ALG_BLSAG: &str = "lingo-blsag";
+const CURVE: &str = "crv";
+const HASH: &str = "hash";
+const RING: &str = "ring";
+
+const UNSIGNED: u8 = 0;
+const BYTES: u8 = 2;
+const TEXT: u8 = 3;
+const ARRAY: u8 = 4;
+const MAP: u8 = 5;
+const TAG: u8 = 6;
+const NULL: u8 = 0xf6;
+
+impl<'a, C: WireCurve> RingSignature<'a, ScalarBigInt<C>, C> {
+    /// The signature and its ring as a tagged COSE_Sign1-style CBOR message, read back with
+    /// [`DecodedSignature::from_cbor`]. The message is not included. Panics if a scalar is not
+    /// canonical.
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let alg = if self.image.is_some() {
+            ALG_BLSAG
+        } else {
+            ALG_SAG
+        };
+        let hashed = self.hash_id != challenge::Sha512::HASH_ID;
+        let mut protected = Vec::new();
+        head(&mut protected, MAP, 2 + hashed as u64);
+        head(&mut protected, UNSIGNED, ALG);
+        text(&mut protected, alg);
+        text(&mut protected, CURVE);
+        text(&mut protected, C::CURVE_NAME);
+        if hashed {
+            text(&mut protected, HASH);
+            head(&mut protected, UNSIGNED, self.hash_id as u64);
+        }
+
+        let mut bytes = Vec::new();
+        head(&mut bytes, TAG, COSE_SIGN1_TAG);
+        head(&mut bytes, ARRAY, 4);
+        byte_string(&mut bytes, &protected);
+        head(&mut bytes, MAP, 1);
+        text(&mut bytes, RING);
+        head(&mut bytes, ARRAY, self.ring.size() as u64);
+        for key in self.ring.keys() {
+            let mut encoded = Vec::new();
+            wire::write(&mut encoded, key);
+            byte_string(&mut bytes, &encoded);
+        }
+        bytes.push(NULL);
+        let mut body = Vec::new();
+        self.write_body(&mut body);
+        byte_string(&mut bytes, &body);
+        bytes
+    }
+}
+
+impl<C: WireCurve> DecodedSignature<C> {
+    pub fn to_cbor(&self) -> Vec<u8> {
+        self.signature().to_cbor()
+    }
+
+    /// Parses a message written by [`RingSignature::to_cbor`] for a signature on curve `C`.
+    pub fn from_cbor(bytes: &[u8]) -> Result<DecodedSignature<C>, DecodeError> {
+        let mut reader = Reader(bytes);
+        reader.expect(TAG, COSE_SIGN1_TAG)?;
+        reader.expect(ARRAY, 4)?;
+
+        let mut protected = Reader(reader.byte_string()?);
+        let entries = protected.head(MAP)?;
+        if entries != 2 && entries != 3 {
+            return Err(DecodeError::InvalidCbor);
+        }
+        protected.expect(UNSIGNED, ALG)?;
+        let linkable = match protected.text()? {
+            ALG_SAG => false,
+            ALG_BLSAG => true,
+            _ => return Err(DecodeError::InvalidCbor),
+        };
+        if protected.text()? != CURVE {
+            return Err(DecodeError::InvalidCbor);
+        }
+        let curve = protected.text()?;
+        if curve != C::CURVE_NAME {
+            return Err(DecodeError::WrongCurveName(curve.to_string()));
+        }
+        let hash_id = if entries == 3 {
+            if protected.text()? != HASH {
+                return Err(DecodeError::InvalidCbor);
+            }
+            // SHA-512 is only ever implied; the re-encoding check below rejects it here
+            u8::try_from(protected.head(UNSIGNED)?).map_err(|_| DecodeError::InvalidCbor)?
+        } else {
+            challenge::Sha512::HASH_ID
+        };
+        protected.finish()?;
+
+        reader.expect(MAP, 1)?;
+        if reader.text()? != RING {
+            return Err(DecodeError::InvalidCbor);
+        }
+        let size = reader.head(ARRAY)?;
+        // bound the allocation by what the input could possibly hold
+        if size > reader.0.len() as u64 {
+            return Err(DecodeError::Truncated);
+        }
+        let keys = (0..size)
+            .map(|_| {
+                let mut key = reader.byte_string()?;
+                let point = wire::read::<CurvePoint<C>>(&mut key)?;
+                if !key.is_empty() {
+                    return Err(DecodeError::InvalidEncoding);
+                }
+                Ok(point)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let ring = Ring::from_fixed_pubkeys(keys).map_err(DecodeError::InvalidRing)?;
+
+        if wire::take(&mut reader.0, 1)? != [NULL] {
+            return Err(DecodeError::InvalidCbor);
+        }
+        let mut body = reader.byte_string()?;
+        let signature = DetachedSignature {
+            hash_id,
+            ..wire::read_body(&mut body, ring.size(), linkable)?
+        }
+        .attach_owned(ring);
+        if !body.is_empty() {
+            return Err(DecodeError::InvalidEncoding);
+        }
+        reader.finish()?;
+
+        // every field has been checked; this catches non-shortest heads
+        if signature.to_cbor() != bytes {
+            return Err(DecodeError::InvalidCbor);
+        }
+        Ok(signature)
+    }
+}
+
+// a data item head in its shortest form
+fn head(bytes: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    match value {
+        0..24 => bytes.push(major | value as u8),
+        24..0x100 => bytes.extend([major | 24, value as u8]),
+        0x100..0x10000 => {
+            bytes.push(major | 25);
+            bytes.extend((value as u16).to_be_bytes());
+        }
+        0x10000..0x1_0000_0000 => {
+            bytes.push(major | 26);
+            bytes.extend((value as u32).to_be_bytes());
+        }
+        _ => {
+            bytes.push(major | 27);
+            bytes.extend(value.to_be_bytes());
+        }
+    }
+}
+
+fn byte_string(bytes: &mut Vec<u8>, value: &[u8]) {
+    head(bytes, BYTES, value.len() as u64);
+    bytes.extend(value);
+}
+
+fn text(bytes: &mut Vec<u8>, value: &str) {
+    head(bytes, TEXT, value.len() as u64);
+    bytes.extend(value.as_bytes());
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    // definite-length heads only; indefinite lengths and other majors are rejected
+    fn head(&mut self, major: u8) -> Result<u64, DecodeError> {
+        let initial = wire::take(&mut self.0, 1)?[0];
+        if initial >> 5 != major {
+            return Err(DecodeError::InvalidCbor);
+        }
+        let length = match initial & 0x1f {
+            info @ 0..24 => return Ok(info as u64),
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            _ => return Err(DecodeError::InvalidCbor),
+        };
+        let mut value = [0u8; 8];
+        value[8 - length..].copy_from_slice(wire::take(&mut self.0, length)?);
+        Ok(u64::from_be_bytes(value))
+    }
+
+    fn expect(&mut self, major: u8, value: u64) -> Result<(), DecodeError> {
+        if self.head(major)? != value {
+            return Err(DecodeError::InvalidCbor);
+        }
+        Ok(())
+    }
+
+    fn byte_string(&mut self) -> Result<&'a [u8], DecodeError> {
+        let length = self.head(BYTES)?;
+        let length = usize::try_from(length).map_err(|_| DecodeError::Truncated)?;
+        wire::take(&mut self.0, length)
+    }
+
+    fn text(&mut self) -> Result<&'a str, DecodeError> {
+        let length = self.head(TEXT)?;
+        let length = usize::try_from(length).map_err(|_| DecodeError::Truncated)?;
+        core::str::from_utf8(wire::take(&mut self.0, length)?).map_err(|_| DecodeError::InvalidCbor)
+    }
+
+    fn finish(&self) -> Result<(), DecodeError> {
+        if !self.0.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use crate::blake2b::Blake2b512;
+use crate::transcript::Merlin;
+use sha2::Digest;
+
+// The hash behind the Fiat–Shamir challenges of SAG and bLSAG signatures. SHA-512 is the
+// default; `Ring::sign_with_hash` and `SigningContext::with_hash` pick another. The id of the
+// hash a signature was made with travels in the signature, so verifiers need not be told.
+//
+// Challenges are reduced from 64 bytes of output so they are uniform modulo the group order.
+// Keccak-256 only has 32, so its output is widened by hashing the absorbed input twice, once
+// followed by a 0x00 byte and once by a 0x01 byte, and concatenating the digests. With the
+// `poseidon` feature, `poseidon::Poseidon` adds a hash circuits can compute cheaply, and
+// `transcript::Merlin` derives challenges from a Merlin transcript with labelled messages.
+
+/// A hash function for Fiat–Shamir challenges. Ids below `0x80` are reserved for the crate's
+/// hashes; applications adding their own should pick ids from `0x80` up and verify with
+/// [`RingSignature::verify_with_hash`](crate::ring::RingSignature::verify_with_hash).
+pub trait ChallengeHash {
+    const HASH_ID: u8;
+    /// Name used by the text encodings, e.g. `"sha512"`.
+    const HASH_NAME: &'static str;
+
+    /// The running hash state, cloned once per challenge in a ring.
+    type State: Clone;
+
+    fn new() -> Self::State;
+
+    fn update(state: &mut Self::State, bytes: &[u8]);
+
+    /// 64 bytes of output for the absorbed input.
+    fn finalize(state: Self::State) -> [u8; 64];
+
+    /// Absorbs `message` under `label`. Byte hashes ignore the label; transcripts such as
+    /// [`Merlin`](crate::transcript::Merlin) record it along with the message's length.
+    fn append_message(state: &mut Self::State, label: &'static [u8], message: &[u8]) {
+        let _ = label;
+        Self::update(state, message);
+    }
+
+    /// Absorbs a variable-length `message` under `label`. Byte hashes prefix it with its length
+    /// as a little-endian u64.
+    fn append_sized(state: &mut Self::State, label: &'static [u8], message: &[u8]) {
+        let _ = label;
+        Self::update(state, &(message.len() as u64).to_le_bytes());
+        Self::update(state, message);
+    }
+
+    /// [`ChallengeHash::finalize`] under `label`.
+    fn challenge(state: Self::State, label: &'static [u8]) -> [u8; 64] {
+        let _ = label;
+        Self::finalize(state)
+    }
+}
+
+/// SHA-512, the default challenge hash.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sha512;
+
+impl ChallengeHash for Sha512 {
+    const HASH_ID: u8 = 0x01;
+    const HASH_NAME: &'static str = "sha512";
+
+    type State = sha2::Sha512;
+
+    fn new() -> sha2::Sha512 {
+        sha2::Sha512::new()
+    }
+
+    fn update(state: &mut sha2::Sha512, bytes: &[u8]) {
+        state.update(bytes);
+    }
+
+    fn finalize(state: sha2::Sha512) -> [u8; 64] {
+        state.finalize().into()
+    }
+}
+
+/// Keccak-256 as Ethereum uses it, i.e. with the original Keccak padding rather than SHA-3's.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Keccak256;
+
+impl ChallengeHash for Keccak256 {
+    const HASH_ID: u8 = 0x02;
+    const HASH_NAME: &'static str = "keccak256";
+
+    type State = sha3::Keccak256;
+
+    fn new() -> sha3::Keccak256 {
+        sha3::Keccak256::new()
+    }
+
+    fn update(state: &mut sha3::Keccak256, bytes: &[u8]) {
+        state.update(bytes);
+    }
+
+    fn finalize(state: sha3::Keccak256) -> [u8; 64] {
+        let mut output = [0u8; 64];
+        for (half, suffix) in output.chunks_exact_mut(32).zip([0u8, 1]) {
+            let mut state = state.clone();
+            state.update([suffix]);
+            half.copy_from_slice(&state.finalize());
+        }
+        output
+    }
+}
+
+/// BLAKE2b with 64 bytes of output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Blake2b;
+
+impl ChallengeHash for Blake2b {
+    const HASH_ID: u8 = 0x03;
+    const HASH_NAME: &'static str = "blake2b";
+
+    type State = Blake2b512;
+
+    fn new() -> Blake2b512 {
+        Blake2b512::new()
+    }
+
+    fn update(state: &mut Blake2b512, bytes: &[u8]) {
+        state.update(bytes);
+    }
+
+    fn finalize(state: Blake2b512) -> [u8; 64] {
+        state.finalize()
+    }
+}
+
+/// The name of the crate's challenge hash with id `hash_id`, if there is one.
+pub fn hash_name(hash_id: u8) -> Option<&'static str> {
+    struct Name;
+    impl HashVisitor for Name {
+        type Output = &'static str;
+        fn visit<H: ChallengeHash>(self) -> &'static str {
+            H::HASH_NAME
+        }
+    }
+    visit_hash(hash_id, Name)
+}
+
+/// The id of the crate's challenge hash named `name`, if there is one.
+pub fn hash_id(name: &str) -> Option<u8> {
+    (0..=u8::MAX).find(|id| hash_name(*id) == Some(name))
+}
+
+// something done with one of the crate's challenge hashes, picked at runtime by its id
+pub(crate) trait HashVisitor {
+    type Output;
+    fn visit<H: ChallengeHash>(self) -> Self::Output;
+}
+
+// the single list of the crate's challenge hashes
+pub(crate) fn visit_hash<V: HashVisitor>(hash_id: u8, visitor: V) -> Option<V::Output> {
+    #[cfg(all(
+        feature = "poseidon",
+        any(
+            feature = "bls12-381",
+            feature = "bn254",
+            feature = "pallas",
+            feature = "vesta"
+        )
+    ))]
+    use crate::poseidon::{Poseidon, PoseidonField};
+
+    match hash_id {
+        Sha512::HASH_ID => Some(visitor.visit::<Sha512>()),
+        Keccak256::HASH_ID => Some(visitor.visit::<Keccak256>()),
+        Blake2b::HASH_ID => Some(visitor.visit::<Blake2b>()),
+        Merlin::HASH_ID => Some(visitor.visit::<Merlin>()),
+        #[cfg(all(feature = "poseidon", feature = "bls12-381"))]
+        crate::curves::bls12_381::Fr::HASH_ID => {
+            Some(visitor.visit::<Poseidon<crate::curves::bls12_381::Fr>>())
+        }
+        #[cfg(all(feature = "poseidon", feature = "bn254"))]
+        crate::curves::bn254::Fr::HASH_ID => {
+            Some(visitor.visit::<Poseidon<crate::curves::bn254::Fr>>())
+        }
+        #[cfg(all(feature = "poseidon", any(feature = "pallas", feature = "vesta")))]
+        crate::curves::pasta::Fp::HASH_ID => {
+            Some(visitor.visit::<Poseidon<crate::curves::pasta::Fp>>())
+        }
+        #[cfg(all(feature = "poseidon", any(feature = "pallas", feature = "vesta")))]
+        crate::curves::pasta::Fq::HASH_ID => {
+            Some(visitor.visit::<Poseidon<crate::curves::pasta::Fq>>())
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,126 @@
+use crate::ct;
+use crate::error::{self, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::key_image::{self, KeyImage};
+use crate::ring::{CurvePoint, RingSignature, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, Zero};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::Digest;
+
+const CLAIM_DOMAIN: &[u8] = b"lingo-claim-v1";
+
+/// Non-interactive proof that the ring member at `index` produced a linkable signature: a
+/// DLEQ proof that `log_G(K) == log_{H_p(K)}(I)` for the member's key `K` and the signature's
+/// key image `I`, bound to that specific signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignerClaim<B: BigInteger> {
+    pub index: usize,
+    pub challenge: B,
+    pub response: B,
+}
+
+impl<'a, C> RingSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    /// Produces a claim of authorship for this signature. Returns `None` if the signature is
+    /// not linkable or `private_key` did not produce it.
+    #[cfg(feature = "std")]
+    pub fn claim(&self, private_key: &SecretKey<C>) -> Option<SignerClaim<ScalarBigInt<C>>> {
+        self.claim_with_rng(private_key, &mut rand::thread_rng())
+    }
+
+    /// Like [`RingSignature::claim`], drawing the proof's nonce from `rng`.
+    pub fn claim_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        private_key: &SecretKey<C>,
+        rng: &mut R,
+    ) -> Option<SignerClaim<ScalarBigInt<C>>> {
+        let image = self.image.as_ref()?;
+        if private_key.expose_secret().is_zero() {
+            return None;
+        }
+        let secret = SecretScalar::<C>::from_private_key(private_key);
+        let public_key = ct::generator_mul::<C>(&secret).into_affine();
+        let index = self
+            .public_keys()
+            .iter()
+            .position(|key| *key == public_key)?;
+        if KeyImage::<C>::derive(*secret, &public_key) != *image {
+            return None;
+        }
+
+        let base = key_image::hash_point::<C>(&public_key);
+        let nonce = SecretScalar::<C>::random(rng);
+        let challenge = self.claim_challenge(
+            index,
+            image,
+            &ct::generator_mul::<C>(&nonce).into_affine(),
+            &ct::secret_mul::<C>(&base.into_affine(), &nonce).into_affine(),
+        );
+
+        Some(SignerClaim {
+            index,
+            challenge: challenge.into_bigint(),
+            response: nonce.response(&challenge, &secret).into_bigint(),
+        })
+    }
+
+    /// Checks a claim of authorship against this signature. The signature itself should be
+    /// verified separately.
+    pub fn verify_claim(&self, claim: &SignerClaim<ScalarBigInt<C>>) -> Result<(), VerifyError> {
+        let image = self
+            .image
+            .as_ref()
+            .filter(|image| image.is_valid())
+            .ok_or(VerifyError::InvalidKeyImage)?;
+        let public_key = self
+            .public_keys()
+            .get(claim.index)
+            .ok_or(VerifyError::LengthMismatch)?;
+        if public_key.is_zero() {
+            return Err(VerifyError::InvalidPoint);
+        }
+        let (Some(challenge), Some(response)) = (
+            Scalar::<C>::from_bigint(claim.challenge),
+            Scalar::<C>::from_bigint(claim.response),
+        ) else {
+            return Err(VerifyError::NonCanonicalScalar);
+        };
+
+        let base = key_image::hash_point::<C>(public_key);
+        let key_commitment = generator::mul::<C>(&response) + *public_key * challenge;
+        let image_commitment = base * response + *image.point() * challenge;
+        let expected = self.claim_challenge(
+            claim.index,
+            image,
+            &key_commitment.into_affine(),
+            &image_commitment.into_affine(),
+        );
+        error::check_challenge(expected, challenge)
+    }
+
+    fn claim_challenge(
+        &self,
+        index: usize,
+        image: &KeyImage<C>,
+        key_commitment: &CurvePoint<C>,
+        image_commitment: &CurvePoint<C>,
+    ) -> Scalar<C> {
+        let mut hasher = hash::challenge_hasher::<C>(CLAIM_DOMAIN, self.public_keys(), &[]);
+        hasher.update(self.challenge.to_bytes_le());
+        for response in &self.ring_sig_vals {
+            hasher.update(response.to_bytes_le());
+        }
+        hasher.update((index as u64).to_le_bytes());
+        hash::append_point::<C>(&mut hasher, image.point());
+        hash::append_point::<C>(&mut hasher, key_commitment);
+        hash::append_point::<C>(&mut hasher, image_commitment);
+        hash::hash_to_scalar(hasher)
+    }
+}
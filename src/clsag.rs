@@ -0,0 +1,242 @@
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::key_image::{self, KeyImage};
+use crate::mlsag::MatrixRing;
+use crate::ring::{self, CurvePoint, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, UniformRand};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+
+const CLSAG_DOMAIN: &[u8] = b"lingo-clsag-v1";
+const CLSAG_AGG_DOMAIN: &[u8] = b"lingo-clsag-agg-v1";
+
+impl<C> MatrixRing<C>
+where
+    C: CurveGroup,
+{
+    /// Produces a CLSAG signature over `message` for the member at `secret_index`. The first
+    /// layer is the linkable signing key; the remaining layers are aggregated into it, so the
+    /// signature carries a single response per member regardless of the number of layers. Fails
+    /// as [`MatrixRing::sign`] does for a signer outside the ring or keys that are not its own.
+    #[cfg(feature = "std")]
+    pub fn sign_clsag(
+        &self,
+        message: &[u8],
+        private_keys: &[SecretKey<C>],
+        secret_index: usize,
+    ) -> Result<ClsagSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_clsag_with_rng(message, private_keys, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`MatrixRing::sign_clsag`], drawing the nonce and decoy responses from `rng`.
+    pub fn sign_clsag_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_keys: &[SecretKey<C>],
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<ClsagSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        let n = self.size();
+        let m = self.width();
+        if secret_index >= n {
+            return Err(RingError::IndexOutOfBounds {
+                index: secret_index,
+                size: n,
+            });
+        }
+        if private_keys.len() != m {
+            return Err(RingError::KeyMismatch);
+        }
+
+        let secrets: Vec<SecretScalar<C>> = private_keys
+            .iter()
+            .zip(self.layers())
+            .map(|(private_key, layer)| layer.signer_secret(private_key, secret_index))
+            .collect::<Result<_, _>>()?;
+
+        // every layer's image is taken over the hash of the signer's first-layer key, picked out
+        // of every member's without indexing on the signer's position
+        let hashes = self.layers()[0].key_hashes();
+        let signer_hash = ct::select(&hashes, secret_index);
+        let images: Vec<KeyImage<C>> = secrets
+            .iter()
+            .map(|secret| {
+                KeyImage::from_point(ct::secret_mul::<C>(&signer_hash, secret).into_affine())
+            })
+            .collect();
+
+        let flattened = self.flattened();
+        let coefficients = aggregation_coefficients::<C>(&flattened, &images, m);
+        let aggregate_keys = aggregate_keys::<C>(self, &coefficients);
+        let aggregate_image = aggregate_image::<C>(&images, &coefficients);
+        let mut aggregate_secret = SecretScalar::<C>::new(Scalar::<C>::from(0u64));
+        for (secret, mu) in secrets.iter().zip(&coefficients) {
+            aggregate_secret.add_product(secret, mu);
+        }
+
+        let prefix = hash::challenge_hasher::<C>(CLSAG_DOMAIN, &flattened, message);
+        let challenge = |left: C, right: C| {
+            let mut hasher = prefix.clone();
+            hash::append_point::<C>(&mut hasher, &left.into_affine());
+            hash::append_point::<C>(&mut hasher, &right.into_affine());
+            hash::hash_to_scalar::<Scalar<C>>(hasher)
+        };
+
+        // the chain is walked over the members rotated so the signer is at position 0, as
+        // `Ring::sign` walks its ring (see `ct`)
+        let aggregate_keys = ct::rotate_left(&C::normalize_batch(&aggregate_keys), secret_index);
+        let hashes = ct::rotate_left(&hashes, secret_index);
+        let mut challenges = vec![Scalar::<C>::from(0u64); n];
+        let mut responses = vec![Scalar::<C>::from(0u64); n];
+
+        let alpha = SecretScalar::<C>::random(rng);
+        challenges[1 % n] = challenge(
+            ct::generator_mul::<C>(&alpha),
+            ct::secret_mul::<C>(&signer_hash, &alpha),
+        );
+
+        for k in 1..n {
+            responses[k] = Scalar::<C>::rand(rng);
+            let left = generator::mul::<C>(&responses[k]) + aggregate_keys[k] * challenges[k];
+            let right = hashes[k] * responses[k] + aggregate_image * challenges[k];
+            challenges[(k + 1) % n] = challenge(left, right);
+        }
+
+        responses[0] = alpha.response(&challenges[0], &aggregate_secret);
+        let (first, responses) = ring::unrotate::<C>(&challenges, &responses, secret_index);
+
+        let mut images = images.into_iter();
+        Ok(ClsagSignature {
+            ring: self,
+            challenge: first.into_bigint(),
+            ring_sig_vals: responses.iter().map(|r| r.into_bigint()).collect(),
+            image: images.next().expect("matrix rings have at least one layer"),
+            auxiliary_images: images.collect(),
+        })
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct ClsagSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: &'a MatrixRing<C>,
+    pub challenge: B,
+    pub ring_sig_vals: Vec<B>,
+    pub image: KeyImage<C>,
+    pub auxiliary_images: Vec<KeyImage<C>>,
+}
+
+impl<'a, B, C> ClsagSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn ring(&self) -> &MatrixRing<C> {
+        self.ring
+    }
+
+    pub fn key_image(&self) -> &KeyImage<C> {
+        &self.image
+    }
+}
+
+impl<'a, C> ClsagSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        let n = self.ring.size();
+        let m = self.ring.width();
+        if self.auxiliary_images.len() + 1 != m || self.ring_sig_vals.len() != n {
+            return Err(VerifyError::LengthMismatch);
+        }
+
+        let images: Vec<KeyImage<C>> = core::iter::once(self.image)
+            .chain(self.auxiliary_images.iter().copied())
+            .collect();
+        if !images.iter().all(KeyImage::is_valid) {
+            return Err(VerifyError::InvalidKeyImage);
+        }
+
+        let flattened = self.ring.flattened();
+        if flattened.iter().any(|key| key.is_zero()) {
+            return Err(VerifyError::InvalidPoint);
+        }
+
+        let initial =
+            Scalar::<C>::from_bigint(self.challenge).ok_or(VerifyError::NonCanonicalScalar)?;
+        let responses = self
+            .ring_sig_vals
+            .iter()
+            .map(|r| Scalar::<C>::from_bigint(*r))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(VerifyError::NonCanonicalScalar)?;
+
+        let coefficients = aggregation_coefficients::<C>(&flattened, &images, m);
+        let aggregate_keys = aggregate_keys::<C>(self.ring, &coefficients);
+        let aggregate_image = aggregate_image::<C>(&images, &coefficients);
+
+        let prefix = hash::challenge_hasher::<C>(CLSAG_DOMAIN, &flattened, message);
+        let mut current = initial;
+        for (i, response) in responses.iter().enumerate() {
+            let left = generator::mul::<C>(response) + aggregate_keys[i] * current;
+            let right = key_image::hash_point::<C>(&self.ring.layers()[0].keys()[i]) * response
+                + aggregate_image * current;
+            let mut hasher = prefix.clone();
+            hash::append_point::<C>(&mut hasher, &left.into_affine());
+            hash::append_point::<C>(&mut hasher, &right.into_affine());
+            current = hash::hash_to_scalar(hasher);
+        }
+
+        error::check_challenge(current, initial)
+    }
+}
+
+// one coefficient per layer, each bound to the full ring and every image
+fn aggregation_coefficients<C: CurveGroup>(
+    keys: &[CurvePoint<C>],
+    images: &[KeyImage<C>],
+    layers: usize,
+) -> Vec<Scalar<C>> {
+    let mut prefix = hash::challenge_hasher::<C>(CLSAG_AGG_DOMAIN, keys, &[]);
+    for image in images {
+        hash::append_point::<C>(&mut prefix, image.point());
+    }
+    (0..layers)
+        .map(|j| {
+            let mut hasher = prefix.clone();
+            sha2::Digest::update(&mut hasher, (j as u64).to_le_bytes());
+            hash::hash_to_scalar(hasher)
+        })
+        .collect()
+}
+
+fn aggregate_keys<C: CurveGroup>(ring: &MatrixRing<C>, coefficients: &[Scalar<C>]) -> Vec<C> {
+    (0..ring.size())
+        .map(|i| {
+            ring.member(i)
+                .iter()
+                .zip(coefficients)
+                .map(|(key, mu)| *key * mu)
+                .sum()
+        })
+        .collect()
+}
+
+fn aggregate_image<C: CurveGroup>(images: &[KeyImage<C>], coefficients: &[Scalar<C>]) -> C {
+    images
+        .iter()
+        .zip(coefficients)
+        .map(|(image, mu)| *image.point() * mu)
+        .sum()
+}
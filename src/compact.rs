@@ -0,0 +1,222 @@
+use crate::challenge::{self, ChallengeHash};
+use crate::error::DecodeError;
+use crate::ring::{CurvePoint, DetachedSignature, Ring, RingSignature, ScalarBigInt};
+use crate::wire::{self, DecodedSignature, WireCurve};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_serialize::CanonicalSerialize;
+
+// The smallest encoding of a signature, for fitting small-ring signatures into a single QR code:
+//
+//   flags u8 | curve id u8 | hash id u8 (optional) | ring size (LEB128)
+//   | ring keys, or the ring digest | body
+//
+// The flags byte holds the format version in its high nibble (1), bit 0 set for bLSAG, bit 1
+// set when the ring is replaced by its 32-byte `Ring::digest`, for verifiers that already hold
+// the ring, and bit 2 set when a hash id (`ChallengeHash::HASH_ID`) follows the curve id.
+// SHA-512 signatures leave bit 2 clear and carry no hash id. The body is the challenge,
+// responses and key image as in the `wire` format. For scanners that only take text, the bytes
+// can also be written as base45 (RFC 9285), which fits QR alphanumeric mode.
+
+const VERSION: u8 = 1;
+const LINKABLE: u8 = 0x01;
+const RING_DIGEST: u8 = 0x02;
+const HASH: u8 = 0x04;
+const FLAGS: u8 = LINKABLE | RING_DIGEST | HASH;
+
+const BASE45: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+impl<'a, C: WireCurve> RingSignature<'a, ScalarBigInt<C>, C> {
+    /// The signature and its ring in the compact format, read back with
+    /// [`DecodedSignature::from_compact`]. Panics if a scalar is not canonical.
+    pub fn to_compact(&self) -> Vec<u8> {
+        let mut bytes = self.compact_header(0);
+        for key in self.ring.keys() {
+            wire::write(&mut bytes, key);
+        }
+        self.write_body(&mut bytes);
+        bytes
+    }
+
+    /// The signature in the compact format with only the digest of its ring, read back with
+    /// [`DetachedSignature::from_compact_ringless`]. Panics if a scalar is not canonical.
+    pub fn to_compact_ringless(&self) -> Vec<u8> {
+        let mut bytes = self.compact_header(RING_DIGEST);
+        bytes.extend(self.ring.digest());
+        self.write_body(&mut bytes);
+        bytes
+    }
+
+    /// [`RingSignature::to_compact`] as base45 text.
+    pub fn to_compact_base45(&self) -> String {
+        base45_encode(&self.to_compact())
+    }
+
+    fn compact_header(&self, flags: u8) -> Vec<u8> {
+        let linkable = if self.image.is_some() { LINKABLE } else { 0 };
+        let mut bytes = if self.hash_id == challenge::Sha512::HASH_ID {
+            vec![VERSION << 4 | flags | linkable, C::CURVE_ID]
+        } else {
+            vec![
+                VERSION << 4 | flags | linkable | HASH,
+                C::CURVE_ID,
+                self.hash_id,
+            ]
+        };
+        write_varint(&mut bytes, self.ring.size() as u64);
+        bytes
+    }
+}
+
+impl<C: WireCurve> DecodedSignature<C> {
+    pub fn to_compact(&self) -> Vec<u8> {
+        self.signature().to_compact()
+    }
+
+    /// Parses a signature written by [`RingSignature::to_compact`]. A ring-less signature fails
+    /// with [`DecodeError::RingMismatch`].
+    pub fn from_compact(bytes: &[u8]) -> Result<DecodedSignature<C>, DecodeError> {
+        let mut reader = bytes;
+        let (flags, hash_id, size) = read_header::<C>(&mut reader)?;
+        if flags & RING_DIGEST != 0 {
+            return Err(DecodeError::RingMismatch);
+        }
+        // bound the allocation by what the input could possibly hold
+        let point_size = CurvePoint::<C>::default().compressed_size();
+        if size > reader.len() / point_size {
+            return Err(DecodeError::Truncated);
+        }
+        let keys = (0..size)
+            .map(|_| wire::read::<CurvePoint<C>>(&mut reader))
+            .collect::<Result<Vec<_>, _>>()?;
+        let ring = Ring::from_fixed_pubkeys(keys).map_err(DecodeError::InvalidRing)?;
+        let signature = DetachedSignature {
+            hash_id,
+            ..wire::read_body(&mut reader, size, flags & LINKABLE != 0)?
+        };
+        if !reader.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        Ok(signature.attach_owned(ring))
+    }
+
+    /// Parses the output of [`RingSignature::to_compact_base45`].
+    pub fn from_compact_base45(text: &str) -> Result<DecodedSignature<C>, DecodeError> {
+        DecodedSignature::from_compact(&base45_decode(text).ok_or(DecodeError::InvalidBase45)?)
+    }
+}
+
+impl<C: WireCurve> DetachedSignature<ScalarBigInt<C>, C> {
+    /// Parses a signature written by [`RingSignature::to_compact_ringless`], given the ring it
+    /// was made over. Fails with [`DecodeError::RingMismatch`] if `ring` is not that ring.
+    pub fn from_compact_ringless(
+        bytes: &[u8],
+        ring: &Ring<C>,
+    ) -> Result<DetachedSignature<ScalarBigInt<C>, C>, DecodeError> {
+        let mut reader = bytes;
+        let (flags, hash_id, size) = read_header::<C>(&mut reader)?;
+        if flags & RING_DIGEST == 0 || size != ring.size() {
+            return Err(DecodeError::RingMismatch);
+        }
+        if wire::take(&mut reader, 32)? != ring.digest() {
+            return Err(DecodeError::RingMismatch);
+        }
+        let signature = DetachedSignature {
+            hash_id,
+            ..wire::read_body(&mut reader, size, flags & LINKABLE != 0)?
+        };
+        if !reader.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        Ok(signature)
+    }
+}
+
+// the flags, hash id and ring size
+fn read_header<C: WireCurve>(reader: &mut &[u8]) -> Result<(u8, u8, usize), DecodeError> {
+    let [flags, curve] = wire::take(reader, 2)? else {
+        unreachable!()
+    };
+    if flags >> 4 != VERSION {
+        return Err(DecodeError::UnsupportedVersion(flags >> 4));
+    }
+    if flags & 0x0f & !FLAGS != 0 {
+        return Err(DecodeError::InvalidEncoding);
+    }
+    if *curve != C::CURVE_ID {
+        return Err(DecodeError::WrongCurve(*curve));
+    }
+    let hash_id = if flags & HASH == 0 {
+        challenge::Sha512::HASH_ID
+    } else {
+        // SHA-512 is only ever implied, so every signature has exactly one compact form
+        match wire::take(reader, 1)?[0] {
+            challenge::Sha512::HASH_ID => return Err(DecodeError::InvalidEncoding),
+            hash_id => hash_id,
+        }
+    };
+    let size = read_varint(reader)?;
+    let size = usize::try_from(size).map_err(|_| DecodeError::Truncated)?;
+    Ok((flags & FLAGS, hash_id, size))
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+// minimal encodings only, so every signature has exactly one compact form
+fn read_varint(reader: &mut &[u8]) -> Result<u64, DecodeError> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = wire::take(reader, 1)?[0];
+        let bits = (byte & 0x7f) as u64;
+        if bits << shift >> shift != bits || (shift > 0 && byte == 0) {
+            return Err(DecodeError::InvalidEncoding);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(DecodeError::InvalidEncoding)
+}
+
+fn base45_encode(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len().div_ceil(2) * 3);
+    for chunk in bytes.chunks(2) {
+        let (mut value, digits) = match chunk {
+            [a, b] => ((*a as u32) << 8 | *b as u32, 3),
+            [a] => (*a as u32, 2),
+            _ => unreachable!(),
+        };
+        for _ in 0..digits {
+            text.push(BASE45[(value % 45) as usize] as char);
+            value /= 45;
+        }
+    }
+    text
+}
+
+fn base45_decode(text: &str) -> Option<Vec<u8>> {
+    let digits = text
+        .bytes()
+        .map(|c| BASE45.iter().position(|d| *d == c).map(|d| d as u32))
+        .collect::<Option<Vec<_>>>()?;
+    let mut bytes = Vec::with_capacity(digits.len() / 3 * 2 + 1);
+    for chunk in digits.chunks(3) {
+        match chunk {
+            [c, d, e] => {
+                let value = c + d * 45 + e * 45 * 45;
+                bytes.extend(u16::try_from(value).ok()?.to_be_bytes());
+            }
+            [c, d] => bytes.push(u8::try_from(c + d * 45).ok()?),
+            _ => return None,
+        }
+    }
+    Some(bytes)
+}
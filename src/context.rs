@@ -0,0 +1,133 @@
+use crate::challenge::{ChallengeHash, Sha512};
+use crate::error::{RingError, VerifyError};
+use crate::ring::{self, Ring, RingSignature, ScalarBigInt};
+use crate::secret::SecretKey;
+use ark_ec::CurveGroup;
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use core::marker::PhantomData;
+
+const CONTEXT_DOMAIN: &[u8] = b"lingo-context-v1";
+
+/// Application label bound into every Fiat–Shamir challenge, so a signature made under one
+/// context never verifies under another.
+///
+/// The context is absorbed into the challenge hash as a labelled input ahead of the ring and the
+/// message, as [`Ring::sign_linkable_salted`] absorbs its salt, so no choice of message can stand
+/// in for it.
+///
+/// The context also picks the challenge hash `H` of the signatures it makes, SHA-512 unless
+/// changed with [`SigningContext::with_hash`], and only verifies signatures made with it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SigningContext<'c, H: ChallengeHash = Sha512> {
+    label: &'c [u8],
+    hash: PhantomData<H>,
+}
+
+impl<'c> SigningContext<'c> {
+    pub fn new(label: &'c [u8]) -> SigningContext<'c> {
+        SigningContext {
+            label,
+            hash: PhantomData,
+        }
+    }
+}
+
+impl<'c, H: ChallengeHash> SigningContext<'c, H> {
+    /// The same context with challenges made by `H2`.
+    pub fn with_hash<H2: ChallengeHash>(self) -> SigningContext<'c, H2> {
+        SigningContext {
+            label: self.label,
+            hash: PhantomData,
+        }
+    }
+
+    pub fn label(&self) -> &[u8] {
+        self.label
+    }
+
+    /// The challenge state this context's signatures start from.
+    pub fn bind(&self) -> H::State {
+        let mut state = H::new();
+        H::append_sized(&mut state, b"domain", CONTEXT_DOMAIN);
+        H::append_sized(&mut state, b"signing-context", self.label);
+        state
+    }
+
+    #[cfg(feature = "std")]
+    pub fn sign<'a, C: CurveGroup>(
+        &self,
+        ring: &'a Ring<C>,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+        self.sign_with_rng(
+            ring,
+            message,
+            private_key,
+            secret_index,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`SigningContext::sign`], drawing the nonces from `rng`.
+    pub fn sign_with_rng<'a, C: CurveGroup, R: RngCore + CryptoRng>(
+        &self,
+        ring: &'a Ring<C>,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+        ring.sign_hashed::<H, R>(self.bind(), message, private_key, secret_index, rng)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn sign_linkable<'a, C: CurveGroup>(
+        &self,
+        ring: &'a Ring<C>,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+        self.sign_linkable_with_rng(
+            ring,
+            message,
+            private_key,
+            secret_index,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`SigningContext::sign_linkable`], drawing the nonces from `rng`.
+    pub fn sign_linkable_with_rng<'a, C: CurveGroup, R: RngCore + CryptoRng>(
+        &self,
+        ring: &'a Ring<C>,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+        ring.sign_linkable_hashed::<H, R>(self.bind(), message, private_key, secret_index, rng)
+    }
+
+    pub fn verify<C: CurveGroup>(
+        &self,
+        signature: &RingSignature<'_, ScalarBigInt<C>, C>,
+        message: &[u8],
+    ) -> Result<(), VerifyError> {
+        if signature.hash_id != H::HASH_ID {
+            return Err(VerifyError::UnsupportedHash);
+        }
+        ring::verify_hashed::<C, H>(
+            self.bind(),
+            signature.public_keys(),
+            signature.challenge,
+            &signature.ring_sig_vals,
+            signature.image.as_ref(),
+            message,
+        )
+    }
+}
@@ -0,0 +1,183 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater};
+use zeroize::Zeroizing;
+
+// Constant-time handling of the signer's position in a ring. Signing walks the challenge chain
+// around a copy of the ring rotated so the signer sits at position 0: every step then reads the
+// same positions and runs the same operations wherever the signer is. The rotation, and the
+// rotation of the results back into ring order, is a barrel shifter: one pass per bit of the
+// index, each conditionally selecting every element between itself and its neighbour `2^bit`
+// along. Values are moved as their uncompressed encodings, byte by byte with `subtle`, since
+// arkworks' field and curve types have no constant-time selection of their own.
+//
+// This removes the branches and memory accesses that depend on the index. The arithmetic is
+// still arkworks', which makes no constant-time promises of its own; multiplications by the
+// private key or a nonce go through `secret_mul`, which the `constant-time` feature replaces with
+// a fixed-window multiplication that runs the same steps for every scalar.
+
+fn encode<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.uncompressed_size());
+    value
+        .serialize_uncompressed(&mut bytes)
+        .expect("serializing into a Vec cannot fail");
+    bytes
+}
+
+// only ever fed encodings `encode` produced
+fn decode<T: CanonicalDeserialize>(bytes: &[u8]) -> T {
+    T::deserialize_uncompressed_unchecked(bytes).expect("re-reading a value's own encoding")
+}
+
+fn select_bytes(a: &[u8], b: &[u8], choice: Choice) -> Vec<u8> {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| u8::conditional_select(a, b, choice))
+        .collect()
+}
+
+/// Whether `i == j`, without branching on either.
+pub(crate) fn index_eq(i: usize, j: usize) -> Choice {
+    (i as u64).ct_eq(&(j as u64))
+}
+
+/// Whether `i > j`, without branching on either.
+pub(crate) fn index_gt(i: usize, j: usize) -> Choice {
+    (i as u64).ct_gt(&(j as u64))
+}
+
+/// Where the element at `position` ends up once the elements at `i` and `j` are swapped.
+pub(crate) fn follow_swap(position: usize, i: usize, j: usize) -> usize {
+    let (at_i, at_j) = (index_eq(position, i), index_eq(position, j));
+    let position = u64::conditional_select(&(position as u64), &(j as u64), at_i);
+    u64::conditional_select(&position, &(i as u64), at_j & !at_i) as usize
+}
+
+/// `b` if `choice` is set, `a` otherwise.
+pub(crate) fn choose<T>(a: &T, b: &T, choice: Choice) -> T
+where
+    T: CanonicalSerialize + CanonicalDeserialize,
+{
+    decode(&select_bytes(&encode(a), &encode(b), choice))
+}
+
+/// `values[index]`, read by touching every element.
+pub(crate) fn select<T>(values: &[T], index: usize) -> T
+where
+    T: CanonicalSerialize + CanonicalDeserialize,
+{
+    let encodings: Vec<Vec<u8>> = values.iter().map(encode).collect();
+    let mut selected = vec![0u8; encodings[0].len()];
+    for (i, encoding) in encodings.iter().enumerate() {
+        selected = select_bytes(&selected, encoding, index_eq(i, index));
+    }
+    decode(&selected)
+}
+
+/// Whether `a == b`, comparing their encodings byte by byte. The encodings are wiped afterwards,
+/// since the values may be secret.
+pub(crate) fn eq<T: CanonicalSerialize>(a: &T, b: &T) -> Choice {
+    Zeroizing::new(encode(a)).ct_eq(&Zeroizing::new(encode(b)))
+}
+
+/// Whether `values[index] == target`, comparing against every element.
+pub(crate) fn holds_at<T: CanonicalSerialize>(values: &[T], target: &T, index: usize) -> Choice {
+    let target = encode(target);
+    values
+        .iter()
+        .enumerate()
+        .fold(Choice::from(0), |found, (i, value)| {
+            found | (index_eq(i, index) & encode(value).ct_eq(&target))
+        })
+}
+
+/// `values` rotated left by `shift < values.len()`: `out[k] = values[(k + shift) % n]`.
+pub(crate) fn rotate_left<T>(values: &[T], shift: usize) -> Vec<T>
+where
+    T: CanonicalSerialize + CanonicalDeserialize,
+{
+    let n = values.len();
+    let mut encodings: Vec<Vec<u8>> = values.iter().map(encode).collect();
+    let mut step = 1;
+    let mut bit = 0;
+    while step < n {
+        let choice = Choice::from(((shift >> bit) & 1) as u8);
+        encodings = (0..n)
+            .map(|k| select_bytes(&encodings[k], &encodings[(k + step) % n], choice))
+            .collect();
+        step <<= 1;
+        bit += 1;
+    }
+    encodings.iter().map(|bytes| decode(bytes)).collect()
+}
+
+/// Undoes [`rotate_left`] by the same `shift`.
+pub(crate) fn rotate_right<T>(values: &[T], shift: usize) -> Vec<T>
+where
+    T: CanonicalSerialize + CanonicalDeserialize,
+{
+    rotate_left(values, complement(shift, values.len()))
+}
+
+/// `(n - shift) % n` for `shift < n`, without branching on `shift`.
+pub(crate) fn complement(shift: usize, n: usize) -> usize {
+    u64::conditional_select(&((n - shift) as u64), &0, (shift as u64).ct_eq(&0)) as usize
+}
+
+// bits of the scalar consumed per window of `secret_mul`
+#[cfg(feature = "constant-time")]
+const WINDOW: usize = 4;
+
+/// `base * scalar` for a secret `scalar`. With the `constant-time` feature this is a fixed-window
+/// multiplication: the same doublings and additions for every scalar of the field, each window's
+/// multiple of `base` picked from a table with [`select`]. Table entries are `(digit + 16) *
+/// base` rather than `digit * base`, so no addition ever meets the identity on a zero digit; the
+/// sum of the offsets depends only on `base` and is subtracted at the end. Without the feature it
+/// is arkworks' own multiplication.
+pub(crate) fn secret_mul<C: ark_ec::CurveGroup>(base: &C::Affine, scalar: &C::ScalarField) -> C {
+    #[cfg(feature = "constant-time")]
+    {
+        use ark_ec::AffineRepr;
+        use ark_ff::PrimeField;
+
+        let base = base.into_group();
+        let mut multiples = Vec::with_capacity(1 << WINDOW);
+        let mut multiple = base.double().double().double().double();
+        for _ in 0..1 << WINDOW {
+            multiples.push(multiple);
+            multiple += base;
+        }
+        let table = C::normalize_batch(&multiples);
+
+        // sized up front: `to_bits_be` collects into a growing `Vec` and would free partial
+        // copies of the scalar's bits along the way
+        let scalar = scalar.into_bigint();
+        let mut bits = zeroize::Zeroizing::new(Vec::with_capacity(64 * scalar.as_ref().len()));
+        bits.extend(ark_ff::BitIteratorBE::new(scalar));
+        let mut acc = C::zero();
+        let mut offset = C::zero();
+        for window in bits.rchunks(WINDOW).rev() {
+            for _ in 0..WINDOW {
+                acc.double_in_place();
+                offset.double_in_place();
+            }
+            let digit = window
+                .iter()
+                .fold(0usize, |digit, bit| (digit << 1) | *bit as usize);
+            acc += select(&table, digit);
+            offset += table[0];
+        }
+        acc - offset
+    }
+    #[cfg(not(feature = "constant-time"))]
+    {
+        *base * scalar
+    }
+}
+
+/// `scalar * G` for the generator `G` of `C` and a secret `scalar`, through [`secret_mul`]. The
+/// generator's precomputed table is indexed by the scalar's bytes and is never used here.
+pub(crate) fn generator_mul<C: ark_ec::CurveGroup>(scalar: &C::ScalarField) -> C {
+    secret_mul::<C>(&C::generator().into_affine(), scalar)
+}
@@ -0,0 +1,52 @@
+//! The G1 group of BLS12-381, so ring signatures can sit alongside SNARKs over the same curve.
+
+use ark_ec::models::CurveConfig;
+use ark_ec::short_weierstrass::{self as sw, SWCurveConfig};
+use ark_ff::fields::{Fp256, Fp384, MontBackend, MontConfig};
+use ark_ff::{AdditiveGroup, MontFp};
+
+#[derive(MontConfig)]
+#[modulus = "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787"]
+#[generator = "2"]
+pub struct FqConfig;
+pub type Fq = Fp384<MontBackend<FqConfig, 6>>;
+
+#[derive(MontConfig)]
+#[modulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
+#[generator = "7"]
+pub struct FrConfig;
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+pub type G1Affine = sw::Affine<Config>;
+pub type G1Projective = sw::Projective<Config>;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+impl CurveConfig for Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    /// 0x396c8c005555e1568c00aaab0000aaab
+    const COFACTOR: &'static [u64] = &[0x8c00aaab0000aaab, 0x396c8c005555e156];
+    const COFACTOR_INV: Fr =
+        MontFp!("52435875175126190458656871551744051925719901746859129887267498875565241663483");
+}
+
+impl SWCurveConfig for Config {
+    const COEFF_A: Fq = Fq::ZERO;
+    const COEFF_B: Fq = MontFp!("4");
+    const GENERATOR: G1Affine = G1Affine::new_unchecked(G1_GENERATOR_X, G1_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+        Self::BaseField::ZERO
+    }
+}
+
+pub const G1_GENERATOR_X: Fq = MontFp!(
+    "3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507"
+);
+pub const G1_GENERATOR_Y: Fq = MontFp!(
+    "1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569"
+);
@@ -0,0 +1,74 @@
+//! The G1 group of BN254 (alt_bn128), the curve behind Ethereum's `ecAdd`/`ecMul` precompiles.
+
+use alloc::vec::Vec;
+use ark_ec::AffineRepr;
+use ark_ec::models::CurveConfig;
+use ark_ec::short_weierstrass::{self as sw, SWCurveConfig};
+use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+use ark_ff::{AdditiveGroup, BigInteger, Field, MontFp, PrimeField};
+
+#[derive(MontConfig)]
+#[modulus = "21888242871839275222246405745257275088696311157297823662689037894645226208583"]
+#[generator = "3"]
+pub struct FqConfig;
+pub type Fq = Fp256<MontBackend<FqConfig, 4>>;
+
+#[derive(MontConfig)]
+#[modulus = "21888242871839275222246405745257275088548364400416034343698204186575808495617"]
+#[generator = "5"]
+pub struct FrConfig;
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+pub type G1Affine = sw::Affine<Config>;
+pub type G1Projective = sw::Projective<Config>;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+impl CurveConfig for Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[0x1];
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+impl SWCurveConfig for Config {
+    const COEFF_A: Fq = Fq::ZERO;
+    const COEFF_B: Fq = MontFp!("3");
+    const GENERATOR: G1Affine = G1Affine::new_unchecked(MontFp!("1"), MontFp!("2"));
+
+    #[inline(always)]
+    fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+        Self::BaseField::ZERO
+    }
+}
+
+/// Encodes `point` the way the EVM precompiles take it: big-endian `x || y`, with the identity
+/// as 64 zero bytes.
+pub fn to_evm_bytes(point: &G1Affine) -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    if let Some((x, y)) = point.xy() {
+        bytes[..32].copy_from_slice(&x.into_bigint().to_bytes_be());
+        bytes[32..].copy_from_slice(&y.into_bigint().to_bytes_be());
+    }
+    bytes
+}
+
+/// Decodes the precompile encoding, rejecting non-canonical coordinates and points off the curve.
+pub fn from_evm_bytes(bytes: &[u8; 64]) -> Option<G1Affine> {
+    if bytes.iter().all(|&b| b == 0) {
+        return Some(G1Affine::identity());
+    }
+    let x = Fq::from_bigint(BigInteger::from_bits_be(&bits(&bytes[..32])))?;
+    let y = Fq::from_bigint(BigInteger::from_bits_be(&bits(&bytes[32..])))?;
+    let point = G1Affine::new_unchecked(x, y);
+    point.is_on_curve().then_some(point)
+}
+
+fn bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1))
+        .collect()
+}
@@ -0,0 +1,58 @@
+//! Jubjub, the twisted Edwards curve over the BLS12-381 scalar field used for Zcash Sapling keys.
+//! Its base field is the scalar field of [`bls12_381`](super::bls12_381), so points can be
+//! handled natively in circuits over that curve.
+
+pub use super::bls12_381::Fr as Fq;
+use ark_ec::models::CurveConfig;
+use ark_ec::twisted_edwards::{self as te, MontCurveConfig, TECurveConfig};
+use ark_ff::MontFp;
+use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+
+#[derive(MontConfig)]
+#[modulus = "6554484396890773809930967563523245729705921265872317281365359162392183254199"]
+#[generator = "3"]
+pub struct FrConfig;
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+pub type EdwardsAffine = te::Affine<EdwardsConfig>;
+pub type EdwardsProjective = te::Projective<EdwardsConfig>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct EdwardsConfig;
+
+impl CurveConfig for EdwardsConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[8];
+    const COFACTOR_INV: Fr =
+        MontFp!("819310549611346726241370945440405716213240158234039660170669895299022906775");
+}
+
+impl TECurveConfig for EdwardsConfig {
+    const COEFF_A: Fq = MontFp!("-1");
+    /// -(10240 / 10241)
+    const COEFF_D: Fq =
+        MontFp!("19257038036680949359750312669786877991949435402254120286184196891950884077233");
+    const GENERATOR: EdwardsAffine = EdwardsAffine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+
+    type MontCurveConfig = EdwardsConfig;
+
+    #[inline(always)]
+    fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
+        -elem
+    }
+}
+
+impl MontCurveConfig for EdwardsConfig {
+    const COEFF_A: Fq = MontFp!("40962");
+    const COEFF_B: Fq =
+        MontFp!("52435875175126190479447740508185965837690552500527637822603658699938581143549");
+
+    type TECurveConfig = EdwardsConfig;
+}
+
+pub const GENERATOR_X: Fq =
+    MontFp!("8076246640662884909881801758704306714034609987455869804520522091855516602923");
+pub const GENERATOR_Y: Fq =
+    MontFp!("13262374693698910701929044844600465831413122818447359594527400194675274060458");
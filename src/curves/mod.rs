@@ -0,0 +1,66 @@
+// Curves without a published arkworks 0.5 crate we can depend on are defined here with the same
+// `MontConfig`/`SWCurveConfig` machinery the arkworks curve crates use. Each one sits behind its
+// own feature so users who only need the built-in curves can opt out.
+
+#[cfg(feature = "p256")]
+pub mod p256;
+
+#[cfg(feature = "bls12-381")]
+pub mod bls12_381;
+
+#[cfg(feature = "bn254")]
+pub mod bn254;
+
+#[cfg(any(feature = "pallas", feature = "vesta"))]
+pub mod pasta;
+
+#[cfg(feature = "pallas")]
+pub mod pallas;
+
+#[cfg(feature = "vesta")]
+pub mod vesta;
+
+#[cfg(feature = "jubjub")]
+pub mod jubjub;
+
+#[doc(hidden)]
+pub use ark_ec as __ark_ec;
+
+/// Registers a custom arkworks curve config with the crate. [`Ring`](crate::ring::Ring) and
+/// every scheme are generic over `CurveGroup`, so there is nothing to implement: the macro checks
+/// at compile time that the config yields a usable curve and can name a ring type for it.
+///
+/// ```ignore
+/// impl_ring_curve!(MyConfig);                           // short Weierstrass
+/// impl_ring_curve!(te MyEdwardsConfig);                 // twisted Edwards
+/// impl_ring_curve!(MyConfig => pub type MyRing);        // also `pub type MyRing = Ring<..>`
+/// ```
+#[macro_export]
+macro_rules! impl_ring_curve {
+    (@check $curve:ty) => {
+        const _: () = {
+            fn registered<C: $crate::curves::__ark_ec::CurveGroup>() {}
+            let _ = registered::<$curve>;
+        };
+    };
+    (te $config:ty => $vis:vis type $name:ident) => {
+        $crate::impl_ring_curve!(te $config);
+        $vis type $name =
+            $crate::ring::Ring<$crate::curves::__ark_ec::twisted_edwards::Projective<$config>>;
+    };
+    (te $config:ty) => {
+        $crate::impl_ring_curve!(
+            @check $crate::curves::__ark_ec::twisted_edwards::Projective<$config>
+        );
+    };
+    ($config:ty => $vis:vis type $name:ident) => {
+        $crate::impl_ring_curve!($config);
+        $vis type $name =
+            $crate::ring::Ring<$crate::curves::__ark_ec::short_weierstrass::Projective<$config>>;
+    };
+    ($config:ty) => {
+        $crate::impl_ring_curve!(
+            @check $crate::curves::__ark_ec::short_weierstrass::Projective<$config>
+        );
+    };
+}
@@ -0,0 +1,44 @@
+//! NIST P-256 (secp256r1), so rings can be formed from keys issued by existing PKI.
+
+use ark_ec::models::CurveConfig;
+use ark_ec::short_weierstrass::{self as sw, SWCurveConfig};
+use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+use ark_ff::{Field, MontFp};
+
+#[derive(MontConfig)]
+#[modulus = "115792089210356248762697446949407573530086143415290314195533631308867097853951"]
+#[generator = "3"]
+pub struct FqConfig;
+pub type Fq = Fp256<MontBackend<FqConfig, 4>>;
+
+#[derive(MontConfig)]
+#[modulus = "115792089210356248762697446949407573529996955224135760342422259061068512044369"]
+#[generator = "7"]
+pub struct FrConfig;
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+pub type Affine = sw::Affine<Config>;
+pub type Projective = sw::Projective<Config>;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+impl CurveConfig for Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[0x1];
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+impl SWCurveConfig for Config {
+    const COEFF_A: Fq = MontFp!("-3");
+    const COEFF_B: Fq =
+        MontFp!("41058363725152142129326129780047268409114441015993725554835256314039467401291");
+    const GENERATOR: Affine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+}
+
+pub const GENERATOR_X: Fq =
+    MontFp!("48439561293906451759052585252797914202762949526041747995844080717082404635286");
+pub const GENERATOR_Y: Fq =
+    MontFp!("36134250956749795798585127919587881956611106672985015071877198253568414405109");
@@ -0,0 +1,32 @@
+//! Pallas, `y^2 = x^3 + 5` over the Pasta `Fp`, as used by Halo2.
+
+pub use super::pasta::{Fp, Fq};
+use ark_ec::models::CurveConfig;
+use ark_ec::short_weierstrass::{self as sw, SWCurveConfig};
+use ark_ff::{AdditiveGroup, Field, MontFp};
+
+pub type Affine = sw::Affine<Config>;
+pub type Projective = sw::Projective<Config>;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+impl CurveConfig for Config {
+    type BaseField = Fp;
+    type ScalarField = Fq;
+
+    const COFACTOR: &'static [u64] = &[0x1];
+    const COFACTOR_INV: Fq = Fq::ONE;
+}
+
+impl SWCurveConfig for Config {
+    const COEFF_A: Fp = Fp::ZERO;
+    const COEFF_B: Fp = MontFp!("5");
+    /// (-1, 2)
+    const GENERATOR: Affine = Affine::new_unchecked(MontFp!("-1"), MontFp!("2"));
+
+    #[inline(always)]
+    fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+        Self::BaseField::ZERO
+    }
+}
@@ -0,0 +1,16 @@
+//! The two fields shared by the Pasta cycle: Pallas is defined over `Fp` with scalars in `Fq`,
+//! Vesta the other way round.
+
+use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+
+#[derive(MontConfig)]
+#[modulus = "28948022309329048855892746252171976963363056481941560715954676764349967630337"]
+#[generator = "5"]
+pub struct PallasBaseConfig;
+pub type Fp = Fp256<MontBackend<PallasBaseConfig, 4>>;
+
+#[derive(MontConfig)]
+#[modulus = "28948022309329048855892746252171976963363056481941647379679742748393362948097"]
+#[generator = "5"]
+pub struct VestaBaseConfig;
+pub type Fq = Fp256<MontBackend<VestaBaseConfig, 4>>;
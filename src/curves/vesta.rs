@@ -0,0 +1,32 @@
+//! Vesta, `y^2 = x^3 + 5` over the Pasta `Fq`; its scalar field is Pallas' base field.
+
+pub use super::pasta::{Fp, Fq};
+use ark_ec::models::CurveConfig;
+use ark_ec::short_weierstrass::{self as sw, SWCurveConfig};
+use ark_ff::{AdditiveGroup, Field, MontFp};
+
+pub type Affine = sw::Affine<Config>;
+pub type Projective = sw::Projective<Config>;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+impl CurveConfig for Config {
+    type BaseField = Fq;
+    type ScalarField = Fp;
+
+    const COFACTOR: &'static [u64] = &[0x1];
+    const COFACTOR_INV: Fp = Fp::ONE;
+}
+
+impl SWCurveConfig for Config {
+    const COEFF_A: Fq = Fq::ZERO;
+    const COEFF_B: Fq = MontFp!("5");
+    /// (-1, 2)
+    const GENERATOR: Affine = Affine::new_unchecked(MontFp!("-1"), MontFp!("2"));
+
+    #[inline(always)]
+    fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+        Self::BaseField::ZERO
+    }
+}
@@ -0,0 +1,221 @@
+// Just enough DER and PEM to read and write the key containers in `pem`: definite-length
+// encodings only, with non-minimal lengths rejected.
+
+use crate::base64;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub(crate) const INTEGER: u8 = 0x02;
+pub(crate) const BIT_STRING: u8 = 0x03;
+pub(crate) const OCTET_STRING: u8 = 0x04;
+pub(crate) const NULL: u8 = 0x05;
+pub(crate) const OID: u8 = 0x06;
+pub(crate) const SEQUENCE: u8 = 0x30;
+
+pub(crate) fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![tag];
+    let length = content.len();
+    if length < 0x80 {
+        bytes.push(length as u8);
+    } else {
+        let length = length.to_be_bytes();
+        let skip = length.iter().take_while(|byte| **byte == 0).count();
+        bytes.push(0x80 | (length.len() - skip) as u8);
+        bytes.extend(&length[skip..]);
+    }
+    bytes.extend(content);
+    bytes
+}
+
+// a non-negative INTEGER in its minimal two's complement form
+pub(crate) fn integer(value: u32) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let skip = bytes.iter().take_while(|byte| **byte == 0).count().min(3);
+    let mut content = if bytes[skip] & 0x80 != 0 {
+        vec![0]
+    } else {
+        vec![]
+    };
+    content.extend(&bytes[skip..]);
+    tlv(INTEGER, &content)
+}
+
+pub(crate) fn sequence(parts: &[&[u8]]) -> Vec<u8> {
+    tlv(SEQUENCE, &parts.concat())
+}
+
+// a BIT STRING with no unused bits
+pub(crate) fn bit_string(bits: &[u8]) -> Vec<u8> {
+    tlv(BIT_STRING, &[&[0], bits].concat())
+}
+
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Reader<'a> {
+        Reader { bytes }
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.bytes.first().copied()
+    }
+
+    /// Reads the next element, which must carry `tag`, and returns its content.
+    pub(crate) fn read(&mut self, tag: u8) -> Option<&'a [u8]> {
+        let (&found, rest) = self.bytes.split_first()?;
+        if found != tag {
+            return None;
+        }
+        let (&first, mut rest) = rest.split_first()?;
+        let length = if first < 0x80 {
+            first as usize
+        } else {
+            let count = (first & 0x7f) as usize;
+            if count == 0 || count > size_of::<usize>() || rest.len() < count || rest[0] == 0 {
+                return None;
+            }
+            let length = rest[..count]
+                .iter()
+                .fold(0usize, |length, byte| length << 8 | *byte as usize);
+            rest = &rest[count..];
+            if length < 0x80 {
+                return None;
+            }
+            length
+        };
+        if rest.len() < length {
+            return None;
+        }
+        let (content, rest) = rest.split_at(length);
+        self.bytes = rest;
+        Some(content)
+    }
+
+    pub(crate) fn read_optional(&mut self, tag: u8) -> Option<Option<&'a [u8]>> {
+        match self.peek() {
+            Some(found) if found == tag => self.read(tag).map(Some),
+            _ => Some(None),
+        }
+    }
+
+    pub(crate) fn read_bit_string(&mut self) -> Option<&'a [u8]> {
+        bit_string_content(self.read(BIT_STRING)?)
+    }
+
+    /// Reads an `AlgorithmIdentifier`, returning its OID and the encoded parameters, if any.
+    pub(crate) fn read_algorithm(&mut self) -> Option<(&'a [u8], &'a [u8])> {
+        let mut algorithm = Reader::new(self.read(SEQUENCE)?);
+        let oid = algorithm.read(OID)?;
+        Some((oid, algorithm.take_rest()))
+    }
+
+    pub(crate) fn take_rest(&mut self) -> &'a [u8] {
+        core::mem::take(&mut self.bytes)
+    }
+
+    pub(crate) fn read_small_integer(&mut self) -> Option<u8> {
+        match self.read(INTEGER)? {
+            &[value] if value < 0x80 => Some(value),
+            _ => None,
+        }
+    }
+
+    // non-negative and minimally encoded
+    pub(crate) fn read_u32(&mut self) -> Option<u32> {
+        let content = self.read(INTEGER)?;
+        let (&first, _) = content.split_first()?;
+        let magnitude = match content {
+            [0, next, ..] if *next & 0x80 != 0 => &content[1..],
+            [0, _, ..] => return None,
+            _ if first & 0x80 != 0 => return None,
+            _ => content,
+        };
+        if magnitude.len() > 4 {
+            return None;
+        }
+        Some(
+            magnitude
+                .iter()
+                .fold(0, |value, byte| value << 8 | *byte as u32),
+        )
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+// the bits of a BIT STRING, which must have no unused bits
+pub(crate) fn bit_string_content(content: &[u8]) -> Option<&[u8]> {
+    match content.split_first()? {
+        (0, bits) => Some(bits),
+        _ => None,
+    }
+}
+
+// a whole DER element whose content must be consumed exactly
+pub(crate) fn parse<'a, T>(
+    bytes: &'a [u8],
+    tag: u8,
+    f: impl FnOnce(&mut Reader<'a>) -> Option<T>,
+) -> Option<T> {
+    let mut outer = Reader::new(bytes);
+    let mut inner = Reader::new(outer.read(tag)?);
+    let value = f(&mut inner)?;
+    (inner.is_empty() && outer.is_empty()).then_some(value)
+}
+
+// the content of an explicitly tagged field, which must hold exactly one element
+pub(crate) fn explicit<'a, T>(
+    content: &'a [u8],
+    f: impl FnOnce(&mut Reader<'a>) -> Option<T>,
+) -> Option<T> {
+    let mut reader = Reader::new(content);
+    let value = f(&mut reader)?;
+    reader.is_empty().then_some(value)
+}
+
+/// Armors `der` as PEM under `label`, with 64-column base64 lines.
+pub(crate) fn pem_encode(label: &str, der: &[u8]) -> String {
+    let encoded = base64::encode(der, base64::STANDARD, true);
+    let mut pem = format!("-----BEGIN {label}-----\n");
+    for line in encoded.as_bytes().chunks(64) {
+        pem.push_str(core::str::from_utf8(line).expect("base64 is ASCII"));
+        pem.push('\n');
+    }
+    pem.push_str(&format!("-----END {label}-----\n"));
+    pem
+}
+
+/// Decodes the body of the first PEM block carrying `label`. Text outside the block, including
+/// other blocks such as the `EC PARAMETERS` OpenSSL writes ahead of a key, is ignored.
+pub(crate) fn pem_decode(label: &str, pem: &str) -> Option<Vec<u8>> {
+    pem_decode_all(label, pem).next()?
+}
+
+/// Decodes the body of every PEM block carrying `label`, in order, with `None` for a block
+/// whose base64 is malformed or that never ends.
+pub(crate) fn pem_decode_all<'a>(
+    label: &str,
+    mut pem: &'a str,
+) -> impl Iterator<Item = Option<Vec<u8>>> + 'a {
+    let begin = format!("-----BEGIN {label}-----");
+    let end = format!("-----END {label}-----");
+    core::iter::from_fn(move || {
+        let start = pem.find(&begin)? + begin.len();
+        let Some(length) = pem[start..].find(&end) else {
+            pem = "";
+            return Some(None);
+        };
+        let symbols: Vec<u8> = pem[start..start + length]
+            .bytes()
+            .filter(|byte| !byte.is_ascii_whitespace())
+            .collect();
+        pem = &pem[start + length + end.len()..];
+        Some(base64::decode(&symbols, base64::STANDARD, true))
+    })
+}
@@ -0,0 +1,261 @@
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::ring::{self, CurvePoint, Ring, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, UniformRand, Zero};
+use ark_serialize::Valid;
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::Sha512;
+
+const DV_DOMAIN: &[u8] = b"lingo-designated-v1";
+
+// A designated-verifier signature is a SAG over the ring extended with the verifier's key, so
+// the verifier could always have produced it themselves (deniability). Every challenge is also
+// keyed by a Diffie–Hellman secret between an ephemeral signer key and the verifier, so nobody
+// without the verifier's secret can even check the challenge chain.
+
+/// Signs `message` for the member at `secret_index`, designating `verifier` as the only party
+/// able to verify the result. Fails with [`RingError::InvalidVerifier`] if `verifier` is the
+/// identity or outside the prime-order subgroup.
+#[cfg(feature = "std")]
+pub fn sign<'a, C>(
+    ring: &'a Ring<C>,
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    verifier: CurvePoint<C>,
+) -> Result<DvRingSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+{
+    sign_with_rng(
+        ring,
+        message,
+        private_key,
+        secret_index,
+        verifier,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Like [`sign`], drawing the ephemeral key, nonce and decoy responses from `rng`.
+pub fn sign_with_rng<'a, C, R>(
+    ring: &'a Ring<C>,
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    verifier: CurvePoint<C>,
+    rng: &mut R,
+) -> Result<DvRingSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+    R: RngCore + CryptoRng,
+{
+    if verifier.is_zero() || verifier.check().is_err() {
+        return Err(RingError::InvalidVerifier);
+    }
+    let secret = ring.signer_secret(private_key, secret_index)?;
+
+    let ephemeral = Scalar::<C>::rand(rng);
+    let shared = ct::secret_mul::<C>(&verifier, &ephemeral).into_affine();
+    let ephemeral = ct::generator_mul::<C>(&ephemeral).into_affine();
+    Ok(sign_extended(
+        ring,
+        message,
+        secret,
+        secret_index,
+        verifier,
+        ephemeral,
+        shared,
+        rng,
+    ))
+}
+
+/// Lets the designated verifier produce a signature indistinguishable from one made by a ring
+/// member, which is what makes designated signatures deniable to third parties.
+#[cfg(feature = "std")]
+pub fn simulate<'a, C>(
+    ring: &'a Ring<C>,
+    message: &[u8],
+    verifier_secret: &SecretKey<C>,
+) -> DvRingSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    simulate_with_rng(ring, message, verifier_secret, &mut rand::thread_rng())
+}
+
+/// Like [`simulate`], drawing its randomness from `rng`.
+pub fn simulate_with_rng<'a, C, R>(
+    ring: &'a Ring<C>,
+    message: &[u8],
+    verifier_secret: &SecretKey<C>,
+    rng: &mut R,
+) -> DvRingSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+    R: RngCore + CryptoRng,
+{
+    let secret = SecretScalar::<C>::from_private_key(verifier_secret);
+    let verifier = ct::generator_mul::<C>(&secret).into_affine();
+
+    let ephemeral = ct::generator_mul::<C>(&Scalar::<C>::rand(rng)).into_affine();
+    let shared = ct::secret_mul::<C>(&ephemeral, &secret).into_affine();
+    sign_extended(
+        ring,
+        message,
+        secret,
+        ring.size(),
+        verifier,
+        ephemeral,
+        shared,
+        rng,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sign_extended<'a, C: CurveGroup, R: RngCore + CryptoRng>(
+    ring: &'a Ring<C>,
+    message: &[u8],
+    secret: SecretScalar<C>,
+    secret_index: usize,
+    verifier: CurvePoint<C>,
+    ephemeral: CurvePoint<C>,
+    shared: CurvePoint<C>,
+    rng: &mut R,
+) -> DvRingSignature<'a, ScalarBigInt<C>, C> {
+    let keys = extended_keys(ring, verifier);
+    let n = keys.len();
+    let prefix = challenge_prefix::<C>(&keys, message, &ephemeral, &shared);
+    let challenge = |point: C| {
+        let mut hasher = prefix.clone();
+        hash::append_point::<C>(&mut hasher, &point.into_affine());
+        hash::hash_to_scalar::<Scalar<C>>(hasher)
+    };
+
+    let mut challenges = vec![Scalar::<C>::from(0u64); n];
+    let mut responses = vec![Scalar::<C>::from(0u64); n];
+
+    // walked over the keys rotated so the signer is at position 0, as in `Ring::sign`
+    let keys = ct::rotate_left(&keys, secret_index);
+    let alpha = SecretScalar::<C>::random(rng);
+    challenges[1 % n] = challenge(ct::generator_mul::<C>(&alpha));
+    for k in 1..n {
+        responses[k] = Scalar::<C>::rand(rng);
+        challenges[(k + 1) % n] =
+            challenge(generator::mul::<C>(&responses[k]) + keys[k] * challenges[k]);
+    }
+    responses[0] = alpha.response(&challenges[0], &secret);
+    let (challenge, responses) = ring::unrotate::<C>(&challenges, &responses, secret_index);
+
+    DvRingSignature {
+        ring,
+        verifier,
+        ephemeral,
+        challenge: challenge.into_bigint(),
+        ring_sig_vals: responses.iter().map(|r| r.into_bigint()).collect(),
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct DvRingSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: &'a Ring<C>,
+    pub verifier: CurvePoint<C>,
+    pub ephemeral: CurvePoint<C>,
+    pub challenge: B,
+    /// One response per ring member, followed by the verifier's.
+    pub ring_sig_vals: Vec<B>,
+}
+
+impl<'a, B, C> DvRingSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn ring(&self) -> &Ring<C> {
+        self.ring
+    }
+
+    pub fn verifier(&self) -> &CurvePoint<C> {
+        &self.verifier
+    }
+}
+
+impl<'a, C> DvRingSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    /// Verifies the signature with the designated verifier's private key. Fails with
+    /// [`VerifyError::WrongVerifier`] for any other key.
+    pub fn verify(
+        &self,
+        message: &[u8],
+        verifier_secret: &SecretKey<C>,
+    ) -> Result<(), VerifyError> {
+        if verifier_secret.expose_secret().is_zero() {
+            return Err(VerifyError::WrongVerifier);
+        }
+        let secret = SecretScalar::<C>::from_private_key(verifier_secret);
+        if ct::generator_mul::<C>(&secret).into_affine() != self.verifier {
+            return Err(VerifyError::WrongVerifier);
+        }
+        // a small-order ephemeral key would pin the shared secret to a handful of values anyone
+        // can compute; `check` refuses points off the curve or outside the prime-order subgroup
+        if self.ephemeral.is_zero() || self.ephemeral.check().is_err() {
+            return Err(VerifyError::InvalidPoint);
+        }
+
+        let keys = extended_keys(self.ring, self.verifier);
+        if keys.len() != self.ring_sig_vals.len() {
+            return Err(VerifyError::LengthMismatch);
+        }
+        if keys.iter().any(|key| key.is_zero()) {
+            return Err(VerifyError::InvalidPoint);
+        }
+        let initial =
+            Scalar::<C>::from_bigint(self.challenge).ok_or(VerifyError::NonCanonicalScalar)?;
+
+        let shared = ct::secret_mul::<C>(&self.ephemeral, &secret).into_affine();
+        let prefix = challenge_prefix::<C>(&keys, message, &self.ephemeral, &shared);
+        let mut current = initial;
+        for (key, response) in keys.iter().zip(&self.ring_sig_vals) {
+            let response =
+                Scalar::<C>::from_bigint(*response).ok_or(VerifyError::NonCanonicalScalar)?;
+            let mut hasher = prefix.clone();
+            let point = generator::mul::<C>(&response) + *key * current;
+            hash::append_point::<C>(&mut hasher, &point.into_affine());
+            current = hash::hash_to_scalar(hasher);
+        }
+
+        error::check_challenge(current, initial)
+    }
+}
+
+fn extended_keys<C: CurveGroup>(ring: &Ring<C>, verifier: CurvePoint<C>) -> Vec<CurvePoint<C>> {
+    let mut keys = ring.keys().to_vec();
+    keys.push(verifier);
+    keys
+}
+
+fn challenge_prefix<C: CurveGroup>(
+    keys: &[CurvePoint<C>],
+    message: &[u8],
+    ephemeral: &CurvePoint<C>,
+    shared: &CurvePoint<C>,
+) -> Sha512 {
+    let mut prefix = hash::challenge_hasher::<C>(DV_DOMAIN, keys, message);
+    hash::append_point::<C>(&mut prefix, ephemeral);
+    hash::append_point::<C>(&mut prefix, shared);
+    prefix
+}
@@ -0,0 +1,95 @@
+use crate::keypair::KeyPair;
+use crate::ring::Ring;
+use alloc::vec::Vec;
+use ark_ec::AffineRepr;
+use ark_ec::twisted_edwards::TECurveConfig;
+use ark_ed25519::{EdwardsAffine, EdwardsConfig, EdwardsProjective, Fq, Fr};
+use ark_ff::{BigInt, BigInteger, Field, One, PrimeField, Zero};
+use sha2::{Digest, Sha512};
+
+// Interop with standard (RFC 8032) ed25519 keys, as produced by ed25519-dalek, OpenSSH and most
+// wallets. Public keys use the RFC's compressed encoding, which differs from arkworks' own: the
+// flag bit carries the parity of x rather than its sign.
+
+/// RFC 8032 encoding of `point`: little-endian y with the parity of x in the top bit.
+pub fn encode(point: &EdwardsAffine) -> [u8; 32] {
+    let (x, y) = point.xy().unwrap_or((Fq::zero(), Fq::one()));
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&y.into_bigint().to_bytes_le());
+    if x.into_bigint().is_odd() {
+        bytes[31] |= 0x80;
+    }
+    bytes
+}
+
+/// Decodes an RFC 8032 public key. Returns `None` for non-canonical encodings, points off the
+/// curve and points outside the prime-order subgroup.
+pub fn decode(bytes: &[u8; 32]) -> Option<EdwardsAffine> {
+    decode_on_curve(bytes).filter(|point| point.is_in_correct_subgroup_assuming_on_curve())
+}
+
+// any point on the curve, torsion included, as Monero accepts for ring members
+pub(crate) fn decode_on_curve(bytes: &[u8; 32]) -> Option<EdwardsAffine> {
+    let x_odd = bytes[31] & 0x80 != 0;
+    let mut y_bytes = *bytes;
+    y_bytes[31] &= 0x7f;
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(y_bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().expect("chunks are 8 bytes"));
+    }
+    let y = Fq::from_bigint(BigInt::new(limbs))?;
+
+    // -x^2 + y^2 = 1 + d x^2 y^2
+    let yy = y.square();
+    let mut x = ((yy - Fq::one()) / (EdwardsConfig::COEFF_D * yy + Fq::one())).sqrt()?;
+    if x.is_zero() && x_odd {
+        return None;
+    }
+    if x.into_bigint().is_odd() != x_odd {
+        x = -x;
+    }
+
+    let point = EdwardsAffine::new_unchecked(x, y);
+    point.is_on_curve().then_some(point)
+}
+
+/// RFC 8032 encoding of the key image for a 32-byte secret key (the seed).
+pub fn key_image(secret: &[u8; 32]) -> [u8; 32] {
+    encode(KeyPair::from_ed25519_secret(secret).key_image().point())
+}
+
+impl KeyPair<EdwardsProjective> {
+    /// Expands a 32-byte RFC 8032 secret key (the seed) into its clamped signing scalar.
+    pub fn from_ed25519_secret(secret: &[u8; 32]) -> KeyPair<EdwardsProjective> {
+        let mut scalar = [0u8; 32];
+        scalar.copy_from_slice(&Sha512::digest(secret)[..32]);
+        KeyPair::from_clamped(scalar)
+    }
+
+    /// Imports a 64-byte expanded secret key: the clamped scalar followed by the nonce prefix,
+    /// which ring signatures do not use.
+    pub fn from_ed25519_expanded(expanded: &[u8; 64]) -> KeyPair<EdwardsProjective> {
+        let mut scalar = [0u8; 32];
+        scalar.copy_from_slice(&expanded[..32]);
+        KeyPair::from_clamped(scalar)
+    }
+
+    fn from_clamped(mut scalar: [u8; 32]) -> KeyPair<EdwardsProjective> {
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        KeyPair::from_scalar(Fr::from_le_bytes_mod_order(&scalar))
+    }
+}
+
+impl Ring<EdwardsProjective> {
+    /// Builds a ring from RFC 8032 public keys, or `None` if any fails to decode.
+    pub fn from_ed25519_keys(keys: &[[u8; 32]]) -> Option<Ring<EdwardsProjective>> {
+        let keys = keys.iter().map(decode).collect::<Option<Vec<_>>>()?;
+        Ring::from_fixed_pubkeys(keys).ok()
+    }
+
+    pub fn ed25519_keys(&self) -> Vec<[u8; 32]> {
+        self.keys().iter().map(encode).collect()
+    }
+}
@@ -0,0 +1,381 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Reason a signature or proof failed to verify.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VerifyError {
+    /// The recomputed challenge does not match the one carried by the signature.
+    ChallengeMismatch,
+    /// A key image or linking tag is the identity or outside the prime-order subgroup.
+    InvalidKeyImage,
+    /// A public key or signature point is the identity, off the curve or outside the
+    /// prime-order subgroup.
+    InvalidPoint,
+    /// The ring has no members, or fewer than the scheme requires.
+    RingTooSmall,
+    /// The signature's shape does not match its ring, e.g. the wrong number of responses.
+    LengthMismatch,
+    /// A scalar in the signature is not canonically encoded.
+    NonCanonicalScalar,
+    /// The key supplied to check the signature is not the one it was made for.
+    WrongVerifier,
+    /// A ring member's membership path does not lead to the committed root.
+    NotAMember,
+    /// The signature was made under a different namespace than the one it is checked for.
+    WrongNamespace,
+    /// The signature was made with a challenge hash this crate does not know, or other than the
+    /// one it is checked with.
+    UnsupportedHash,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            VerifyError::ChallengeMismatch => "challenge mismatch",
+            VerifyError::InvalidKeyImage => "invalid key image",
+            VerifyError::InvalidPoint => "invalid curve point",
+            VerifyError::RingTooSmall => "ring too small",
+            VerifyError::LengthMismatch => "signature length does not match the ring",
+            VerifyError::NonCanonicalScalar => "non-canonical scalar",
+            VerifyError::WrongVerifier => "wrong verifier key",
+            VerifyError::NotAMember => "ring member not in the committed set",
+            VerifyError::WrongNamespace => "signature made for another namespace",
+            VerifyError::UnsupportedHash => "unsupported challenge hash",
+        };
+        f.write_str(reason)
+    }
+}
+
+impl core::error::Error for VerifyError {}
+
+/// Reason a key failed to import.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyError {
+    /// The secret is zero or not below the group order.
+    InvalidSecretKey,
+    /// The public key is malformed, off the curve or the identity.
+    InvalidPublicKey,
+    /// The PEM armor or DER structure around the key is malformed.
+    MalformedEncoding,
+    /// The container holds a key for another algorithm or curve.
+    UnsupportedAlgorithm,
+    /// The password is wrong or the encrypted key is corrupt.
+    DecryptionFailed,
+    /// The recovery phrase has an unknown word, the wrong length or a bad checksum.
+    InvalidMnemonic,
+    /// The derivation path is malformed or asks for a non-hardened child.
+    InvalidPath,
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            KeyError::InvalidSecretKey => "invalid secret key",
+            KeyError::InvalidPublicKey => "invalid public key",
+            KeyError::MalformedEncoding => "malformed key encoding",
+            KeyError::UnsupportedAlgorithm => "unsupported key algorithm",
+            KeyError::DecryptionFailed => "key decryption failed",
+            KeyError::InvalidMnemonic => "invalid mnemonic phrase",
+            KeyError::InvalidPath => "invalid derivation path",
+        };
+        f.write_str(reason)
+    }
+}
+
+impl core::error::Error for KeyError {}
+
+/// Reason a ring could not be built.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RingError {
+    /// The signer's private key is zero.
+    ZeroPrivateKey,
+    /// The signer's index is not below the ring size.
+    IndexOutOfBounds { index: usize, size: usize },
+    /// No public keys were given.
+    EmptyRing,
+    /// The public keys at these indices are malformed, off the curve, outside the prime-order
+    /// subgroup or the identity.
+    InvalidPublicKeys(Vec<usize>),
+    /// No public key was found for the addresses at these indices.
+    UnresolvedAddresses(Vec<usize>),
+    /// The public keys at these indices repeat a key found earlier in the ring.
+    DuplicatePublicKeys(Vec<usize>),
+    /// The ring was built without a signer.
+    NoSigner,
+    /// A signer was given more than once.
+    MultipleSigners,
+    /// The layers of a matrix ring at these indices hold a different number of members than the
+    /// first.
+    LayerSizeMismatch(Vec<usize>),
+    /// The members of a matrix ring at these indices hold a different number of keys than the
+    /// first.
+    MemberWidthMismatch(Vec<usize>),
+    /// The scheme cannot sign over a ring of this size, e.g. one that is not a power of two for
+    /// the one-out-of-many proofs.
+    UnsupportedSize(usize),
+    /// The designated verifier's key is the identity or outside the prime-order subgroup.
+    InvalidVerifier,
+    /// The signer's private keys do not belong to the ring members at the signer's indices, or
+    /// there are not as many keys and indices as the signature needs: one per layer of a matrix
+    /// ring, or one per ring of a Borromean signature.
+    KeyMismatch,
+}
+
+impl fmt::Display for RingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RingError::ZeroPrivateKey => f.write_str("zero private key"),
+            RingError::IndexOutOfBounds { index, size } => {
+                write!(f, "signer index {index} out of bounds for a ring of {size}")
+            }
+            RingError::EmptyRing => f.write_str("empty ring"),
+            RingError::InvalidPublicKeys(indices) => {
+                f.write_str("invalid public keys at indices")?;
+                write_indices(f, indices)
+            }
+            RingError::UnresolvedAddresses(indices) => {
+                f.write_str("no public key for addresses at indices")?;
+                write_indices(f, indices)
+            }
+            RingError::DuplicatePublicKeys(indices) => {
+                f.write_str("duplicate public keys at indices")?;
+                write_indices(f, indices)
+            }
+            RingError::NoSigner => f.write_str("no signer in the ring"),
+            RingError::MultipleSigners => f.write_str("more than one signer in the ring"),
+            RingError::LayerSizeMismatch(indices) => {
+                f.write_str("matrix ring layers of another size at indices")?;
+                write_indices(f, indices)
+            }
+            RingError::MemberWidthMismatch(indices) => {
+                f.write_str("matrix ring members of another width at indices")?;
+                write_indices(f, indices)
+            }
+            RingError::UnsupportedSize(size) => write!(f, "unsupported ring size {size}"),
+            RingError::InvalidVerifier => f.write_str("invalid designated verifier key"),
+            RingError::KeyMismatch => {
+                f.write_str("private key does not match the ring member at the signer's index")
+            }
+        }
+    }
+}
+
+impl core::error::Error for RingError {}
+
+fn write_indices(f: &mut fmt::Formatter<'_>, indices: &[usize]) -> fmt::Result {
+    for (n, index) in indices.iter().enumerate() {
+        let separator = if n == 0 { " " } else { ", " };
+        write!(f, "{separator}{index}")?;
+    }
+    Ok(())
+}
+
+/// Reason signing with an external [`RingSigner`](crate::external::RingSigner) failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SignerError<E> {
+    /// The device reported an error of its own.
+    Device(E),
+    /// The signer's index is not below the ring size.
+    IndexOutOfBounds { index: usize, size: usize },
+    /// The device's public key is not the ring member at the signer's index.
+    NotRingMember,
+    /// The device returned a malformed value or one that does not complete a valid signature.
+    InvalidResponse,
+}
+
+impl<E: fmt::Display> fmt::Display for SignerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignerError::Device(error) => write!(f, "signing device error: {error}"),
+            SignerError::IndexOutOfBounds { index, size } => {
+                write!(f, "signer index {index} out of bounds for a ring of {size}")
+            }
+            SignerError::NotRingMember => f.write_str("signer is not the ring member at its index"),
+            SignerError::InvalidResponse => f.write_str("invalid response from signing device"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for SignerError<E> {}
+
+/// Reason a party in an interactive signing session refused a message from another.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SessionError {
+    /// A scalar received from another party is not canonically encoded.
+    NonCanonicalScalar,
+    /// The signer set is empty, larger than the ring, repeats a member or names one outside it.
+    InvalidSigners,
+    /// The index is not one of the session's signers.
+    NotASigner(usize),
+    /// The signer at this index has already committed.
+    AlreadyCommitted(usize),
+    /// The signers at these indices have not committed yet.
+    MissingCommitments(Vec<usize>),
+    /// The response from the signer at this index does not answer its commitment.
+    InvalidResponse(usize),
+    /// The signers at these indices have not responded yet.
+    MissingResponses(Vec<usize>),
+    /// The message came out of turn: a commitment after the challenges were dealt, or a response
+    /// or the final signature before.
+    OutOfOrder,
+    /// The message carries a different number of entries than the ring has members.
+    LengthMismatch,
+    /// The points at these indices are the identity or outside the prime-order subgroup.
+    InvalidPoints(Vec<usize>),
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionError::NonCanonicalScalar => f.write_str("non-canonical scalar in message"),
+            SessionError::InvalidSigners => f.write_str("invalid signer set"),
+            SessionError::NotASigner(index) => write!(f, "member {index} is not a signer"),
+            SessionError::AlreadyCommitted(index) => {
+                write!(f, "signer {index} has already committed")
+            }
+            SessionError::MissingCommitments(indices) => {
+                f.write_str("no commitment from signers at indices")?;
+                write_indices(f, indices)
+            }
+            SessionError::InvalidResponse(index) => {
+                write!(f, "invalid response from signer {index}")
+            }
+            SessionError::MissingResponses(indices) => {
+                f.write_str("no response from signers at indices")?;
+                write_indices(f, indices)
+            }
+            SessionError::OutOfOrder => f.write_str("session message out of order"),
+            SessionError::LengthMismatch => f.write_str("session message does not match the ring"),
+            SessionError::InvalidPoints(indices) => {
+                f.write_str("invalid points at indices")?;
+                write_indices(f, indices)
+            }
+        }
+    }
+}
+
+impl core::error::Error for SessionError {}
+
+/// Reason an encoded signature or key image could not be decoded.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecodeError {
+    /// The text is not an even number of hex digits.
+    InvalidHex,
+    /// The text is not unpadded URL-safe base64.
+    InvalidBase64,
+    /// The text is not base45.
+    InvalidBase45,
+    /// The JSON is malformed, has missing, unknown or duplicate fields, or does not describe a
+    /// signature over its ring.
+    InvalidJson,
+    /// The CBOR is malformed or not in the shape and deterministic encoding lingo writes.
+    InvalidCbor,
+    /// The ASCII armor is missing its begin, end or checksum line, or has malformed headers
+    /// or base64.
+    InvalidArmor,
+    /// The armor's CRC-24 checksum does not match its contents.
+    BadChecksum,
+    /// A protobuf message is missing its ring, has an unknown scheme, or does not describe a
+    /// signature over its ring.
+    InvalidProto,
+    /// The input does not start with the format's magic bytes.
+    BadMagic,
+    /// The format version is newer than this crate understands.
+    UnsupportedVersion(u8),
+    /// The signature was made on the curve with this id, not the one requested.
+    WrongCurve(u8),
+    /// The signature was made on the curve with this name, not the one requested.
+    WrongCurveName(String),
+    /// The scheme id is not one this crate knows.
+    UnknownScheme(u8),
+    /// The message hash algorithm is not one this crate knows.
+    UnknownHash(String),
+    /// The input ends before the signature does.
+    Truncated,
+    /// Bytes follow the end of the signature.
+    TrailingBytes,
+    /// A point is off the curve or outside the prime-order subgroup, or a scalar is not
+    /// canonically encoded.
+    InvalidEncoding,
+    /// The key image is the identity or outside the prime-order subgroup.
+    InvalidKeyImage,
+    /// The ring's public keys do not form a valid ring.
+    InvalidRing(RingError),
+    /// The ring supplied for the signature has the wrong size or number of layers, or is not
+    /// the ring the signature was made over.
+    RingMismatch,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidHex => f.write_str("invalid hex"),
+            DecodeError::InvalidBase64 => f.write_str("invalid base64"),
+            DecodeError::InvalidBase45 => f.write_str("invalid base45"),
+            DecodeError::InvalidJson => f.write_str("invalid signature JSON"),
+            DecodeError::InvalidCbor => f.write_str("invalid signature CBOR"),
+            DecodeError::InvalidArmor => f.write_str("invalid signature armor"),
+            DecodeError::BadChecksum => f.write_str("armor checksum mismatch"),
+            DecodeError::InvalidProto => f.write_str("invalid signature message"),
+            DecodeError::BadMagic => f.write_str("not a lingo signature"),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {version}")
+            }
+            DecodeError::WrongCurve(id) => write!(f, "signature is on curve {id:#04x}"),
+            DecodeError::WrongCurveName(name) => write!(f, "signature is on curve {name}"),
+            DecodeError::UnknownScheme(id) => write!(f, "unknown signature scheme {id:#04x}"),
+            DecodeError::UnknownHash(name) => write!(f, "unknown hash algorithm {name}"),
+            DecodeError::Truncated => f.write_str("truncated input"),
+            DecodeError::TrailingBytes => f.write_str("trailing bytes after signature"),
+            DecodeError::InvalidEncoding => f.write_str("invalid point or scalar encoding"),
+            DecodeError::InvalidKeyImage => f.write_str("invalid key image"),
+            DecodeError::InvalidRing(error) => write!(f, "invalid ring: {error}"),
+            DecodeError::RingMismatch => f.write_str("ring does not match the signature"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Reason a test vector file failed to load or check.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VectorError {
+    /// The file is not JSON in the vector file layout, or has an unsupported version.
+    InvalidFile,
+    /// The vector at this index does not decode: its signature envelope, message, secret key
+    /// or seed is malformed, or it names another curve or scheme than the file.
+    InvalidVector(usize, DecodeError),
+    /// The expected signature of the vector at this index does not verify.
+    VerifyFailed(usize, VerifyError),
+    /// Signing the vector at this index did not reproduce its expected signature.
+    SignatureMismatch(usize),
+}
+
+impl fmt::Display for VectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorError::InvalidFile => f.write_str("invalid test vector file"),
+            VectorError::InvalidVector(index, error) => {
+                write!(f, "invalid test vector {index}: {error}")
+            }
+            VectorError::VerifyFailed(index, error) => {
+                write!(f, "test vector {index} does not verify: {error}")
+            }
+            VectorError::SignatureMismatch(index) => {
+                write!(f, "test vector {index} does not reproduce its signature")
+            }
+        }
+    }
+}
+
+impl core::error::Error for VectorError {}
+
+// every chain- or sum-based scheme ends by comparing a recomputed challenge with the stored one
+pub(crate) fn check_challenge<F: PartialEq>(computed: F, expected: F) -> Result<(), VerifyError> {
+    if computed == expected {
+        Ok(())
+    } else {
+        Err(VerifyError::ChallengeMismatch)
+    }
+}
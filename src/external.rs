@@ -0,0 +1,246 @@
+use crate::challenge::{self, ChallengeHash};
+use crate::ct;
+use crate::error::SignerError;
+use crate::key_image::KeyImage;
+use crate::ring::{self, CurvePoint, Ring, RingSignature, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, UniformRand};
+#[cfg(feature = "std")]
+use ark_std::rand::{self, rngs::ThreadRng};
+use ark_std::rand::{CryptoRng, RngCore};
+use core::convert::Infallible;
+
+// Ring signing with a key that lives outside the process. The library walks the ring and
+// simulates every decoy; the device only ever sees points to multiply and the one challenge
+// for its own slot, and hands back `alpha - c * x` without revealing `alpha` or `x`.
+
+/// A private key held by an external device such as an HSM or hardware wallet.
+///
+/// A signing session calls [`RingSigner::commit`] once, then [`RingSigner::respond`] once. The
+/// device must keep the nonce from `commit` to itself, use it for exactly one response and
+/// forget it afterwards; answering two challenges with the same nonce leaks the key.
+pub trait RingSigner<C: CurveGroup> {
+    type Error;
+
+    fn public_key(&self) -> Result<CurvePoint<C>, Self::Error>;
+
+    /// Multiplies `base` by the private key. Linkable signing calls this once, with the hash of
+    /// the signer's public key, to form the key image.
+    fn multiply(&mut self, base: &CurvePoint<C>) -> Result<CurvePoint<C>, Self::Error>;
+
+    /// Samples a fresh nonce `alpha` and returns `alpha * base` for each of `bases`.
+    fn commit(&mut self, bases: &[CurvePoint<C>]) -> Result<Vec<CurvePoint<C>>, Self::Error>;
+
+    /// Answers `challenge` with `alpha - challenge * x` for the nonce of the last commitment.
+    fn respond(&mut self, challenge: ScalarBigInt<C>) -> Result<ScalarBigInt<C>, Self::Error>;
+}
+
+/// In-process [`RingSigner`] over a [`SecretKey`], for testing device integrations against the
+/// regular signing path. Nonces are drawn from `R`.
+pub struct SoftwareSigner<C: CurveGroup, R = DefaultRng> {
+    secret: SecretScalar<C>,
+    nonce: Option<SecretScalar<C>>,
+    rng: R,
+}
+
+// without `std` there is no thread-local generator, and `with_rng` is the only constructor
+#[cfg(feature = "std")]
+type DefaultRng = ThreadRng;
+#[cfg(not(feature = "std"))]
+type DefaultRng = ark_std::rand::rngs::StdRng;
+
+#[cfg(feature = "std")]
+impl<C: CurveGroup> SoftwareSigner<C> {
+    pub fn new(private_key: &SecretKey<C>) -> SoftwareSigner<C> {
+        SoftwareSigner::with_rng(private_key, rand::thread_rng())
+    }
+}
+
+impl<C: CurveGroup, R: RngCore + CryptoRng> SoftwareSigner<C, R> {
+    pub fn with_rng(private_key: &SecretKey<C>, rng: R) -> SoftwareSigner<C, R> {
+        SoftwareSigner {
+            secret: SecretScalar::from_private_key(private_key),
+            nonce: None,
+            rng,
+        }
+    }
+}
+
+impl<C: CurveGroup, R: RngCore + CryptoRng> RingSigner<C> for SoftwareSigner<C, R> {
+    type Error = Infallible;
+
+    fn public_key(&self) -> Result<CurvePoint<C>, Infallible> {
+        Ok(ct::generator_mul::<C>(&self.secret).into_affine())
+    }
+
+    fn multiply(&mut self, base: &CurvePoint<C>) -> Result<CurvePoint<C>, Infallible> {
+        Ok(ct::secret_mul::<C>(base, &self.secret).into_affine())
+    }
+
+    fn commit(&mut self, bases: &[CurvePoint<C>]) -> Result<Vec<CurvePoint<C>>, Infallible> {
+        let nonce = SecretScalar::new(Scalar::<C>::rand(&mut self.rng));
+        let commitments = bases
+            .iter()
+            .map(|base| ct::secret_mul::<C>(base, &nonce))
+            .collect::<Vec<C>>();
+        self.nonce = Some(nonce);
+        Ok(C::normalize_batch(&commitments))
+    }
+
+    fn respond(&mut self, challenge: ScalarBigInt<C>) -> Result<ScalarBigInt<C>, Infallible> {
+        let nonce = self
+            .nonce
+            .take()
+            .expect("respond called without a commitment");
+        let challenge =
+            Scalar::<C>::from_bigint(challenge).expect("challenge must be a canonical scalar");
+        Ok((*nonce - challenge * *self.secret).into_bigint())
+    }
+}
+
+impl<C> Ring<C>
+where
+    C: CurveGroup,
+{
+    /// Produces a SAG signature with a key held by `signer`, which must be the member at
+    /// `secret_index`. The finished signature is verified before it is returned, so a device
+    /// that answers with the wrong key is caught here.
+    #[cfg(feature = "std")]
+    pub fn sign_external<S: RingSigner<C>>(
+        &self,
+        message: &[u8],
+        signer: &mut S,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, SignerError<S::Error>> {
+        self.sign_external_with_rng(message, signer, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`Ring::sign_external`], drawing the decoy responses from `rng`.
+    pub fn sign_external_with_rng<S: RingSigner<C>, R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        signer: &mut S,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, SignerError<S::Error>> {
+        self.check_signer(signer, secret_index)?;
+
+        let commitment = signer
+            .commit(&[C::generator().into_affine()])
+            .map_err(SignerError::Device)?;
+        let [commitment] = commitment[..] else {
+            return Err(SignerError::InvalidResponse);
+        };
+        let (challenges, mut responses) = self.sag_chain::<challenge::Sha512, _>(
+            challenge::Sha512::new(),
+            message,
+            secret_index,
+            commitment.into_group(),
+            rng,
+        );
+        responses[0] = respond(signer, challenges[0])?;
+        let (challenge, responses) = ring::unrotate::<C>(&challenges, &responses, secret_index);
+
+        self.finish_external(message, challenge, &responses, None)
+    }
+
+    /// Linkable counterpart of [`Ring::sign_external`], producing a bLSAG signature.
+    #[cfg(feature = "std")]
+    pub fn sign_linkable_external<S: RingSigner<C>>(
+        &self,
+        message: &[u8],
+        signer: &mut S,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, SignerError<S::Error>> {
+        self.sign_linkable_external_with_rng(message, signer, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`Ring::sign_linkable_external`], drawing the decoy responses from `rng`.
+    pub fn sign_linkable_external_with_rng<S: RingSigner<C>, R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        signer: &mut S,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, SignerError<S::Error>> {
+        self.check_signer(signer, secret_index)?;
+
+        let hashes = self.key_hashes();
+        let signer_hash = ct::select(&hashes, secret_index);
+        let image = signer.multiply(&signer_hash).map_err(SignerError::Device)?;
+        if image.is_zero() {
+            return Err(SignerError::InvalidResponse);
+        }
+        let image = KeyImage::from_point(image);
+
+        let commitment = signer
+            .commit(&[C::generator().into_affine(), signer_hash])
+            .map_err(SignerError::Device)?;
+        let [left, right] = commitment[..] else {
+            return Err(SignerError::InvalidResponse);
+        };
+        let (challenges, mut responses) = self.blsag_chain::<challenge::Sha512, _>(
+            challenge::Sha512::new(),
+            message,
+            secret_index,
+            &hashes,
+            &image,
+            (left.into_group(), right.into_group()),
+            rng,
+        );
+        responses[0] = respond(signer, challenges[0])?;
+        let (challenge, responses) = ring::unrotate::<C>(&challenges, &responses, secret_index);
+
+        self.finish_external(message, challenge, &responses, Some(image))
+    }
+
+    fn check_signer<S: RingSigner<C>>(
+        &self,
+        signer: &S,
+        secret_index: usize,
+    ) -> Result<(), SignerError<S::Error>> {
+        if secret_index >= self.size() {
+            return Err(SignerError::IndexOutOfBounds {
+                index: secret_index,
+                size: self.size(),
+            });
+        }
+        let public_key = signer.public_key().map_err(SignerError::Device)?;
+        if public_key != self.keys()[secret_index] {
+            return Err(SignerError::NotRingMember);
+        }
+        Ok(())
+    }
+
+    fn finish_external<E>(
+        &self,
+        message: &[u8],
+        challenge: Scalar<C>,
+        responses: &[Scalar<C>],
+        image: Option<KeyImage<C>>,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, SignerError<E>> {
+        let signature = RingSignature {
+            ring: self,
+            challenge: challenge.into_bigint(),
+            ring_sig_vals: responses.iter().map(|r| r.into_bigint()).collect(),
+            image,
+            hash_id: challenge::Sha512::HASH_ID,
+        };
+        signature
+            .verify(message)
+            .map_err(|_| SignerError::InvalidResponse)?;
+        Ok(signature)
+    }
+}
+
+fn respond<C: CurveGroup, S: RingSigner<C>>(
+    signer: &mut S,
+    challenge: Scalar<C>,
+) -> Result<Scalar<C>, SignerError<S::Error>> {
+    let response = signer
+        .respond(challenge.into_bigint())
+        .map_err(SignerError::Device)?;
+    Scalar::<C>::from_bigint(response).ok_or(SignerError::InvalidResponse)
+}
@@ -0,0 +1,113 @@
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+use ark_ec::CurveGroup;
+#[cfg(feature = "std")]
+use ark_ff::{BigInteger, PrimeField};
+#[cfg(feature = "std")]
+use core::any::{Any, TypeId};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::sync::{OnceLock, RwLock};
+
+// Multiplication of a curve's generator through a precomputed table. The scalar is cut into
+// bytes, and the table holds `d * 256^i * G` for every byte value `d` and position `i`, so
+// `k * G` is one table lookup and one mixed addition per byte of `k`: 32 additions for a 256-bit
+// curve against the ~256 doublings and ~128 additions of a plain multiplication.
+//
+// A curve's table is built the first time its generator is multiplied and kept for the life of
+// the process, in a registry keyed by the curve's type. That is 8192 points for a 256-bit curve,
+// a few hundred KiB. Every signature and verification multiplies the generator once per ring
+// member, so the cost is repaid within the first signature over a ring of any size.
+//
+// Each lookup reads the table at an offset given by a byte of the scalar, which a cache-timing
+// attacker can observe, so the table is for public scalars only: verification, and the decoy
+// responses a signature publishes anyway. Private keys, nonces and blindings are multiplied by
+// `ct::generator_mul` instead.
+//
+// The registry needs `std`'s locks; without `std` the generator is multiplied the plain way.
+
+#[cfg(feature = "std")]
+const WINDOW_BITS: usize = 8;
+#[cfg(feature = "std")]
+const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+
+#[cfg(feature = "std")]
+// `windows[i][d] = d * 256^i * G`
+struct Table<C: CurveGroup> {
+    windows: Vec<Vec<C::Affine>>,
+}
+
+#[cfg(feature = "std")]
+impl<C: CurveGroup> Table<C> {
+    fn new() -> Table<C> {
+        let count = (C::ScalarField::MODULUS_BIT_SIZE as usize).div_ceil(WINDOW_BITS);
+        let mut base = C::generator();
+        let windows = (0..count)
+            .map(|_| {
+                let mut multiples = Vec::with_capacity(WINDOW_SIZE);
+                let mut multiple = C::zero();
+                for _ in 0..WINDOW_SIZE {
+                    multiples.push(multiple);
+                    multiple += base;
+                }
+                base = multiple;
+                C::normalize_batch(&multiples)
+            })
+            .collect();
+        Table { windows }
+    }
+
+    // `scalar` must be public, see the comment at the top
+    fn mul(&self, scalar: &C::ScalarField) -> C {
+        let bytes = scalar.into_bigint().to_bytes_le();
+        self.windows
+            .iter()
+            .zip(bytes.iter())
+            .fold(C::zero(), |acc, (window, byte)| {
+                acc + window[*byte as usize]
+            })
+    }
+}
+
+#[cfg(feature = "std")]
+type Registry = RwLock<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>;
+
+#[cfg(feature = "std")]
+fn table<C: CurveGroup>() -> &'static Table<C> {
+    static TABLES: OnceLock<Registry> = OnceLock::new();
+    let tables = TABLES.get_or_init(Default::default);
+    let curve = TypeId::of::<C>();
+
+    let found = tables
+        .read()
+        .expect("generator table registry poisoned")
+        .get(&curve)
+        .copied();
+    let table = match found {
+        Some(table) => table,
+        None => *tables
+            .write()
+            .expect("generator table registry poisoned")
+            .entry(curve)
+            .or_insert_with(|| Box::leak(Box::new(Table::<C>::new()))),
+    };
+    table
+        .downcast_ref()
+        .expect("generator tables are keyed by their curve's type")
+}
+
+/// `scalar * G` for the generator `G` of `C` and a public `scalar`, through `C`'s precomputed
+/// table with `std`.
+pub(crate) fn mul<C: CurveGroup>(scalar: &C::ScalarField) -> C {
+    #[cfg(feature = "std")]
+    {
+        table::<C>().mul(scalar)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        C::generator() * scalar
+    }
+}
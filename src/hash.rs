@@ -0,0 +1,145 @@
+use crate::challenge::{self, ChallengeHash};
+use crate::hash_to_curve;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use sha2::{Digest, Sha512};
+
+pub(crate) fn append_point<C: CurveGroup>(hasher: &mut Sha512, point: &C::Affine) {
+    append_point_with::<C, challenge::Sha512>(hasher, b"point", point);
+}
+
+// absorbs the domain tag, every ring member and the message so each challenge is bound to the
+// full statement being signed (strong Fiat–Shamir); schemes append their key images next
+pub(crate) fn challenge_hasher<C: CurveGroup>(
+    domain: &[u8],
+    ring: &[C::Affine],
+    message: &[u8],
+) -> Sha512 {
+    challenge_state::<C, challenge::Sha512>(domain, ring, message)
+}
+
+pub(crate) fn hash_to_scalar<F: PrimeField>(hasher: Sha512) -> F {
+    state_to_scalar::<F, challenge::Sha512>(hasher)
+}
+
+// `challenge_hasher`, `append_point` and `hash_to_scalar` for any challenge hash
+pub(crate) fn challenge_state<C: CurveGroup, H: ChallengeHash>(
+    domain: &[u8],
+    ring: &[C::Affine],
+    message: &[u8],
+) -> H::State {
+    challenge_state_from::<C, H>(H::new(), domain, ring, message)
+}
+
+// `challenge_state` continuing from `state`, e.g. a transcript other proofs were appended to
+pub(crate) fn challenge_state_from<C: CurveGroup, H: ChallengeHash>(
+    state: H::State,
+    domain: &[u8],
+    ring: &[C::Affine],
+    message: &[u8],
+) -> H::State {
+    let mut state = ring_state_from::<C, H>(state, domain, ring);
+    H::append_sized(&mut state, b"message", message);
+    state
+}
+
+// the part of `challenge_state_from` before the message, which depends only on the ring and can
+// be kept for every message signed or verified over it
+pub(crate) fn ring_state_from<C: CurveGroup, H: ChallengeHash>(
+    mut state: H::State,
+    domain: &[u8],
+    ring: &[C::Affine],
+) -> H::State {
+    H::append_sized(&mut state, b"dom-sep", domain);
+    H::append_message(&mut state, b"ring-size", &(ring.len() as u64).to_le_bytes());
+    for key in ring {
+        append_point_with::<C, H>(&mut state, b"key", key);
+    }
+    state
+}
+
+pub(crate) fn append_point_with<C: CurveGroup, H: ChallengeHash>(
+    state: &mut H::State,
+    label: &'static [u8],
+    point: &C::Affine,
+) {
+    H::append_message(state, label, &point_bytes::<C>(point));
+}
+
+pub(crate) fn state_to_scalar<F: PrimeField, H: ChallengeHash>(state: H::State) -> F {
+    F::from_le_bytes_mod_order(&H::challenge(state, b"challenge"))
+}
+
+pub(crate) fn point_bytes<C: CurveGroup>(point: &C::Affine) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(point.compressed_size());
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("serializing into a Vec cannot fail");
+    bytes
+}
+
+// RFC 9380 hash_to_curve for the crate's curves; for others, try-and-increment: hash the input
+// with a counter until the digest decodes to a curve point, then clear the cofactor so the
+// result lands in the prime-order subgroup
+pub(crate) fn hash_to_point<C: CurveGroup>(domain: &[u8], message: &[u8]) -> C::Affine {
+    if let Some(point) = hash_to_curve::hash_to_curve::<C>(domain, message) {
+        return point;
+    }
+    let mut counter = 0u64;
+    loop {
+        let mut hasher = Sha512::new();
+        hasher.update((domain.len() as u64).to_le_bytes());
+        hasher.update(domain);
+        hasher.update((message.len() as u64).to_le_bytes());
+        hasher.update(message);
+        hasher.update(counter.to_le_bytes());
+        let digest = hasher.finalize();
+        if let Some(candidate) = C::Affine::from_random_bytes(&digest) {
+            let candidate = candidate.clear_cofactor();
+            if !candidate.is_zero() {
+                return candidate;
+            }
+        }
+        counter += 1;
+    }
+}
+
+const HMAC_BLOCK: usize = 128;
+
+// HMAC-SHA512 (RFC 2104) over the concatenation of `chunks`
+pub(crate) fn hmac_sha512(key: &[u8], chunks: &[&[u8]]) -> [u8; 64] {
+    let mut block = [0u8; HMAC_BLOCK];
+    if key.len() > HMAC_BLOCK {
+        block[..64].copy_from_slice(&Sha512::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha512::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    for chunk in chunks {
+        inner.update(chunk);
+    }
+    let mut outer = Sha512::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+// HKDF-SHA512 (RFC 5869): extract a pseudorandom key from `ikm`, then expand it under `info`
+pub(crate) fn hkdf_sha512(salt: &[u8], ikm: &[u8], info: &[u8], length: usize) -> Vec<u8> {
+    assert!(length <= 255 * 64);
+    let prk = hmac_sha512(salt, &[ikm]);
+    let mut okm = Vec::with_capacity(length);
+    let mut block: Vec<u8> = Vec::new();
+    let mut counter = 1u8;
+    while okm.len() < length {
+        block = hmac_sha512(&prk, &[&block, info, &[counter]]).to_vec();
+        okm.extend(&block);
+        counter += 1;
+    }
+    okm.truncate(length);
+    okm
+}
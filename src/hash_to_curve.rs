@@ -0,0 +1,620 @@
+use crate::hash;
+use crate::hex;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::short_weierstrass::{self as sw, SWCurveConfig};
+use ark_ec::twisted_edwards::{self as te, TECurveConfig};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, LegendreSymbol, One, PrimeField, Zero};
+use core::any::Any;
+use core::marker::PhantomData;
+use sha2::digest::core_api::BlockSizeUser;
+use sha2::{Digest, Sha256, Sha512};
+
+// RFC 9380 hash_to_curve for the crate's curves, behind `hash_to_point` and so every key image
+// base `H_p(P)` and derived generator. Each curve is hashed with a random-oracle
+// suite: two field elements from expand_message_xmd, each mapped to the curve, added, and the
+// sum's cofactor cleared. The DST is the caller's domain, "-with-" and the suite ID.
+//
+//   secp256k1     secp256k1_XMD:SHA-256_SSWU_RO_        (RFC suite, 3-isogeny)
+//   ed25519       edwards25519_XMD:SHA-512_ELL2_RO_     (RFC suite)
+//   p256          P256_XMD:SHA-256_SSWU_RO_             (RFC suite)
+//   bls12-381 G1  BLS12381G1_XMD:SHA-256_SSWU_RO_       (RFC suite, 11-isogeny)
+//   bn254 G1      BN254G1_XMD:SHA-256_SVDW_RO_
+//   pallas        pallas_XMD:SHA-256_SVDW_RO_
+//   vesta         vesta_XMD:SHA-256_SVDW_RO_
+//   jubjub        jubjub_XMD:SHA-256_ELL2_RO_
+//
+// The first four are the RFC's own suites and match its test vectors. The others have no RFC
+// suite; they use the RFC's Shallue–van de Woestijne map, with the Z its Appendix H.1 procedure
+// finds for each curve, or Elligator 2 through the Appendix D.1 rational map to the twisted
+// Edwards form. Curves registered by applications have no suite and keep try-and-increment.
+
+// a curve with an RFC 9380 hash-to-curve suite
+trait Suite: CurveGroup {
+    const SUITE_ID: &'static str;
+
+    fn hash_to_field(dst: &[u8], message: &[u8]) -> [Self::BaseField; 2];
+
+    fn map_to_curve(u: Self::BaseField) -> Self;
+
+    // multiplication by the suite's h_eff, the cofactor unless the RFC picks a cheaper scalar
+    fn clear_cofactor(point: Self) -> Self {
+        point.into_affine().clear_cofactor().into_group()
+    }
+}
+
+/// Hashes `message` to a point of `C`'s prime-order subgroup under `domain`, with the mapping
+/// lingo uses for key image bases and derived generators. Other protocols on the same curve
+/// can call it with their own `domain` to get points nobody knows the discrete log of.
+pub fn hash_to_point<C: CurveGroup>(domain: &[u8], message: &[u8]) -> C::Affine {
+    hash::hash_to_point::<C>(domain, message)
+}
+
+/// The RFC 9380 suite ID [`hash_to_point`] uses for `C`, or `None` for curves registered by
+/// applications, which are hashed by try-and-increment.
+pub fn suite_id<C: CurveGroup>() -> Option<&'static str> {
+    struct Id;
+    impl SuiteVisitor for Id {
+        type Output = &'static str;
+        fn visit<S: Suite>(self) -> &'static str {
+            S::SUITE_ID
+        }
+    }
+    visit_suite::<C, _>(Id)
+}
+
+/// The RFC 9380 domain separation tag [`hash_to_point`] uses for `C` under `domain`, so other
+/// implementations can reproduce its points: `domain`, then "-with-", then the suite ID.
+pub fn dst<C: CurveGroup>(domain: &[u8]) -> Option<Vec<u8>> {
+    suite_id::<C>().map(|suite| [domain, b"-with-", suite.as_bytes()].concat())
+}
+
+// `hash_to_curve` for the crate's curve `C`, or `None` if it has no suite
+pub(crate) fn hash_to_curve<C: CurveGroup>(domain: &[u8], message: &[u8]) -> Option<C::Affine> {
+    struct Hash<'h, C> {
+        domain: &'h [u8],
+        message: &'h [u8],
+        curve: PhantomData<C>,
+    }
+    impl<C: CurveGroup> SuiteVisitor for Hash<'_, C> {
+        type Output = Option<C>;
+        fn visit<S: Suite>(self) -> Option<C> {
+            let dst = [self.domain, b"-with-", S::SUITE_ID.as_bytes()].concat();
+            let point = hash_with::<S>(&dst, self.message);
+            (&point as &dyn Any).downcast_ref::<C>().copied()
+        }
+    }
+    let hash = Hash {
+        domain,
+        message,
+        curve: PhantomData,
+    };
+    visit_suite::<C, _>(hash)
+        .flatten()
+        .map(|point: C| point.into_affine())
+}
+
+// something done with the suite of the crate's curve `C`, found by its type
+trait SuiteVisitor {
+    type Output;
+    fn visit<S: Suite>(self) -> Self::Output;
+}
+
+// the single list of the crate's curves with a suite
+fn visit_suite<C: CurveGroup, V: SuiteVisitor>(visitor: V) -> Option<V::Output> {
+    use core::any::TypeId;
+    let curve = TypeId::of::<C>();
+    if curve == TypeId::of::<ark_secp256k1::Projective>() {
+        return Some(visitor.visit::<ark_secp256k1::Projective>());
+    }
+    if curve == TypeId::of::<ark_ed25519::EdwardsProjective>() {
+        return Some(visitor.visit::<ark_ed25519::EdwardsProjective>());
+    }
+    #[cfg(feature = "p256")]
+    if curve == TypeId::of::<crate::curves::p256::Projective>() {
+        return Some(visitor.visit::<crate::curves::p256::Projective>());
+    }
+    #[cfg(feature = "bls12-381")]
+    if curve == TypeId::of::<crate::curves::bls12_381::G1Projective>() {
+        return Some(visitor.visit::<crate::curves::bls12_381::G1Projective>());
+    }
+    #[cfg(feature = "bn254")]
+    if curve == TypeId::of::<crate::curves::bn254::G1Projective>() {
+        return Some(visitor.visit::<crate::curves::bn254::G1Projective>());
+    }
+    #[cfg(feature = "pallas")]
+    if curve == TypeId::of::<crate::curves::pallas::Projective>() {
+        return Some(visitor.visit::<crate::curves::pallas::Projective>());
+    }
+    #[cfg(feature = "vesta")]
+    if curve == TypeId::of::<crate::curves::vesta::Projective>() {
+        return Some(visitor.visit::<crate::curves::vesta::Projective>());
+    }
+    #[cfg(feature = "jubjub")]
+    if curve == TypeId::of::<crate::curves::jubjub::EdwardsProjective>() {
+        return Some(visitor.visit::<crate::curves::jubjub::EdwardsProjective>());
+    }
+    None
+}
+
+// hash_to_curve with the full DST, as the RFC's test vectors give it
+fn hash_with<S: Suite>(dst: &[u8], message: &[u8]) -> S {
+    let [u0, u1] = S::hash_to_field(dst, message);
+    S::clear_cofactor(S::map_to_curve(u0) + S::map_to_curve(u1))
+}
+
+// RFC 9380 §5.3.1
+fn expand_message_xmd<D: Digest + BlockSizeUser>(
+    message: &[u8],
+    dst: &[u8],
+    length: usize,
+) -> Vec<u8> {
+    let oversize;
+    let dst = if dst.len() > 255 {
+        oversize = D::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(dst)
+            .finalize();
+        &oversize[..]
+    } else {
+        dst
+    };
+    let output = <D as Digest>::output_size();
+    let blocks = length.div_ceil(output);
+    assert!(blocks <= 255 && length <= u16::MAX as usize);
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let b0 = D::new()
+        .chain_update(vec![0u8; D::block_size()])
+        .chain_update(message)
+        .chain_update((length as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut previous = D::new()
+        .chain_update(&b0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut uniform = previous.to_vec();
+    for i in 2..=blocks {
+        let mixed: Vec<u8> = b0.iter().zip(&previous).map(|(a, b)| a ^ b).collect();
+        previous = D::new()
+            .chain_update(mixed)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform.extend(&previous);
+    }
+    uniform.truncate(length);
+    uniform
+}
+
+// RFC 9380 §5.2 with k = 128
+fn hash_to_field<F: PrimeField, D: Digest + BlockSizeUser>(dst: &[u8], message: &[u8]) -> [F; 2] {
+    let length = (F::MODULUS_BIT_SIZE as usize + 128).div_ceil(8);
+    let uniform = expand_message_xmd::<D>(message, dst, 2 * length);
+    [
+        F::from_be_bytes_mod_order(&uniform[..length]),
+        F::from_be_bytes_mod_order(&uniform[length..]),
+    ]
+}
+
+fn sgn0<F: PrimeField>(x: F) -> bool {
+    x.into_bigint().is_odd()
+}
+
+fn is_square<F: PrimeField>(x: F) -> bool {
+    x.legendre() != LegendreSymbol::QuadraticNonResidue
+}
+
+fn inv0<F: PrimeField>(x: F) -> F {
+    x.inverse().unwrap_or_else(F::zero)
+}
+
+fn sqrt<F: PrimeField>(x: F) -> F {
+    x.sqrt()
+        .expect("the map only takes square roots of squares")
+}
+
+fn constant<F: PrimeField>(value: &str) -> F {
+    F::from_be_bytes_mod_order(&hex::decode(value).expect("constants are hex"))
+}
+
+// RFC 9380 §6.6.2, for y^2 = x^3 + a * x + b with a * b != 0
+fn sswu<F: PrimeField>(u: F, a: F, b: F, z: F) -> (F, F) {
+    let g = |x: F| x * x * x + a * x + b;
+    let u2 = u * u;
+    let tv1 = inv0(z * z * u2 * u2 + z * u2);
+    let x1 = if tv1.is_zero() {
+        b / (z * a)
+    } else {
+        -b / a * (F::one() + tv1)
+    };
+    let x2 = z * u2 * x1;
+    let (x, mut y) = if is_square(g(x1)) {
+        (x1, sqrt(g(x1)))
+    } else {
+        (x2, sqrt(g(x2)))
+    };
+    if sgn0(u) != sgn0(y) {
+        y = -y;
+    }
+    (x, y)
+}
+
+// RFC 9380 §6.6.1, for any y^2 = x^3 + a * x + b, with Z from Appendix H.1 and
+// c3 = sqrt(-g(Z) * (3 * Z^2 + 4 * a)) of even sign, both fixed per curve
+#[cfg(any(feature = "bn254", feature = "pallas", feature = "vesta"))]
+fn svdw<F: PrimeField>(u: F, a: F, b: F, z: F, c3: F) -> (F, F) {
+    let g = |x: F| x * x * x + a * x + b;
+    let two = F::from(2u64);
+    let gz = g(z);
+    let h = F::from(3u64) * z * z + F::from(4u64) * a;
+    let c2 = -z / two;
+    debug_assert!(c3 * c3 == -gz * h && !sgn0(c3));
+    let c4 = -F::from(4u64) * gz / h;
+
+    let tv1 = u * u * gz;
+    let tv2 = F::one() + tv1;
+    let tv1 = F::one() - tv1;
+    let tv3 = inv0(tv1 * tv2);
+    let tv4 = u * tv1 * tv3 * c3;
+    let x1 = c2 - tv4;
+    let x2 = c2 + tv4;
+    let x3 = {
+        let t = tv2 * tv2 * tv3;
+        t * t * c4 + z
+    };
+    let x = if is_square(g(x1)) {
+        x1
+    } else if is_square(g(x2)) {
+        x2
+    } else {
+        x3
+    };
+    let mut y = sqrt(g(x));
+    if sgn0(u) != sgn0(y) {
+        y = -y;
+    }
+    (x, y)
+}
+
+// RFC 9380 §6.7.1, onto the Montgomery curve k * t^2 = s^3 + j * s^2 + s
+fn elligator2<F: PrimeField>(u: F, j: F, k: F) -> (F, F) {
+    let z = (1u64..)
+        .flat_map(|ctr| [F::from(ctr), -F::from(ctr)])
+        .find(|z| !is_square(*z))
+        .expect("the field has non-squares");
+    let jk = j / k;
+    let g = |x: F| x * x * x + jk * x * x + x / (k * k);
+    let mut x1 = -jk * inv0(F::one() + z * u * u);
+    if x1.is_zero() {
+        x1 = -jk;
+    }
+    let x2 = -x1 - jk;
+    let (x, y) = if is_square(g(x1)) {
+        let y = sqrt(g(x1));
+        (x1, if sgn0(y) { y } else { -y })
+    } else {
+        let y = sqrt(g(x2));
+        (x2, if sgn0(y) { -y } else { y })
+    };
+    (x * k, y * k)
+}
+
+// Appendix D.1: the Montgomery form of a * v^2 + w^2 = 1 + d * v^2 * w^2 and its rational map
+#[cfg(feature = "jubjub")]
+fn elligator2_edwards<P: TECurveConfig>(u: P::BaseField) -> te::Projective<P>
+where
+    P::BaseField: PrimeField,
+{
+    let (a, d) = (P::COEFF_A, P::COEFF_D);
+    let j = (a + d + a + d) / (a - d);
+    let k = P::BaseField::from(4u64) / (a - d);
+    let (s, t) = elligator2(u, j, k);
+    edwards_point::<P>(s / t, t, s)
+}
+
+// (s / t, (s - 1) / (s + 1)), or the identity where the map is undefined
+fn edwards_point<P: TECurveConfig>(
+    v: P::BaseField,
+    t: P::BaseField,
+    s: P::BaseField,
+) -> te::Projective<P>
+where
+    P::BaseField: PrimeField,
+{
+    let one = P::BaseField::one();
+    if t.is_zero() || (s + one).is_zero() {
+        return te::Projective::zero();
+    }
+    let point = te::Affine::<P>::new_unchecked(v, (s - one) / (s + one));
+    debug_assert!(point.is_on_curve());
+    point.into_group()
+}
+
+// a polynomial in x, its coefficients in hex from the highest degree down, after a leading 1
+// if `monic`
+fn polynomial<F: PrimeField>(x: F, coefficients: &[&str], monic: bool) -> F {
+    let leading = if monic { F::one() } else { F::zero() };
+    coefficients.iter().fold(leading, |acc, coefficient| {
+        acc * x + constant::<F>(coefficient)
+    })
+}
+
+fn sw_point<P: SWCurveConfig>((x, y): (P::BaseField, P::BaseField)) -> sw::Projective<P> {
+    let point = sw::Affine::<P>::new_unchecked(x, y);
+    debug_assert!(point.is_on_curve());
+    point.into_group()
+}
+
+impl Suite for ark_secp256k1::Projective {
+    const SUITE_ID: &'static str = "secp256k1_XMD:SHA-256_SSWU_RO_";
+
+    fn hash_to_field(dst: &[u8], message: &[u8]) -> [ark_secp256k1::Fq; 2] {
+        hash_to_field::<_, Sha256>(dst, message)
+    }
+
+    // SSWU onto the 3-isogenous curve of RFC 9380 Appendix E.1, then the isogeny
+    fn map_to_curve(u: ark_secp256k1::Fq) -> ark_secp256k1::Projective {
+        let k = constant::<ark_secp256k1::Fq>;
+        let a = k("3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533");
+        let (x, y) = sswu(
+            u,
+            a,
+            ark_secp256k1::Fq::from(1771u64),
+            -ark_secp256k1::Fq::from(11u64),
+        );
+
+        let x_num = polynomial(
+            x,
+            &[
+                "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa88c",
+                "534c328d23f234e6e2a413deca25caece4506144037c40314ecbd0b53d9dd262",
+                "07d3d4c80bc321d5b9f315cea7fd44c5d595d2fc0bf63b92dfff1044f17c6581",
+                "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa8c7",
+            ],
+            false,
+        );
+        let x_den = polynomial(
+            x,
+            &[
+                "edadc6f64383dc1df7c4b2d51b54225406d36b641f5e41bbc52a56612a8c6d14",
+                "d35771193d94918a9ca34ccbb7b640dd86cd409542f8487d9fe6b745781eb49b",
+            ],
+            true,
+        );
+        let y_num = polynomial(
+            x,
+            &[
+                "2f684bda12f684bda12f684bda12f684bda12f684bda12f684bda12f38e38d84",
+                "29a6194691f91a73715209ef6512e576722830a201be2018a765e85a9ecee931",
+                "c75e0c32d5cb7c0fa9d0a54b12a0a6d5647ab046d686da6fdffc90fc201d71a3",
+                "4bda12f684bda12f684bda12f684bda12f684bda12f684bda12f684b8e38e23c",
+            ],
+            false,
+        );
+        let y_den = polynomial(
+            x,
+            &[
+                "6484aa716545ca2cf3a70c3fa8fe337e0a3d21162f0d6299a7bf8192bfd2a76f",
+                "7a06534bb8bdb49fd5e9e6632722c2989467c1bfc8e8d978dfb425d2685c2573",
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffff93b",
+            ],
+            true,
+        );
+        if x_den.is_zero() || y_den.is_zero() {
+            return ark_secp256k1::Projective::zero();
+        }
+        sw_point::<ark_secp256k1::Config>((x_num / x_den, y * y_num / y_den))
+    }
+}
+
+impl Suite for ark_ed25519::EdwardsProjective {
+    const SUITE_ID: &'static str = "edwards25519_XMD:SHA-512_ELL2_RO_";
+
+    fn hash_to_field(dst: &[u8], message: &[u8]) -> [ark_ed25519::Fq; 2] {
+        hash_to_field::<_, Sha512>(dst, message)
+    }
+
+    // Elligator 2 onto curve25519, then the map of RFC 9380 Appendix D.1 with the scaling
+    // sqrt(-486664) RFC 7748 uses
+    fn map_to_curve(u: ark_ed25519::Fq) -> ark_ed25519::EdwardsProjective {
+        let (s, t) = elligator2(u, ark_ed25519::Fq::from(486662u64), ark_ed25519::Fq::one());
+        let mut c1 = sqrt(-ark_ed25519::Fq::from(486664u64));
+        if sgn0(c1) {
+            c1 = -c1;
+        }
+        edwards_point::<ark_ed25519::EdwardsConfig>(c1 * s / t, t, s)
+    }
+}
+
+#[cfg(feature = "p256")]
+impl Suite for crate::curves::p256::Projective {
+    const SUITE_ID: &'static str = "P256_XMD:SHA-256_SSWU_RO_";
+
+    fn hash_to_field(dst: &[u8], message: &[u8]) -> [crate::curves::p256::Fq; 2] {
+        hash_to_field::<_, Sha256>(dst, message)
+    }
+
+    fn map_to_curve(u: crate::curves::p256::Fq) -> crate::curves::p256::Projective {
+        use crate::curves::p256::{Config, Fq};
+        sw_point::<Config>(sswu(u, Config::COEFF_A, Config::COEFF_B, -Fq::from(10u64)))
+    }
+}
+
+#[cfg(feature = "bls12-381")]
+impl Suite for crate::curves::bls12_381::G1Projective {
+    const SUITE_ID: &'static str = "BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+    fn hash_to_field(dst: &[u8], message: &[u8]) -> [crate::curves::bls12_381::Fq; 2] {
+        hash_to_field::<_, Sha256>(dst, message)
+    }
+
+    // SSWU onto the 11-isogenous curve of RFC 9380 Appendix E.2, then the isogeny
+    fn map_to_curve(u: crate::curves::bls12_381::Fq) -> crate::curves::bls12_381::G1Projective {
+        use crate::curves::bls12_381::{Config, Fq, G1Projective};
+        let (x, y) = sswu(
+            u,
+            constant::<Fq>(
+                "00144698a3b8e9433d693a02c96d4982b0ea985383ee66a8d8e8981aefd881ac98936f8da0e0f97f5cf428082d584c1d",
+            ),
+            constant::<Fq>(
+                "12e2908d11688030018b12e8753eee3b2016c1f0f24f4070a0b9c14fcef35ef55a23215a316ceaa5d1cc48e98e172be0",
+            ),
+            Fq::from(11u64),
+        );
+
+        let x_num = polynomial(
+            x,
+            &[
+                "06e08c248e260e70bd1e962381edee3d31d79d7e22c837bc23c0bf1bc24c6b68c24b1b80b64d391fa9c8ba2e8ba2d229",
+                "10321da079ce07e272d8ec09d2565b0dfa7dccdde6787f96d50af36003b14866f69b771f8c285decca67df3f1605fb7b",
+                "169b1f8e1bcfa7c42e0c37515d138f22dd2ecb803a0c5c99676314baf4bb1b7fa3190b2edc0327797f241067be390c9e",
+                "080d3cf1f9a78fc47b90b33563be990dc43b756ce79f5574a2c596c928c5d1de4fa295f296b74e956d71986a8497e317",
+                "17b81e7701abdbe2e8743884d1117e53356de5ab275b4db1a682c62ef0f2753339b7c8f8c8f475af9ccb5618e3f0c88e",
+                "0d6ed6553fe44d296a3726c38ae652bfb11586264f0f8ce19008e218f9c86b2a8da25128c1052ecaddd7f225a139ed84",
+                "1630c3250d7313ff01d1201bf7a74ab5db3cb17dd952799b9ed3ab9097e68f90a0870d2dcae73d19cd13c1c66f652983",
+                "0e99726a3199f4436642b4b3e4118e5499db995a1257fb3f086eeb65982fac18985a286f301e77c451154ce9ac8895d9",
+                "1778e7166fcc6db74e0609d307e55412d7f5e4656a8dbf25f1b33289f1b330835336e25ce3107193c5b388641d9b6861",
+                "0d54005db97678ec1d1048c5d10a9a1bce032473295983e56878e501ec68e25c958c3e3d2a09729fe0179f9dac9edcb0",
+                "17294ed3e943ab2f0588bab22147a81c7c17e75b2f6a8417f565e33c70d1e86b4838f2a6f318c356e834eef1b3cb83bb",
+                "11a05f2b1e833340b809101dd99815856b303e88a2d7005ff2627b56cdb4e2c85610c2d5f2e62d6eaeac1662734649b7",
+            ],
+            false,
+        );
+        let x_den = polynomial(
+            x,
+            &[
+                "095fc13ab9e92ad4476d6e3eb3a56680f682b4ee96f7d03776df533978f31c1593174e4b4b7865002d6384d168ecdd0a",
+                "0a10ecf6ada54f825e920b3dafc7a3cce07f8d1d7161366b74100da67f39883503826692abba43704776ec3a79a1d641",
+                "14a7ac2a9d64a8b230b3f5b074cf01996e7f63c21bca68a81996e1cdf9822c580fa5b9489d11e2d311f7d99bbdcc5a5e",
+                "0772caacf16936190f3e0c63e0596721570f5799af53a1894e2e073062aede9cea73b3538f0de06cec2574496ee84a3a",
+                "0e7355f8e4e667b955390f7f0506c6e9395735e9ce9cad4d0a43bcef24b8982f7400d24bc4228f11c02df9a29f6304a5",
+                "13a8e162022914a80a6f1d5f43e7a07dffdfc759a12062bb8d6b44e833b306da9bd29ba81f35781d539d395b3532a21e",
+                "03425581a58ae2fec83aafef7c40eb545b08243f16b1655154cca8abc28d6fd04976d5243eecf5c4130de8938dc62cd8",
+                "0b2962fe57a3225e8137e629bff2991f6f89416f5a718cd1fca64e00b11aceacd6a3d0967c94fedcfcc239ba5cb83e19",
+                "12561a5deb559c4348b4711298e536367041e8ca0cf0800c0126c2588c48bf5713daa8846cb026e9e5c8276ec82b3bff",
+                "08ca8d548cff19ae18b2e62f4bd3fa6f01d5ef4ba35b48ba9c9588617fc8ac62b558d681be343df8993cf9fa40d21b1c",
+            ],
+            true,
+        );
+        let y_num = polynomial(
+            x,
+            &[
+                "15e6be4e990f03ce4ea50b3b42df2eb5cb181d8f84965a3957add4fa95af01b2b665027efec01c7704b456be69c8b604",
+                "05c129645e44cf1102a159f748c4a3fc5e673d81d7e86568d9ab0f5d396a7ce46ba1049b6579afb7866b1e715475224b",
+                "0245a394ad1eca9b72fc00ae7be315dc757b3b080d4c158013e6632d3c40659cc6cf90ad1c232a6442d9d3f5db980133",
+                "0b182cac101b9399d155096004f53f447aa7b12a3426b08ec02710e807b4633f06c851c1919211f20d4c04f00b971ef8",
+                "18b46a908f36f6deb918c143fed2edcc523559b8aaf0c2462e6bfe7f911f643249d9cdf41b44d606ce07c8a4d0074d8e",
+                "19713e47937cd1be0dfd0b8f1d43fb93cd2fcbcb6caf493fd1183e416389e61031bf3a5cce3fbafce813711ad011c132",
+                "0e1bba7a1186bdb5223abde7ada14a23c42a0ca7915af6fe06985e7ed1e4d43b9b3f7055dd4eba6f2bafaaebca731c30",
+                "09fc4018bd96684be88c9e221e4da1bb8f3abd16679dc26c1e8b6e6a1f20cabe69d65201c78607a360370e577bdba587",
+                "0987c8d5333ab86fde9926bd2ca6c674170a05bfe3bdd81ffd038da6c26c842642f64550fedfe935a15e4ca31870fb29",
+                "04ab0b9bcfac1bbcb2c977d027796b3ce75bb8ca2be184cb5231413c4d634f3747a87ac2460f415ec961f8855fe9d6f2",
+                "16603fca40634b6a2211e11db8f0a6a074a7d0d4afadb7bd76505c3d3ad5544e203f6326c95a807299b23ab13633a5f0",
+                "08cc03fdefe0ff135caf4fe2a21529c4195536fbe3ce50b879833fd221351adc2ee7f8dc099040a841b6daecf2e8fedb",
+                "01f86376e8981c217898751ad8746757d42aa7b90eeb791c09e4a3ec03251cf9de405aba9ec61deca6355c77b0e5f4cb",
+                "00cc786baa966e66f4a384c86a3b49942552e2d658a31ce2c344be4b91400da7d26d521628b00523b8dfe240c72de1f6",
+                "134996a104ee5811d51036d776fb46831223e96c254f383d0f906343eb67ad34d6c56711962fa8bfe097e75a2e41c696",
+                "090d97c81ba24ee0259d1f094980dcfa11ad138e48a869522b52af6c956543d3cd0c7aee9b3ba3c2be9845719707bb33",
+            ],
+            false,
+        );
+        let y_den = polynomial(
+            x,
+            &[
+                "0e0fa1d816ddc03e6b24255e0d7819c171c40f65e273b853324efcd6356caa205ca2f570f13497804415473a1d634b8f",
+                "02660400eb2e4f3b628bdd0d53cd76f2bf565b94e72927c1cb748df27942480e420517bd8714cc80d1fadc1326ed06f7",
+                "0ad6b9514c767fe3c3613144b45f1496543346d98adf02267d5ceef9a00d9b8693000763e3b90ac11e99b138573345cc",
+                "0accbb67481d033ff5852c1e48c50c477f94ff8aefce42d28c0f9a88cea7913516f968986f7ebbea9684b529e2561092",
+                "04d2f259eea405bd48f010a01ad2911d9c6dd039bb61a6290e591b36e636a5c871a5c29f4f83060400f8b49cba8f6aa8",
+                "167a55cda70a6e1cea820597d94a84903216f763e13d87bb5308592e7ea7d4fbc7385ea3d529b35e346ef48bb8913f55",
+                "1866c8ed336c61231a1be54fd1d74cc4f9fb0ce4c6af5920abc5750c4bf39b4852cfe2f7bb9248836b233d9d55535d4a",
+                "16a3ef08be3ea7ea03bcddfabba6ff6ee5a4375efa1f4fd7feb34fd206357132b920f5b00801dee460ee415a15812ed9",
+                "166007c08a99db2fc3ba8734ace9824b5eecfdfa8d0cf8ef5dd365bc400a0051d5fa9c01a58b1fb93d1a1399126a775c",
+                "08d9e5297186db2d9fb266eaac783182b70152c65550d881c5ecd87b6f0f5a6449f38db9dfa9cce202c6477faaf9b7ac",
+                "0be0e079545f43e4b00cc912f8228ddcc6d19c9f0f69bbb0542eda0fc9dec916a20b15dc0fd2ededda39142311a5001d",
+                "16b7d288798e5395f20d23bf89edb4d1d115c5dbddbcd30e123da489e726af41727364f2c28297ada8d26d98445f5416",
+                "058df3306640da276faaae7d6e8eb15778c4855551ae7f310c35a5dd279cd2eca6757cd636f96f891e2538b53dbf67f2",
+                "1962d75c2381201e1a0cbd6c43c348b885c84ff731c4d59ca4a10356f453e01f78a4260763529e3532f6102c2e49a03d",
+                "16112c4c3a9c98b252181140fad0eae9601a6de578980be6eec3232b5be72e7a07f3688ef60c206d01479253b03663c1",
+            ],
+            true,
+        );
+        if x_den.is_zero() || y_den.is_zero() {
+            return G1Projective::zero();
+        }
+        sw_point::<Config>((x_num / x_den, y * y_num / y_den))
+    }
+
+    // h_eff = 1 - x for the curve parameter x, as RFC 9380 §8.8.1 gives it
+    fn clear_cofactor(point: Self) -> Self {
+        use ark_ec::PrimeGroup;
+        point.mul_bigint([0xd201000000010001])
+    }
+}
+
+// SvdW suites for short Weierstrass curves without an RFC suite of their own
+#[cfg(any(feature = "bn254", feature = "pallas", feature = "vesta"))]
+macro_rules! svdw_suite {
+    ($curve:ty, $config:ty, $field:ty, $suite:literal, $z:expr, $c3:literal) => {
+        impl Suite for $curve {
+            const SUITE_ID: &'static str = $suite;
+
+            fn hash_to_field(dst: &[u8], message: &[u8]) -> [$field; 2] {
+                hash_to_field::<_, Sha256>(dst, message)
+            }
+
+            fn map_to_curve(u: $field) -> $curve {
+                let (a, b) = (<$config>::COEFF_A, <$config>::COEFF_B);
+                sw_point::<$config>(svdw(u, a, b, $z, constant::<$field>($c3)))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "bn254")]
+svdw_suite!(
+    crate::curves::bn254::G1Projective,
+    crate::curves::bn254::Config,
+    crate::curves::bn254::Fq,
+    "BN254G1_XMD:SHA-256_SVDW_RO_",
+    crate::curves::bn254::Fq::from(1u64),
+    "00000000000000016789af3a83522eb353c98fc6b36d713d5d8d1cc5dffffffa"
+);
+
+#[cfg(feature = "pallas")]
+svdw_suite!(
+    crate::curves::pallas::Projective,
+    crate::curves::pallas::Config,
+    crate::curves::pallas::Fp,
+    "pallas_XMD:SHA-256_SVDW_RO_",
+    crate::curves::pallas::Fp::from(1u64),
+    "065225124062e143231502db2a100bbbefd6864490a792aba8567a0f9475497a"
+);
+
+#[cfg(feature = "vesta")]
+svdw_suite!(
+    crate::curves::vesta::Projective,
+    crate::curves::vesta::Config,
+    crate::curves::vesta::Fq,
+    "vesta_XMD:SHA-256_SVDW_RO_",
+    crate::curves::vesta::Fq::from(1u64),
+    "30c4f1b232eeade45431845830464409df1e1f600b0cd205e29b24ea18ba74b8"
+);
+
+#[cfg(feature = "jubjub")]
+impl Suite for crate::curves::jubjub::EdwardsProjective {
+    const SUITE_ID: &'static str = "jubjub_XMD:SHA-256_ELL2_RO_";
+
+    fn hash_to_field(dst: &[u8], message: &[u8]) -> [crate::curves::jubjub::Fq; 2] {
+        hash_to_field::<_, Sha256>(dst, message)
+    }
+
+    fn map_to_curve(u: crate::curves::jubjub::Fq) -> crate::curves::jubjub::EdwardsProjective {
+        elligator2_edwards::<crate::curves::jubjub::EdwardsConfig>(u)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::error::KeyError;
+use crate::hash;
+use crate::keypair::KeyPair;
+use alloc::vec::Vec;
+use ark_ec::CurveGroup;
+use ark_ed25519::EdwardsProjective;
+use ark_ff::{BigInteger, PrimeField, Zero};
+use core::marker::PhantomData;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+// SLIP-10 hierarchical derivation (BIP32 generalised to other curves). Only hardened children are
+// supported: ed25519 has no public derivation, and hardened paths keep sibling rings' keys
+// unlinkable even if a child private key leaks.
+
+/// Offset of the first hardened child index, written `'` in paths.
+pub const HARDENED: u32 = 1 << 31;
+
+/// A curve with a SLIP-10 derivation rule.
+pub trait Slip10Curve: CurveGroup {
+    /// HMAC key for the master node, e.g. `b"Bitcoin seed"`.
+    const CURVE_KEY: &'static [u8];
+
+    /// Turns the left half of an HMAC output into a child private key, or `None` if SLIP-10
+    /// requires deriving again. `parent` is `None` for the master node.
+    fn child_key(parent: Option<&[u8; 32]>, il: &[u8; 32]) -> Option<[u8; 32]>;
+
+    /// The signing key pair for a derived private key.
+    fn keypair(key: &[u8; 32]) -> KeyPair<Self>;
+}
+
+impl Slip10Curve for ark_secp256k1::Projective {
+    const CURVE_KEY: &'static [u8] = b"Bitcoin seed";
+
+    fn child_key(parent: Option<&[u8; 32]>, il: &[u8; 32]) -> Option<[u8; 32]> {
+        let tweak = ark_secp256k1::Fr::from_bigint(BigInteger::from_bits_be(&be_bits(il)))?;
+        let parent = match parent {
+            Some(parent) => ark_secp256k1::Fr::from_be_bytes_mod_order(parent),
+            None => ark_secp256k1::Fr::zero(),
+        };
+        let key = tweak + parent;
+        if key.is_zero() {
+            return None;
+        }
+        key.into_bigint().to_bytes_be().try_into().ok()
+    }
+
+    fn keypair(key: &[u8; 32]) -> KeyPair<Self> {
+        KeyPair::from_scalar(ark_secp256k1::Fr::from_be_bytes_mod_order(key))
+    }
+}
+
+impl Slip10Curve for EdwardsProjective {
+    const CURVE_KEY: &'static [u8] = b"ed25519 seed";
+
+    fn child_key(_parent: Option<&[u8; 32]>, il: &[u8; 32]) -> Option<[u8; 32]> {
+        Some(*il)
+    }
+
+    // derived ed25519 keys are RFC 8032 secret keys, expanded to the clamped signing scalar
+    fn keypair(key: &[u8; 32]) -> KeyPair<Self> {
+        KeyPair::from_ed25519_secret(key)
+    }
+}
+
+/// A node of a SLIP-10 derivation tree: a private key and its chain code, both zeroed on drop.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExtendedKey<C: Slip10Curve> {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+    curve: PhantomData<C>,
+}
+
+impl<C: Slip10Curve> ExtendedKey<C> {
+    /// The master node for `seed`.
+    pub fn master(seed: &[u8]) -> ExtendedKey<C> {
+        let mut output = hash::hmac_sha512(C::CURVE_KEY, &[seed]);
+        loop {
+            let (il, ir) = split(&output);
+            if let Some(key) = C::child_key(None, &il) {
+                return ExtendedKey::new(key, ir);
+            }
+            output = hash::hmac_sha512(C::CURVE_KEY, &[&output]);
+        }
+    }
+
+    /// The hardened child at `index`. Fails with [`KeyError::InvalidPath`] if `index` is below
+    /// [`HARDENED`], as only hardened derivation is supported.
+    pub fn child(&self, index: u32) -> Result<ExtendedKey<C>, KeyError> {
+        if index < HARDENED {
+            return Err(KeyError::InvalidPath);
+        }
+        let index = index.to_be_bytes();
+        let mut output = hash::hmac_sha512(&self.chain_code, &[&[0], &self.key, &index]);
+        loop {
+            let (il, ir) = split(&output);
+            if let Some(key) = C::child_key(Some(&self.key), &il) {
+                return Ok(ExtendedKey::new(key, ir));
+            }
+            output = hash::hmac_sha512(&self.chain_code, &[&[1], &ir, &index]);
+        }
+    }
+
+    /// Follows a path such as `m/44'/0'/1'` from this node. Every component must be hardened,
+    /// marked with `'` or `h`; fails with [`KeyError::InvalidPath`] for malformed paths.
+    pub fn derive_path(&self, path: &str) -> Result<ExtendedKey<C>, KeyError> {
+        let mut components = path.split('/');
+        if components.next() != Some("m") {
+            return Err(KeyError::InvalidPath);
+        }
+        components.try_fold(self.clone(), |node, component| {
+            let index = component
+                .strip_suffix('\'')
+                .or_else(|| component.strip_suffix('h'))
+                .and_then(|index| index.parse::<u32>().ok())
+                .filter(|index| *index < HARDENED)
+                .ok_or(KeyError::InvalidPath)?;
+            node.child(index + HARDENED)
+        })
+    }
+
+    pub fn private_key(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    pub fn keypair(&self) -> KeyPair<C> {
+        C::keypair(&self.key)
+    }
+
+    fn new(key: [u8; 32], chain_code: [u8; 32]) -> ExtendedKey<C> {
+        ExtendedKey {
+            key,
+            chain_code,
+            curve: PhantomData,
+        }
+    }
+}
+
+impl<C: Slip10Curve> Drop for ExtendedKey<C> {
+    fn drop(&mut self) {
+        self.key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+impl<C: Slip10Curve> ZeroizeOnDrop for ExtendedKey<C> {}
+
+fn split(output: &[u8; 64]) -> ([u8; 32], [u8; 32]) {
+    let mut il = [0u8; 32];
+    let mut ir = [0u8; 32];
+    il.copy_from_slice(&output[..32]);
+    ir.copy_from_slice(&output[32..]);
+    (il, ir)
+}
+
+fn be_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1))
+        .collect()
+}
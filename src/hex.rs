@@ -0,0 +1,129 @@
+use crate::error::{DecodeError, KeyError, RingError};
+use crate::key_image::KeyImage;
+use crate::ring::{CurvePoint, Ring, RingSignature, ScalarBigInt};
+use crate::serialize;
+use crate::wire::{DecodedSignature, WireCurve};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+
+// Lowercase hex for the text encodings. Decoding accepts either case but nothing else, not
+// even the sign `u8::from_str_radix` would let through. Public keys and key images are hex of
+// their compressed arkworks encodings, signatures hex of the `wire` format.
+
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+pub(crate) fn decode(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+// a value in its compressed arkworks encoding, with nothing left over
+pub(crate) fn decode_canonical<T>(hex: &str) -> Option<T>
+where
+    T: CanonicalDeserialize + CanonicalSerialize + Default,
+{
+    let bytes = decode(hex)?;
+    let mut reader = &bytes[..];
+    let value = serialize::read_canonical(&mut reader, Compress::Yes, Validate::Yes).ok()?;
+    reader.is_empty().then_some(value)
+}
+
+/// `point` as hex of its compressed encoding.
+pub fn public_key_to_hex<C: CurveGroup>(point: &CurvePoint<C>) -> String {
+    let mut bytes = Vec::new();
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("serializing to a Vec cannot fail");
+    encode(&bytes)
+}
+
+/// Rejects the identity and points outside the prime-order subgroup.
+pub fn public_key_from_hex<C: CurveGroup>(hex: &str) -> Result<CurvePoint<C>, KeyError> {
+    decode(hex).ok_or(KeyError::MalformedEncoding)?;
+    decode_canonical::<CurvePoint<C>>(hex)
+        .filter(|point| !point.is_zero())
+        .ok_or(KeyError::InvalidPublicKey)
+}
+
+impl<C: CurveGroup> Ring<C> {
+    /// Builds a ring from hex public keys, one per member, in the encoding
+    /// [`public_key_to_hex`] writes. Surrounding whitespace and a `0x` prefix are allowed. Fails
+    /// with [`RingError::InvalidPublicKeys`] listing every key that does not decode.
+    pub fn from_hex_keys<S: AsRef<str>>(keys: &[S]) -> Result<Ring<C>, RingError> {
+        let keys = keys.iter().map(|key| {
+            let key = key.as_ref().trim();
+            public_key_from_hex::<C>(key.strip_prefix("0x").unwrap_or(key)).ok()
+        });
+        collect_keys(keys.collect())
+    }
+
+    /// Builds a ring from a list of hex public keys separated by commas, semicolons or
+    /// whitespace, such as a CSV column or one key per line. Empty entries are skipped and
+    /// the indices in an error count the remaining keys.
+    pub fn from_hex_list(list: &str) -> Result<Ring<C>, RingError> {
+        let keys: Vec<&str> = list
+            .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            .filter(|key| !key.is_empty())
+            .collect();
+        Ring::from_hex_keys(&keys)
+    }
+}
+
+// the keys that parsed, or the positions of those that did not
+pub(crate) fn collect_keys<C: CurveGroup>(
+    keys: Vec<Option<CurvePoint<C>>>,
+) -> Result<Ring<C>, RingError> {
+    let invalid: Vec<usize> = keys
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| key.is_none())
+        .map(|(index, _)| index)
+        .collect();
+    if !invalid.is_empty() {
+        return Err(RingError::InvalidPublicKeys(invalid));
+    }
+    Ring::from_fixed_pubkeys(keys.into_iter().flatten().collect())
+}
+
+impl<C: CurveGroup> KeyImage<C> {
+    pub fn to_hex(&self) -> String {
+        public_key_to_hex::<C>(self.point())
+    }
+
+    /// Rejects the identity and points outside the prime-order subgroup.
+    pub fn from_hex(hex: &str) -> Result<KeyImage<C>, DecodeError> {
+        decode(hex).ok_or(DecodeError::InvalidHex)?;
+        decode_canonical::<CurvePoint<C>>(hex)
+            .map(KeyImage::from_point)
+            .filter(KeyImage::is_valid)
+            .ok_or(DecodeError::InvalidKeyImage)
+    }
+}
+
+impl<'a, C: WireCurve> RingSignature<'a, ScalarBigInt<C>, C> {
+    /// Hex of [`RingSignature::to_bytes`], ring included.
+    pub fn to_hex(&self) -> String {
+        encode(&self.to_bytes())
+    }
+}
+
+impl<C: WireCurve> DecodedSignature<C> {
+    pub fn to_hex(&self) -> String {
+        encode(&self.to_bytes())
+    }
+
+    /// Parses the output of [`RingSignature::to_hex`].
+    pub fn from_hex(hex: &str) -> Result<DecodedSignature<C>, DecodeError> {
+        DecodedSignature::from_bytes(&decode(hex).ok_or(DecodeError::InvalidHex)?)
+    }
+}
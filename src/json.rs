@@ -0,0 +1,170 @@
+use crate::challenge::{self, ChallengeHash};
+use crate::error::DecodeError;
+use crate::hex;
+use crate::key_image::KeyImage;
+use crate::ring::{CurvePoint, Ring, RingSignature, Scalar, ScalarBigInt};
+use crate::wire::{DecodedSignature, WireCurve};
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use serde::{Deserialize, Serialize};
+
+// A JSON envelope carrying a signature and its ring, for services that do not share a binary
+// format:
+//
+//   {
+//     "version": 1,
+//     "curve": "secp256k1",
+//     "scheme": "blsag",
+//     "hash": "keccak256",
+//     "ring": ["…", …],
+//     "challenge": "…",
+//     "responses": ["…", …],
+//     "key_image": "…"
+//   }
+//
+// `curve` is the `WireCurve::CURVE_NAME` and `scheme` is "sag" or "blsag". `hash` is the
+// `ChallengeHash::HASH_NAME` of the challenge hash and is left out for SHA-512. Points and scalars
+// are lowercase hex of their compressed arkworks encodings, and `key_image` is null for SAG.
+// Fields are written in this order without whitespace; parsing rejects unknown or duplicate
+// fields, uppercase hex, non-canonical scalars and invalid points.
+
+const VERSION: u8 = 1;
+const SAG: &str = "sag";
+const BLSAG: &str = "blsag";
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Envelope {
+    version: u8,
+    curve: String,
+    scheme: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hash: Option<String>,
+    ring: Vec<String>,
+    challenge: String,
+    responses: Vec<String>,
+    key_image: Option<String>,
+}
+
+impl<'a, C: WireCurve> RingSignature<'a, ScalarBigInt<C>, C> {
+    /// The signature and its ring as a JSON envelope, read back with
+    /// [`DecodedSignature::from_json`]. Panics if a scalar is not canonical or the challenge hash
+    /// is not one of the crate's.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&Envelope::new(self)).expect("envelope fields always serialize")
+    }
+}
+
+impl<C: WireCurve> DecodedSignature<C> {
+    pub fn to_json(&self) -> String {
+        self.signature().to_json()
+    }
+
+    /// Parses an envelope written by [`RingSignature::to_json`] for a signature on curve `C`.
+    pub fn from_json(json: &str) -> Result<DecodedSignature<C>, DecodeError> {
+        let envelope: Envelope =
+            serde_json::from_str(json).map_err(|_| DecodeError::InvalidJson)?;
+        envelope.to_signature()
+    }
+}
+
+impl Envelope {
+    pub(crate) fn new<C: WireCurve>(signature: &RingSignature<'_, ScalarBigInt<C>, C>) -> Envelope {
+        let scalar = |value: &ScalarBigInt<C>| {
+            let scalar = Scalar::<C>::from_bigint(*value).expect("signature scalars are canonical");
+            encode(&scalar)
+        };
+        let scheme = if signature.image.is_some() {
+            BLSAG
+        } else {
+            SAG
+        };
+        Envelope {
+            version: VERSION,
+            curve: C::CURVE_NAME.to_string(),
+            scheme: scheme.to_string(),
+            hash: (signature.hash_id != challenge::Sha512::HASH_ID).then(|| {
+                challenge::hash_name(signature.hash_id)
+                    .expect("challenge hash has a name")
+                    .to_string()
+            }),
+            ring: signature.ring.keys().iter().map(encode).collect(),
+            challenge: scalar(&signature.challenge),
+            responses: signature.ring_sig_vals.iter().map(scalar).collect(),
+            key_image: signature.image.map(|image| encode(image.point())),
+        }
+    }
+
+    pub(crate) fn to_signature<C: WireCurve>(&self) -> Result<DecodedSignature<C>, DecodeError> {
+        if self.version != VERSION {
+            return Err(DecodeError::UnsupportedVersion(self.version));
+        }
+        if self.curve != C::CURVE_NAME {
+            return Err(DecodeError::WrongCurveName(self.curve.clone()));
+        }
+        let linkable = match self.scheme.as_str() {
+            SAG => false,
+            BLSAG => true,
+            _ => return Err(DecodeError::InvalidJson),
+        };
+        let hash_id = match self.hash.as_deref() {
+            None => challenge::Sha512::HASH_ID,
+            // SHA-512 is only ever implied, so every signature has one envelope
+            Some(challenge::Sha512::HASH_NAME) => return Err(DecodeError::InvalidJson),
+            Some(name) => challenge::hash_id(name)
+                .ok_or_else(|| DecodeError::UnknownHash(name.to_string()))?,
+        };
+        if linkable != self.key_image.is_some() || self.responses.len() != self.ring.len() {
+            return Err(DecodeError::InvalidJson);
+        }
+
+        let keys = self
+            .ring
+            .iter()
+            .map(|key| decode::<CurvePoint<C>>(key))
+            .collect::<Result<Vec<_>, _>>()?;
+        let ring = Ring::from_fixed_pubkeys(keys).map_err(DecodeError::InvalidRing)?;
+        let challenge = decode::<Scalar<C>>(&self.challenge)?.into_bigint();
+        let responses = self
+            .responses
+            .iter()
+            .map(|response| decode::<Scalar<C>>(response).map(|r| r.into_bigint()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let image = self
+            .key_image
+            .as_deref()
+            .map(|image| {
+                Some(KeyImage::from_point(decode::<CurvePoint<C>>(image)?))
+                    .filter(KeyImage::is_valid)
+                    .ok_or(DecodeError::InvalidKeyImage)
+            })
+            .transpose()?;
+
+        Ok(DecodedSignature {
+            ring,
+            challenge,
+            ring_sig_vals: responses,
+            image,
+            hash_id,
+        })
+    }
+}
+
+pub(crate) fn encode<T: ark_serialize::CanonicalSerialize>(value: &T) -> String {
+    let mut bytes = Vec::new();
+    value
+        .serialize_compressed(&mut bytes)
+        .expect("serializing to a Vec cannot fail");
+    hex::encode(&bytes)
+}
+
+pub(crate) fn decode<T>(text: &str) -> Result<T, DecodeError>
+where
+    T: ark_serialize::CanonicalDeserialize + CanonicalSerialize + Default,
+{
+    if text.bytes().any(|c| c.is_ascii_uppercase()) {
+        return Err(DecodeError::InvalidHex);
+    }
+    hex::decode(text).ok_or(DecodeError::InvalidHex)?;
+    hex::decode_canonical(text).ok_or(DecodeError::InvalidEncoding)
+}
@@ -0,0 +1,102 @@
+use crate::ct;
+use crate::error::KeyError;
+use crate::hash;
+use crate::ring::CurvePoint;
+use crate::secret::{SecretKey, SecretScalar};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+
+/// The domain [`hash_point`] hashes public keys under with
+/// [`hash_to_point`](crate::hash_to_curve::hash_to_point).
+pub const KEY_IMAGE_DOMAIN: &[u8] = b"lingo-key-image-v1";
+
+/// The domain [`salted_hash_point`] hashes public keys and their contexts under. It differs
+/// from [`KEY_IMAGE_DOMAIN`], so no context, not even an empty one, salts a key image into the
+/// unsalted one.
+pub const SALTED_KEY_IMAGE_DOMAIN: &[u8] = b"lingo-salted-key-image-v1";
+
+/// Linking tag `k * H_p(K)` of a signer's key pair. Two linkable signatures produced with the
+/// same private key carry equal key images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyImage<C: CurveGroup>(C::Affine);
+
+impl<C: CurveGroup> KeyImage<C> {
+    pub(crate) fn derive(secret: C::ScalarField, public_key: &C::Affine) -> KeyImage<C> {
+        KeyImage(
+            ct::secret_mul::<C>(&hash_point::<C>(public_key).into_affine(), &secret).into_affine(),
+        )
+    }
+
+    pub(crate) fn from_point(point: C::Affine) -> KeyImage<C> {
+        KeyImage(point)
+    }
+
+    pub fn point(&self) -> &C::Affine {
+        &self.0
+    }
+
+    // a key image outside the prime-order subgroup (or the identity) would let one key produce
+    // several distinct images, breaking linkability
+    pub fn is_valid(&self) -> bool {
+        !self.0.is_zero() && self.0.mul_bigint(C::ScalarField::MODULUS).is_zero()
+    }
+}
+
+/// The key image of the key pair `(private_key, public_key)`, equal to the one every linkable
+/// signature made with it carries. Wallets can compute it ahead of time to index spent keys.
+///
+/// Returns [`KeyError::InvalidSecretKey`] for a zero private key and
+/// [`KeyError::InvalidPublicKey`] if `public_key` does not belong to `private_key`.
+pub fn key_image<C: CurveGroup>(
+    private_key: &SecretKey<C>,
+    public_key: &CurvePoint<C>,
+) -> Result<KeyImage<C>, KeyError> {
+    let secret = pair_secret(private_key, public_key)?;
+    Ok(KeyImage::derive(*secret, public_key))
+}
+
+/// The key image of the key pair `(private_key, public_key)` salted with `context`, equal to the
+/// one every signature made with [`Ring::sign_linkable_salted`](crate::ring::Ring::sign_linkable_salted)
+/// under that context carries.
+///
+/// Fails as [`key_image`] does.
+pub fn salted_key_image<C: CurveGroup>(
+    private_key: &SecretKey<C>,
+    public_key: &CurvePoint<C>,
+    context: &[u8],
+) -> Result<KeyImage<C>, KeyError> {
+    let secret = pair_secret(private_key, public_key)?;
+    let base = salted_hash_point::<C>(public_key, context).into_affine();
+    Ok(KeyImage(ct::secret_mul::<C>(&base, &secret).into_affine()))
+}
+
+// the public key is public, so comparing it against the derived one need not be constant time
+fn pair_secret<C: CurveGroup>(
+    private_key: &SecretKey<C>,
+    public_key: &CurvePoint<C>,
+) -> Result<SecretScalar<C>, KeyError> {
+    if private_key.expose_secret().is_zero() {
+        return Err(KeyError::InvalidSecretKey);
+    }
+    let secret = SecretScalar::<C>::from_private_key(private_key);
+    if ct::generator_mul::<C>(&secret).into_affine() != *public_key {
+        return Err(KeyError::InvalidPublicKey);
+    }
+    Ok(secret)
+}
+
+/// The base `H_p(K)` of key images for `public_key`: its compressed encoding hashed to the curve
+/// under [`KEY_IMAGE_DOMAIN`].
+pub fn hash_point<C: CurveGroup>(public_key: &C::Affine) -> C {
+    hash::hash_to_point::<C>(KEY_IMAGE_DOMAIN, &hash::point_bytes::<C>(public_key)).into_group()
+}
+
+/// The base `H_p(K || context)` of key images for `public_key` salted with `context`: the key's
+/// compressed encoding followed by `context`, hashed to the curve under
+/// [`SALTED_KEY_IMAGE_DOMAIN`]. Encodings have a fixed length, so the split between key and
+/// context is unambiguous.
+pub fn salted_hash_point<C: CurveGroup>(public_key: &C::Affine, context: &[u8]) -> C {
+    let mut input = hash::point_bytes::<C>(public_key);
+    input.extend(context);
+    hash::hash_to_point::<C>(SALTED_KEY_IMAGE_DOMAIN, &input).into_group()
+}
@@ -0,0 +1,88 @@
+use crate::ct;
+use crate::error::RingError;
+use crate::hash;
+use crate::key_image::KeyImage;
+use crate::ring::{CurvePoint, Ring, Scalar};
+use crate::secret::{SecretKey, SecretScalar};
+use ark_ec::CurveGroup;
+use ark_ff::{PrimeField, UniformRand, Zero};
+use ark_std::rand::{CryptoRng, Rng, RngCore};
+
+const SEED_SALT: &[u8] = b"lingo-keypair-seed-v1";
+
+/// A private key on curve `C` together with its public key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct KeyPair<C: CurveGroup> {
+    private_key: SecretKey<C>,
+    public_key: CurvePoint<C>,
+}
+
+impl<C: CurveGroup> KeyPair<C> {
+    /// Samples a uniformly random nonzero scalar as the private key.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> KeyPair<C> {
+        loop {
+            let secret = Scalar::<C>::rand(rng);
+            if !secret.is_zero() {
+                return KeyPair::from_scalar(secret);
+            }
+        }
+    }
+
+    /// Derives a key pair from `seed` with HKDF-SHA512, so the same seed always yields the same
+    /// keys. The derivation does not depend on the curve beyond the final reduction.
+    pub fn from_seed(seed: &[u8; 32]) -> KeyPair<C> {
+        // a zero scalar has negligible probability; retry under the next counter if it happens
+        let mut counter = 0u64;
+        loop {
+            let okm = hash::hkdf_sha512(SEED_SALT, seed, &counter.to_le_bytes(), 64);
+            let secret = Scalar::<C>::from_le_bytes_mod_order(&okm);
+            if !secret.is_zero() {
+                return KeyPair::from_scalar(secret);
+            }
+            counter += 1;
+        }
+    }
+
+    /// Wraps an existing nonzero private key.
+    pub fn from_private_key(private_key: &SecretKey<C>) -> KeyPair<C> {
+        KeyPair::from_scalar(*SecretScalar::<C>::from_private_key(private_key))
+    }
+
+    pub(crate) fn from_scalar(secret: Scalar<C>) -> KeyPair<C> {
+        assert!(!secret.is_zero());
+        KeyPair {
+            private_key: SecretKey::new(secret),
+            public_key: ct::generator_mul::<C>(&secret).into_affine(),
+        }
+    }
+
+    pub fn private_key(&self) -> &SecretKey<C> {
+        &self.private_key
+    }
+
+    pub fn public_key(&self) -> &CurvePoint<C> {
+        &self.public_key
+    }
+
+    /// The key image every linkable signature made with this key pair carries.
+    pub fn key_image(&self) -> KeyImage<C> {
+        // the pair holds together by construction, so there is nothing to check
+        KeyImage::derive(*self.private_key.expose_secret(), &self.public_key)
+    }
+
+    /// A ring of `ring_size` members holding this key pair at `index`, with the others random.
+    #[cfg(feature = "std")]
+    pub fn ring(&self, ring_size: usize, index: usize) -> Result<Ring<C>, RingError> {
+        Ring::new(ring_size, &self.private_key, index)
+    }
+
+    /// Like [`KeyPair::ring`], drawing the other members from `rng`.
+    pub fn ring_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        ring_size: usize,
+        index: usize,
+        rng: &mut R,
+    ) -> Result<Ring<C>, RingError> {
+        Ring::new_with_rng(ring_size, &self.private_key, index, rng)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::error::KeyError;
+use crate::hex;
+use crate::keypair::KeyPair;
+use crate::ring::{CurvePoint, Scalar};
+use crate::secret::SecretKey;
+use crate::serialize;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use ark_ec::CurveGroup;
+use ark_ff::Zero;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use ark_std::rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use zeroize::{Zeroize, Zeroizing};
+
+// Encrypted JSON keystores in the shape of Ethereum's Web3 Secret Storage: the private key is
+// sealed under a scrypt-stretched password and stored next to the KDF and cipher parameters. The
+// cipher is AES-256-GCM rather than AES-CTR with a Keccak MAC, and the file names the public key
+// instead of an address; the public key is bound in as associated data.
+
+const VERSION: u32 = 1;
+const CIPHER: &str = "aes-256-gcm";
+const KDF: &str = "scrypt";
+const KEY_LENGTH: usize = 32;
+
+/// scrypt cost parameters for a new keystore.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScryptParams {
+    /// Base-2 logarithm of the CPU/memory cost `N`.
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+/// Geth's standard parameters: `N = 2^18`, `r = 8`, `p = 1`, about 256 MiB per derivation.
+impl Default for ScryptParams {
+    fn default() -> ScryptParams {
+        ScryptParams {
+            log_n: 18,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+/// A password-encrypted private key, as stored on disk.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    version: u32,
+    public_key: String,
+    crypto: Crypto,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Crypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KdfParams,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct CipherParams {
+    nonce: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KdfParams {
+    dklen: usize,
+    n: u64,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+impl Keystore {
+    /// The public key the keystore was made for, readable without the password.
+    pub fn public_key<C: CurveGroup>(&self) -> Result<CurvePoint<C>, KeyError> {
+        let bytes = hex::decode(&self.public_key).ok_or(KeyError::MalformedEncoding)?;
+        serialize::read_canonical::<CurvePoint<C>, _>(&bytes[..], Compress::Yes, Validate::Yes)
+            .map_err(|_| KeyError::InvalidPublicKey)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("keystore fields always serialize")
+    }
+
+    pub fn from_json(json: &str) -> Result<Keystore, KeyError> {
+        let keystore: Keystore =
+            serde_json::from_str(json).map_err(|_| KeyError::MalformedEncoding)?;
+        if keystore.version != VERSION {
+            return Err(KeyError::MalformedEncoding);
+        }
+        if keystore.crypto.cipher != CIPHER || keystore.crypto.kdf != KDF {
+            return Err(KeyError::UnsupportedAlgorithm);
+        }
+        Ok(keystore)
+    }
+
+    /// Writes the keystore to `path`, readable only by the owner on Unix.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options.open(path)?.write_all(self.to_json().as_bytes())
+    }
+
+    /// Reads a keystore from `path`. A file that is not a keystore fails with
+    /// [`io::ErrorKind::InvalidData`] wrapping the [`KeyError`].
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Keystore> {
+        let json = std::fs::read_to_string(path)?;
+        Keystore::from_json(&json)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Re-encrypts the same key under `new_password`, with a fresh salt and nonce and the
+    /// existing scrypt parameters.
+    pub fn change_password<R: Rng + ?Sized>(
+        &self,
+        old_password: &[u8],
+        new_password: &[u8],
+        rng: &mut R,
+    ) -> Result<Keystore, KeyError> {
+        let plaintext = self.decrypt(old_password)?;
+        seal(
+            &plaintext,
+            self.public_key.clone(),
+            new_password,
+            self.params()?,
+            rng,
+        )
+    }
+
+    fn params(&self) -> Result<ScryptParams, KeyError> {
+        let kdf = &self.crypto.kdfparams;
+        if !kdf.n.is_power_of_two() || kdf.n < 2 {
+            return Err(KeyError::MalformedEncoding);
+        }
+        Ok(ScryptParams {
+            log_n: kdf.n.trailing_zeros() as u8,
+            r: kdf.r,
+            p: kdf.p,
+        })
+    }
+
+    // the plaintext is the private key's canonical little-endian encoding, whatever the curve
+    fn decrypt(&self, password: &[u8]) -> Result<Zeroizing<Vec<u8>>, KeyError> {
+        let params = self.params()?;
+        let kdf = &self.crypto.kdfparams;
+        let salt = hex::decode(&kdf.salt).ok_or(KeyError::MalformedEncoding)?;
+        let nonce = hex::decode(&self.crypto.cipherparams.nonce)
+            .filter(|nonce| nonce.len() == 12)
+            .ok_or(KeyError::MalformedEncoding)?;
+        let ciphertext = hex::decode(&self.crypto.ciphertext).ok_or(KeyError::MalformedEncoding)?;
+        if kdf.dklen != KEY_LENGTH {
+            return Err(KeyError::UnsupportedAlgorithm);
+        }
+
+        let mut key = derive_key(password, &salt, params)?;
+        let plaintext = Aes256Gcm::new(&key.into()).decrypt(
+            nonce[..].into(),
+            Payload {
+                msg: &ciphertext,
+                aad: self.public_key.as_bytes(),
+            },
+        );
+        key.zeroize();
+        plaintext
+            .map(Zeroizing::new)
+            .map_err(|_| KeyError::DecryptionFailed)
+    }
+}
+
+impl<C: CurveGroup> KeyPair<C> {
+    /// Encrypts the key pair under `password` with the default [`ScryptParams`].
+    pub fn to_keystore<R: Rng + ?Sized>(
+        &self,
+        password: &[u8],
+        rng: &mut R,
+    ) -> Result<Keystore, KeyError> {
+        self.to_keystore_with_params(password, ScryptParams::default(), rng)
+    }
+
+    /// Encrypts the key pair under `password` with the given scrypt cost.
+    ///
+    /// Returns [`KeyError::UnsupportedAlgorithm`] if scrypt rejects `params`.
+    pub fn to_keystore_with_params<R: Rng + ?Sized>(
+        &self,
+        password: &[u8],
+        params: ScryptParams,
+        rng: &mut R,
+    ) -> Result<Keystore, KeyError> {
+        let mut plaintext = Zeroizing::new(Vec::new());
+        self.private_key()
+            .expose_secret()
+            .serialize_compressed(&mut *plaintext)
+            .expect("serializing to a Vec cannot fail");
+        seal(
+            &plaintext,
+            hex::public_key_to_hex::<C>(self.public_key()),
+            password,
+            params,
+            rng,
+        )
+    }
+
+    /// Decrypts a keystore made for a key on curve `C`.
+    pub fn from_keystore(keystore: &Keystore, password: &[u8]) -> Result<KeyPair<C>, KeyError> {
+        let public_key = keystore.public_key::<C>()?;
+        let plaintext = keystore.decrypt(password)?;
+        let secret = Scalar::<C>::deserialize_compressed(&plaintext[..])
+            .ok()
+            .filter(|secret| !secret.is_zero())
+            .ok_or(KeyError::InvalidSecretKey)?;
+        let keypair = KeyPair::from_private_key(&SecretKey::new(secret));
+        if *keypair.public_key() != public_key {
+            return Err(KeyError::InvalidPublicKey);
+        }
+        Ok(keypair)
+    }
+
+    /// Replaces the key in `keystore` with a freshly generated one under the same password and
+    /// scrypt parameters. The old keystore is only read, so the caller decides when to retire it.
+    pub fn rotate_keystore<R: Rng + ?Sized>(
+        keystore: &Keystore,
+        password: &[u8],
+        rng: &mut R,
+    ) -> Result<(KeyPair<C>, Keystore), KeyError> {
+        KeyPair::<C>::from_keystore(keystore, password)?;
+        let keypair = KeyPair::<C>::generate(rng);
+        let rotated = keypair.to_keystore_with_params(password, keystore.params()?, rng)?;
+        Ok((keypair, rotated))
+    }
+}
+
+fn seal<R: Rng + ?Sized>(
+    plaintext: &[u8],
+    public_key: String,
+    password: &[u8],
+    params: ScryptParams,
+    rng: &mut R,
+) -> Result<Keystore, KeyError> {
+    let salt: [u8; 32] = rng.r#gen();
+    let nonce: [u8; 12] = rng.r#gen();
+    let mut key = derive_key(password, &salt, params)?;
+    let ciphertext = Aes256Gcm::new(&key.into())
+        .encrypt(
+            &nonce.into(),
+            Payload {
+                msg: plaintext,
+                aad: public_key.as_bytes(),
+            },
+        )
+        .expect("a private key is within the AES-GCM length limit");
+    key.zeroize();
+
+    Ok(Keystore {
+        version: VERSION,
+        public_key,
+        crypto: Crypto {
+            cipher: CIPHER.to_string(),
+            cipherparams: CipherParams {
+                nonce: hex::encode(&nonce),
+            },
+            ciphertext: hex::encode(&ciphertext),
+            kdf: KDF.to_string(),
+            kdfparams: KdfParams {
+                dklen: KEY_LENGTH,
+                n: 1 << params.log_n,
+                r: params.r,
+                p: params.p,
+                salt: hex::encode(&salt),
+            },
+        },
+    })
+}
+
+fn derive_key(
+    password: &[u8],
+    salt: &[u8],
+    params: ScryptParams,
+) -> Result<[u8; KEY_LENGTH], KeyError> {
+    // scrypt takes n = 1, but a keystore with it could never be opened again
+    if params.log_n == 0 {
+        return Err(KeyError::UnsupportedAlgorithm);
+    }
+    let params = scrypt::Params::new(params.log_n, params.r, params.p, KEY_LENGTH)
+        .map_err(|_| KeyError::UnsupportedAlgorithm)?;
+    let mut key = [0u8; KEY_LENGTH];
+    scrypt::scrypt(password, salt, &params, &mut key).expect("output length is 32 bytes");
+    Ok(key)
+}
@@ -1 +1,79 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod aggregate;
+pub mod aos;
+mod armor;
+mod base64;
+mod blake2b;
+pub mod blind;
+pub mod borromean;
+#[cfg(feature = "borsh")]
+mod borsh_impls;
+pub mod builder;
+mod cbor;
+pub mod challenge;
+pub mod claim;
+pub mod clsag;
+mod compact;
+pub mod context;
+mod ct;
+pub mod curves;
+mod der;
+pub mod designated;
+pub mod ed25519;
+pub mod error;
+pub mod external;
+mod generator;
+mod hash;
+pub mod hash_to_curve;
+pub mod hd;
+pub mod hex;
+#[cfg(feature = "json")]
+mod json;
+pub mod key_image;
+pub mod keypair;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+pub mod merkle;
+pub mod mlsag;
+#[cfg(feature = "mnemonic")]
+pub mod mnemonic;
+pub mod monero;
+mod nonce;
+pub mod one_of_many;
+mod parallel;
+pub mod pem;
+pub mod pkcs8;
+#[cfg(feature = "poseidon")]
+pub mod poseidon;
+pub mod prepared;
+#[cfg(feature = "proto")]
+pub mod proto;
+#[cfg(feature = "std")]
+pub mod registry;
+pub mod revocable;
 pub mod ring;
+pub mod ristretto;
+pub mod scheme;
+mod sec1;
+pub mod secp256k1;
+pub mod secret;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod serialize;
+#[cfg(feature = "std")]
+pub mod sshsig;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(feature = "json")]
+pub mod test_vectors;
+pub mod threshold;
+mod trace;
+pub mod traceable;
+pub mod transcript;
+pub mod triptych;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod wire;
@@ -0,0 +1,261 @@
+use crate::error::{RingError, VerifyError};
+use crate::hash;
+use crate::ring::{CurvePoint, Ring, RingSignature, ScalarBigInt};
+use crate::secret::SecretKey;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::CurveGroup;
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+const MERKLE_DOMAIN: &[u8] = b"lingo-merkle-v1";
+
+const LEAF: u8 = 0;
+const NODE: u8 = 1;
+// fills the tree out to a power of two; not the hash of any leaf
+const EMPTY: [u8; 32] = [0; 32];
+
+// A large anonymity set committed to by a Merkle root. Signers pick a ring out of the set and
+// sign over it as usual, with the root bound into the message; each ring member ships with a
+// path from its leaf to the root. Verifiers then only keep the root rather than the whole set,
+// and still need every key of the (small) ring itself, since the signature is checked over it.
+//
+// Leaves are `H(0x00 || key)` and nodes `H(0x01 || left || right)`, where `H` is SHA-512 cut to
+// 32 bytes and keys use their compressed encoding. The leaves are padded with zeros to a power
+// of two, so every path has the same length.
+
+/// A Merkle tree over a set of public keys, from which rings with membership paths are drawn.
+pub struct KeyTree<C: CurveGroup> {
+    keys: Vec<CurvePoint<C>>,
+    // leaves first, the root last
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl<C: CurveGroup> KeyTree<C> {
+    /// Applies the same checks to `keys` as [`Ring::from_fixed_pubkeys`].
+    pub fn new(keys: Vec<CurvePoint<C>>) -> Result<KeyTree<C>, RingError> {
+        let keys = Ring::<C>::from_fixed_pubkeys(keys)?.keys().to_vec();
+        let mut leaves: Vec<[u8; 32]> = keys.iter().map(leaf::<C>).collect();
+        leaves.resize(keys.len().next_power_of_two(), EMPTY);
+
+        let mut levels = vec![leaves];
+        while let [.., level] = levels.as_slice()
+            && level.len() > 1
+        {
+            let parents = level.chunks_exact(2).map(|pair| node(&pair[0], &pair[1]));
+            levels.push(parents.collect());
+        }
+        Ok(KeyTree { keys, levels })
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().expect("a tree has at least one level")[0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn keys(&self) -> &[CurvePoint<C>] {
+        &self.keys
+    }
+
+    /// The membership path of the key at `index`, or `None` if the set has no such key.
+    pub fn path(&self, index: usize) -> Option<MerklePath> {
+        if index >= self.keys.len() {
+            return None;
+        }
+        let levels = &self.levels[..self.levels.len() - 1];
+        let siblings = levels
+            .iter()
+            .enumerate()
+            .map(|(height, level)| level[(index >> height) ^ 1])
+            .collect();
+        Some(MerklePath {
+            index: index as u64,
+            siblings,
+        })
+    }
+
+    /// The ring made of the keys at `indices`, in that order, with their membership paths.
+    pub fn ring(&self, indices: &[usize]) -> Result<MerkleRing<C>, RingError> {
+        let size = self.keys.len();
+        let paths = indices
+            .iter()
+            .map(|&index| {
+                self.path(index)
+                    .ok_or(RingError::IndexOutOfBounds { index, size })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if paths.is_empty() {
+            return Err(RingError::EmptyRing);
+        }
+        let keys = indices.iter().map(|&index| self.keys[index]).collect();
+        Ok(MerkleRing {
+            ring: Ring::from_unchecked_pubkeys(keys),
+            paths,
+        })
+    }
+}
+
+/// The sibling hashes from a leaf up to the root, and the leaf's position in the set.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerklePath {
+    pub index: u64,
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl MerklePath {
+    /// The root reached by hashing `key` up the path.
+    pub fn root<C: CurveGroup>(&self, key: &CurvePoint<C>) -> Option<[u8; 32]> {
+        if self.siblings.len() < 64 && self.index >> self.siblings.len() != 0 {
+            return None;
+        }
+        let mut hash = leaf::<C>(key);
+        for (height, sibling) in self.siblings.iter().enumerate() {
+            hash = match (self.index >> height) & 1 {
+                0 => node(&hash, sibling),
+                _ => node(sibling, &hash),
+            };
+        }
+        Some(hash)
+    }
+}
+
+/// A ring drawn from a [`KeyTree`], with one membership path per member.
+pub struct MerkleRing<C: CurveGroup> {
+    ring: Ring<C>,
+    paths: Vec<MerklePath>,
+}
+
+impl<C: CurveGroup> MerkleRing<C> {
+    pub fn ring(&self) -> &Ring<C> {
+        &self.ring
+    }
+
+    pub fn paths(&self) -> &[MerklePath] {
+        &self.paths
+    }
+
+    /// The root the paths lead to, taken from the first member.
+    pub fn root(&self) -> [u8; 32] {
+        self.paths[0]
+            .root::<C>(&self.ring.keys()[0])
+            .expect("paths from a tree are well formed")
+    }
+
+    /// A SAG signature over `message` and the root of the set the ring was drawn from.
+    #[cfg(feature = "std")]
+    pub fn sign(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<MerkleSignature<'_, C>, RingError> {
+        self.sign_with_rng(message, private_key, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`MerkleRing::sign`], drawing the nonces from `rng`.
+    pub fn sign_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<MerkleSignature<'_, C>, RingError> {
+        let bound = bind(&self.root(), message);
+        Ok(MerkleSignature {
+            signature: self
+                .ring
+                .sign_with_rng(&bound, private_key, secret_index, rng)?,
+            paths: &self.paths,
+        })
+    }
+
+    /// A linkable bLSAG signature over `message` and the root of the set.
+    #[cfg(feature = "std")]
+    pub fn sign_linkable(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<MerkleSignature<'_, C>, RingError> {
+        self.sign_linkable_with_rng(message, private_key, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`MerkleRing::sign_linkable`], drawing the nonces from `rng`.
+    pub fn sign_linkable_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<MerkleSignature<'_, C>, RingError> {
+        let bound = bind(&self.root(), message);
+        Ok(MerkleSignature {
+            signature: self
+                .ring
+                .sign_linkable_with_rng(&bound, private_key, secret_index, rng)?,
+            paths: &self.paths,
+        })
+    }
+}
+
+/// A ring signature together with the membership paths of its ring.
+pub struct MerkleSignature<'a, C: CurveGroup> {
+    pub signature: RingSignature<'a, ScalarBigInt<C>, C>,
+    pub paths: &'a [MerklePath],
+}
+
+impl<'a, C: CurveGroup> MerkleSignature<'a, C> {
+    /// Checks that every ring member belongs to the set committed to by `root`, then the
+    /// signature over `message` and `root`.
+    pub fn verify(&self, root: &[u8; 32], message: &[u8]) -> Result<(), VerifyError> {
+        let keys = self.signature.public_keys();
+        if keys.len() != self.paths.len() {
+            return Err(VerifyError::LengthMismatch);
+        }
+        for (key, path) in keys.iter().zip(self.paths) {
+            if path.root::<C>(key).as_ref() != Some(root) {
+                return Err(VerifyError::NotAMember);
+            }
+        }
+        self.signature.verify(&bind(root, message))
+    }
+}
+
+fn leaf<C: CurveGroup>(key: &CurvePoint<C>) -> [u8; 32] {
+    let mut hasher = Sha512::new();
+    hasher.update([LEAF]);
+    hasher.update(hash::point_bytes::<C>(key));
+    truncate(hasher)
+}
+
+fn node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha512::new();
+    hasher.update([NODE]);
+    hasher.update(left);
+    hasher.update(right);
+    truncate(hasher)
+}
+
+fn truncate(hasher: Sha512) -> [u8; 32] {
+    hasher.finalize()[..32]
+        .try_into()
+        .expect("SHA-512 digests are 64 bytes")
+}
+
+// the domain and root have fixed lengths, so the message can follow them directly
+fn bind(root: &[u8; 32], message: &[u8]) -> Vec<u8> {
+    let mut bound = Vec::with_capacity(MERKLE_DOMAIN.len() + root.len() + message.len());
+    bound.extend(MERKLE_DOMAIN);
+    bound.extend(root);
+    bound.extend(message);
+    bound
+}
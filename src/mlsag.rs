@@ -0,0 +1,308 @@
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::key_image::{self, KeyImage};
+use crate::ring::{CurvePoint, Ring, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, UniformRand};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+
+const MLSAG_DOMAIN: &[u8] = b"lingo-mlsag-v1";
+
+/// An `n x m` ring: `n` members, each holding one key in every one of the `m` layers. Layer `j`
+/// is an ordinary [`Ring`] made of the `j`-th key of every member.
+#[derive(PartialEq, Eq)]
+pub struct MatrixRing<C>
+where
+    C: CurveGroup,
+{
+    layers: Vec<Ring<C>>,
+}
+
+impl<C> MatrixRing<C>
+where
+    C: CurveGroup,
+{
+    /// Stacks rings of the same size into layers, the first holding each member's linkable key.
+    /// Fails if there are no layers, listing the indices of any whose size differs from the
+    /// first's.
+    pub fn from_layers(layers: Vec<Ring<C>>) -> Result<MatrixRing<C>, RingError> {
+        let members = layers.first().ok_or(RingError::EmptyRing)?.size();
+        let mismatched = mismatches(layers.iter().map(Ring::size), members);
+        if !mismatched.is_empty() {
+            return Err(RingError::LayerSizeMismatch(mismatched));
+        }
+        Ok(MatrixRing { layers })
+    }
+
+    /// Builds the ring from each member's keys, one per layer. Every key must pass
+    /// [`Ring::from_fixed_pubkeys`]; the error lists the members holding one that does not, or
+    /// whose number of keys differs from the first member's.
+    pub fn from_members(members: &[Vec<CurvePoint<C>>]) -> Result<MatrixRing<C>, RingError> {
+        let width = members.first().ok_or(RingError::EmptyRing)?.len();
+        let mismatched = mismatches(members.iter().map(Vec::len), width);
+        if !mismatched.is_empty() {
+            return Err(RingError::MemberWidthMismatch(mismatched));
+        }
+
+        let mut layers = Vec::with_capacity(width);
+        let mut invalid = BTreeSet::new();
+        for j in 0..width {
+            match Ring::from_fixed_pubkeys(members.iter().map(|member| member[j]).collect()) {
+                Ok(layer) => layers.push(layer),
+                Err(RingError::InvalidPublicKeys(indices)) => invalid.extend(indices),
+                Err(error) => return Err(error),
+            }
+        }
+        if !invalid.is_empty() {
+            return Err(RingError::InvalidPublicKeys(invalid.into_iter().collect()));
+        }
+        MatrixRing::from_layers(layers)
+    }
+
+    /// Number of ring members (rows).
+    pub fn size(&self) -> usize {
+        self.layers[0].size()
+    }
+
+    /// Number of keys per member (columns).
+    pub fn width(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn layers(&self) -> &[Ring<C>] {
+        &self.layers
+    }
+
+    pub fn member(&self, index: usize) -> Vec<CurvePoint<C>> {
+        self.layers
+            .iter()
+            .map(|layer| layer.keys()[index])
+            .collect()
+    }
+
+    // member-major flattening so the challenge commits to the whole matrix
+    pub(crate) fn flattened(&self) -> Vec<CurvePoint<C>> {
+        (0..self.size()).flat_map(|i| self.member(i)).collect()
+    }
+
+    /// Produces an MLSAG signature over `message` for the member at `secret_index`, using one
+    /// private key per layer. Fails with [`RingError::KeyMismatch`] unless there are as many
+    /// private keys as layers and each belongs to the member's key in its layer.
+    #[cfg(feature = "std")]
+    pub fn sign(
+        &self,
+        message: &[u8],
+        private_keys: &[SecretKey<C>],
+        secret_index: usize,
+    ) -> Result<MlsagSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_with_rng(message, private_keys, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`MatrixRing::sign`], drawing the nonces and decoy responses from `rng`.
+    pub fn sign_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_keys: &[SecretKey<C>],
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<MlsagSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        let n = self.size();
+        let m = self.width();
+        if secret_index >= n {
+            return Err(RingError::IndexOutOfBounds {
+                index: secret_index,
+                size: n,
+            });
+        }
+        if private_keys.len() != m {
+            return Err(RingError::KeyMismatch);
+        }
+
+        // the chain is walked over the members rotated so the signer is at position 0, as
+        // `Ring::sign` walks its ring (see `ct`)
+        let members = ct::rotate_left(
+            &(0..n).map(|i| self.member(i)).collect::<Vec<_>>(),
+            secret_index,
+        );
+        let signer = &members[0];
+        let secrets: Vec<SecretScalar<C>> = private_keys
+            .iter()
+            .zip(&self.layers)
+            .map(|(private_key, layer)| layer.signer_secret(private_key, secret_index))
+            .collect::<Result<_, _>>()?;
+        let images: Vec<KeyImage<C>> = secrets
+            .iter()
+            .zip(signer)
+            .map(|(secret, public_key)| KeyImage::derive(**secret, public_key))
+            .collect();
+
+        let prefix = challenge_prefix::<C>(&self.flattened(), message, &images);
+        let challenge = |points: Vec<C>| {
+            let mut hasher = prefix.clone();
+            for point in C::normalize_batch(&points) {
+                hash::append_point::<C>(&mut hasher, &point);
+            }
+            hash::hash_to_scalar::<Scalar<C>>(hasher)
+        };
+
+        let mut challenges = vec![Scalar::<C>::from(0u64); n];
+        let mut responses = vec![vec![Scalar::<C>::from(0u64); m]; n];
+
+        let alphas: Vec<SecretScalar<C>> = (0..m).map(|_| SecretScalar::random(rng)).collect();
+        let commitments = alphas
+            .iter()
+            .zip(signer)
+            .flat_map(|(alpha, key)| {
+                [
+                    ct::generator_mul::<C>(alpha),
+                    ct::secret_mul::<C>(&key_image::hash_point::<C>(key).into_affine(), alpha),
+                ]
+            })
+            .collect();
+        challenges[1 % n] = challenge(commitments);
+
+        for k in 1..n {
+            for response in responses[k].iter_mut() {
+                *response = Scalar::<C>::rand(rng);
+            }
+            let points = layer_points::<C>(&members[k], &images, &responses[k], challenges[k]);
+            challenges[(k + 1) % n] = challenge(points);
+        }
+
+        for (j, secret) in secrets.iter().enumerate() {
+            responses[0][j] = alphas[j].response(&challenges[0], secret);
+        }
+        let first = ct::select(&challenges, ct::complement(secret_index, n));
+        let responses = ct::rotate_right(&responses, secret_index);
+
+        Ok(MlsagSignature {
+            ring: self,
+            challenge: first.into_bigint(),
+            ring_sig_vals: responses
+                .iter()
+                .map(|row| row.iter().map(|r| r.into_bigint()).collect())
+                .collect(),
+            images,
+        })
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct MlsagSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: &'a MatrixRing<C>,
+    pub challenge: B,
+    pub ring_sig_vals: Vec<Vec<B>>,
+    pub images: Vec<KeyImage<C>>,
+}
+
+impl<'a, B, C> MlsagSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn ring(&self) -> &MatrixRing<C> {
+        self.ring
+    }
+
+    pub fn key_images(&self) -> &[KeyImage<C>] {
+        &self.images
+    }
+}
+
+impl<'a, C> MlsagSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        let n = self.ring.size();
+        let m = self.ring.width();
+        if self.images.len() != m || self.ring_sig_vals.len() != n {
+            return Err(VerifyError::LengthMismatch);
+        }
+        if self.ring_sig_vals.iter().any(|row| row.len() != m) {
+            return Err(VerifyError::LengthMismatch);
+        }
+        if !self.images.iter().all(KeyImage::is_valid) {
+            return Err(VerifyError::InvalidKeyImage);
+        }
+
+        let flattened = self.ring.flattened();
+        if flattened.iter().any(|key| key.is_zero()) {
+            return Err(VerifyError::InvalidPoint);
+        }
+
+        let initial =
+            Scalar::<C>::from_bigint(self.challenge).ok_or(VerifyError::NonCanonicalScalar)?;
+
+        let prefix = challenge_prefix::<C>(&flattened, message, &self.images);
+        let mut current = initial;
+        for (i, row) in self.ring_sig_vals.iter().enumerate() {
+            let responses = row
+                .iter()
+                .map(|r| Scalar::<C>::from_bigint(*r))
+                .collect::<Option<Vec<_>>>()
+                .ok_or(VerifyError::NonCanonicalScalar)?;
+            let points = layer_points::<C>(&self.ring.member(i), &self.images, &responses, current);
+            let mut hasher = prefix.clone();
+            for point in C::normalize_batch(&points) {
+                hash::append_point::<C>(&mut hasher, &point);
+            }
+            current = hash::hash_to_scalar(hasher);
+        }
+
+        error::check_challenge(current, initial)
+    }
+}
+
+fn challenge_prefix<C: CurveGroup>(
+    keys: &[CurvePoint<C>],
+    message: &[u8],
+    images: &[KeyImage<C>],
+) -> sha2::Sha512 {
+    let mut prefix = hash::challenge_hasher::<C>(MLSAG_DOMAIN, keys, message);
+    for image in images {
+        hash::append_point::<C>(&mut prefix, image.point());
+    }
+    prefix
+}
+
+// `r G + c K` and `r H_p(K) + c I` for every layer of one member
+fn layer_points<C: CurveGroup>(
+    member: &[CurvePoint<C>],
+    images: &[KeyImage<C>],
+    responses: &[Scalar<C>],
+    challenge: Scalar<C>,
+) -> Vec<C> {
+    member
+        .iter()
+        .zip(images)
+        .zip(responses)
+        .flat_map(|((key, image), response)| {
+            [
+                generator::mul::<C>(response) + *key * challenge,
+                key_image::hash_point::<C>(key) * response + *image.point() * challenge,
+            ]
+        })
+        .collect()
+}
+
+// the positions whose length is not `expected`
+fn mismatches(lengths: impl Iterator<Item = usize>, expected: usize) -> Vec<usize> {
+    lengths
+        .enumerate()
+        .filter(|(_, length)| *length != expected)
+        .map(|(i, _)| i)
+        .collect()
+}
@@ -0,0 +1,57 @@
+use crate::error::KeyError;
+use crate::hd::{ExtendedKey, Slip10Curve};
+use crate::keypair::KeyPair;
+use ark_std::rand::Rng;
+use bip39::{Language, Mnemonic};
+
+// BIP39 recovery phrases. A phrase and optional passphrase stretch to a 64-byte seed that roots a
+// SLIP-10 tree, so one backup covers ring-signing keys on every supported curve.
+
+/// Length of a generated phrase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WordCount {
+    /// 128 bits of entropy.
+    Twelve,
+    /// 256 bits of entropy.
+    TwentyFour,
+}
+
+/// A fresh English phrase drawn from `rng`.
+pub fn generate<R: Rng + ?Sized>(words: WordCount, rng: &mut R) -> String {
+    let entropy: [u8; 32] = rng.r#gen();
+    let length = match words {
+        WordCount::Twelve => 16,
+        WordCount::TwentyFour => 32,
+    };
+    Mnemonic::from_entropy_in(Language::English, &entropy[..length])
+        .expect("16 and 32 bytes are valid BIP39 entropy lengths")
+        .to_string()
+}
+
+/// The 64-byte BIP39 seed for `phrase`, after checking its words and checksum. Both inputs are
+/// NFKD-normalised as the standard requires.
+pub fn to_seed(phrase: &str, passphrase: &str) -> Result<[u8; 64], KeyError> {
+    let mnemonic =
+        Mnemonic::parse_in(Language::English, phrase).map_err(|_| KeyError::InvalidMnemonic)?;
+    Ok(mnemonic.to_seed(passphrase))
+}
+
+impl<C: Slip10Curve> ExtendedKey<C> {
+    /// The master node for a recovery phrase.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<ExtendedKey<C>, KeyError> {
+        Ok(ExtendedKey::master(&to_seed(phrase, passphrase)?))
+    }
+}
+
+impl<C: Slip10Curve> KeyPair<C> {
+    /// The key pair at `path`, e.g. `m/44'/60'/0'`, under a recovery phrase.
+    pub fn from_mnemonic(
+        phrase: &str,
+        passphrase: &str,
+        path: &str,
+    ) -> Result<KeyPair<C>, KeyError> {
+        ExtendedKey::<C>::from_mnemonic(phrase, passphrase)?
+            .derive_path(path)
+            .map(|node| node.keypair())
+    }
+}
@@ -0,0 +1,384 @@
+use crate::clsag::ClsagSignature;
+use crate::ed25519;
+use crate::error::{self, DecodeError, VerifyError};
+use crate::generator;
+use crate::key_image::KeyImage;
+use crate::mlsag::MatrixRing;
+use crate::ring::ScalarBigInt;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ed25519::{EdwardsAffine, EdwardsProjective, Fq, Fr};
+use ark_ff::{AdditiveGroup, BigInt, BigInteger, Field, PrimeField, Zero};
+use sha3::{Digest, Keccak256};
+
+// Monero's on-chain CLSAG layout (`rctSigPrunable::CLSAGs`): the responses s_0..s_{n-1}, the
+// challenge c1 at index 0 and the commitment image D scaled by 1/8, each 32 bytes. Points use
+// the RFC 8032 encoding and scalars 32 little-endian bytes. The key image lives in the
+// transaction input and the ring size comes from the input's key offsets, so neither is part of
+// the signature bytes.
+//
+// lingo's own CLSAG transcript (SHA-512, its own domain tags and hash-to-point) is not Monero's,
+// so converting between `ClsagSignature` and `MoneroClsag` only shares the layout. Signatures
+// taken from real transactions are checked with `MoneroClsag::verify` and `MoneroMlsag::verify`
+// instead, which follow Monero's `CLSAG_Ver` and `MLSAG_Ver` (Keccak-256 transcripts and
+// `hash_to_ec`) for the per-input rings every RingCT type since `RCTTypeSimple` uses.
+
+type ScalarBytes = [u8; 32];
+
+// domain separators, zero-padded to 32 bytes in the transcripts
+const CLSAG_AGG_0: &[u8] = b"CLSAG_agg_0";
+const CLSAG_AGG_1: &[u8] = b"CLSAG_agg_1";
+const CLSAG_ROUND: &[u8] = b"CLSAG_round";
+
+// Montgomery coefficient of curve25519
+const A: u64 = 486662;
+
+/// A ring member of a RingCT input (Monero's `ctkey`): the output's one-time key and its amount
+/// commitment, as they appear on chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CtKey {
+    pub dest: [u8; 32],
+    pub mask: [u8; 32],
+}
+
+/// A CLSAG signature in Monero's serialization, for a two-layer ring of one-time keys and
+/// commitments.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MoneroClsag {
+    pub s: Vec<ScalarBytes>,
+    pub c1: ScalarBytes,
+    /// The commitment key image multiplied by the inverse of 8.
+    pub d: [u8; 32],
+}
+
+impl MoneroClsag {
+    /// `s || c1 || D`, as in a transaction's prunable data.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 * (self.s.len() + 2));
+        for s in &self.s {
+            bytes.extend(s);
+        }
+        bytes.extend(self.c1);
+        bytes.extend(self.d);
+        bytes
+    }
+
+    /// Splits the bytes of a signature over `ring_size` members. Nothing is decoded yet; that
+    /// happens in [`MoneroClsag::to_signature`].
+    pub fn from_bytes(bytes: &[u8], ring_size: usize) -> Result<MoneroClsag, DecodeError> {
+        let expected = 32 * (ring_size + 2);
+        if bytes.len() < expected {
+            return Err(DecodeError::Truncated);
+        }
+        if bytes.len() > expected {
+            return Err(DecodeError::TrailingBytes);
+        }
+        let mut chunks = bytes
+            .chunks_exact(32)
+            .map(|chunk| ScalarBytes::try_from(chunk).expect("chunks are 32 bytes"));
+        let s = chunks.by_ref().take(ring_size).collect();
+        let c1 = chunks.next().expect("length checked above");
+        let d = chunks.next().expect("length checked above");
+        Ok(MoneroClsag { s, c1, d })
+    }
+
+    /// Attaches the signature to `ring`, whose first layer holds the one-time keys and second the
+    /// commitments, with `key_image` taken from the transaction input. Scalars must be canonical
+    /// and points valid RFC 8032 encodings in the prime-order subgroup.
+    pub fn to_signature<'a>(
+        &self,
+        ring: &'a MatrixRing<EdwardsProjective>,
+        key_image: &[u8; 32],
+    ) -> Result<ClsagSignature<'a, ScalarBigInt<EdwardsProjective>, EdwardsProjective>, DecodeError>
+    {
+        if ring.width() != 2 || ring.size() != self.s.len() {
+            return Err(DecodeError::RingMismatch);
+        }
+        let image = decode_image(key_image)?;
+        let d = ed25519::decode(&self.d).ok_or(DecodeError::InvalidEncoding)?;
+        let auxiliary = KeyImage::from_point((d * Fr::from(8u64)).into_affine());
+        if !auxiliary.is_valid() {
+            return Err(DecodeError::InvalidKeyImage);
+        }
+        Ok(ClsagSignature {
+            ring,
+            challenge: decode_scalar(&self.c1)?,
+            ring_sig_vals: self.s.iter().map(decode_scalar).collect::<Result<_, _>>()?,
+            image,
+            auxiliary_images: vec![auxiliary],
+        })
+    }
+    /// Checks the signature as Monero's `CLSAG_Ver` does, for an input spending one of `ring`
+    /// with `key_image` and pseudo-output commitment `pseudo_out`. `message` is the transaction's
+    /// pre-MLSAG hash (`get_pre_mlsag_hash`), which covers the prefix and the RingCT base.
+    pub fn verify(
+        &self,
+        ring: &[CtKey],
+        key_image: &[u8; 32],
+        pseudo_out: &[u8; 32],
+        message: &[u8; 32],
+    ) -> Result<(), VerifyError> {
+        if ring.is_empty() {
+            return Err(VerifyError::RingTooSmall);
+        }
+        if ring.len() != self.s.len() {
+            return Err(VerifyError::LengthMismatch);
+        }
+        let s = self
+            .s
+            .iter()
+            .map(monero_scalar)
+            .collect::<Result<Vec<_>, _>>()?;
+        let c1 = monero_scalar(&self.c1)?;
+        let image = monero_image(key_image)?;
+        let offset = monero_point(pseudo_out)?;
+        let d = monero_point(&self.d)?.mul_by_cofactor_to_group();
+        if d.is_zero() {
+            return Err(VerifyError::InvalidKeyImage);
+        }
+        let (keys, commitments) = ring_points(ring, &offset)?;
+
+        let mut transcript = Vec::with_capacity(32 * (2 * ring.len() + 5));
+        transcript.extend(domain(CLSAG_AGG_0));
+        transcript.extend(ring.iter().flat_map(|member| member.dest));
+        transcript.extend(ring.iter().flat_map(|member| member.mask));
+        transcript.extend(key_image);
+        transcript.extend(self.d);
+        transcript.extend(pseudo_out);
+        let mu_p = hash_to_scalar(&transcript);
+        transcript[..32].copy_from_slice(&domain(CLSAG_AGG_1));
+        let mu_c = hash_to_scalar(&transcript);
+
+        transcript.truncate(32 * (2 * ring.len() + 1));
+        transcript[..32].copy_from_slice(&domain(CLSAG_ROUND));
+        transcript.extend(pseudo_out);
+        transcript.extend(message);
+        let prefix = transcript.len();
+
+        let mut c = c1;
+        for (i, member) in ring.iter().enumerate() {
+            let (c_p, c_c) = (mu_p * c, mu_c * c);
+            let left =
+                generator::mul::<EdwardsProjective>(&s[i]) + keys[i] * c_p + commitments[i] * c_c;
+            let right = hash_to_ec(&member.dest) * s[i] + image * c_p + d * c_c;
+            transcript.truncate(prefix);
+            transcript.extend(ed25519::encode(&left.into_affine()));
+            transcript.extend(ed25519::encode(&right.into_affine()));
+            c = hash_to_scalar(&transcript);
+            if c.is_zero() {
+                return Err(VerifyError::ChallengeMismatch);
+            }
+        }
+        error::check_challenge(c, c1)
+    }
+}
+
+/// A simple-RingCT MLSAG signature in Monero's serialization (`rctSigPrunable::MGs`): two
+/// responses per ring member, for the one-time key and the commitment difference, followed by
+/// the initial challenge. The key image is kept in the transaction input.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MoneroMlsag {
+    pub ss: Vec<[ScalarBytes; 2]>,
+    pub cc: ScalarBytes,
+}
+
+impl MoneroMlsag {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 * (2 * self.ss.len() + 1));
+        for [key, commitment] in &self.ss {
+            bytes.extend(key);
+            bytes.extend(commitment);
+        }
+        bytes.extend(self.cc);
+        bytes
+    }
+
+    /// Splits the bytes of a signature over `ring_size` members.
+    pub fn from_bytes(bytes: &[u8], ring_size: usize) -> Result<MoneroMlsag, DecodeError> {
+        let expected = 32 * (2 * ring_size + 1);
+        if bytes.len() < expected {
+            return Err(DecodeError::Truncated);
+        }
+        if bytes.len() > expected {
+            return Err(DecodeError::TrailingBytes);
+        }
+        let mut chunks = bytes
+            .chunks_exact(32)
+            .map(|chunk| ScalarBytes::try_from(chunk).expect("chunks are 32 bytes"));
+        let ss = (0..ring_size)
+            .map(|_| [chunks.next(), chunks.next()].map(|s| s.expect("length checked above")))
+            .collect();
+        let cc = chunks.next().expect("length checked above");
+        Ok(MoneroMlsag { ss, cc })
+    }
+
+    /// Checks the signature as Monero's `verRctMGSimple` does; the arguments are as for
+    /// [`MoneroClsag::verify`].
+    pub fn verify(
+        &self,
+        ring: &[CtKey],
+        key_image: &[u8; 32],
+        pseudo_out: &[u8; 32],
+        message: &[u8; 32],
+    ) -> Result<(), VerifyError> {
+        if ring.is_empty() {
+            return Err(VerifyError::RingTooSmall);
+        }
+        if ring.len() != self.ss.len() {
+            return Err(VerifyError::LengthMismatch);
+        }
+        let ss = self
+            .ss
+            .iter()
+            .map(|pair| Ok([monero_scalar(&pair[0])?, monero_scalar(&pair[1])?]))
+            .collect::<Result<Vec<_>, VerifyError>>()?;
+        let cc = monero_scalar(&self.cc)?;
+        let image = monero_image(key_image)?;
+        let offset = monero_point(pseudo_out)?;
+        let (keys, commitments) = ring_points(ring, &offset)?;
+
+        // message, then per member: key, L and R for the signing key, and the commitment
+        // difference and its L
+        let mut c = cc;
+        for (i, member) in ring.iter().enumerate() {
+            let [s_key, s_commitment] = ss[i];
+            let left = generator::mul::<EdwardsProjective>(&s_key) + keys[i] * c;
+            let right = hash_to_ec(&member.dest) * s_key + image * c;
+            let commitment = commitments[i].into_affine();
+            let commitment_left =
+                generator::mul::<EdwardsProjective>(&s_commitment) + commitment * c;
+            let mut transcript = Vec::with_capacity(32 * 6);
+            transcript.extend(message);
+            transcript.extend(member.dest);
+            transcript.extend(ed25519::encode(&left.into_affine()));
+            transcript.extend(ed25519::encode(&right.into_affine()));
+            transcript.extend(ed25519::encode(&commitment));
+            transcript.extend(ed25519::encode(&commitment_left.into_affine()));
+            c = hash_to_scalar(&transcript);
+            if c.is_zero() {
+                return Err(VerifyError::ChallengeMismatch);
+            }
+        }
+        error::check_challenge(c, cc)
+    }
+}
+
+impl<'a> ClsagSignature<'a, ScalarBigInt<EdwardsProjective>, EdwardsProjective> {
+    /// The signature in Monero's layout. The key image, which Monero keeps in the transaction
+    /// input, is `ed25519::encode(self.key_image().point())`.
+    ///
+    /// Panics unless the ring has exactly two layers, as Monero's do.
+    pub fn to_monero(&self) -> MoneroClsag {
+        assert_eq!(self.ring.width(), 2, "Monero rings have two layers");
+        let inverse_eight = Fr::from(8u64).inverse().expect("8 is invertible");
+        let d = (*self.auxiliary_images[0].point() * inverse_eight).into_affine();
+        MoneroClsag {
+            s: self.ring_sig_vals.iter().map(encode_scalar).collect(),
+            c1: encode_scalar(&self.challenge),
+            d: ed25519::encode(&d),
+        }
+    }
+}
+
+fn encode_scalar(value: &ScalarBigInt<EdwardsProjective>) -> ScalarBytes {
+    value
+        .to_bytes_le()
+        .try_into()
+        .expect("ed25519 scalars are 32 bytes")
+}
+
+// Monero's `sc_check`: the little-endian value must be below the group order
+fn decode_scalar(bytes: &ScalarBytes) -> Result<ScalarBigInt<EdwardsProjective>, DecodeError> {
+    let scalar = Fr::from_le_bytes_mod_order(bytes);
+    if encode_scalar(&scalar.into_bigint()) != *bytes {
+        return Err(DecodeError::InvalidEncoding);
+    }
+    Ok(scalar.into_bigint())
+}
+
+fn decode_image(bytes: &[u8; 32]) -> Result<KeyImage<EdwardsProjective>, DecodeError> {
+    let point = ed25519::decode(bytes).ok_or(DecodeError::InvalidKeyImage)?;
+    let image = KeyImage::from_point(point);
+    if !image.is_valid() {
+        return Err(DecodeError::InvalidKeyImage);
+    }
+    Ok(image)
+}
+
+/// Monero's `hash_to_ec`: Keccak-256 of `bytes`, mapped onto the curve with
+/// `ge_fromfe_frombytes_vartime` and multiplied by the cofactor. This is the `H_p` Monero takes
+/// key images over.
+pub fn hash_to_ec(bytes: &[u8; 32]) -> EdwardsAffine {
+    let u = Fq::from_le_bytes_mod_order(&Keccak256::digest(bytes));
+    let a = Fq::from(A);
+    let v = u.square().double();
+    let w = v + Fq::ONE;
+    let x = w.square() - a.square() * v;
+
+    // (w / x)^((p + 3) / 8), written as w x^3 (w x^7)^((p - 5) / 8) so x = 0 needs no inverse
+    let mut exponent = Fq::MODULUS;
+    exponent.sub_with_borrow(&BigInt::from(5u64));
+    exponent >>= 3;
+    let x3 = x.square() * x;
+    let root = w * x3 * (w * x3.square() * x).pow(exponent);
+    let x = root.square() * x;
+
+    // only y and the sign of x are needed; the point is then decoded from them
+    let negative = !(w - x).is_zero() && !(w + x).is_zero();
+    let z = if negative { -a } else { -a * v };
+    let y = (z - w)
+        * (z + w)
+            .inverse()
+            .expect("z + w is nonzero for Keccak outputs");
+    let mut encoded = [0u8; 32];
+    encoded.copy_from_slice(&y.into_bigint().to_bytes_le());
+    encoded[31] |= (negative as u8) << 7;
+    ed25519::decode_on_curve(&encoded)
+        .expect("the map always lands on the curve")
+        .mul_by_cofactor()
+}
+
+fn domain(tag: &[u8]) -> [u8; 32] {
+    let mut padded = [0u8; 32];
+    padded[..tag.len()].copy_from_slice(tag);
+    padded
+}
+
+// Monero's `hash_to_scalar`: Keccak-256 reduced modulo the group order
+fn hash_to_scalar(data: &[u8]) -> Fr {
+    Fr::from_le_bytes_mod_order(&Keccak256::digest(data))
+}
+
+fn monero_scalar(bytes: &ScalarBytes) -> Result<Fr, VerifyError> {
+    decode_scalar(bytes)
+        .map(|scalar| Fr::from_bigint(scalar).expect("decoded scalars are canonical"))
+        .map_err(|_| VerifyError::NonCanonicalScalar)
+}
+
+fn monero_point(bytes: &[u8; 32]) -> Result<EdwardsAffine, VerifyError> {
+    ed25519::decode_on_curve(bytes).ok_or(VerifyError::InvalidPoint)
+}
+
+// key images must be in the prime-order subgroup since Monero's v11 hard fork
+fn monero_image(bytes: &[u8; 32]) -> Result<EdwardsAffine, VerifyError> {
+    decode_image(bytes)
+        .map(|image| *image.point())
+        .map_err(|_| VerifyError::InvalidKeyImage)
+}
+
+// the one-time keys and the commitments less the pseudo-output
+fn ring_points(
+    ring: &[CtKey],
+    offset: &EdwardsAffine,
+) -> Result<(Vec<EdwardsAffine>, Vec<EdwardsProjective>), VerifyError> {
+    ring.iter()
+        .map(|member| {
+            Ok((
+                monero_point(&member.dest)?,
+                monero_point(&member.mask)? - *offset,
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|pairs| pairs.into_iter().unzip())
+}
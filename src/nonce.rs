@@ -0,0 +1,89 @@
+use crate::hash;
+use crate::secret::SecretKey;
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::rand::{self, CryptoRng, RngCore};
+use sha2::Digest;
+use zeroize::{Zeroize, Zeroizing};
+
+/// RFC 6979-style nonce source: HMAC-SHA512 keyed with the private key, run in counter mode
+/// over a digest of the signed statement. Signing the same message over the same ring with the
+/// same key always draws the same nonces, and no entropy is needed at signing time.
+pub(crate) struct NonceRng {
+    key: [u8; 64],
+    counter: u64,
+    block: [u8; 64],
+    offset: usize,
+}
+
+impl NonceRng {
+    pub(crate) fn new<C: CurveGroup>(
+        domain: &[u8],
+        private_key: &SecretKey<C>,
+        ring: &[C::Affine],
+        message: &[u8],
+    ) -> NonceRng {
+        let statement = hash::challenge_hasher::<C>(domain, ring, message).finalize();
+        let secret = Zeroizing::new(private_key.expose_secret().into_bigint().to_bytes_le());
+        let key = hash::hmac_sha512(&secret, &[&statement]);
+        NonceRng {
+            key,
+            counter: 0,
+            block: [0u8; 64],
+            offset: 64,
+        }
+    }
+
+    // the same stream keyed by an arbitrary seed, for reproducible test vectors
+    #[cfg(feature = "json")]
+    pub(crate) fn from_seed(domain: &[u8], seed: &[u8]) -> NonceRng {
+        NonceRng {
+            key: hash::hmac_sha512(seed, &[domain]),
+            counter: 0,
+            block: [0u8; 64],
+            offset: 64,
+        }
+    }
+}
+
+// the key, and every block drawn from it, determine the nonces and so the private key
+impl Drop for NonceRng {
+    fn drop(&mut self) {
+        self.key.zeroize();
+        self.block.zeroize();
+    }
+}
+
+impl RngCore for NonceRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            if self.offset == self.block.len() {
+                self.block = hash::hmac_sha512(&self.key, &[&self.counter.to_le_bytes()]);
+                self.counter += 1;
+                self.offset = 0;
+            }
+            *byte = self.block[self.offset];
+            self.offset += 1;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+// an HMAC-DRBG keyed with secret material
+impl CryptoRng for NonceRng {}
@@ -0,0 +1,433 @@
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::ring::{CurvePoint, Ring, Scalar, ScalarBigInt};
+use crate::secret::SecretKey;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField, UniformRand};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, Rng, RngCore};
+use sha2::Digest;
+use zeroize::{Zeroize, Zeroizing};
+
+const ONE_OF_MANY_DOMAIN: &[u8] = b"lingo-one-of-many-v1";
+const GENERATOR_DOMAIN: &[u8] = b"lingo-one-of-many-generator-v1";
+
+// ring indices are decomposed into base-`BASE` digits; binary keeps proofs smallest in practice
+pub(crate) const BASE: usize = 2;
+
+/// Produces a Groth–Kohlweiss one-out-of-many proof that the prover knows the private key of
+/// one member of `ring`. The proof is bound to `message`, so with a non-empty message it doubles
+/// as a (non-linkable) ring signature; pass an empty message for plain membership. Fails with
+/// [`RingError::UnsupportedSize`] unless the ring size is a power of two.
+#[cfg(feature = "std")]
+pub fn prove<'a, C>(
+    ring: &'a Ring<C>,
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+) -> Result<OneOfManyProof<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+{
+    prove_with_rng(
+        ring,
+        message,
+        private_key,
+        secret_index,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Like [`prove`], drawing the proof's randomness from `rng`.
+pub fn prove_with_rng<'a, C, R>(
+    ring: &'a Ring<C>,
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    rng: &mut R,
+) -> Result<OneOfManyProof<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+    R: RngCore + CryptoRng,
+{
+    let size = ring.size();
+    let m = proving_digit_count(size)?;
+    let secret = ring.signer_secret(private_key, secret_index)?;
+
+    let generators = Generators::<C>::new(m);
+    let digits = DigitCommitments::<C>::new(&generators, secret_index, m, rng);
+    // the coefficients give away the signer's index and `rho` masks the key in `z`
+    let coefficients = Zeroizing::new(digits.member_coefficients(size));
+
+    let rho = Zeroizing::new((0..m).map(|_| Scalar::<C>::rand(rng)).collect::<Vec<_>>());
+    let x_points: Vec<C> = (0..m)
+        .map(|j| {
+            let scalars: Vec<Scalar<C>> = coefficients.iter().map(|poly| poly[j]).collect();
+            C::msm_unchecked(ring.keys(), &scalars) + ct::generator_mul::<C>(&rho[j])
+        })
+        .collect();
+
+    let mut points = digits.points.to_vec();
+    points.extend(&x_points);
+    let points = C::normalize_batch(&points);
+    let xi = challenge::<C>(ONE_OF_MANY_DOMAIN, ring.keys(), message, &points);
+
+    let (f, z_a, z_c) = digits.respond(xi);
+    let z = mask_secret(*secret, &rho, xi);
+
+    Ok(OneOfManyProof {
+        ring,
+        a: points[0],
+        b: points[1],
+        c: points[2],
+        d: points[3],
+        x: points[4..].to_vec(),
+        f: f.iter()
+            .map(|row| row.iter().map(|s| s.into_bigint()).collect())
+            .collect(),
+        z_a: z_a.into_bigint(),
+        z_c: z_c.into_bigint(),
+        z: z.into_bigint(),
+    })
+}
+
+#[derive(PartialEq, Eq)]
+pub struct OneOfManyProof<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: &'a Ring<C>,
+    pub a: CurvePoint<C>,
+    pub b: CurvePoint<C>,
+    pub c: CurvePoint<C>,
+    pub d: CurvePoint<C>,
+    pub x: Vec<CurvePoint<C>>,
+    pub f: Vec<Vec<B>>,
+    pub z_a: B,
+    pub z_c: B,
+    pub z: B,
+}
+
+impl<'a, B, C> OneOfManyProof<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn ring(&self) -> &Ring<C> {
+        self.ring
+    }
+}
+
+impl<'a, C> OneOfManyProof<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        let keys = self.ring.keys();
+        let m = verified_digit_count(keys.len())?;
+        if self.x.len() != m || !matrix_fits(&self.f, m) {
+            return Err(VerifyError::LengthMismatch);
+        }
+        if keys.iter().any(|key| key.is_zero()) {
+            return Err(VerifyError::InvalidPoint);
+        }
+        let (Some(f), Some(z_a), Some(z_c), Some(z)) = (
+            scalar_matrix::<C>(&self.f, m),
+            Scalar::<C>::from_bigint(self.z_a),
+            Scalar::<C>::from_bigint(self.z_c),
+            Scalar::<C>::from_bigint(self.z),
+        ) else {
+            return Err(VerifyError::NonCanonicalScalar);
+        };
+
+        let mut points = vec![self.a, self.b, self.c, self.d];
+        points.extend(&self.x);
+        let xi = challenge::<C>(ONE_OF_MANY_DOMAIN, keys, message, &points);
+
+        let generators = Generators::<C>::new(m);
+        let commitments = [self.a, self.b, self.c, self.d];
+        let full_f = verify_digits(&generators, &commitments, &f, z_a, z_c, xi)
+            .ok_or(VerifyError::ChallengeMismatch)?;
+
+        let powers = powers(xi, m);
+        let key_sum = C::msm_unchecked(keys, &member_scalars(&full_f, keys.len()));
+        error::check_challenge(
+            key_sum - C::msm_unchecked(&self.x, &powers),
+            generator::mul::<C>(&z),
+        )
+    }
+}
+
+pub(crate) struct Generators<C: CurveGroup> {
+    matrix: Vec<CurvePoint<C>>,
+}
+
+impl<C: CurveGroup> Generators<C> {
+    pub(crate) fn new(digits: usize) -> Generators<C> {
+        let matrix = (0..digits * BASE)
+            .map(|index| hash::hash_to_point::<C>(GENERATOR_DOMAIN, &index.to_le_bytes()))
+            .collect();
+        Generators { matrix }
+    }
+
+    // Pedersen commitment to a `digits x BASE` matrix, blinded with the curve generator
+    pub(crate) fn commit(&self, values: &[Vec<Scalar<C>>], blinding: Scalar<C>) -> C {
+        let scalars: Vec<Scalar<C>> = values.iter().flatten().copied().collect();
+        C::msm_unchecked(&self.matrix, &scalars) + ct::generator_mul::<C>(&blinding)
+    }
+}
+
+/// Prover state for the commitments `A`, `B`, `C`, `D` to the signer's index digits. The digits
+/// and their masks are wiped on drop.
+pub(crate) struct DigitCommitments<C: CurveGroup> {
+    sigma: Vec<Vec<Scalar<C>>>,
+    a: Vec<Vec<Scalar<C>>>,
+    blindings: [Scalar<C>; 4],
+    pub(crate) points: [C; 4],
+}
+
+impl<C: CurveGroup> DigitCommitments<C> {
+    pub(crate) fn new<R: Rng>(
+        generators: &Generators<C>,
+        index: usize,
+        m: usize,
+        rng: &mut R,
+    ) -> DigitCommitments<C> {
+        let zero = Scalar::<C>::from(0u64);
+        let one = Scalar::<C>::from(1u64);
+        let two = Scalar::<C>::from(2u64);
+
+        let sigma: Vec<Vec<Scalar<C>>> = digits(index, m)
+            .into_iter()
+            .map(|digit| {
+                (0..BASE)
+                    .map(|i| if i == digit { one } else { zero })
+                    .collect()
+            })
+            .collect();
+        let a: Vec<Vec<Scalar<C>>> = (0..m)
+            .map(|_| {
+                let mut row: Vec<Scalar<C>> = (0..BASE).map(|_| Scalar::<C>::rand(rng)).collect();
+                row[0] = -row[1..].iter().sum::<Scalar<C>>();
+                row
+            })
+            .collect();
+        let blindings = [
+            Scalar::<C>::rand(rng),
+            Scalar::<C>::rand(rng),
+            Scalar::<C>::rand(rng),
+            Scalar::<C>::rand(rng),
+        ];
+
+        let c_matrix = zip_matrix(&a, &sigma, |a, s| a * (one - two * s));
+        let d_matrix = zip_matrix(&a, &a, |a, b| -(a * b));
+        let points = [
+            generators.commit(&a, blindings[0]),
+            generators.commit(&sigma, blindings[1]),
+            generators.commit(&c_matrix, blindings[2]),
+            generators.commit(&d_matrix, blindings[3]),
+        ];
+
+        DigitCommitments {
+            sigma,
+            a,
+            blindings,
+            points,
+        }
+    }
+
+    // coefficients of p_k(x) = prod_j (sigma_{j,k_j} x + a_{j,k_j}) for every ring member
+    pub(crate) fn member_coefficients(&self, size: usize) -> Vec<Vec<Scalar<C>>> {
+        let m = self.sigma.len();
+        (0..size)
+            .map(|k| {
+                let mut poly = vec![Scalar::<C>::from(1u64)];
+                for (j, digit) in digits(k, m).into_iter().enumerate() {
+                    poly = multiply_linear(&poly, self.sigma[j][digit], self.a[j][digit]);
+                }
+                poly
+            })
+            .collect()
+    }
+
+    /// Returns the reduced response matrix (digit 0 omitted), `z_A` and `z_C`.
+    pub(crate) fn respond(&self, xi: Scalar<C>) -> (Vec<Vec<Scalar<C>>>, Scalar<C>, Scalar<C>) {
+        let f = self
+            .sigma
+            .iter()
+            .zip(&self.a)
+            .map(|(sigma, a)| (1..BASE).map(|i| sigma[i] * xi + a[i]).collect())
+            .collect();
+        let [r_a, r_b, r_c, r_d] = self.blindings;
+        (f, r_a + xi * r_b, xi * r_c + r_d)
+    }
+}
+
+impl<C: CurveGroup> Drop for DigitCommitments<C> {
+    fn drop(&mut self) {
+        self.sigma.zeroize();
+        self.a.zeroize();
+        self.blindings.zeroize();
+    }
+}
+
+/// Checks `A + xi B == Com(f, z_A)` and `xi C + D == Com(f (xi - f), z_C)`, returning the full
+/// response matrix (with the implied digit-0 column) on success.
+pub(crate) fn verify_digits<C: CurveGroup>(
+    generators: &Generators<C>,
+    commitments: &[CurvePoint<C>; 4],
+    f: &[Vec<Scalar<C>>],
+    z_a: Scalar<C>,
+    z_c: Scalar<C>,
+    xi: Scalar<C>,
+) -> Option<Vec<Vec<Scalar<C>>>> {
+    let [a, b, c, d] = *commitments;
+    let full_f: Vec<Vec<Scalar<C>>> = f
+        .iter()
+        .map(|row| {
+            let mut full = Vec::with_capacity(BASE);
+            full.push(xi - row.iter().sum::<Scalar<C>>());
+            full.extend(row);
+            full
+        })
+        .collect();
+
+    if b * xi + a != generators.commit(&full_f, z_a) {
+        return None;
+    }
+    let f_complement: Vec<Vec<Scalar<C>>> = full_f
+        .iter()
+        .map(|row| row.iter().map(|f| *f * (xi - f)).collect())
+        .collect();
+    if c * xi + d != generators.commit(&f_complement, z_c) {
+        return None;
+    }
+    Some(full_f)
+}
+
+// evaluations p_k(xi) = prod_j f_{j,k_j} for every ring member
+pub(crate) fn member_scalars<F: Field>(full_f: &[Vec<F>], size: usize) -> Vec<F> {
+    (0..size)
+        .map(|k| {
+            digits(k, full_f.len())
+                .into_iter()
+                .enumerate()
+                .map(|(j, digit)| full_f[j][digit])
+                .product()
+        })
+        .collect()
+}
+
+// `z = k xi^m - sum_j rho_j xi^j`
+pub(crate) fn mask_secret<F: Field>(secret: F, rho: &[F], xi: F) -> F {
+    let powers = powers(xi, rho.len() + 1);
+    let masked: F = rho.iter().zip(&powers).map(|(rho, p)| *rho * p).sum();
+    secret * powers[rho.len()] - masked
+}
+
+pub(crate) fn powers<F: Field>(base: F, count: usize) -> Vec<F> {
+    let mut powers = Vec::with_capacity(count);
+    let mut current = F::one();
+    for _ in 0..count {
+        powers.push(current);
+        current *= base;
+    }
+    powers
+}
+
+pub(crate) fn scalar_matrix<C: CurveGroup>(
+    rows: &[Vec<ScalarBigInt<C>>],
+    m: usize,
+) -> Option<Vec<Vec<Scalar<C>>>> {
+    if !matrix_fits(rows, m) {
+        return None;
+    }
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|s| Scalar::<C>::from_bigint(*s))
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect()
+}
+
+// `digit_count` for verifiers: rings below `BASE` members are too small, other sizes that are
+// not a power of `BASE` cannot match any proof
+pub(crate) fn verified_digit_count(size: usize) -> Result<usize, VerifyError> {
+    match digit_count(size) {
+        Some(m) => Ok(m),
+        None if size < BASE => Err(VerifyError::RingTooSmall),
+        None => Err(VerifyError::LengthMismatch),
+    }
+}
+
+pub(crate) fn matrix_fits<B>(rows: &[Vec<B>], m: usize) -> bool {
+    rows.len() == m && rows.iter().all(|row| row.len() == BASE - 1)
+}
+
+pub(crate) fn proving_digit_count(size: usize) -> Result<usize, RingError> {
+    digit_count(size).ok_or(RingError::UnsupportedSize(size))
+}
+
+pub(crate) fn digit_count(size: usize) -> Option<usize> {
+    if size < BASE {
+        return None;
+    }
+    let mut m = 0;
+    let mut remaining = size;
+    while remaining > 1 {
+        if !remaining.is_multiple_of(BASE) {
+            return None;
+        }
+        remaining /= BASE;
+        m += 1;
+    }
+    Some(m)
+}
+
+fn digits(mut index: usize, m: usize) -> Vec<usize> {
+    (0..m)
+        .map(|_| {
+            let digit = index % BASE;
+            index /= BASE;
+            digit
+        })
+        .collect()
+}
+
+// multiplies `poly` (little-endian coefficients) by `slope * x + constant`
+fn multiply_linear<F: Field>(poly: &[F], slope: F, constant: F) -> Vec<F> {
+    let mut product = vec![F::zero(); poly.len() + 1];
+    for (i, coefficient) in poly.iter().enumerate() {
+        product[i] += *coefficient * constant;
+        product[i + 1] += *coefficient * slope;
+    }
+    product
+}
+
+fn zip_matrix<F: Field>(left: &[Vec<F>], right: &[Vec<F>], op: impl Fn(F, F) -> F) -> Vec<Vec<F>> {
+    left.iter()
+        .zip(right)
+        .map(|(l, r)| l.iter().zip(r).map(|(l, r)| op(*l, *r)).collect())
+        .collect()
+}
+
+pub(crate) fn challenge<C: CurveGroup>(
+    domain: &[u8],
+    keys: &[CurvePoint<C>],
+    message: &[u8],
+    points: &[CurvePoint<C>],
+) -> Scalar<C> {
+    let mut hasher = hash::challenge_hasher::<C>(domain, keys, message);
+    hasher.update((points.len() as u64).to_le_bytes());
+    for point in points {
+        hash::append_point::<C>(&mut hasher, point);
+    }
+    hash::hash_to_scalar(hasher)
+}
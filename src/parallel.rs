@@ -0,0 +1,17 @@
+use alloc::vec::Vec;
+// Work that does not depend on the challenge chain, such as hashing a ring's keys or computing
+// one round of commitments for every chain of a batch. With the `parallel` feature it is spread
+// over rayon's thread pool; without it, it runs in order on the calling thread.
+
+/// `[f(0), f(1), .., f(count - 1)]`.
+#[cfg(feature = "parallel")]
+pub(crate) fn map<U: Send>(count: usize, f: impl Fn(usize) -> U + Sync + Send) -> Vec<U> {
+    use rayon::prelude::*;
+    (0..count).into_par_iter().map(f).collect()
+}
+
+/// `[f(0), f(1), .., f(count - 1)]`.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn map<U>(count: usize, f: impl Fn(usize) -> U) -> Vec<U> {
+    (0..count).map(f).collect()
+}
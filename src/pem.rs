@@ -0,0 +1,213 @@
+use crate::der::{self, OCTET_STRING, OID, Reader, SEQUENCE};
+use crate::ed25519;
+use crate::error::{KeyError, RingError};
+use crate::hex;
+use crate::keypair::KeyPair;
+use crate::ring::{CurvePoint, Ring};
+use crate::sec1;
+use crate::secret::SecretScalar;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::CurveGroup;
+use ark_ec::short_weierstrass::{Projective, SWCurveConfig};
+use ark_ed25519::EdwardsProjective;
+use ark_ff::{BigInteger, PrimeField, Zero};
+
+// The DER containers OpenSSL and most key management tools read and write: X.509
+// `SubjectPublicKeyInfo` for public keys on every supported curve, and the RFC 5915
+// `ECPrivateKey` for private keys on named short Weierstrass curves.
+
+const PUBLIC_KEY_LABEL: &str = "PUBLIC KEY";
+const EC_PRIVATE_KEY_LABEL: &str = "EC PRIVATE KEY";
+
+// 1.2.840.10045.2.1
+const ID_EC_PUBLIC_KEY: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+// 1.3.101.112
+const ID_ED25519: &[u8] = &[0x2b, 0x65, 0x70];
+
+const EC_PARAMETERS: u8 = 0xa0;
+const EC_PUBLIC_KEY: u8 = 0xa1;
+
+/// A short Weierstrass curve with a named-curve OID, whose keys use the SEC1 containers.
+pub trait NamedCurve: SWCurveConfig<BaseField: PrimeField> {
+    /// Content octets of the curve's OID, e.g. `1.3.132.0.10` for secp256k1.
+    const OID: &'static [u8];
+}
+
+impl NamedCurve for ark_secp256k1::Config {
+    // 1.3.132.0.10
+    const OID: &'static [u8] = &[0x2b, 0x81, 0x04, 0x00, 0x0a];
+}
+
+#[cfg(feature = "p256")]
+impl NamedCurve for crate::curves::p256::Config {
+    // 1.2.840.10045.3.1.7
+    const OID: &'static [u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+}
+
+/// A curve whose public keys have a standard `SubjectPublicKeyInfo` encoding.
+pub trait SpkiCurve: CurveGroup {
+    /// Content octets of the `AlgorithmIdentifier` naming the curve.
+    fn algorithm() -> Vec<u8>;
+
+    /// The bytes carried in the `subjectPublicKey` bit string.
+    fn encode_point(point: &CurvePoint<Self>) -> Vec<u8>;
+
+    fn decode_point(bytes: &[u8]) -> Option<CurvePoint<Self>>;
+}
+
+impl<P: NamedCurve> SpkiCurve for Projective<P> {
+    fn algorithm() -> Vec<u8> {
+        [der::tlv(OID, ID_EC_PUBLIC_KEY), der::tlv(OID, P::OID)].concat()
+    }
+
+    // uncompressed, as OpenSSL writes it; both forms are accepted on import
+    fn encode_point(point: &CurvePoint<Self>) -> Vec<u8> {
+        sec1::encode_uncompressed(point)
+    }
+
+    fn decode_point(bytes: &[u8]) -> Option<CurvePoint<Self>> {
+        sec1::decode(bytes)
+    }
+}
+
+impl SpkiCurve for EdwardsProjective {
+    // RFC 8410: the parameters are absent
+    fn algorithm() -> Vec<u8> {
+        der::tlv(OID, ID_ED25519)
+    }
+
+    fn encode_point(point: &CurvePoint<Self>) -> Vec<u8> {
+        ed25519::encode(point).to_vec()
+    }
+
+    fn decode_point(bytes: &[u8]) -> Option<CurvePoint<Self>> {
+        ed25519::decode(bytes.try_into().ok()?)
+    }
+}
+
+/// DER `SubjectPublicKeyInfo` for `point`, which must not be the identity.
+pub fn public_key_to_der<C: SpkiCurve>(point: &CurvePoint<C>) -> Vec<u8> {
+    der::sequence(&[
+        &der::tlv(SEQUENCE, &C::algorithm()),
+        &der::bit_string(&C::encode_point(point)),
+    ])
+}
+
+pub fn public_key_from_der<C: SpkiCurve>(der: &[u8]) -> Result<CurvePoint<C>, KeyError> {
+    let (algorithm, key) = der::parse(der, SEQUENCE, |spki| {
+        Some((spki.read(SEQUENCE)?, spki.read_bit_string()?))
+    })
+    .ok_or(KeyError::MalformedEncoding)?;
+    if algorithm != C::algorithm() {
+        return Err(KeyError::UnsupportedAlgorithm);
+    }
+    C::decode_point(key).ok_or(KeyError::InvalidPublicKey)
+}
+
+/// `point` as a `PUBLIC KEY` PEM block.
+pub fn public_key_to_pem<C: SpkiCurve>(point: &CurvePoint<C>) -> String {
+    der::pem_encode(PUBLIC_KEY_LABEL, &public_key_to_der::<C>(point))
+}
+
+pub fn public_key_from_pem<C: SpkiCurve>(pem: &str) -> Result<CurvePoint<C>, KeyError> {
+    let der = der::pem_decode(PUBLIC_KEY_LABEL, pem).ok_or(KeyError::MalformedEncoding)?;
+    public_key_from_der::<C>(&der)
+}
+
+impl<P: NamedCurve> KeyPair<Projective<P>> {
+    /// RFC 5915 `ECPrivateKey` DER, carrying the curve OID and the public key.
+    pub fn to_sec1_der(&self) -> Vec<u8> {
+        self.ec_private_key(true)
+    }
+
+    // PKCS#8 names the curve in its own algorithm identifier, so it leaves out the parameters
+    pub(crate) fn ec_private_key(&self, parameters: bool) -> Vec<u8> {
+        let secret =
+            SecretScalar::<Projective<P>>::from_private_key(self.private_key()).into_bigint();
+        let secret = secret.to_bytes_be();
+        let width = sec1::field_width::<P::ScalarField>();
+        let mut fields = vec![
+            der::tlv(der::INTEGER, &[1]),
+            der::tlv(OCTET_STRING, &secret[secret.len() - width..]),
+        ];
+        if parameters {
+            fields.push(der::tlv(EC_PARAMETERS, &der::tlv(OID, P::OID)));
+        }
+        fields.push(der::tlv(
+            EC_PUBLIC_KEY,
+            &der::bit_string(&sec1::encode_uncompressed(self.public_key())),
+        ));
+        der::tlv(SEQUENCE, &fields.concat())
+    }
+
+    /// Imports an `ECPrivateKey`. The optional curve OID and public key must match when present.
+    pub fn from_sec1_der(der: &[u8]) -> Result<KeyPair<Projective<P>>, KeyError> {
+        let (secret, parameters, public_key) = der::parse(der, SEQUENCE, |key| {
+            (key.read_small_integer()? == 1).then_some(())?;
+            let secret = key.read(OCTET_STRING)?;
+            let parameters = match key.read_optional(EC_PARAMETERS)? {
+                Some(field) => Some(der::explicit(field, |field| field.read(OID))?),
+                None => None,
+            };
+            let public_key = match key.read_optional(EC_PUBLIC_KEY)? {
+                Some(field) => Some(der::explicit(field, Reader::read_bit_string)?),
+                None => None,
+            };
+            Some((secret, parameters, public_key))
+        })
+        .ok_or(KeyError::MalformedEncoding)?;
+
+        if parameters.is_some_and(|oid| oid != P::OID) {
+            return Err(KeyError::UnsupportedAlgorithm);
+        }
+        if secret.len() != sec1::field_width::<P::ScalarField>() {
+            return Err(KeyError::InvalidSecretKey);
+        }
+        let secret = sec1::field_element::<P::ScalarField>(secret)
+            .filter(|secret| !secret.is_zero())
+            .ok_or(KeyError::InvalidSecretKey)?;
+        let keypair = KeyPair::from_scalar(secret);
+        if public_key.is_some_and(|key| sec1::decode(key).as_ref() != Some(keypair.public_key())) {
+            return Err(KeyError::InvalidPublicKey);
+        }
+        Ok(keypair)
+    }
+
+    /// The key pair as an `EC PRIVATE KEY` PEM block.
+    pub fn to_sec1_pem(&self) -> String {
+        der::pem_encode(EC_PRIVATE_KEY_LABEL, &self.to_sec1_der())
+    }
+
+    pub fn from_sec1_pem(pem: &str) -> Result<KeyPair<Projective<P>>, KeyError> {
+        let der = der::pem_decode(EC_PRIVATE_KEY_LABEL, pem).ok_or(KeyError::MalformedEncoding)?;
+        KeyPair::from_sec1_der(&der)
+    }
+}
+
+impl<C: SpkiCurve> Ring<C> {
+    /// Builds a ring from `PUBLIC KEY` PEM blocks, one per member.
+    pub fn from_public_key_pems<S: AsRef<str>>(pems: &[S]) -> Result<Ring<C>, KeyError> {
+        let keys = pems
+            .iter()
+            .map(|pem| public_key_from_pem::<C>(pem.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ring::from_fixed_pubkeys(keys).map_err(|_| KeyError::InvalidPublicKey)
+    }
+
+    /// Builds a ring from every `PUBLIC KEY` block in `bundle`, in order, such as a set of PEM
+    /// files concatenated together. Text between the blocks is ignored. Fails with
+    /// [`RingError::InvalidPublicKeys`] listing the position of every block that does not hold a
+    /// valid key, or [`RingError::EmptyRing`] if there are no blocks.
+    pub fn from_pem_bundle(bundle: &str) -> Result<Ring<C>, RingError> {
+        let keys: Vec<Option<CurvePoint<C>>> = der::pem_decode_all(PUBLIC_KEY_LABEL, bundle)
+            .map(|der| public_key_from_der::<C>(&der?).ok())
+            .collect();
+        hex::collect_keys(keys)
+    }
+
+    pub fn public_key_pems(&self) -> Vec<String> {
+        self.keys().iter().map(public_key_to_pem::<C>).collect()
+    }
+}
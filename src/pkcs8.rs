@@ -0,0 +1,234 @@
+use crate::der::{self, NULL, OCTET_STRING, OID, SEQUENCE};
+use crate::error::KeyError;
+use crate::keypair::KeyPair;
+use crate::pem::{NamedCurve, SpkiCurve};
+use aes::cipher::block_padding::Pkcs7;
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use aes::{Aes128, Aes256};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::short_weierstrass::Projective;
+use ark_ed25519::EdwardsProjective;
+use ark_std::rand::Rng;
+use sha2::{Sha256, Sha512};
+
+// PKCS#8 private keys (RFC 5208 and its v2 successor, RFC 5958), plain and password-encrypted
+// with PBES2 (RFC 8018). Encryption uses PBKDF2-HMAC-SHA256 and AES-256-CBC, the OpenSSL
+// default; decryption also accepts HMAC-SHA512 and AES-128-CBC.
+
+const PRIVATE_KEY_LABEL: &str = "PRIVATE KEY";
+const ENCRYPTED_PRIVATE_KEY_LABEL: &str = "ENCRYPTED PRIVATE KEY";
+
+// 1.2.840.113549.1.5.13
+const ID_PBES2: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x05, 0x0d];
+// 1.2.840.113549.1.5.12
+const ID_PBKDF2: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x05, 0x0c];
+// 1.2.840.113549.2.9
+const ID_HMAC_WITH_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x09];
+// 1.2.840.113549.2.11
+const ID_HMAC_WITH_SHA512: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x0b];
+// 2.16.840.1.101.3.4.1.2
+const ID_AES128_CBC: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x01, 0x02];
+// 2.16.840.1.101.3.4.1.42
+const ID_AES256_CBC: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x01, 0x2a];
+
+const ATTRIBUTES: u8 = 0xa0;
+const PUBLIC_KEY: u8 = 0x81;
+
+/// PBKDF2 rounds for newly encrypted keys, per OWASP's guidance for HMAC-SHA256.
+pub const PBKDF2_ROUNDS: u32 = 600_000;
+
+/// Which PKCS#8 structure to write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Version {
+    /// RFC 5208 `PrivateKeyInfo`, the form most tools expect.
+    V1,
+    /// RFC 5958 `OneAsymmetricKey`, which also carries the public key.
+    V2,
+}
+
+/// A curve whose private keys can be read from PKCS#8.
+pub trait Pkcs8Curve: SpkiCurve {
+    /// Decodes the `privateKey` octets of a PKCS#8 structure.
+    fn decode_private_key(bytes: &[u8]) -> Result<KeyPair<Self>, KeyError>;
+}
+
+impl<P: NamedCurve> Pkcs8Curve for Projective<P> {
+    fn decode_private_key(bytes: &[u8]) -> Result<KeyPair<Self>, KeyError> {
+        KeyPair::from_sec1_der(bytes)
+    }
+}
+
+impl Pkcs8Curve for EdwardsProjective {
+    // RFC 8410: the octets wrap the 32-byte RFC 8032 secret key in a further OCTET STRING
+    fn decode_private_key(bytes: &[u8]) -> Result<KeyPair<Self>, KeyError> {
+        let secret = der::parse(bytes, OCTET_STRING, |secret| Some(secret.take_rest()))
+            .ok_or(KeyError::MalformedEncoding)?;
+        let secret = secret.try_into().map_err(|_| KeyError::InvalidSecretKey)?;
+        Ok(KeyPair::from_ed25519_secret(secret))
+    }
+}
+
+impl<C: Pkcs8Curve> KeyPair<C> {
+    /// Imports a v1 or v2 PKCS#8 structure. A v2 public key must match the private key.
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<KeyPair<C>, KeyError> {
+        let (algorithm, private_key, public_key) = der::parse(der, SEQUENCE, |info| {
+            let version = info.read_small_integer()?;
+            let algorithm = info.read(SEQUENCE)?;
+            let private_key = info.read(OCTET_STRING)?;
+            info.read_optional(ATTRIBUTES)?;
+            let public_key = match (version, info.read_optional(PUBLIC_KEY)?) {
+                (0, None) => None,
+                (1, Some(bits)) => Some(der::bit_string_content(bits)?),
+                (1, None) => None,
+                _ => return None,
+            };
+            Some((algorithm, private_key, public_key))
+        })
+        .ok_or(KeyError::MalformedEncoding)?;
+
+        if algorithm != C::algorithm() {
+            return Err(KeyError::UnsupportedAlgorithm);
+        }
+        let keypair = C::decode_private_key(private_key)?;
+        if public_key.is_some_and(|key| C::decode_point(key).as_ref() != Some(keypair.public_key()))
+        {
+            return Err(KeyError::InvalidPublicKey);
+        }
+        Ok(keypair)
+    }
+
+    pub fn from_pkcs8_pem(pem: &str) -> Result<KeyPair<C>, KeyError> {
+        let der = der::pem_decode(PRIVATE_KEY_LABEL, pem).ok_or(KeyError::MalformedEncoding)?;
+        KeyPair::from_pkcs8_der(&der)
+    }
+
+    /// Imports a PBES2-encrypted PKCS#8 structure.
+    pub fn from_encrypted_pkcs8_der(der: &[u8], password: &[u8]) -> Result<KeyPair<C>, KeyError> {
+        KeyPair::from_pkcs8_der(&decrypt(der, password)?)
+    }
+
+    pub fn from_encrypted_pkcs8_pem(pem: &str, password: &[u8]) -> Result<KeyPair<C>, KeyError> {
+        let der =
+            der::pem_decode(ENCRYPTED_PRIVATE_KEY_LABEL, pem).ok_or(KeyError::MalformedEncoding)?;
+        KeyPair::from_encrypted_pkcs8_der(&der, password)
+    }
+}
+
+// ed25519 key pairs hold only the clamped scalar, not the RFC 8032 secret PKCS#8 stores, so
+// only the SEC1 curves can be exported
+impl<P: NamedCurve> KeyPair<Projective<P>> {
+    pub fn to_pkcs8_der(&self, version: Version) -> Vec<u8> {
+        let mut fields = vec![
+            der::tlv(der::INTEGER, &[version as u8]),
+            der::tlv(SEQUENCE, &Projective::<P>::algorithm()),
+            der::tlv(OCTET_STRING, &self.ec_private_key(false)),
+        ];
+        if version == Version::V2 {
+            let point = Projective::<P>::encode_point(self.public_key());
+            fields.push(der::tlv(PUBLIC_KEY, &[&[0], point.as_slice()].concat()));
+        }
+        der::tlv(SEQUENCE, &fields.concat())
+    }
+
+    /// The key pair as a `PRIVATE KEY` PEM block.
+    pub fn to_pkcs8_pem(&self, version: Version) -> String {
+        der::pem_encode(PRIVATE_KEY_LABEL, &self.to_pkcs8_der(version))
+    }
+
+    /// A v1 PKCS#8 structure encrypted under `password` with a random salt and IV.
+    pub fn to_encrypted_pkcs8_der<R: Rng + ?Sized>(&self, password: &[u8], rng: &mut R) -> Vec<u8> {
+        encrypt(&self.to_pkcs8_der(Version::V1), password, rng)
+    }
+
+    /// The key pair as an `ENCRYPTED PRIVATE KEY` PEM block.
+    pub fn to_encrypted_pkcs8_pem<R: Rng + ?Sized>(&self, password: &[u8], rng: &mut R) -> String {
+        let der = self.to_encrypted_pkcs8_der(password, rng);
+        der::pem_encode(ENCRYPTED_PRIVATE_KEY_LABEL, &der)
+    }
+}
+
+fn encrypt<R: Rng + ?Sized>(plaintext: &[u8], password: &[u8], rng: &mut R) -> Vec<u8> {
+    let salt: [u8; 16] = rng.r#gen();
+    let iv: [u8; 16] = rng.r#gen();
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password, &salt, PBKDF2_ROUNDS, &mut key);
+    let ciphertext = cbc::Encryptor::<Aes256>::new(&key.into(), &iv.into())
+        .encrypt_padded_vec_mut::<Pkcs7>(plaintext);
+
+    let kdf = der::sequence(&[
+        &der::tlv(OID, ID_PBKDF2),
+        &der::sequence(&[
+            &der::tlv(OCTET_STRING, &salt),
+            &der::integer(PBKDF2_ROUNDS),
+            &der::sequence(&[&der::tlv(OID, ID_HMAC_WITH_SHA256), &der::tlv(NULL, &[])]),
+        ]),
+    ]);
+    let cipher = der::sequence(&[&der::tlv(OID, ID_AES256_CBC), &der::tlv(OCTET_STRING, &iv)]);
+    der::sequence(&[
+        &der::sequence(&[&der::tlv(OID, ID_PBES2), &der::sequence(&[&kdf, &cipher])]),
+        &der::tlv(OCTET_STRING, &ciphertext),
+    ])
+}
+
+fn decrypt(der: &[u8], password: &[u8]) -> Result<Vec<u8>, KeyError> {
+    let ((scheme, parameters), ciphertext) = der::parse(der, SEQUENCE, |info| {
+        Some((info.read_algorithm()?, info.read(OCTET_STRING)?))
+    })
+    .ok_or(KeyError::MalformedEncoding)?;
+    if scheme != ID_PBES2 {
+        return Err(KeyError::UnsupportedAlgorithm);
+    }
+    let ((kdf, kdf_parameters), (cipher, iv)) = der::parse(parameters, SEQUENCE, |pbes2| {
+        Some((pbes2.read_algorithm()?, pbes2.read_algorithm()?))
+    })
+    .ok_or(KeyError::MalformedEncoding)?;
+    if kdf != ID_PBKDF2 {
+        return Err(KeyError::UnsupportedAlgorithm);
+    }
+
+    // an absent PRF means HMAC-SHA1, which is not supported
+    let (salt, rounds, key_length, prf) = der::parse(kdf_parameters, SEQUENCE, |pbkdf2| {
+        let salt = pbkdf2.read(OCTET_STRING)?;
+        let rounds = pbkdf2.read_u32().filter(|rounds| *rounds > 0)?;
+        let key_length = match pbkdf2.peek() {
+            Some(der::INTEGER) => Some(pbkdf2.read_u32()?),
+            _ => None,
+        };
+        let prf = match pbkdf2.peek() {
+            Some(SEQUENCE) => pbkdf2.read_algorithm()?,
+            _ => (&[][..], &[][..]),
+        };
+        Some((salt, rounds, key_length, prf))
+    })
+    .ok_or(KeyError::MalformedEncoding)?;
+    let width = match cipher {
+        ID_AES128_CBC => 16,
+        ID_AES256_CBC => 32,
+        _ => return Err(KeyError::UnsupportedAlgorithm),
+    };
+    let iv = der::parse(iv, OCTET_STRING, |iv| Some(iv.take_rest()))
+        .filter(|iv| iv.len() == 16)
+        .ok_or(KeyError::MalformedEncoding)?;
+    if key_length.is_some_and(|length| length as usize != width) || !matches!(prf.1, [] | [NULL, 0])
+    {
+        return Err(KeyError::MalformedEncoding);
+    }
+
+    let mut key = vec![0u8; width];
+    match prf.0 {
+        ID_HMAC_WITH_SHA256 => pbkdf2::pbkdf2_hmac::<Sha256>(password, salt, rounds, &mut key),
+        ID_HMAC_WITH_SHA512 => pbkdf2::pbkdf2_hmac::<Sha512>(password, salt, rounds, &mut key),
+        _ => return Err(KeyError::UnsupportedAlgorithm),
+    }
+    let plaintext = match width {
+        16 => cbc::Decryptor::<Aes128>::new_from_slices(&key, iv)
+            .expect("key and IV lengths are checked")
+            .decrypt_padded_vec_mut::<Pkcs7>(ciphertext),
+        _ => cbc::Decryptor::<Aes256>::new_from_slices(&key, iv)
+            .expect("key and IV lengths are checked")
+            .decrypt_padded_vec_mut::<Pkcs7>(ciphertext),
+    };
+    plaintext.map_err(|_| KeyError::DecryptionFailed)
+}
@@ -0,0 +1,228 @@
+use crate::challenge::ChallengeHash;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use ark_ff::{BigInteger, PrimeField};
+use core::marker::PhantomData;
+use sha2::{Digest, Sha512};
+
+// Poseidon (Grassi et al., USENIX Security 2021) as a challenge hash, behind the `poseidon`
+// feature, so SNARK circuits over a pairing curve's scalar field can recompute ring signature
+// challenges without emulating SHA-512.
+//
+// The permutation has width 3 with the capacity element first, the x^5 S-box, 8 full and 57
+// partial rounds: the 128-bit parameter set for 254- and 255-bit fields. Rounds add constants,
+// apply the S-box (to every element in full rounds, the first in partial ones) and multiply by
+// the MDS matrix, in the order arkworks' `PoseidonSponge` uses, so `PoseidonConfig::new` can
+// take the constants and matrix from `PoseidonParameters` and run the same permutation in a
+// circuit. The round constants are
+//
+//   SHA-512("lingo-poseidon-v1" | field name | round u32 (LE) | position u32 (LE)) mod p
+//
+// and the MDS matrix is the Cauchy matrix M[i][j] = 1 / (i + j + 3).
+//
+// Bytes are absorbed as little-endian field elements of `(MODULUS_BIT_SIZE - 1) / 8` bytes each
+// (31 for the supported fields), after padding the input with 0x01 and then zeros to a whole
+// element. The capacity starts at the hash id, and the 64 bytes of output are the two rate
+// elements after the final permutation, each as 32 little-endian bytes, so a challenge is
+// `out[0] + 2^256 * out[1]` reduced modulo the group order.
+
+const DOMAIN: &[u8] = b"lingo-poseidon-v1";
+const WIDTH: usize = 3;
+const RATE: usize = 2;
+const CAPACITY: usize = 1;
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 57;
+const ALPHA: u64 = 5;
+
+/// A field Poseidon challenges can be computed over, with its own challenge hash id.
+pub trait PoseidonField: PrimeField {
+    const HASH_ID: u8;
+    const HASH_NAME: &'static str;
+}
+
+#[cfg(feature = "bls12-381")]
+impl PoseidonField for crate::curves::bls12_381::Fr {
+    const HASH_ID: u8 = 0x04;
+    const HASH_NAME: &'static str = "poseidon-bls12-381";
+}
+
+#[cfg(feature = "bn254")]
+impl PoseidonField for crate::curves::bn254::Fr {
+    const HASH_ID: u8 = 0x05;
+    const HASH_NAME: &'static str = "poseidon-bn254";
+}
+
+#[cfg(any(feature = "pallas", feature = "vesta"))]
+impl PoseidonField for crate::curves::pasta::Fp {
+    const HASH_ID: u8 = 0x06;
+    const HASH_NAME: &'static str = "poseidon-pasta-fp";
+}
+
+#[cfg(any(feature = "pallas", feature = "vesta"))]
+impl PoseidonField for crate::curves::pasta::Fq {
+    const HASH_ID: u8 = 0x07;
+    const HASH_NAME: &'static str = "poseidon-pasta-fq";
+}
+
+/// The permutation's parameters, laid out like the fields of arkworks' `PoseidonConfig`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonParameters<F: PrimeField> {
+    pub full_rounds: usize,
+    pub partial_rounds: usize,
+    pub alpha: u64,
+    /// Round constants, one row of `rate + capacity` per round.
+    pub ark: Vec<Vec<F>>,
+    pub mds: Vec<Vec<F>>,
+    pub rate: usize,
+    pub capacity: usize,
+}
+
+impl<F: PoseidonField> PoseidonParameters<F> {
+    pub fn new() -> PoseidonParameters<F> {
+        let ark = (0..FULL_ROUNDS + PARTIAL_ROUNDS)
+            .map(|round| {
+                (0..WIDTH)
+                    .map(|position| {
+                        let mut hasher = Sha512::new();
+                        hasher.update(DOMAIN);
+                        hasher.update(F::HASH_NAME);
+                        hasher.update((round as u32).to_le_bytes());
+                        hasher.update((position as u32).to_le_bytes());
+                        F::from_le_bytes_mod_order(&hasher.finalize())
+                    })
+                    .collect()
+            })
+            .collect();
+        let mds = (0..WIDTH)
+            .map(|i| {
+                (0..WIDTH)
+                    .map(|j| {
+                        F::from((i + j + WIDTH) as u64)
+                            .inverse()
+                            .expect("Cauchy entries are nonzero")
+                    })
+                    .collect()
+            })
+            .collect();
+        PoseidonParameters {
+            full_rounds: FULL_ROUNDS,
+            partial_rounds: PARTIAL_ROUNDS,
+            alpha: ALPHA,
+            ark,
+            mds,
+            rate: RATE,
+            capacity: CAPACITY,
+        }
+    }
+}
+
+impl<F: PoseidonField> Default for PoseidonParameters<F> {
+    fn default() -> PoseidonParameters<F> {
+        PoseidonParameters::new()
+    }
+}
+
+impl<F: PrimeField> PoseidonParameters<F> {
+    /// Applies the permutation to `state`, the capacity element first.
+    pub fn permute(&self, state: &mut [F; WIDTH]) {
+        let half = self.full_rounds / 2;
+        for (round, constants) in self.ark.iter().enumerate() {
+            for (element, constant) in state.iter_mut().zip(constants) {
+                *element += constant;
+            }
+            if round < half || round >= half + self.partial_rounds {
+                for element in state.iter_mut() {
+                    *element = element.pow([self.alpha]);
+                }
+            } else {
+                state[0] = state[0].pow([self.alpha]);
+            }
+            let mixed: Vec<F> = self
+                .mds
+                .iter()
+                .map(|row| row.iter().zip(state.iter()).map(|(m, s)| *m * s).sum())
+                .collect();
+            state.copy_from_slice(&mixed);
+        }
+    }
+}
+
+/// Poseidon over `F` as a [`ChallengeHash`]. Pick the scalar field of the curve whose circuits
+/// will check the signatures, e.g. `Poseidon<bls12_381::Fr>` for Jubjub rings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Poseidon<F>(PhantomData<F>);
+
+/// The running sponge of [`Poseidon`]: the permutation state and the bytes of an element not
+/// yet absorbed.
+#[derive(Clone, Debug)]
+pub struct PoseidonSponge<F: PrimeField> {
+    parameters: Arc<PoseidonParameters<F>>,
+    state: [F; WIDTH],
+    // rate position the next element is added to
+    position: usize,
+    pending: Vec<u8>,
+}
+
+impl<F: PrimeField> PoseidonSponge<F> {
+    fn absorb_element(&mut self, element: F) {
+        self.state[CAPACITY + self.position] += element;
+        self.position += 1;
+        if self.position == RATE {
+            self.parameters.permute(&mut self.state);
+            self.position = 0;
+        }
+    }
+}
+
+// the number of bytes packed into each field element
+fn chunk_size<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize - 1) / 8
+}
+
+impl<F: PoseidonField> ChallengeHash for Poseidon<F> {
+    const HASH_ID: u8 = F::HASH_ID;
+    const HASH_NAME: &'static str = F::HASH_NAME;
+
+    type State = PoseidonSponge<F>;
+
+    fn new() -> PoseidonSponge<F> {
+        let mut state = [F::zero(); WIDTH];
+        state[0] = F::from(F::HASH_ID as u64);
+        PoseidonSponge {
+            parameters: Arc::new(PoseidonParameters::new()),
+            state,
+            position: 0,
+            pending: Vec::with_capacity(chunk_size::<F>()),
+        }
+    }
+
+    fn update(sponge: &mut PoseidonSponge<F>, mut bytes: &[u8]) {
+        let chunk = chunk_size::<F>();
+        while !bytes.is_empty() {
+            let take = (chunk - sponge.pending.len()).min(bytes.len());
+            sponge.pending.extend(&bytes[..take]);
+            bytes = &bytes[take..];
+            if sponge.pending.len() == chunk {
+                let element = F::from_le_bytes_mod_order(&sponge.pending);
+                sponge.pending.clear();
+                sponge.absorb_element(element);
+            }
+        }
+    }
+
+    fn finalize(mut sponge: PoseidonSponge<F>) -> [u8; 64] {
+        sponge.pending.push(0x01);
+        let element = F::from_le_bytes_mod_order(&sponge.pending);
+        sponge.absorb_element(element);
+        if sponge.position != 0 {
+            sponge.parameters.permute(&mut sponge.state);
+        }
+
+        let mut output = [0u8; 64];
+        for (half, element) in output.chunks_exact_mut(32).zip(&sponge.state[CAPACITY..]) {
+            let bytes = element.into_bigint().to_bytes_le();
+            half[..bytes.len().min(32)].copy_from_slice(&bytes[..bytes.len().min(32)]);
+        }
+        output
+    }
+}
@@ -0,0 +1,140 @@
+use crate::challenge::{self, ChallengeHash, HashVisitor};
+use crate::error::{RingError, VerifyError};
+use crate::hash;
+use crate::ring::{
+    self, BLSAG_DOMAIN, CurvePoint, DetachedSignature, Ring, SAG_DOMAIN, ScalarBigInt,
+};
+use crate::trace;
+use alloc::vec::Vec;
+use ark_ec::CurveGroup;
+
+// Verifying a SAG or bLSAG signature does three things that depend only on the ring: it checks
+// the keys, absorbs them into the challenge hash ahead of the message, and, for bLSAG, hashes
+// every key to the curve for `H_p(K)`. The last is the costliest part of verification after the
+// chain itself, and the second grows with the ring like the chain does. A `PreparedRing` does
+// all three once, so a service verifying many signatures over one ring (an anonymity set, a
+// membership list) pays for them once. The hash states are kept for SHA-512, the default
+// challenge hash; signatures made with another hash reuse the checked keys and the key hashes
+// and absorb the ring afresh.
+
+type Sha512State = <challenge::Sha512 as ChallengeHash>::State;
+
+/// A ring checked and preprocessed once for verifying many signatures over it. Build it with
+/// [`PreparedRing::new`] and verify with [`DetachedSignature::verify_with_prepared`].
+#[derive(Clone)]
+pub struct PreparedRing<C: CurveGroup> {
+    ring: Ring<C>,
+    // `H_p(K)` for every member, in ring order
+    hashes: Vec<CurvePoint<C>>,
+    // SHA-512 challenge states with the ring absorbed under each scheme's domain
+    sag_state: Sha512State,
+    blsag_state: Sha512State,
+}
+
+impl<C: CurveGroup> PreparedRing<C> {
+    /// Checks every key of `ring` as [`Ring::from_fixed_pubkeys`] does, which rings decoded
+    /// without validation have skipped, and precomputes what verification over it needs.
+    pub fn new(ring: Ring<C>) -> Result<PreparedRing<C>, RingError> {
+        let _span = trace::ring(trace::curve::<C>(), ring.size());
+        let ring = Ring::from_fixed_pubkeys(ring.keys().to_vec())?;
+        let hashes = ring.key_hashes();
+        let sag_state = ring_state::<C, challenge::Sha512>(SAG_DOMAIN, &ring);
+        let blsag_state = ring_state::<C, challenge::Sha512>(BLSAG_DOMAIN, &ring);
+        Ok(PreparedRing {
+            ring,
+            hashes,
+            sag_state,
+            blsag_state,
+        })
+    }
+
+    pub fn ring(&self) -> &Ring<C> {
+        &self.ring
+    }
+
+    pub fn into_ring(self) -> Ring<C> {
+        self.ring
+    }
+
+    fn verify_hashed<H: ChallengeHash>(
+        &self,
+        sag_state: impl FnOnce() -> H::State,
+        blsag_state: impl FnOnce() -> H::State,
+        signature: &DetachedSignature<ScalarBigInt<C>, C>,
+        message: &[u8],
+    ) -> Result<(), VerifyError> {
+        let keys = self.ring.keys();
+        let _span = trace::verify(
+            trace::curve::<C>(),
+            keys.len(),
+            signature.image.is_some(),
+            H::HASH_ID,
+        );
+        let (initial, responses) =
+            ring::decode_scalars::<C>(keys, signature.challenge, &signature.ring_sig_vals)?;
+        match &signature.image {
+            None => ring::verify_sag::<C, H>(sag_state(), keys, message, initial, &responses),
+            Some(image) => {
+                if !image.is_valid() {
+                    return Err(VerifyError::InvalidKeyImage);
+                }
+                ring::verify_blsag::<C, H>(
+                    blsag_state(),
+                    keys,
+                    &self.hashes,
+                    message,
+                    image,
+                    initial,
+                    &responses,
+                )
+            }
+        }
+    }
+}
+
+fn ring_state<C: CurveGroup, H: ChallengeHash>(domain: &[u8], ring: &Ring<C>) -> H::State {
+    hash::ring_state_from::<C, H>(H::new(), domain, ring.keys())
+}
+
+impl<C: CurveGroup> DetachedSignature<ScalarBigInt<C>, C> {
+    /// [`DetachedSignature::verify_with_ring`] over a [`PreparedRing`], skipping the work that
+    /// depends only on the ring.
+    pub fn verify_with_prepared(
+        &self,
+        ring: &PreparedRing<C>,
+        message: &[u8],
+    ) -> Result<(), VerifyError> {
+        if self.hash_id == challenge::Sha512::HASH_ID {
+            return ring.verify_hashed::<challenge::Sha512>(
+                || ring.sag_state.clone(),
+                || ring.blsag_state.clone(),
+                self,
+                message,
+            );
+        }
+
+        struct Verify<'v, C: CurveGroup> {
+            ring: &'v PreparedRing<C>,
+            signature: &'v DetachedSignature<ScalarBigInt<C>, C>,
+            message: &'v [u8],
+        }
+        impl<C: CurveGroup> HashVisitor for Verify<'_, C> {
+            type Output = Result<(), VerifyError>;
+            fn visit<H: ChallengeHash>(self) -> Result<(), VerifyError> {
+                let ring = &self.ring.ring;
+                self.ring.verify_hashed::<H>(
+                    || ring_state::<C, H>(SAG_DOMAIN, ring),
+                    || ring_state::<C, H>(BLSAG_DOMAIN, ring),
+                    self.signature,
+                    self.message,
+                )
+            }
+        }
+        let verify = Verify {
+            ring,
+            signature: self,
+            message,
+        };
+        challenge::visit_hash(self.hash_id, verify).unwrap_or(Err(VerifyError::UnsupportedHash))
+    }
+}
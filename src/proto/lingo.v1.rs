@@ -0,0 +1,64 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ring {
+    /// Compressed public keys, in ring order.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyImage {
+    /// Compressed point.
+    #[prost(bytes = "vec", tag = "1")]
+    pub point: ::prost::alloc::vec::Vec<u8>,
+}
+/// A signature together with the ring it was made over.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RingSignature {
+    /// The curve's name, e.g. "secp256k1" or "ed25519".
+    #[prost(string, tag = "1")]
+    pub curve: ::prost::alloc::string::String,
+    #[prost(enumeration = "Scheme", tag = "2")]
+    pub scheme: i32,
+    #[prost(message, optional, tag = "3")]
+    pub ring: ::core::option::Option<Ring>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub challenge: ::prost::alloc::vec::Vec<u8>,
+    /// One response per ring member.
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub responses: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// Present for bLSAG signatures only.
+    #[prost(message, optional, tag = "6")]
+    pub key_image: ::core::option::Option<KeyImage>,
+    /// The lingo challenge hash id, e.g. 2 for Keccak-256; 0 for SHA-512.
+    #[prost(uint32, tag = "7")]
+    pub hash_id: u32,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Scheme {
+    Unspecified = 0,
+    Sag = 1,
+    Blsag = 2,
+}
+impl Scheme {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SCHEME_UNSPECIFIED",
+            Self::Sag => "SCHEME_SAG",
+            Self::Blsag => "SCHEME_BLSAG",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SCHEME_UNSPECIFIED" => Some(Self::Unspecified),
+            "SCHEME_SAG" => Some(Self::Sag),
+            "SCHEME_BLSAG" => Some(Self::Blsag),
+            _ => None,
+        }
+    }
+}
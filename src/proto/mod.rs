@@ -0,0 +1,155 @@
+// Protobuf messages from `proto/lingo.proto`, behind the `proto` feature, with conversions to
+// and from the native types. Encode and decode them with `prost::Message`; conversions into
+// native types apply the same checks as the other decoders.
+
+use crate::challenge::{self, ChallengeHash};
+use crate::error::DecodeError;
+use crate::key_image::KeyImage;
+use crate::ring::{CurvePoint, Ring, RingSignature, Scalar, ScalarBigInt};
+use crate::wire::{self, DecodedSignature, WireCurve};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+#[allow(clippy::all)]
+pub mod v1 {
+    include!("lingo.v1.rs");
+}
+
+fn encode<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    wire::write(&mut bytes, value);
+    bytes
+}
+
+// a whole field holding one compressed point or scalar
+fn decode<T: CanonicalDeserialize + CanonicalSerialize + Default>(
+    bytes: &[u8],
+) -> Result<T, DecodeError> {
+    let mut reader = bytes;
+    let value = wire::read(&mut reader)?;
+    if !reader.is_empty() {
+        return Err(DecodeError::InvalidEncoding);
+    }
+    Ok(value)
+}
+
+impl<C: CurveGroup> From<&Ring<C>> for v1::Ring {
+    fn from(ring: &Ring<C>) -> v1::Ring {
+        v1::Ring {
+            keys: ring.keys().iter().map(encode).collect(),
+        }
+    }
+}
+
+/// Applies the same checks as [`Ring::from_fixed_pubkeys`].
+impl<C: CurveGroup> TryFrom<&v1::Ring> for Ring<C> {
+    type Error = DecodeError;
+
+    fn try_from(ring: &v1::Ring) -> Result<Ring<C>, DecodeError> {
+        let keys = ring
+            .keys
+            .iter()
+            .map(|key| decode::<CurvePoint<C>>(key))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ring::from_fixed_pubkeys(keys).map_err(DecodeError::InvalidRing)
+    }
+}
+
+impl<C: CurveGroup> From<&KeyImage<C>> for v1::KeyImage {
+    fn from(image: &KeyImage<C>) -> v1::KeyImage {
+        v1::KeyImage {
+            point: encode(image.point()),
+        }
+    }
+}
+
+/// Rejects the identity and points outside the prime-order subgroup.
+impl<C: CurveGroup> TryFrom<&v1::KeyImage> for KeyImage<C> {
+    type Error = DecodeError;
+
+    fn try_from(image: &v1::KeyImage) -> Result<KeyImage<C>, DecodeError> {
+        let image = KeyImage::from_point(decode::<CurvePoint<C>>(&image.point)?);
+        if !image.is_valid() {
+            return Err(DecodeError::InvalidKeyImage);
+        }
+        Ok(image)
+    }
+}
+
+/// Panics if a scalar is not canonical.
+impl<'a, C: WireCurve> From<&RingSignature<'a, ScalarBigInt<C>, C>> for v1::RingSignature {
+    fn from(signature: &RingSignature<'a, ScalarBigInt<C>, C>) -> v1::RingSignature {
+        let scalar = |value: &ScalarBigInt<C>| {
+            encode(&Scalar::<C>::from_bigint(*value).expect("signature scalars are canonical"))
+        };
+        let scheme = match signature.image {
+            Some(_) => v1::Scheme::Blsag,
+            None => v1::Scheme::Sag,
+        };
+        v1::RingSignature {
+            curve: C::CURVE_NAME.to_string(),
+            scheme: scheme.into(),
+            ring: Some(signature.ring.into()),
+            challenge: scalar(&signature.challenge),
+            responses: signature.ring_sig_vals.iter().map(scalar).collect(),
+            key_image: signature.image.as_ref().map(Into::into),
+            hash_id: match signature.hash_id {
+                challenge::Sha512::HASH_ID => 0,
+                hash_id => hash_id.into(),
+            },
+        }
+    }
+}
+
+impl<C: WireCurve> From<&DecodedSignature<C>> for v1::RingSignature {
+    fn from(signature: &DecodedSignature<C>) -> v1::RingSignature {
+        (&signature.signature()).into()
+    }
+}
+
+/// The message must name curve `C`, carry a key image exactly when its scheme is bLSAG, and
+/// have one canonical response per ring member. SHA-512 is only accepted as the default
+/// `hash_id` of 0.
+impl<C: WireCurve> TryFrom<&v1::RingSignature> for DecodedSignature<C> {
+    type Error = DecodeError;
+
+    fn try_from(signature: &v1::RingSignature) -> Result<DecodedSignature<C>, DecodeError> {
+        if signature.curve != C::CURVE_NAME {
+            return Err(DecodeError::WrongCurveName(signature.curve.clone()));
+        }
+        let linkable = match v1::Scheme::try_from(signature.scheme) {
+            Ok(v1::Scheme::Sag) => false,
+            Ok(v1::Scheme::Blsag) => true,
+            _ => return Err(DecodeError::InvalidProto),
+        };
+        let hash_id = match u8::try_from(signature.hash_id) {
+            Ok(0) => challenge::Sha512::HASH_ID,
+            Ok(challenge::Sha512::HASH_ID) | Err(_) => return Err(DecodeError::InvalidProto),
+            Ok(hash_id) => hash_id,
+        };
+        let ring = Ring::try_from(signature.ring.as_ref().ok_or(DecodeError::InvalidProto)?)?;
+        if linkable != signature.key_image.is_some() || signature.responses.len() != ring.size() {
+            return Err(DecodeError::InvalidProto);
+        }
+
+        let challenge = decode::<Scalar<C>>(&signature.challenge)?.into_bigint();
+        let responses = signature
+            .responses
+            .iter()
+            .map(|response| decode::<Scalar<C>>(response).map(|r| r.into_bigint()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let image = signature
+            .key_image
+            .as_ref()
+            .map(KeyImage::try_from)
+            .transpose()?;
+        Ok(DecodedSignature {
+            ring,
+            challenge,
+            ring_sig_vals: responses,
+            image,
+            hash_id,
+        })
+    }
+}
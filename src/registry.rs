@@ -0,0 +1,171 @@
+use crate::hash;
+use crate::key_image::KeyImage;
+use ark_ec::CurveGroup;
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufReader, Read, Write};
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// Storage backend for a [`KeyImageSet`]. Key images reach the backend as their compressed
+/// encoding, so one backend works for every curve.
+pub trait KeyImageStore {
+    type Error;
+
+    /// Records `image`, returning `false` if it was already present.
+    fn insert(&mut self, image: &[u8]) -> Result<bool, Self::Error>;
+
+    fn contains(&self, image: &[u8]) -> Result<bool, Self::Error>;
+
+    /// Makes every insert so far durable.
+    fn persist(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Registry of spent key images. Applications insert the key image of every signature they
+/// accept and reject any signature whose image is already present.
+pub struct KeyImageSet<C: CurveGroup, S: KeyImageStore> {
+    store: S,
+    curve: PhantomData<C>,
+}
+
+impl<C: CurveGroup, S: KeyImageStore> KeyImageSet<C, S> {
+    pub fn new(store: S) -> KeyImageSet<C, S> {
+        KeyImageSet {
+            store,
+            curve: PhantomData,
+        }
+    }
+
+    /// Records `image`, returning `false` if it had already been seen (a double spend).
+    pub fn insert(&mut self, image: &KeyImage<C>) -> Result<bool, S::Error> {
+        self.store.insert(&hash::point_bytes::<C>(image.point()))
+    }
+
+    pub fn contains(&self, image: &KeyImage<C>) -> Result<bool, S::Error> {
+        self.store.contains(&hash::point_bytes::<C>(image.point()))
+    }
+
+    pub fn persist(&mut self) -> Result<(), S::Error> {
+        self.store.persist()
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn into_store(self) -> S {
+        self.store
+    }
+}
+
+/// In-memory backend; `persist` is a no-op.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStore {
+    images: HashSet<Vec<u8>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> MemoryStore {
+        MemoryStore::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+}
+
+impl KeyImageStore for MemoryStore {
+    type Error = Infallible;
+
+    fn insert(&mut self, image: &[u8]) -> Result<bool, Infallible> {
+        Ok(self.images.insert(image.to_vec()))
+    }
+
+    fn contains(&self, image: &[u8]) -> Result<bool, Infallible> {
+        Ok(self.images.contains(image))
+    }
+
+    fn persist(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+/// Append-only file backend. Every image is held in memory; inserts since the last `persist`
+/// are appended to the file as length-prefixed records and synced to disk.
+pub struct FileStore {
+    file: File,
+    images: HashSet<Vec<u8>>,
+    pending: Vec<Vec<u8>>,
+}
+
+impl FileStore {
+    /// Opens (or creates) the file at `path` and loads every image already recorded in it.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<FileStore> {
+        let file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+
+        let mut images = HashSet::new();
+        let mut reader = BufReader::new(&file);
+        let mut length = [0u8; 4];
+        loop {
+            match reader.read_exact(&mut length) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err),
+            }
+            let mut image = vec![0u8; u32::from_le_bytes(length) as usize];
+            reader.read_exact(&mut image)?;
+            images.insert(image);
+        }
+
+        Ok(FileStore {
+            file,
+            images,
+            pending: Vec::new(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+}
+
+impl KeyImageStore for FileStore {
+    type Error = io::Error;
+
+    fn insert(&mut self, image: &[u8]) -> io::Result<bool> {
+        let inserted = self.images.insert(image.to_vec());
+        if inserted {
+            self.pending.push(image.to_vec());
+        }
+        Ok(inserted)
+    }
+
+    fn contains(&self, image: &[u8]) -> io::Result<bool> {
+        Ok(self.images.contains(image))
+    }
+
+    fn persist(&mut self) -> io::Result<()> {
+        let mut records = Vec::new();
+        for image in &self.pending {
+            records.extend((image.len() as u32).to_le_bytes());
+            records.extend(image);
+        }
+        self.file.write_all(&records)?;
+        self.file.sync_data()?;
+        self.pending.clear();
+        Ok(())
+    }
+}
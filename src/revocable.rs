@@ -0,0 +1,249 @@
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::key_image::KeyImage;
+use crate::ring::{self, CurvePoint, Ring, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField, UniformRand};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+const REVOCABLE_DOMAIN: &[u8] = b"lingo-revocable-v1";
+const TAG_POINT_DOMAIN: &[u8] = b"lingo-revocable-tag-v1";
+const SHARE_POINT_DOMAIN: &[u8] = b"lingo-revocable-share-v1";
+const SHARE_SCALAR_DOMAIN: &[u8] = b"lingo-revocable-share-scalar-v1";
+
+// Every signature under an event publishes the tag `L = x H_1(event)` and a revocation share
+// `D = x (G + h H_2(event))`, where `h` is derived from the event and message. `D` is `K + h Y`
+// with `Y = x H_2` fixed per event, so two shares with different `h` determine `Y` and from it
+// the signer's key `K`; a single share reveals nothing.
+
+/// Signs `message` under `event` for the member at `secret_index`. Signing two different
+/// messages under the same event lets anyone [`revoke`] the signer's anonymity.
+#[cfg(feature = "std")]
+pub fn sign<'a, C>(
+    ring: &'a Ring<C>,
+    event: &[u8],
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+) -> Result<RevocableSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+{
+    sign_with_rng(
+        ring,
+        event,
+        message,
+        private_key,
+        secret_index,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Like [`sign`], drawing the nonce and decoy responses from `rng`.
+pub fn sign_with_rng<'a, C, R>(
+    ring: &'a Ring<C>,
+    event: &[u8],
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    rng: &mut R,
+) -> Result<RevocableSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+    R: RngCore + CryptoRng,
+{
+    let n = ring.size();
+    let secret = ring.signer_secret(private_key, secret_index)?;
+
+    let tag_point = hash::hash_to_point::<C>(TAG_POINT_DOMAIN, event);
+    let share_base = share_base::<C>(event, message);
+    let tag = KeyImage::<C>::from_point(ct::secret_mul::<C>(&tag_point, &secret).into_affine());
+    let revocation = ct::secret_mul::<C>(&share_base.into_affine(), &secret).into_affine();
+
+    let prefix = challenge_prefix::<C>(ring, event, message, &tag, &revocation);
+    let challenge = |key: C, tag: C, share: C| {
+        let mut hasher = prefix.clone();
+        for point in C::normalize_batch(&[key, tag, share]) {
+            hash::append_point::<C>(&mut hasher, &point);
+        }
+        hash::hash_to_scalar::<Scalar<C>>(hasher)
+    };
+
+    let mut challenges = vec![Scalar::<C>::from(0u64); n];
+    let mut responses = vec![Scalar::<C>::from(0u64); n];
+
+    // walked over the ring rotated so the signer is at position 0, as in `Ring::sign`
+    let keys = ct::rotate_left(ring.keys(), secret_index);
+    let alpha = SecretScalar::<C>::random(rng);
+    challenges[1 % n] = challenge(
+        ct::generator_mul::<C>(&alpha),
+        ct::secret_mul::<C>(&tag_point, &alpha),
+        ct::secret_mul::<C>(&share_base.into_affine(), &alpha),
+    );
+
+    for k in 1..n {
+        responses[k] = Scalar::<C>::rand(rng);
+        challenges[(k + 1) % n] = challenge(
+            generator::mul::<C>(&responses[k]) + keys[k] * challenges[k],
+            tag_point * responses[k] + *tag.point() * challenges[k],
+            share_base * responses[k] + revocation * challenges[k],
+        );
+    }
+
+    responses[0] = alpha.response(&challenges[0], &secret);
+    let (challenge, responses) = ring::unrotate::<C>(&challenges, &responses, secret_index);
+
+    Ok(RevocableSignature {
+        ring,
+        event: event.to_vec(),
+        tag,
+        revocation,
+        challenge: challenge.into_bigint(),
+        ring_sig_vals: responses.iter().map(|r| r.into_bigint()).collect(),
+    })
+}
+
+/// Recovers the signer's public key from two valid signatures made with the same key under the
+/// same event over different messages. Returns `None` if the signatures are not linked or were
+/// made over the same message.
+pub fn revoke<B, C>(
+    a: &RevocableSignature<'_, B, C>,
+    message_a: &[u8],
+    b: &RevocableSignature<'_, B, C>,
+    message_b: &[u8],
+) -> Option<CurvePoint<C>>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    if !a.links_with(b) {
+        return None;
+    }
+
+    let h_a = share_scalar::<C>(&a.event, message_a);
+    let h_b = share_scalar::<C>(&b.event, message_b);
+    let inverse = (h_a - h_b).inverse()?;
+    let share = (a.revocation.into_group() - b.revocation) * inverse;
+    Some((share * -h_a + a.revocation).into_affine())
+}
+
+#[derive(PartialEq, Eq)]
+pub struct RevocableSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: &'a Ring<C>,
+    pub event: Vec<u8>,
+    pub tag: KeyImage<C>,
+    pub revocation: CurvePoint<C>,
+    pub challenge: B,
+    pub ring_sig_vals: Vec<B>,
+}
+
+impl<'a, B, C> RevocableSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn ring(&self) -> &Ring<C> {
+        self.ring
+    }
+
+    pub fn event(&self) -> &[u8] {
+        &self.event
+    }
+
+    /// Whether both signatures were made with the same key under the same event, regardless
+    /// of the rings they were made over.
+    pub fn links_with(&self, other: &RevocableSignature<'_, B, C>) -> bool {
+        self.event == other.event && self.tag == other.tag
+    }
+}
+
+impl<'a, C> RevocableSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        let keys = self.ring.keys();
+        if keys.is_empty() {
+            return Err(VerifyError::RingTooSmall);
+        }
+        if keys.len() != self.ring_sig_vals.len() {
+            return Err(VerifyError::LengthMismatch);
+        }
+        if keys.iter().any(|key| key.is_zero()) {
+            return Err(VerifyError::InvalidPoint);
+        }
+        if !self.tag.is_valid() {
+            return Err(VerifyError::InvalidKeyImage);
+        }
+        // like the tag, the share must sit in the prime-order subgroup or revocation could
+        // recover a key shifted by a torsion point
+        if !KeyImage::<C>::from_point(self.revocation).is_valid() {
+            return Err(VerifyError::InvalidPoint);
+        }
+
+        let initial =
+            Scalar::<C>::from_bigint(self.challenge).ok_or(VerifyError::NonCanonicalScalar)?;
+        let tag_point = hash::hash_to_point::<C>(TAG_POINT_DOMAIN, &self.event);
+        let share_base = share_base::<C>(&self.event, message);
+        let prefix =
+            challenge_prefix::<C>(self.ring, &self.event, message, &self.tag, &self.revocation);
+
+        let mut current = initial;
+        for (key, response) in keys.iter().zip(&self.ring_sig_vals) {
+            let response =
+                Scalar::<C>::from_bigint(*response).ok_or(VerifyError::NonCanonicalScalar)?;
+            let points = [
+                generator::mul::<C>(&response) + *key * current,
+                tag_point * response + *self.tag.point() * current,
+                share_base * response + self.revocation * current,
+            ];
+            let mut hasher = prefix.clone();
+            for point in C::normalize_batch(&points) {
+                hash::append_point::<C>(&mut hasher, &point);
+            }
+            current = hash::hash_to_scalar(hasher);
+        }
+
+        error::check_challenge(current, initial)
+    }
+}
+
+fn share_scalar<C: CurveGroup>(event: &[u8], message: &[u8]) -> Scalar<C> {
+    let mut hasher = hash::challenge_hasher::<C>(SHARE_SCALAR_DOMAIN, &[], event);
+    hasher.update((message.len() as u64).to_le_bytes());
+    hasher.update(message);
+    hash::hash_to_scalar(hasher)
+}
+
+// G + h H_2(event), the base the revocation share is taken over
+fn share_base<C: CurveGroup>(event: &[u8], message: &[u8]) -> C {
+    let share_point = hash::hash_to_point::<C>(SHARE_POINT_DOMAIN, event);
+    share_point * share_scalar::<C>(event, message) + C::generator()
+}
+
+fn challenge_prefix<C: CurveGroup>(
+    ring: &Ring<C>,
+    event: &[u8],
+    message: &[u8],
+    tag: &KeyImage<C>,
+    revocation: &CurvePoint<C>,
+) -> Sha512 {
+    let mut prefix = hash::challenge_hasher::<C>(REVOCABLE_DOMAIN, ring.keys(), message);
+    prefix.update((event.len() as u64).to_le_bytes());
+    prefix.update(event);
+    hash::append_point::<C>(&mut prefix, tag.point());
+    hash::append_point::<C>(&mut prefix, revocation);
+    prefix
+}
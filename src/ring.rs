@@ -1,100 +1,623 @@
-use ark_ec::{
-    CurveConfig, CurveGroup, PrimeGroup, hashing::HashToCurve, short_weierstrass::SWCurveConfig,
-    twisted_edwards::TECurveConfig,
-};
-use ark_ff::{BigInt, BigInteger, BigInteger256, FftField, UniformRand};
+use crate::challenge::{self, ChallengeHash};
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::key_image::{self, KeyImage};
+use crate::nonce::NonceRng;
+use crate::parallel;
+use crate::secret::{SecretKey, SecretScalar};
+use crate::trace;
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
+use ark_ff::{BigInteger, PrimeField, UniformRand, Zero};
+use ark_serialize::Valid;
+#[cfg(feature = "std")]
 use ark_std::rand;
-use std::ops::Mul;
+use ark_std::rand::{CryptoRng, Rng, RngCore};
+use sha2::{Digest, Sha512};
 
-type CurvePoint<C> = <<C as CurveConfig>::ScalarField as Mul<BigInt<4>>>::Output;
+#[cfg(feature = "serde")]
+pub use crate::serde_impls::RingSignatureSeed;
 
-#[derive(PartialEq, Eq)]
+pub(crate) type CurvePoint<C> = <C as CurveGroup>::Affine;
+pub(crate) type Scalar<C> = <C as PrimeGroup>::ScalarField;
+pub(crate) type ScalarBigInt<C> = <Scalar<C> as PrimeField>::BigInt;
+
+// Challenges use strong Fiat–Shamir: each one hashes the whole statement, not just the previous
+// commitment. The prefix shared by every challenge of a signature is
+//
+//   domain tag | ring size | every public key, in ring order | message | key image (bLSAG only)
+//
+// with the domain tag and message length-prefixed. The domain tag doubles as the scheme
+// identifier, so a SAG signature cannot be replayed as a bLSAG one or as any other scheme in the
+// crate. Each challenge then appends the commitments at one ring position. With weak
+// Fiat–Shamir, hashing only the commitments, a forger could choose the ring, the message or the
+// key image after the challenges were fixed; here changing any of them changes every challenge
+// and the chain no longer closes.
+
+pub(crate) const SAG_DOMAIN: &[u8] = b"lingo-sag-v1";
+pub(crate) const BLSAG_DOMAIN: &[u8] = b"lingo-blsag-v1";
+const RING_DOMAIN: &[u8] = b"lingo-ring-v1";
+
+/// A ring of public keys over any arkworks curve, e.g. `Ring<ark_secp256k1::Projective>` or
+/// `Ring<ark_ed25519::EdwardsProjective>`.
+#[derive(Clone, PartialEq, Eq)]
 pub struct Ring<C>
 where
-    C: ark_ec::CurveConfig + HashToCurve<C> + CurveGroup,
-    <C as CurveConfig>::ScalarField: CurveGroup,
-    <C as CurveConfig>::ScalarField: Mul<BigInt<4>>,
-    <<C as CurveConfig>::ScalarField as Mul<BigInt<4>>>::Output: CurveGroup,
+    C: CurveGroup,
 {
     keys: Vec<CurvePoint<C>>,
 }
 
 impl<C> Ring<C>
 where
-    C: ark_ec::CurveConfig + HashToCurve<C> + CurveGroup,
-    <C as CurveConfig>::ScalarField: CurveGroup,
-    <C as CurveConfig>::ScalarField: Mul<BigInt<4>>,
-    <<C as CurveConfig>::ScalarField as Mul<BigInt<4>>>::Output: CurveGroup,
+    C: CurveGroup,
 {
-    pub fn new(ring_size: usize, private_key: BigInteger256, index: usize) -> Ring<C> {
-        assert!(index < ring_size);
-        assert!(!BigInteger::is_zero(&private_key));
+    /// A ring of `ring_size` members with the signer's public key at `index` and random keys
+    /// everywhere else.
+    #[cfg(feature = "std")]
+    pub fn new(
+        ring_size: usize,
+        private_key: &SecretKey<C>,
+        index: usize,
+    ) -> Result<Ring<C>, RingError> {
+        Ring::new_with_rng(ring_size, private_key, index, &mut rand::thread_rng())
+    }
 
+    /// Like [`Ring::new`], drawing the random keys from `rng`.
+    pub fn new_with_rng<R: RngCore + CryptoRng>(
+        ring_size: usize,
+        private_key: &SecretKey<C>,
+        index: usize,
+        rng: &mut R,
+    ) -> Result<Ring<C>, RingError> {
+        let _span = trace::ring(trace::curve::<C>(), ring_size);
+        check_signer(private_key, index, ring_size)?;
+
+        // every slot draws a random key and then makes the same constant-time choice between it
+        // and the signer's key, so no slot is filled, copied or moved differently from the others
         let public_key: CurvePoint<C> =
-            <<C as CurveConfig>::ScalarField>::GENERATOR.mul(private_key);
-        let mut public_keys: Vec<CurvePoint<C>> = (0..ring_size)
-            .into_iter()
-            .map(|_| {
-                let mut rng = rand::thread_rng();
-                let pk = BigInteger256::rand(&mut rng);
-                <<C as CurveConfig>::ScalarField>::GENERATOR.mul(pk)
+            ct::generator_mul::<C>(private_key.expose_secret()).into_affine();
+        let keys = (0..ring_size)
+            .map(|i| {
+                let decoy = generator::mul::<C>(&Scalar::<C>::rand(rng)).into_affine();
+                ct::choose(&decoy, &public_key, ct::index_eq(i, index))
             })
             .collect();
+        Ok(Ring { keys })
+    }
 
-        public_keys.push(public_key);
-        public_keys.swap(index, ring_size - 1);
-        Ring { keys: public_keys }
+    /// A ring of `ring_size` members with the signer's public key at a uniformly random
+    /// position, and that position. Callers that pick the index themselves tend to pick the same
+    /// one every time; this leaves nothing about the ring's layout to the caller.
+    #[cfg(feature = "std")]
+    pub fn new_at_random_position(
+        ring_size: usize,
+        private_key: &SecretKey<C>,
+    ) -> Result<(Ring<C>, usize), RingError> {
+        Ring::new_at_random_position_with_rng(ring_size, private_key, &mut rand::thread_rng())
     }
 
-    // does order matter for set of pubkeys in this method outside of the one we designate at a
-    // given index?
+    /// Like [`Ring::new_at_random_position`], drawing the position and the random keys from
+    /// `rng`.
+    pub fn new_at_random_position_with_rng<R: RngCore + CryptoRng>(
+        ring_size: usize,
+        private_key: &SecretKey<C>,
+        rng: &mut R,
+    ) -> Result<(Ring<C>, usize), RingError> {
+        if ring_size == 0 {
+            return Err(RingError::EmptyRing);
+        }
+        let index = rng.gen_range(0..ring_size);
+        Ok((
+            Ring::new_with_rng(ring_size, private_key, index, rng)?,
+            index,
+        ))
+    }
+
+    /// A ring of the keys in `pubs` with the signer's public key spliced in at `index`, and
+    /// that index. The keys from `index` on move up one place, so the ring has `pubs.len() + 1`
+    /// members and `index` may be anything up to `pubs.len()`. The keys in `pubs` are checked as
+    /// [`Ring::from_fixed_pubkeys`] checks them, the error listing their positions in the ring.
     pub fn from_pubkeys(
         pubs: &[CurvePoint<C>],
-        private_key: BigInteger256,
+        private_key: &SecretKey<C>,
         index: usize,
-    ) -> Ring<C> {
+    ) -> Result<(Ring<C>, usize), RingError> {
         let size = pubs.len() + 1;
-        assert!(!private_key.is_zero());
-        assert!(index < size);
-        let mut ring: Vec<CurvePoint<C>> = Vec::with_capacity(size);
-        let public_key = <C as CurveConfig>::ScalarField::GENERATOR.mul(private_key);
-        ring.copy_from_slice(&pubs[..index]);
-        ring[index] = public_key;
-        ring.copy_from_slice(&pubs[index + 1..]);
-        Ring { keys: ring }
+        check_signer(private_key, index, size)?;
+
+        // like `new_with_rng`, every slot makes the same constant-time choices: between the key
+        // that lands there from below the signer and the one from above it, then the signer's
+        let public_key: CurvePoint<C> =
+            ct::generator_mul::<C>(private_key.expose_secret()).into_affine();
+        let keys = (0..size)
+            .map(|i| {
+                let below = pubs.get(i).unwrap_or(&public_key);
+                let above = i.checked_sub(1).map_or(&public_key, |i| &pubs[i]);
+                let decoy = ct::choose(below, above, ct::index_gt(i, index));
+                ct::choose(&decoy, &public_key, ct::index_eq(i, index))
+            })
+            .collect();
+        Ok((Ring::from_fixed_pubkeys(keys)?, index))
+    }
+
+    /// Builds a ring from existing public keys, which must be non-empty. Every key must be on the
+    /// curve, in the prime-order subgroup and not the identity; the error lists the indices of any
+    /// that are not.
+    pub fn from_fixed_pubkeys(public_keys: Vec<CurvePoint<C>>) -> Result<Ring<C>, RingError> {
+        let _span = trace::ring(trace::curve::<C>(), public_keys.len());
+        if public_keys.is_empty() {
+            return Err(RingError::EmptyRing);
+        }
+        let invalid: Vec<usize> = public_keys
+            .iter()
+            .enumerate()
+            .filter(|(_, key)| key.is_zero() || key.check().is_err())
+            .map(|(i, _)| i)
+            .collect();
+        if !invalid.is_empty() {
+            return Err(RingError::InvalidPublicKeys(invalid));
+        }
+        Ok(Ring { keys: public_keys })
     }
 
-    pub fn from_fixed_pubkeys(public_keys: Vec<CurvePoint<C>>) -> Ring<C> {
+    // deserialization without validation skips the checks `from_fixed_pubkeys` makes
+    pub(crate) fn from_unchecked_pubkeys(public_keys: Vec<CurvePoint<C>>) -> Ring<C> {
         Ring { keys: public_keys }
     }
 
+    /// The same ring with its keys sorted by their compressed encoding, and the position the
+    /// member at `secret_index` moved to. Parties building a ring from the same set of keys get
+    /// identical rings, whatever order they collected the keys in. Fails if a key appears more
+    /// than once, listing the indices of the repeats.
+    pub fn canonicalize(&self, secret_index: usize) -> Result<(Ring<C>, usize), RingError> {
+        let size = self.size();
+        if secret_index >= size {
+            return Err(RingError::IndexOutOfBounds {
+                index: secret_index,
+                size,
+            });
+        }
+        let encodings: Vec<Vec<u8>> = self.keys.iter().map(hash::point_bytes::<C>).collect();
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|a, b| encodings[*a].cmp(&encodings[*b]).then(a.cmp(b)));
+
+        let mut duplicates: Vec<usize> = order
+            .windows(2)
+            .filter(|pair| encodings[pair[0]] == encodings[pair[1]])
+            .map(|pair| pair[1])
+            .collect();
+        if !duplicates.is_empty() {
+            duplicates.sort_unstable();
+            return Err(RingError::DuplicatePublicKeys(duplicates));
+        }
+
+        let position = order
+            .iter()
+            .position(|index| *index == secret_index)
+            .expect("the order is a permutation of the ring");
+        let keys = order.iter().map(|index| self.keys[*index]).collect();
+        Ok((Ring { keys }, position))
+    }
+
+    /// Whether the keys are strictly increasing by compressed encoding, as
+    /// [`Ring::canonicalize`] leaves them.
+    pub fn is_canonical(&self) -> bool {
+        let encodings: Vec<Vec<u8>> = self.keys.iter().map(hash::point_bytes::<C>).collect();
+        encodings.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
     pub fn size(&self) -> usize {
         self.keys.len()
     }
+
+    pub fn keys(&self) -> &[CurvePoint<C>] {
+        &self.keys
+    }
+
+    /// A 32-byte digest of the keys in order, for looking a ring up by hash, e.g. when
+    /// signatures are stored without their ring.
+    pub fn digest(&self) -> [u8; 32] {
+        let digest = hash::challenge_hasher::<C>(RING_DOMAIN, &self.keys, &[]).finalize();
+        digest[..32]
+            .try_into()
+            .expect("SHA-512 digests are 64 bytes")
+    }
+
+    // every scheme signing over a ring checks the signer's key the same way
+    pub(crate) fn signer_secret(
+        &self,
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<SecretScalar<C>, RingError> {
+        check_signer(private_key, secret_index, self.size())?;
+        let secret = SecretScalar::<C>::from_private_key(private_key);
+        let public_key = ct::generator_mul::<C>(&secret).into_affine();
+        if !bool::from(ct::holds_at(&self.keys, &public_key, secret_index)) {
+            return Err(RingError::KeyMismatch);
+        }
+        Ok(secret)
+    }
+
+    /// Produces a SAG (Spontaneous Anonymous Group) signature over `message` on behalf of the
+    /// ring member at `secret_index`, whose private key must be `private_key`. Every challenge
+    /// commits to the whole ring, in order, and the message, so the signature verifies under no
+    /// other ring, ordering of it or message.
+    ///
+    /// Fails with [`RingError::IndexOutOfBounds`] if `secret_index` is not in the ring, and with
+    /// [`RingError::KeyMismatch`] if `private_key` does not belong to the member there. Every
+    /// other signing method in the crate fails the same way.
+    #[cfg(feature = "std")]
+    pub fn sign(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_with_rng(message, private_key, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`Ring::sign`], but every nonce is derived from the private key, the ring and the
+    /// message, so the signature is reproducible and needs no entropy source.
+    pub fn sign_deterministic(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        let mut rng = NonceRng::new::<C>(SAG_DOMAIN, private_key, &self.keys, message);
+        self.sign_with_rng(message, private_key, secret_index, &mut rng)
+    }
+
+    /// Like [`Ring::sign`], with Fiat–Shamir challenges made by `H` rather than SHA-512. The
+    /// signature records which hash it used.
+    #[cfg(feature = "std")]
+    pub fn sign_with_hash<H: ChallengeHash>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_with_hash_and_rng::<H, _>(
+            message,
+            private_key,
+            secret_index,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`Ring::sign_with_hash`], drawing the nonces from `rng`.
+    pub fn sign_with_hash_and_rng<H: ChallengeHash, R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_hashed::<H, R>(H::new(), message, private_key, secret_index, rng)
+    }
+
+    /// Like [`Ring::sign`], drawing the nonces from `rng`.
+    pub fn sign_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_hashed::<challenge::Sha512, R>(
+            challenge::Sha512::new(),
+            message,
+            private_key,
+            secret_index,
+            rng,
+        )
+    }
+
+    pub(crate) fn sign_hashed<H: ChallengeHash, R: Rng>(
+        &self,
+        start: H::State,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        let _span = trace::sign(trace::curve::<C>(), self.size(), false, H::HASH_ID);
+        let secret = self.signer_secret(private_key, secret_index)?;
+
+        let alpha = SecretScalar::<C>::random(rng);
+        let (challenges, mut responses) = self.sag_chain::<H, R>(
+            start,
+            message,
+            secret_index,
+            ct::generator_mul::<C>(&alpha),
+            rng,
+        );
+        responses[0] = alpha.response(&challenges[0], &secret);
+        let (challenge, responses) = unrotate::<C>(&challenges, &responses, secret_index);
+
+        Ok(RingSignature {
+            ring: self,
+            challenge: challenge.into_bigint(),
+            ring_sig_vals: responses.iter().map(|r| r.into_bigint()).collect(),
+            image: None,
+            hash_id: H::HASH_ID,
+        })
+    }
+
+    // Runs the SAG chain around the ring starting from the signer's commitment `alpha * G`.
+    // Every challenge is bound to the full ring and message, see the comment at the top. The
+    // chain is walked over the ring rotated so the signer is at position 0 (see `ct`), and the
+    // challenges and responses come back in that order: responses are filled in for every decoy,
+    // position 0 is left for the caller, and `unrotate` puts them back in ring order.
+    pub(crate) fn sag_chain<H: ChallengeHash, R: Rng + ?Sized>(
+        &self,
+        start: H::State,
+        message: &[u8],
+        secret_index: usize,
+        commitment: C,
+        rng: &mut R,
+    ) -> (Vec<Scalar<C>>, Vec<Scalar<C>>) {
+        let n = self.size();
+        let prefix = hash::challenge_state_from::<C, H>(start, SAG_DOMAIN, &self.keys, message);
+        let challenge = |point: C| {
+            let mut state = prefix.clone();
+            hash::append_point_with::<C, H>(&mut state, b"commitment", &point.into_affine());
+            hash::state_to_scalar::<Scalar<C>, H>(state)
+        };
+
+        let keys = ct::rotate_left(&self.keys, secret_index);
+        let mut challenges = vec![Scalar::<C>::from(0u64); n];
+        let mut responses = vec![Scalar::<C>::from(0u64); n];
+        challenges[1 % n] = challenge(commitment);
+
+        for k in 1..n {
+            responses[k] = Scalar::<C>::rand(rng);
+            let point = generator::mul::<C>(&responses[k]) + keys[k] * challenges[k];
+            challenges[(k + 1) % n] = challenge(point);
+        }
+
+        (challenges, responses)
+    }
+
+    /// Produces a linkable bLSAG signature. The returned signature carries the signer's
+    /// [`KeyImage`], which is identical for every signature made with the same private key.
+    #[cfg(feature = "std")]
+    pub fn sign_linkable(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_linkable_with_rng(message, private_key, secret_index, &mut rand::thread_rng())
+    }
+
+    /// Deterministic counterpart of [`Ring::sign_linkable`]; see [`Ring::sign_deterministic`].
+    pub fn sign_linkable_deterministic(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        let mut rng = NonceRng::new::<C>(BLSAG_DOMAIN, private_key, &self.keys, message);
+        self.sign_linkable_with_rng(message, private_key, secret_index, &mut rng)
+    }
+
+    /// Like [`Ring::sign_linkable`], with Fiat–Shamir challenges made by `H` rather than
+    /// SHA-512. The key image does not depend on the hash.
+    #[cfg(feature = "std")]
+    pub fn sign_linkable_with_hash<H: ChallengeHash>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_linkable_with_hash_and_rng::<H, _>(
+            message,
+            private_key,
+            secret_index,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`Ring::sign_linkable_with_hash`], drawing the nonces from `rng`.
+    pub fn sign_linkable_with_hash_and_rng<H: ChallengeHash, R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_linkable_hashed::<H, R>(H::new(), message, private_key, secret_index, rng)
+    }
+
+    /// Like [`Ring::sign_linkable`], drawing the nonces from `rng`.
+    pub fn sign_linkable_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_linkable_hashed::<challenge::Sha512, R>(
+            challenge::Sha512::new(),
+            message,
+            private_key,
+            secret_index,
+            rng,
+        )
+    }
+
+    /// Produces a linkable bLSAG signature whose key image is salted with `context`: it is
+    /// `k * H_p(K || context)` rather than `k * H_p(K)`. Signatures made under the same context
+    /// link as usual, while the same key signing under two contexts leaves two unrelated key
+    /// images, so applications using different contexts cannot link the key's activity across
+    /// them. The context is also bound into every challenge; verify with
+    /// [`RingSignature::verify_salted`] and the same context.
+    #[cfg(feature = "std")]
+    pub fn sign_linkable_salted(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        context: &[u8],
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_linkable_salted_with_rng(
+            message,
+            private_key,
+            secret_index,
+            context,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`Ring::sign_linkable_salted`], drawing the nonces from `rng`.
+    pub fn sign_linkable_salted_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        context: &[u8],
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_blsag::<challenge::Sha512, R>(
+            salted_start::<challenge::Sha512>(context),
+            salted_hash_keys::<C>(&self.keys, context),
+            message,
+            private_key,
+            secret_index,
+            rng,
+        )
+    }
+
+    pub(crate) fn sign_linkable_hashed<H: ChallengeHash, R: Rng>(
+        &self,
+        start: H::State,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_blsag::<H, R>(
+            start,
+            self.key_hashes(),
+            message,
+            private_key,
+            secret_index,
+            rng,
+        )
+    }
+
+    // bLSAG signing with the members' key image bases `hashes` given, plain or salted
+    fn sign_blsag<H: ChallengeHash, R: Rng>(
+        &self,
+        start: H::State,
+        hashes: Vec<CurvePoint<C>>,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        let _span = trace::sign(trace::curve::<C>(), self.size(), true, H::HASH_ID);
+        let secret = self.signer_secret(private_key, secret_index)?;
+
+        let signer_hash = ct::select(&hashes, secret_index);
+        let image =
+            KeyImage::<C>::from_point(ct::secret_mul::<C>(&signer_hash, &secret).into_affine());
+        let alpha = SecretScalar::<C>::random(rng);
+        let (challenges, mut responses) = self.blsag_chain::<H, R>(
+            start,
+            message,
+            secret_index,
+            &hashes,
+            &image,
+            (
+                ct::generator_mul::<C>(&alpha),
+                ct::secret_mul::<C>(&signer_hash, &alpha),
+            ),
+            rng,
+        );
+        responses[0] = alpha.response(&challenges[0], &secret);
+        let (challenge, responses) = unrotate::<C>(&challenges, &responses, secret_index);
+
+        Ok(RingSignature {
+            ring: self,
+            challenge: challenge.into_bigint(),
+            ring_sig_vals: responses.iter().map(|r| r.into_bigint()).collect(),
+            image: Some(image),
+            hash_id: H::HASH_ID,
+        })
+    }
+
+    // `H_p(P)` for every member `P`, in ring order, computed for all of them so the signer's is
+    // not singled out
+    pub(crate) fn key_hashes(&self) -> Vec<CurvePoint<C>> {
+        hash_keys::<C>(&self.keys)
+    }
+
+    // bLSAG counterpart of `sag_chain`; `hashes` are the members' `key_hashes` and `commitment`
+    // is `(alpha * G, alpha * H_p(P))` for the signer's public key `P`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn blsag_chain<H: ChallengeHash, R: Rng + ?Sized>(
+        &self,
+        start: H::State,
+        message: &[u8],
+        secret_index: usize,
+        hashes: &[CurvePoint<C>],
+        image: &KeyImage<C>,
+        commitment: (C, C),
+        rng: &mut R,
+    ) -> (Vec<Scalar<C>>, Vec<Scalar<C>>) {
+        let n = self.size();
+        let mut prefix =
+            hash::challenge_state_from::<C, H>(start, BLSAG_DOMAIN, &self.keys, message);
+        hash::append_point_with::<C, H>(&mut prefix, b"key-image", image.point());
+        let challenge = |left: C, right: C| {
+            let mut state = prefix.clone();
+            hash::append_point_with::<C, H>(&mut state, b"commitment", &left.into_affine());
+            hash::append_point_with::<C, H>(&mut state, b"commitment", &right.into_affine());
+            hash::state_to_scalar::<Scalar<C>, H>(state)
+        };
+
+        let mut challenges = vec![Scalar::<C>::from(0u64); n];
+        let mut responses = vec![Scalar::<C>::from(0u64); n];
+
+        let keys = ct::rotate_left(&self.keys, secret_index);
+        let hashes = ct::rotate_left(hashes, secret_index);
+        challenges[1 % n] = challenge(commitment.0, commitment.1);
+
+        for k in 1..n {
+            responses[k] = Scalar::<C>::rand(rng);
+            let left = generator::mul::<C>(&responses[k]) + keys[k] * challenges[k];
+            let right = hashes[k] * responses[k] + *image.point() * challenges[k];
+            challenges[(k + 1) % n] = challenge(left, right);
+        }
+
+        (challenges, responses)
+    }
 }
 
 #[derive(PartialEq, Eq)]
 pub struct RingSignature<'a, B, C>
 where
     B: BigInteger,
-    C: ark_ec::CurveConfig + HashToCurve<C> + CurveGroup,
-    <C as CurveConfig>::ScalarField: CurveGroup,
-    <C as CurveConfig>::ScalarField: Mul<BigInt<4>>,
-    <<C as CurveConfig>::ScalarField as Mul<BigInt<4>>>::Output: CurveGroup,
+    C: CurveGroup,
 {
     pub ring: &'a Ring<C>,
     pub challenge: B,
     pub ring_sig_vals: Vec<B>,
-    pub image: CurvePoint<C>,
+    pub image: Option<KeyImage<C>>,
+    /// The [`ChallengeHash::HASH_ID`] of the hash the challenges were made with.
+    pub hash_id: u8,
 }
 
 impl<'a, B, C> RingSignature<'a, B, C>
 where
     B: BigInteger,
-    C: ark_ec::CurveConfig + HashToCurve<C> + CurveGroup,
-    <C as CurveConfig>::ScalarField: CurveGroup,
-    <C as CurveConfig>::ScalarField: Mul<BigInt<4>>,
-    <<C as CurveConfig>::ScalarField as Mul<BigInt<4>>>::Output: CurveGroup,
+    C: CurveGroup,
 {
     pub fn public_keys(&self) -> &[CurvePoint<C>] {
         &self.ring.keys
@@ -103,4 +626,580 @@ where
     pub fn ring(&self) -> &Ring<C> {
         self.ring
     }
+
+    pub fn key_image(&self) -> Option<&KeyImage<C>> {
+        self.image.as_ref()
+    }
+
+    /// Whether both signatures are linkable and carry the same key image, i.e. were made with
+    /// the same private key. Always `false` if either one is a plain SAG signature.
+    pub fn links_with(&self, other: &RingSignature<'_, B, C>) -> bool {
+        matches!((&self.image, &other.image), (Some(a), Some(b)) if a == b)
+    }
+}
+
+impl<'a, C> RingSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    /// Checks the signature against `message`: plain SAG when no key image is attached,
+    /// bLSAG otherwise.
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        verify_parts::<C>(
+            self.public_keys(),
+            self.challenge,
+            &self.ring_sig_vals,
+            self.image.as_ref(),
+            self.hash_id,
+            message,
+        )
+    }
+
+    /// Checks the signature against `message` with the challenge hash `H`, for hashes outside
+    /// the crate. Fails with [`VerifyError::UnsupportedHash`] if the signature was made with
+    /// another hash.
+    pub fn verify_with_hash<H: ChallengeHash>(&self, message: &[u8]) -> Result<(), VerifyError> {
+        if self.hash_id != H::HASH_ID {
+            return Err(VerifyError::UnsupportedHash);
+        }
+        verify_hashed::<C, H>(
+            H::new(),
+            self.public_keys(),
+            self.challenge,
+            &self.ring_sig_vals,
+            self.image.as_ref(),
+            message,
+        )
+    }
+
+    /// Checks a signature made with [`Ring::sign_linkable_salted`] against `message` and the
+    /// `context` it was salted with.
+    pub fn verify_salted(&self, message: &[u8], context: &[u8]) -> Result<(), VerifyError> {
+        if self.hash_id != challenge::Sha512::HASH_ID {
+            return Err(VerifyError::UnsupportedHash);
+        }
+        verify_with_hashes::<C, challenge::Sha512>(
+            salted_start::<challenge::Sha512>(context),
+            self.public_keys(),
+            self.challenge,
+            &self.ring_sig_vals,
+            self.image.as_ref(),
+            message,
+            |keys| salted_hash_keys::<C>(keys, context),
+        )
+    }
+
+    /// Drops the borrowed ring, keeping only what must be stored next to the signature.
+    pub fn detach(&self) -> DetachedSignature<ScalarBigInt<C>, C> {
+        DetachedSignature {
+            challenge: self.challenge,
+            ring_sig_vals: self.ring_sig_vals.clone(),
+            image: self.image,
+            hash_id: self.hash_id,
+        }
+    }
+
+    /// Copies the ring into the signature, which then no longer borrows it.
+    pub fn into_owned(self) -> OwnedRingSignature<ScalarBigInt<C>, C> {
+        OwnedRingSignature {
+            ring: self.ring.clone(),
+            challenge: self.challenge,
+            ring_sig_vals: self.ring_sig_vals,
+            image: self.image,
+            hash_id: self.hash_id,
+        }
+    }
+
+    /// Verifies every `(message, signature)` pair, failing if any of them is invalid. Mixing
+    /// SAG and bLSAG signatures and rings of different sizes is allowed. Signatures made with a
+    /// challenge hash other than SHA-512 are checked one by one, and an unknown hash fails with
+    /// [`VerifyError::UnsupportedHash`].
+    ///
+    /// This is not a random-linear-combination check over one multi-scalar multiplication: a
+    /// signature carries only its first challenge and the responses, not the commitments, and
+    /// each commitment is recovered from the challenge before it. Every signature's chain is
+    /// still walked member by member; the batch saves work by advancing all chains together and
+    /// sharing each round's normalization.
+    ///
+    /// Only signatures made by [`Ring::sign`], [`Ring::sign_linkable`] and their `_with_rng` and
+    /// `_with_hash` variants are supported. Salted, signing-context and transcript signatures
+    /// look the same on the wire but start their chains from state the batch does not have, so
+    /// they fail here; check them with [`RingSignature::verify_salted`],
+    /// [`SigningContext::verify`](crate::context::SigningContext::verify) and
+    /// [`RingSignature::verify_with_transcript`] instead.
+    // all chains advance in lockstep: the generator's terms come from its table, the variable
+    // bases of a bLSAG commitment share one multi-scalar multiplication, each round's commitments
+    // are computed across the chains in parallel with the `parallel` feature, and every round is
+    // normalized with one batch inversion
+    pub fn batch_verify(batch: &[(&[u8], &Self)]) -> Result<(), VerifyError> {
+        let _span = trace::batch_verify(trace::curve::<C>(), batch.len());
+        let mut chains = Vec::with_capacity(batch.len());
+        for (message, signature) in batch {
+            if signature.hash_id != challenge::Sha512::HASH_ID {
+                signature.verify(message)?;
+                continue;
+            }
+            let keys = signature.public_keys();
+            let (initial, responses) =
+                decode_scalars::<C>(keys, signature.challenge, &signature.ring_sig_vals)?;
+            let prefix = match &signature.image {
+                None => hash::challenge_hasher::<C>(SAG_DOMAIN, keys, message),
+                Some(image) => {
+                    if !image.is_valid() {
+                        return Err(VerifyError::InvalidKeyImage);
+                    }
+                    let mut prefix = hash::challenge_hasher::<C>(BLSAG_DOMAIN, keys, message);
+                    hash::append_point::<C>(&mut prefix, image.point());
+                    prefix
+                }
+            };
+            let hashes = match &signature.image {
+                None => Vec::new(),
+                Some(_) => hash_keys::<C>(keys),
+            };
+            chains.push(Chain {
+                signature,
+                prefix,
+                hashes,
+                responses,
+                initial,
+                current: initial,
+            });
+        }
+
+        let rounds = chains
+            .iter()
+            .map(|chain| chain.responses.len())
+            .max()
+            .unwrap_or(0);
+        for round in 0..rounds {
+            let points = parallel::map(chains.len(), |j| {
+                let chain = &chains[j];
+                let Some(response) = chain.responses.get(round) else {
+                    return Vec::new();
+                };
+                let key = chain.signature.public_keys()[round];
+                let mut points = vec![generator::mul::<C>(response) + key * chain.current];
+                if let Some(image) = &chain.signature.image {
+                    points.push(C::msm_unchecked(
+                        &[chain.hashes[round], *image.point()],
+                        &[*response, chain.current],
+                    ));
+                }
+                points
+            });
+
+            let mut points = C::normalize_batch(&points.concat()).into_iter();
+            for chain in &mut chains {
+                if round >= chain.responses.len() {
+                    continue;
+                }
+                let mut hasher = chain.prefix.clone();
+                let count = if chain.signature.image.is_some() {
+                    2
+                } else {
+                    1
+                };
+                for point in points.by_ref().take(count) {
+                    hash::append_point::<C>(&mut hasher, &point);
+                }
+                chain.current = hash::hash_to_scalar(hasher);
+            }
+        }
+
+        chains
+            .iter()
+            .try_for_each(|chain| error::check_challenge(chain.current, chain.initial))
+    }
+}
+
+/// A [`RingSignature`] without its ring, for when rings are stored apart from signatures (e.g.
+/// in a database). Verification takes the ring separately and must be given the same keys, in
+/// the same order, that the signature was made over.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DetachedSignature<B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub challenge: B,
+    pub ring_sig_vals: Vec<B>,
+    pub image: Option<KeyImage<C>>,
+    /// The [`ChallengeHash::HASH_ID`] of the hash the challenges were made with.
+    pub hash_id: u8,
+}
+
+impl<B, C> DetachedSignature<B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn key_image(&self) -> Option<&KeyImage<C>> {
+        self.image.as_ref()
+    }
+
+    pub fn links_with(&self, other: &DetachedSignature<B, C>) -> bool {
+        matches!((&self.image, &other.image), (Some(a), Some(b)) if a == b)
+    }
+
+    /// Re-attaches the signature to `ring`.
+    pub fn attach(self, ring: &Ring<C>) -> RingSignature<'_, B, C> {
+        RingSignature {
+            ring,
+            challenge: self.challenge,
+            ring_sig_vals: self.ring_sig_vals,
+            image: self.image,
+            hash_id: self.hash_id,
+        }
+    }
+
+    /// Attaches the signature to `ring`, taking ownership of it.
+    pub fn attach_owned(self, ring: Ring<C>) -> OwnedRingSignature<B, C> {
+        OwnedRingSignature {
+            ring,
+            challenge: self.challenge,
+            ring_sig_vals: self.ring_sig_vals,
+            image: self.image,
+            hash_id: self.hash_id,
+        }
+    }
+}
+
+impl<C> DetachedSignature<ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify_with_ring(&self, ring: &Ring<C>, message: &[u8]) -> Result<(), VerifyError> {
+        verify_parts::<C>(
+            ring.keys(),
+            self.challenge,
+            &self.ring_sig_vals,
+            self.image.as_ref(),
+            self.hash_id,
+            message,
+        )
+    }
+}
+
+/// A [`RingSignature`] that owns its ring rather than borrowing it, so it can be kept in
+/// long-lived structs or returned from the function that built the ring.
+#[derive(Clone, PartialEq, Eq)]
+pub struct OwnedRingSignature<B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: Ring<C>,
+    pub challenge: B,
+    pub ring_sig_vals: Vec<B>,
+    pub image: Option<KeyImage<C>>,
+    /// The [`ChallengeHash::HASH_ID`] of the hash the challenges were made with.
+    pub hash_id: u8,
+}
+
+impl<B, C> OwnedRingSignature<B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn public_keys(&self) -> &[CurvePoint<C>] {
+        &self.ring.keys
+    }
+
+    pub fn ring(&self) -> &Ring<C> {
+        &self.ring
+    }
+
+    pub fn key_image(&self) -> Option<&KeyImage<C>> {
+        self.image.as_ref()
+    }
+
+    pub fn links_with(&self, other: &OwnedRingSignature<B, C>) -> bool {
+        matches!((&self.image, &other.image), (Some(a), Some(b)) if a == b)
+    }
+
+    /// The signature, borrowing the owned ring.
+    pub fn signature(&self) -> RingSignature<'_, B, C> {
+        RingSignature {
+            ring: &self.ring,
+            challenge: self.challenge,
+            ring_sig_vals: self.ring_sig_vals.clone(),
+            image: self.image,
+            hash_id: self.hash_id,
+        }
+    }
+
+    /// Splits off the ring, e.g. to store it apart from the signature.
+    pub fn detach(self) -> (Ring<C>, DetachedSignature<B, C>) {
+        let signature = DetachedSignature {
+            challenge: self.challenge,
+            ring_sig_vals: self.ring_sig_vals,
+            image: self.image,
+            hash_id: self.hash_id,
+        };
+        (self.ring, signature)
+    }
+}
+
+impl<C> OwnedRingSignature<ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        verify_parts::<C>(
+            self.public_keys(),
+            self.challenge,
+            &self.ring_sig_vals,
+            self.image.as_ref(),
+            self.hash_id,
+            message,
+        )
+    }
+}
+
+impl<'a, C> From<RingSignature<'a, ScalarBigInt<C>, C>> for OwnedRingSignature<ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    fn from(signature: RingSignature<'a, ScalarBigInt<C>, C>) -> Self {
+        signature.into_owned()
+    }
+}
+
+// dispatches on the crate's challenge hashes
+fn verify_parts<C: CurveGroup>(
+    keys: &[CurvePoint<C>],
+    challenge: ScalarBigInt<C>,
+    ring_sig_vals: &[ScalarBigInt<C>],
+    image: Option<&KeyImage<C>>,
+    hash_id: u8,
+    message: &[u8],
+) -> Result<(), VerifyError> {
+    struct Verify<'v, C: CurveGroup> {
+        keys: &'v [CurvePoint<C>],
+        challenge: ScalarBigInt<C>,
+        ring_sig_vals: &'v [ScalarBigInt<C>],
+        image: Option<&'v KeyImage<C>>,
+        message: &'v [u8],
+    }
+    impl<C: CurveGroup> challenge::HashVisitor for Verify<'_, C> {
+        type Output = Result<(), VerifyError>;
+        fn visit<H: ChallengeHash>(self) -> Result<(), VerifyError> {
+            verify_hashed::<C, H>(
+                H::new(),
+                self.keys,
+                self.challenge,
+                self.ring_sig_vals,
+                self.image,
+                self.message,
+            )
+        }
+    }
+    let verify = Verify {
+        keys,
+        challenge,
+        ring_sig_vals,
+        image,
+        message,
+    };
+    challenge::visit_hash(hash_id, verify).unwrap_or(Err(VerifyError::UnsupportedHash))
+}
+
+pub(crate) fn verify_hashed<C: CurveGroup, H: ChallengeHash>(
+    start: H::State,
+    keys: &[CurvePoint<C>],
+    challenge: ScalarBigInt<C>,
+    ring_sig_vals: &[ScalarBigInt<C>],
+    image: Option<&KeyImage<C>>,
+    message: &[u8],
+) -> Result<(), VerifyError> {
+    verify_with_hashes::<C, H>(
+        start,
+        keys,
+        challenge,
+        ring_sig_vals,
+        image,
+        message,
+        hash_keys::<C>,
+    )
+}
+
+// `verify_hashed` with the key image bases of bLSAG signatures made by `hashes`, plain or salted
+fn verify_with_hashes<C: CurveGroup, H: ChallengeHash>(
+    start: H::State,
+    keys: &[CurvePoint<C>],
+    challenge: ScalarBigInt<C>,
+    ring_sig_vals: &[ScalarBigInt<C>],
+    image: Option<&KeyImage<C>>,
+    message: &[u8],
+    hashes: impl FnOnce(&[CurvePoint<C>]) -> Vec<CurvePoint<C>>,
+) -> Result<(), VerifyError> {
+    let _span = trace::verify(trace::curve::<C>(), keys.len(), image.is_some(), H::HASH_ID);
+    let (initial, responses) = decode_scalars::<C>(keys, challenge, ring_sig_vals)?;
+    match image {
+        None => {
+            let ring_state = hash::ring_state_from::<C, H>(start, SAG_DOMAIN, keys);
+            verify_sag::<C, H>(ring_state, keys, message, initial, &responses)
+        }
+        Some(image) => {
+            if !image.is_valid() {
+                return Err(VerifyError::InvalidKeyImage);
+            }
+            let ring_state = hash::ring_state_from::<C, H>(start, BLSAG_DOMAIN, keys);
+            let hashes = hashes(keys);
+            verify_blsag::<C, H>(
+                ring_state, keys, &hashes, message, image, initial, &responses,
+            )
+        }
+    }
+}
+
+// The first challenge and the responses in ring order, from a chain walked with the signer at
+// position 0.
+pub(crate) fn unrotate<C: CurveGroup>(
+    challenges: &[Scalar<C>],
+    responses: &[Scalar<C>],
+    secret_index: usize,
+) -> (Scalar<C>, Vec<Scalar<C>>) {
+    let first = ct::select(challenges, ct::complement(secret_index, challenges.len()));
+    (first, ct::rotate_right(responses, secret_index))
+}
+
+fn check_signer<C: CurveGroup>(
+    private_key: &SecretKey<C>,
+    index: usize,
+    size: usize,
+) -> Result<(), RingError> {
+    if private_key.expose_secret().is_zero() {
+        return Err(RingError::ZeroPrivateKey);
+    }
+    if index >= size {
+        return Err(RingError::IndexOutOfBounds { index, size });
+    }
+    Ok(())
+}
+
+// structural checks shared by single and batch verification, returning the decoded initial
+// challenge and responses
+pub(crate) fn decode_scalars<C: CurveGroup>(
+    keys: &[CurvePoint<C>],
+    challenge: ScalarBigInt<C>,
+    ring_sig_vals: &[ScalarBigInt<C>],
+) -> Result<(Scalar<C>, Vec<Scalar<C>>), VerifyError> {
+    if keys.is_empty() {
+        return Err(VerifyError::RingTooSmall);
+    }
+    if keys.len() != ring_sig_vals.len() {
+        return Err(VerifyError::LengthMismatch);
+    }
+    if keys.iter().any(|key| key.is_zero()) {
+        return Err(VerifyError::InvalidPoint);
+    }
+
+    let initial = Scalar::<C>::from_bigint(challenge).ok_or(VerifyError::NonCanonicalScalar)?;
+    let responses = ring_sig_vals
+        .iter()
+        .map(|r| Scalar::<C>::from_bigint(*r))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(VerifyError::NonCanonicalScalar)?;
+    Ok((initial, responses))
+}
+
+// per-signature state while batch verifying
+struct Chain<'s, 'a, C: CurveGroup> {
+    signature: &'s RingSignature<'a, ScalarBigInt<C>, C>,
+    prefix: Sha512,
+    // the members' `H_p(K)`, for bLSAG signatures only
+    hashes: Vec<CurvePoint<C>>,
+    responses: Vec<Scalar<C>>,
+    initial: Scalar<C>,
+    current: Scalar<C>,
+}
+
+// recomputes the challenge chain around the ring; the signature is valid iff it closes on the
+// stored initial challenge. `ring_state` has the ring absorbed under `SAG_DOMAIN` already (see
+// `hash::ring_state_from`).
+pub(crate) fn verify_sag<C: CurveGroup, H: ChallengeHash>(
+    ring_state: H::State,
+    keys: &[CurvePoint<C>],
+    message: &[u8],
+    initial: Scalar<C>,
+    responses: &[Scalar<C>],
+) -> Result<(), VerifyError> {
+    let mut prefix = ring_state;
+    H::append_sized(&mut prefix, b"message", message);
+    let mut current = initial;
+    for (key, response) in keys.iter().zip(responses) {
+        let point = generator::mul::<C>(response) + *key * current;
+        let mut state = prefix.clone();
+        hash::append_point_with::<C, H>(&mut state, b"commitment", &point.into_affine());
+        current = hash::state_to_scalar::<Scalar<C>, H>(state);
+    }
+    error::check_challenge(current, initial)
+}
+
+// `H_p(K)` for every key, normalized with one inversion
+pub(crate) fn hash_keys<C: CurveGroup>(keys: &[CurvePoint<C>]) -> Vec<CurvePoint<C>> {
+    C::normalize_batch(&parallel::map(keys.len(), |i| {
+        key_image::hash_point::<C>(&keys[i])
+    }))
+}
+
+// `H_p(K || context)` for every key, the bases of key images salted with `context`
+fn salted_hash_keys<C: CurveGroup>(keys: &[CurvePoint<C>], context: &[u8]) -> Vec<CurvePoint<C>> {
+    C::normalize_batch(&parallel::map(keys.len(), |i| {
+        key_image::salted_hash_point::<C>(&keys[i], context)
+    }))
+}
+
+// the challenge state salted signatures start from, binding their context into every challenge
+// so a signature verifies under no other context
+fn salted_start<H: ChallengeHash>(context: &[u8]) -> H::State {
+    let mut state = H::new();
+    H::append_sized(&mut state, b"key-image-salt", context);
+    state
+}
+
+// `s * G + c * K` and `s * H_p(K) + c * I`, normalized together. Each challenge hashes the
+// commitments before it, so a ring's multiplications cannot be gathered into one MSM; instead
+// each step keeps to the fewest doublings it can: the generator's term comes from its table and
+// the two variable bases on the right share one multi-scalar multiplication.
+fn blsag_commitments<C: CurveGroup>(
+    key: &CurvePoint<C>,
+    hash: &CurvePoint<C>,
+    image: &KeyImage<C>,
+    response: Scalar<C>,
+    challenge: Scalar<C>,
+) -> [CurvePoint<C>; 2] {
+    let left = generator::mul::<C>(&response) + *key * challenge;
+    let right = C::msm_unchecked(&[*hash, *image.point()], &[response, challenge]);
+    let [left, right] = C::normalize_batch(&[left, right])[..] else {
+        unreachable!("two points in, two points out")
+    };
+    [left, right]
+}
+
+// bLSAG counterpart of `verify_sag`, with `ring_state` absorbed under `BLSAG_DOMAIN`, the
+// members' `hash_keys` and a key image the caller has checked is valid
+pub(crate) fn verify_blsag<C: CurveGroup, H: ChallengeHash>(
+    ring_state: H::State,
+    keys: &[CurvePoint<C>],
+    hashes: &[CurvePoint<C>],
+    message: &[u8],
+    image: &KeyImage<C>,
+    initial: Scalar<C>,
+    responses: &[Scalar<C>],
+) -> Result<(), VerifyError> {
+    let mut prefix = ring_state;
+    H::append_sized(&mut prefix, b"message", message);
+    hash::append_point_with::<C, H>(&mut prefix, b"key-image", image.point());
+    let mut current = initial;
+    for ((key, hash), response) in keys.iter().zip(hashes).zip(responses) {
+        let [left, right] = blsag_commitments::<C>(key, hash, image, *response, current);
+        let mut state = prefix.clone();
+        hash::append_point_with::<C, H>(&mut state, b"commitment", &left);
+        hash::append_point_with::<C, H>(&mut state, b"commitment", &right);
+        current = hash::state_to_scalar::<Scalar<C>, H>(state);
+    }
+    error::check_challenge(current, initial)
 }
@@ -0,0 +1,135 @@
+use crate::ring::Ring;
+use alloc::vec::Vec;
+use ark_ec::twisted_edwards::TECurveConfig;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ed25519::{EdwardsAffine, EdwardsConfig, EdwardsProjective, Fq, Fr};
+use ark_ff::{BigInt, Field, MontFp, One, PrimeField, Zero};
+
+// Ristretto255 (RFC 9496) encoding on top of the ed25519 curve. A ristretto255 element is a
+// coset of the 4-torsion subgroup; decoding picks the coset's prime-order representative, so
+// keys arriving through this module can never smuggle a torsion component into key images.
+
+/// A ring over ristretto255: ed25519 points restricted to the prime-order subgroup, exchanged
+/// in the 32-byte ristretto encoding.
+pub type RistrettoRing = Ring<EdwardsProjective>;
+
+const SQRT_M1: Fq =
+    MontFp!("19681161376707505956807079304988542015446066515923890162744021073123829784752");
+const INVSQRT_A_MINUS_D: Fq =
+    MontFp!("54469307008909316920995813868745141605393597292927456921205312896311721017578");
+
+/// Ristretto255 encoding of `point`. Points differing by 4-torsion encode identically.
+pub fn encode(point: &EdwardsAffine) -> [u8; 32] {
+    let (x0, y0) = (point.x, point.y);
+    let t0 = x0 * y0;
+
+    let u1 = (Fq::one() + y0) * (Fq::one() - y0);
+    let u2 = x0 * y0;
+    let (_, invsqrt) = sqrt_ratio_m1(Fq::one(), u1 * u2.square());
+    let den1 = invsqrt * u1;
+    let den2 = invsqrt * u2;
+    let z_inv = den1 * den2 * t0;
+
+    let rotate = is_negative(t0 * z_inv);
+    let (x, mut y, den_inv) = if rotate {
+        (y0 * SQRT_M1, x0 * SQRT_M1, den1 * INVSQRT_A_MINUS_D)
+    } else {
+        (x0, y0, den2)
+    };
+    if is_negative(x * z_inv) {
+        y = -y;
+    }
+
+    to_bytes(abs(den_inv * (Fq::one() - y)))
+}
+
+/// Decodes a ristretto255 encoding to the prime-order ed25519 point it stands for. Returns
+/// `None` for non-canonical or invalid encodings.
+pub fn decode(bytes: &[u8; 32]) -> Option<EdwardsAffine> {
+    let s = from_bytes(bytes)?;
+    if is_negative(s) {
+        return None;
+    }
+
+    let ss = s.square();
+    let u1 = Fq::one() - ss;
+    let u2 = Fq::one() + ss;
+    let u2_sqr = u2.square();
+    let v = -(EdwardsConfig::COEFF_D * u1.square()) - u2_sqr;
+    let (was_square, invsqrt) = sqrt_ratio_m1(Fq::one(), v * u2_sqr);
+    let den_x = invsqrt * u2;
+    let den_y = invsqrt * den_x * v;
+
+    let x = abs((s + s) * den_x);
+    let y = u1 * den_y;
+    if !was_square || is_negative(x * y) || y.is_zero() {
+        return None;
+    }
+
+    // the decoded point may carry 4-torsion; [4^-1 mod l] [4] P strips it without changing the
+    // ristretto element
+    let point = EdwardsAffine::new_unchecked(x, y);
+    let quadrupled = point.mul_bigint([4u64]);
+    let inverse = Fr::from(4u64).inverse().expect("4 is invertible mod l");
+    Some((quadrupled * inverse).into_affine())
+}
+
+impl Ring<EdwardsProjective> {
+    /// Builds a ring from ristretto255-encoded public keys, or `None` if any fails to decode.
+    pub fn from_ristretto_keys(keys: &[[u8; 32]]) -> Option<RistrettoRing> {
+        let keys = keys.iter().map(decode).collect::<Option<Vec<_>>>()?;
+        Ring::from_fixed_pubkeys(keys).ok()
+    }
+
+    pub fn ristretto_keys(&self) -> Vec<[u8; 32]> {
+        self.keys().iter().map(encode).collect()
+    }
+}
+
+// returns `(was_square, sqrt(u / v))`, or `sqrt(i u / v)` when `u / v` is not a square
+fn sqrt_ratio_m1(u: Fq, v: Fq) -> (bool, Fq) {
+    let v3 = v.square() * v;
+    let v7 = v3.square() * v;
+    // (p - 5) / 8
+    let exponent = BigInt::<4>([
+        0xfffffffffffffffd,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x0fffffffffffffff,
+    ]);
+    let mut r = (u * v3) * (u * v7).pow(exponent);
+    let check = v * r.square();
+
+    let correct_sign = check == u;
+    let flipped_sign = check == -u;
+    let flipped_sign_i = check == -u * SQRT_M1;
+    if flipped_sign || flipped_sign_i {
+        r *= SQRT_M1;
+    }
+    (correct_sign || flipped_sign, abs(r))
+}
+
+fn is_negative(value: Fq) -> bool {
+    value.into_bigint().0[0] & 1 == 1
+}
+
+fn abs(value: Fq) -> Fq {
+    if is_negative(value) { -value } else { value }
+}
+
+fn to_bytes(value: Fq) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(value.into_bigint().0) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    bytes
+}
+
+// rejects encodings of values `>= p`
+fn from_bytes(bytes: &[u8; 32]) -> Option<Fq> {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().expect("chunks are 8 bytes"));
+    }
+    Fq::from_bigint(BigInt::new(limbs))
+}
@@ -0,0 +1,141 @@
+use crate::clsag::ClsagSignature;
+use crate::error::{RingError, VerifyError};
+use crate::key_image::KeyImage;
+use crate::mlsag::MatrixRing;
+use crate::ring::{Ring, RingSignature, ScalarBigInt};
+use crate::secret::SecretKey;
+use ark_ec::CurveGroup;
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+
+/// A ring signature algorithm over curve `C`, so downstream code can be generic over the
+/// scheme it signs with.
+pub trait RingScheme<C: CurveGroup> {
+    type Ring;
+    type PrivateKey: ?Sized;
+    type Signature<'a>
+    where
+        Self::Ring: 'a;
+    /// Linking tag carried by signatures; `()` for unlinkable schemes.
+    type KeyImage;
+
+    #[cfg(feature = "std")]
+    fn sign<'a>(
+        ring: &'a Self::Ring,
+        message: &[u8],
+        private_key: &Self::PrivateKey,
+        secret_index: usize,
+    ) -> Result<Self::Signature<'a>, RingError> {
+        Self::sign_with_rng(
+            ring,
+            message,
+            private_key,
+            secret_index,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`RingScheme::sign`], drawing the nonces from `rng`.
+    fn sign_with_rng<'a, R: RngCore + CryptoRng>(
+        ring: &'a Self::Ring,
+        message: &[u8],
+        private_key: &Self::PrivateKey,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<Self::Signature<'a>, RingError>;
+
+    fn verify(signature: &Self::Signature<'_>, message: &[u8]) -> Result<(), VerifyError>;
+
+    fn key_image(signature: &Self::Signature<'_>) -> Self::KeyImage;
+}
+
+/// Unlinkable SAG signatures, see [`Ring::sign`].
+pub struct Sag;
+
+/// Linkable bLSAG signatures, see [`Ring::sign_linkable`].
+pub struct Blsag;
+
+/// Concise linkable signatures over a [`MatrixRing`], see [`MatrixRing::sign_clsag`]. The
+/// private key holds one key per layer.
+pub struct Clsag;
+
+impl<C: CurveGroup> RingScheme<C> for Sag {
+    type Ring = Ring<C>;
+    type PrivateKey = SecretKey<C>;
+    type Signature<'a> = RingSignature<'a, ScalarBigInt<C>, C>;
+    type KeyImage = ();
+
+    fn sign_with_rng<'a, R: RngCore + CryptoRng>(
+        ring: &'a Ring<C>,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<Self::Signature<'a>, RingError> {
+        ring.sign_with_rng(message, private_key, secret_index, rng)
+    }
+
+    fn verify(signature: &Self::Signature<'_>, message: &[u8]) -> Result<(), VerifyError> {
+        signature.verify(message)
+    }
+
+    fn key_image(_signature: &Self::Signature<'_>) {}
+}
+
+impl<C: CurveGroup> RingScheme<C> for Blsag {
+    type Ring = Ring<C>;
+    type PrivateKey = SecretKey<C>;
+    type Signature<'a> = RingSignature<'a, ScalarBigInt<C>, C>;
+    type KeyImage = KeyImage<C>;
+
+    fn sign_with_rng<'a, R: RngCore + CryptoRng>(
+        ring: &'a Ring<C>,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<Self::Signature<'a>, RingError> {
+        ring.sign_linkable_with_rng(message, private_key, secret_index, rng)
+    }
+
+    // a bLSAG signature must carry its key image; a SAG one passed here is rejected rather than
+    // verified as unlinkable
+    fn verify(signature: &Self::Signature<'_>, message: &[u8]) -> Result<(), VerifyError> {
+        if signature.image.is_none() {
+            return Err(VerifyError::InvalidKeyImage);
+        }
+        signature.verify(message)
+    }
+
+    fn key_image(signature: &Self::Signature<'_>) -> KeyImage<C> {
+        signature
+            .image
+            .expect("bLSAG signatures always carry a key image")
+    }
+}
+
+impl<C: CurveGroup> RingScheme<C> for Clsag {
+    type Ring = MatrixRing<C>;
+    type PrivateKey = [SecretKey<C>];
+    type Signature<'a> = ClsagSignature<'a, ScalarBigInt<C>, C>;
+    type KeyImage = KeyImage<C>;
+
+    fn sign_with_rng<'a, R: RngCore + CryptoRng>(
+        ring: &'a MatrixRing<C>,
+        message: &[u8],
+        private_key: &[SecretKey<C>],
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<Self::Signature<'a>, RingError> {
+        ring.sign_clsag_with_rng(message, private_key, secret_index, rng)
+    }
+
+    fn verify(signature: &Self::Signature<'_>, message: &[u8]) -> Result<(), VerifyError> {
+        signature.verify(message)
+    }
+
+    fn key_image(signature: &Self::Signature<'_>) -> KeyImage<C> {
+        *signature.key_image()
+    }
+}
@@ -0,0 +1,67 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::AffineRepr;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ff::{BigInteger, Field, PrimeField};
+
+// SEC1 point encodings for short Weierstrass curves over prime fields, shared by the curve
+// modules that import keys from other libraries.
+
+pub(crate) fn encode_compressed<P: SWCurveConfig>(point: &Affine<P>) -> Vec<u8>
+where
+    P::BaseField: PrimeField,
+{
+    let (x, y) = point.xy().expect("the identity has no SEC1 encoding");
+    let mut bytes = vec![if y.into_bigint().is_odd() { 0x03 } else { 0x02 }];
+    bytes.extend(x.into_bigint().to_bytes_be());
+    bytes
+}
+
+pub(crate) fn encode_uncompressed<P: SWCurveConfig>(point: &Affine<P>) -> Vec<u8>
+where
+    P::BaseField: PrimeField,
+{
+    let (x, y) = point.xy().expect("the identity has no SEC1 encoding");
+    let mut bytes = vec![0x04];
+    bytes.extend(x.into_bigint().to_bytes_be());
+    bytes.extend(y.into_bigint().to_bytes_be());
+    bytes
+}
+
+// accepts either encoding; rejects other lengths, unknown prefixes, non-canonical coordinates,
+// points off the curve and points outside the prime-order subgroup
+pub(crate) fn decode<P: SWCurveConfig>(bytes: &[u8]) -> Option<Affine<P>>
+where
+    P::BaseField: PrimeField,
+{
+    let width = field_width::<P::BaseField>();
+    let point = match bytes.split_first()? {
+        (&prefix @ (0x02 | 0x03), x) if x.len() == width => {
+            let x = field_element::<P::BaseField>(x)?;
+            let mut y = (x.square() * x + P::mul_by_a(x) + P::COEFF_B).sqrt()?;
+            if y.into_bigint().is_odd() != (prefix == 0x03) {
+                y = -y;
+            }
+            Affine::new_unchecked(x, y)
+        }
+        (0x04, xy) if xy.len() == 2 * width => Affine::new_unchecked(
+            field_element::<P::BaseField>(&xy[..width])?,
+            field_element::<P::BaseField>(&xy[width..])?,
+        ),
+        _ => return None,
+    };
+    (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+}
+
+pub(crate) fn field_width<F: PrimeField>() -> usize {
+    F::MODULUS_BIT_SIZE.div_ceil(8) as usize
+}
+
+// big-endian, rejecting values `>= p`
+pub(crate) fn field_element<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    let bits: Vec<bool> = bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1))
+        .collect();
+    F::from_bigint(F::BigInt::from_bits_be(&bits))
+}
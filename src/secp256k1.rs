@@ -0,0 +1,168 @@
+use crate::error::{KeyError, RingError};
+use crate::keypair::KeyPair;
+use crate::ring::Ring;
+use crate::sec1;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_secp256k1::{Affine, Fr, Projective};
+use sha3::{Digest, Keccak256};
+
+// SEC1 interop for secp256k1 keys as used by Bitcoin, Ethereum, k256 and libsecp256k1: 32-byte
+// big-endian secret keys and 33-byte compressed or 65-byte uncompressed public keys, plus the
+// 32-byte x-only keys of BIP340 and Taproot, which stand for the point with even y. Rings can
+// also be assembled from Ethereum addresses, given the public keys behind them.
+
+/// 33-byte compressed SEC1 encoding of `point`, which must not be the identity.
+pub fn encode(point: &Affine) -> [u8; 33] {
+    sec1::encode_compressed(point)
+        .try_into()
+        .expect("secp256k1 coordinates are 32 bytes")
+}
+
+/// 65-byte uncompressed SEC1 encoding of `point`, which must not be the identity.
+pub fn encode_uncompressed(point: &Affine) -> [u8; 65] {
+    sec1::encode_uncompressed(point)
+        .try_into()
+        .expect("secp256k1 coordinates are 32 bytes")
+}
+
+/// Decodes a compressed or uncompressed SEC1 public key. Returns `None` for any other length,
+/// an unknown prefix, non-canonical coordinates or a point off the curve.
+pub fn decode(bytes: &[u8]) -> Option<Affine> {
+    sec1::decode(bytes)
+}
+
+/// BIP340 x-only encoding of `point`: its 32-byte big-endian x coordinate. The parity of y is
+/// dropped, so a point and its negation encode identically.
+pub fn encode_x_only(point: &Affine) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&encode(point)[1..]);
+    bytes
+}
+
+/// Decodes a BIP340 x-only public key to the point with that x coordinate and even y. Returns
+/// `None` if `x` is not below the field order or is not the x coordinate of a curve point.
+pub fn decode_x_only(x: &[u8; 32]) -> Option<Affine> {
+    let mut bytes = [0u8; 33];
+    bytes[0] = 0x02;
+    bytes[1..].copy_from_slice(x);
+    sec1::decode(&bytes)
+}
+
+/// The Ethereum address of `point`: the last 20 bytes of the Keccak-256 hash of its uncompressed
+/// encoding without the `0x04` prefix.
+pub fn eth_address(point: &Affine) -> [u8; 20] {
+    let digest = Keccak256::digest(&encode_uncompressed(point)[1..]);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&digest[12..]);
+    address
+}
+
+/// Compressed SEC1 encoding of the key image for a 32-byte big-endian secret key.
+pub fn key_image(secret: &[u8; 32]) -> Result<[u8; 33], KeyError> {
+    let keypair = KeyPair::<Projective>::try_from(secret)?;
+    Ok(encode(keypair.key_image().point()))
+}
+
+impl TryFrom<&[u8; 32]> for KeyPair<Projective> {
+    type Error = KeyError;
+
+    /// Imports a 32-byte big-endian secret key, which must lie in `[1, n)`.
+    fn try_from(secret: &[u8; 32]) -> Result<KeyPair<Projective>, KeyError> {
+        let secret = sec1::field_element::<Fr>(secret)
+            .filter(|secret| !secret.is_zero())
+            .ok_or(KeyError::InvalidSecretKey)?;
+        Ok(KeyPair::from_scalar(secret))
+    }
+}
+
+impl KeyPair<Projective> {
+    /// Imports a 32-byte big-endian secret key for use with x-only public keys. As in BIP340, the
+    /// secret is negated when its public key has odd y, so the key pair matches the member
+    /// [`Ring::from_x_only_keys`] decodes for it.
+    pub fn from_x_only_secret(secret: &[u8; 32]) -> Result<KeyPair<Projective>, KeyError> {
+        let keypair = KeyPair::try_from(secret)?;
+        if keypair.public_key().y.into_bigint().is_even() {
+            return Ok(keypair);
+        }
+        Ok(KeyPair::from_scalar(
+            -*keypair.private_key().expose_secret(),
+        ))
+    }
+
+    /// The 32-byte big-endian secret key.
+    pub fn secp256k1_secret(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(
+            &self
+                .private_key()
+                .expose_secret()
+                .into_bigint()
+                .to_bytes_be(),
+        );
+        bytes
+    }
+}
+
+impl Ring<Projective> {
+    /// Builds a ring from SEC1-encoded public keys, compressed or uncompressed.
+    pub fn from_sec1_keys<K: AsRef<[u8]>>(keys: &[K]) -> Result<Ring<Projective>, KeyError> {
+        let keys = keys
+            .iter()
+            .map(|key| decode(key.as_ref()))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(KeyError::InvalidPublicKey)?;
+        Ring::from_fixed_pubkeys(keys).map_err(|_| KeyError::InvalidPublicKey)
+    }
+
+    pub fn sec1_keys(&self) -> Vec<[u8; 33]> {
+        self.keys().iter().map(encode).collect()
+    }
+
+    /// Builds a ring from BIP340 x-only public keys such as Taproot output keys, taking the
+    /// even-y point for each.
+    pub fn from_x_only_keys(keys: &[[u8; 32]]) -> Result<Ring<Projective>, KeyError> {
+        let keys = keys
+            .iter()
+            .map(decode_x_only)
+            .collect::<Option<Vec<_>>>()
+            .ok_or(KeyError::InvalidPublicKey)?;
+        Ring::from_fixed_pubkeys(keys).map_err(|_| KeyError::InvalidPublicKey)
+    }
+
+    /// Builds a ring with one member per Ethereum address, in order. Addresses only commit to a
+    /// hash of the key, so the keys come from `candidates`, e.g. public keys recovered from
+    /// signed transactions; candidates matching no address are ignored. Fails with
+    /// [`RingError::UnresolvedAddresses`] listing every address no candidate matches.
+    pub fn from_eth_addresses<I: IntoIterator<Item = Affine>>(
+        addresses: &[[u8; 20]],
+        candidates: I,
+    ) -> Result<Ring<Projective>, RingError> {
+        let known: BTreeMap<[u8; 20], Affine> = candidates
+            .into_iter()
+            .filter(|key| !key.is_zero())
+            .map(|key| (eth_address(&key), key))
+            .collect();
+        let unresolved: Vec<usize> = addresses
+            .iter()
+            .enumerate()
+            .filter(|(_, address)| !known.contains_key(*address))
+            .map(|(i, _)| i)
+            .collect();
+        if !unresolved.is_empty() {
+            return Err(RingError::UnresolvedAddresses(unresolved));
+        }
+        Ring::from_fixed_pubkeys(addresses.iter().map(|address| known[address]).collect())
+    }
+
+    pub fn eth_addresses(&self) -> Vec<[u8; 20]> {
+        self.keys().iter().map(eth_address).collect()
+    }
+
+    /// The members' x-only encodings. Members with odd y encode the same as their negations.
+    pub fn x_only_keys(&self) -> Vec<[u8; 32]> {
+        self.keys().iter().map(encode_x_only).collect()
+    }
+}
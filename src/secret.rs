@@ -0,0 +1,125 @@
+use crate::ct;
+use crate::ring::Scalar;
+use ark_ec::CurveGroup;
+use ark_ff::{PrimeField, UniformRand, Zero};
+use ark_std::rand::Rng;
+use core::ops::Deref;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A private key on curve `C`, held as an element of its scalar field so it is always canonical.
+/// It is zeroed when dropped and has no `Debug`, `Display` or serialization impls, so it cannot
+/// end up in a log by accident; the raw value is only reachable through
+/// [`SecretKey::expose_secret`]. Equality runs in constant time, through [`ConstantTimeEq`].
+#[derive(Clone)]
+pub struct SecretKey<C: CurveGroup>(Scalar<C>);
+
+impl<C: CurveGroup> SecretKey<C> {
+    pub fn new(private_key: Scalar<C>) -> SecretKey<C> {
+        SecretKey(private_key)
+    }
+
+    /// Samples a uniformly random nonzero private key.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> SecretKey<C> {
+        loop {
+            let secret = Scalar::<C>::rand(rng);
+            if !secret.is_zero() {
+                return SecretKey(secret);
+            }
+        }
+    }
+
+    /// Interprets `bytes` as a little-endian integer and reduces it modulo the group order.
+    pub fn from_le_bytes_mod_order(bytes: &[u8]) -> SecretKey<C> {
+        SecretKey(Scalar::<C>::from_le_bytes_mod_order(bytes))
+    }
+
+    /// Interprets `bytes` as a big-endian integer and reduces it modulo the group order.
+    pub fn from_be_bytes_mod_order(bytes: &[u8]) -> SecretKey<C> {
+        SecretKey(Scalar::<C>::from_be_bytes_mod_order(bytes))
+    }
+
+    pub fn expose_secret(&self) -> &Scalar<C> {
+        &self.0
+    }
+}
+
+impl<C: CurveGroup> ConstantTimeEq for SecretKey<C> {
+    fn ct_eq(&self, other: &SecretKey<C>) -> Choice {
+        ct::eq(&self.0, &other.0)
+    }
+}
+
+impl<C: CurveGroup> PartialEq for SecretKey<C> {
+    fn eq(&self, other: &SecretKey<C>) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: CurveGroup> Eq for SecretKey<C> {}
+
+impl<C: CurveGroup> Drop for SecretKey<C> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<C: CurveGroup> ZeroizeOnDrop for SecretKey<C> {}
+
+/// A private scalar on curve `C` that is zeroed when dropped.
+///
+/// Every signing path holds the signer's key in one of these once it has been checked, so the key
+/// does not linger in freed memory once the signature is made, and draws its nonces as ones too:
+/// a leaked nonce gives away the key as surely as the key itself. Arithmetic goes through
+/// `Deref`; the type is deliberately neither `Copy` nor `Debug`.
+pub struct SecretScalar<C: CurveGroup>(Scalar<C>);
+
+impl<C: CurveGroup> SecretScalar<C> {
+    pub fn new(secret: Scalar<C>) -> SecretScalar<C> {
+        SecretScalar(secret)
+    }
+
+    /// Copies a nonzero private key out of its [`SecretKey`].
+    pub fn from_private_key(private_key: &SecretKey<C>) -> SecretScalar<C> {
+        assert!(!private_key.0.is_zero());
+        SecretScalar(private_key.0)
+    }
+
+    /// Samples a uniformly random scalar, for the nonces signing draws.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> SecretScalar<C> {
+        SecretScalar(Scalar::<C>::rand(rng))
+    }
+
+    /// `self - challenge * secret` for a nonce `self`: the response that closes a ring at the
+    /// signer's position. The product, which is as sensitive as `secret`, is wiped before
+    /// returning; the response itself is public.
+    pub(crate) fn response(&self, challenge: &Scalar<C>, secret: &SecretScalar<C>) -> Scalar<C> {
+        let mut product = *challenge * secret.0;
+        let response = self.0 - product;
+        product.zeroize();
+        response
+    }
+
+    /// Adds `factor * secret` to `self`, wiping the product as [`SecretScalar::response`] does.
+    pub(crate) fn add_product(&mut self, secret: &SecretScalar<C>, factor: &Scalar<C>) {
+        let mut product = secret.0 * factor;
+        self.0 += product;
+        product.zeroize();
+    }
+}
+
+impl<C: CurveGroup> Deref for SecretScalar<C> {
+    type Target = Scalar<C>;
+
+    fn deref(&self) -> &Scalar<C> {
+        &self.0
+    }
+}
+
+impl<C: CurveGroup> Drop for SecretScalar<C> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<C: CurveGroup> ZeroizeOnDrop for SecretScalar<C> {}
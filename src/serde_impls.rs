@@ -0,0 +1,291 @@
+use crate::challenge::{self, ChallengeHash};
+use crate::hex;
+use crate::key_image::KeyImage;
+use crate::ring::{
+    CurvePoint, DetachedSignature, OwnedRingSignature, Ring, RingSignature, Scalar, ScalarBigInt,
+};
+use crate::serialize;
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use serde::de::{self, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Error as _, SerializeSeq, SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// serde support, behind the `serde` feature. Points and scalars go through their compressed
+// arkworks encodings: hex strings in human-readable formats such as JSON, byte strings in binary
+// ones. Signatures borrow their ring, so they deserialize through `RingSignatureSeed`; detached
+// and owned signatures deserialize on their own. Only sequences, byte strings, structs and
+// options are used, so non-self-describing formats such as bincode round-trip as well. There is
+// no field for the challenge hash, so only SHA-512 signatures serialize.
+
+// a point or scalar in its compressed arkworks encoding
+struct Canonical<T>(T);
+
+impl<T: CanonicalSerialize> Serialize for Canonical<&T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = Vec::new();
+        self.0
+            .serialize_compressed(&mut bytes)
+            .map_err(S::Error::custom)?;
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&hex::encode(&bytes))
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de, T: CanonicalDeserialize + CanonicalSerialize> Deserialize<'de> for Canonical<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Canonical<T>, D::Error> {
+        let bytes = if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BytesVisitor)?
+        } else {
+            deserializer.deserialize_bytes(BytesVisitor)?
+        };
+        let mut reader = &bytes[..];
+        let value = T::deserialize_compressed(&mut reader).map_err(de::Error::custom)?;
+        if !reader.is_empty() {
+            return Err(de::Error::custom("trailing bytes"));
+        }
+        if !serialize::is_canonical(&value, &bytes) {
+            return Err(de::Error::custom("non-canonical encoding"));
+        }
+        Ok(Canonical(value))
+    }
+}
+
+// hex text, a byte string, or a sequence of bytes for formats without a byte string type
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("hex string or bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Vec<u8>, E> {
+        hex::decode(value).ok_or_else(|| E::custom("invalid hex"))
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(value.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+/// A sequence of compressed public keys.
+impl<C: CurveGroup> Serialize for Ring<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.size()))?;
+        for key in self.keys() {
+            seq.serialize_element(&Canonical(key))?;
+        }
+        seq.end()
+    }
+}
+
+/// Applies the same checks as [`Ring::from_fixed_pubkeys`].
+impl<'de, C: CurveGroup> Deserialize<'de> for Ring<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Ring<C>, D::Error> {
+        let keys = Vec::<Canonical<CurvePoint<C>>>::deserialize(deserializer)?;
+        Ring::from_fixed_pubkeys(keys.into_iter().map(|key| key.0).collect())
+            .map_err(de::Error::custom)
+    }
+}
+
+impl<C: CurveGroup> Serialize for KeyImage<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Canonical(self).serialize(serializer)
+    }
+}
+
+/// Rejects the identity and points outside the prime-order subgroup.
+impl<'de, C: CurveGroup> Deserialize<'de> for KeyImage<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<KeyImage<C>, D::Error> {
+        Ok(Canonical::<KeyImage<C>>::deserialize(deserializer)?.0)
+    }
+}
+
+/// A struct of `challenge`, `responses` and `key_image`, the last `None` for SAG signatures.
+/// The ring is not included.
+impl<'a, C: CurveGroup> Serialize for RingSignature<'a, ScalarBigInt<C>, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_signature(
+            serializer,
+            "RingSignature",
+            None,
+            &self.challenge,
+            &self.ring_sig_vals,
+            &self.image,
+            self.hash_id,
+        )
+    }
+}
+
+/// The same struct as [`RingSignature`]'s.
+impl<C: CurveGroup> Serialize for DetachedSignature<ScalarBigInt<C>, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_signature(
+            serializer,
+            "DetachedSignature",
+            None,
+            &self.challenge,
+            &self.ring_sig_vals,
+            &self.image,
+            self.hash_id,
+        )
+    }
+}
+
+impl<'de, C: CurveGroup> Deserialize<'de> for DetachedSignature<ScalarBigInt<C>, C> {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DetachedSignature<ScalarBigInt<C>, C>, D::Error> {
+        let fields = SignatureFields::<C>::deserialize(deserializer)?;
+        Ok(DetachedSignature {
+            challenge: fields.challenge.0.into_bigint(),
+            ring_sig_vals: fields.responses.iter().map(|r| r.0.into_bigint()).collect(),
+            image: fields.key_image,
+            hash_id: challenge::Sha512::HASH_ID,
+        })
+    }
+}
+
+/// [`RingSignature`]'s struct with the `ring` as its first field.
+impl<C: CurveGroup> Serialize for OwnedRingSignature<ScalarBigInt<C>, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_signature(
+            serializer,
+            "OwnedRingSignature",
+            Some(&self.ring),
+            &self.challenge,
+            &self.ring_sig_vals,
+            &self.image,
+            self.hash_id,
+        )
+    }
+}
+
+/// Applies the checks of [`Ring`]'s impl; the number of responses must match the ring size.
+impl<'de, C: CurveGroup> Deserialize<'de> for OwnedRingSignature<ScalarBigInt<C>, C> {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OwnedRingSignature<ScalarBigInt<C>, C>, D::Error> {
+        let fields = OwnedSignatureFields::<C>::deserialize(deserializer)?;
+        if fields.responses.len() != fields.ring.size() {
+            return Err(de::Error::invalid_length(
+                fields.responses.len(),
+                &"one response per ring member",
+            ));
+        }
+        Ok(OwnedRingSignature {
+            ring: fields.ring,
+            challenge: fields.challenge.0.into_bigint(),
+            ring_sig_vals: fields.responses.iter().map(|r| r.0.into_bigint()).collect(),
+            image: fields.key_image,
+            hash_id: challenge::Sha512::HASH_ID,
+        })
+    }
+}
+
+fn serialize_signature<S: Serializer, C: CurveGroup>(
+    serializer: S,
+    name: &'static str,
+    ring: Option<&Ring<C>>,
+    challenge: &ScalarBigInt<C>,
+    ring_sig_vals: &[ScalarBigInt<C>],
+    image: &Option<KeyImage<C>>,
+    hash_id: u8,
+) -> Result<S::Ok, S::Error> {
+    if hash_id != challenge::Sha512::HASH_ID {
+        return Err(S::Error::custom("challenge hash other than sha512"));
+    }
+    let scalar = |value: &ScalarBigInt<C>| {
+        Scalar::<C>::from_bigint(*value).ok_or_else(|| S::Error::custom("non-canonical scalar"))
+    };
+    let responses = ring_sig_vals
+        .iter()
+        .map(scalar)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut state = serializer.serialize_struct(name, 3 + ring.is_some() as usize)?;
+    if let Some(ring) = ring {
+        state.serialize_field("ring", ring)?;
+    }
+    state.serialize_field("challenge", &Canonical(&scalar(challenge)?))?;
+    state.serialize_field(
+        "responses",
+        &responses.iter().map(Canonical).collect::<Vec<_>>(),
+    )?;
+    state.serialize_field("key_image", image)?;
+    state.end()
+}
+
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct SignatureFields<C: CurveGroup> {
+    challenge: Canonical<Scalar<C>>,
+    responses: Vec<Canonical<Scalar<C>>>,
+    key_image: Option<KeyImage<C>>,
+}
+
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct OwnedSignatureFields<C: CurveGroup> {
+    ring: Ring<C>,
+    challenge: Canonical<Scalar<C>>,
+    responses: Vec<Canonical<Scalar<C>>>,
+    key_image: Option<KeyImage<C>>,
+}
+
+/// Deserializes a [`RingSignature`] over `ring`, e.g.
+/// `RingSignatureSeed::new(&ring).deserialize(&mut serde_json::Deserializer::from_str(json))`.
+/// The number of responses must match the ring size.
+pub struct RingSignatureSeed<'a, C: CurveGroup> {
+    ring: &'a Ring<C>,
+}
+
+impl<'a, C: CurveGroup> RingSignatureSeed<'a, C> {
+    pub fn new(ring: &'a Ring<C>) -> RingSignatureSeed<'a, C> {
+        RingSignatureSeed { ring }
+    }
+}
+
+impl<'a, 'de, C: CurveGroup> DeserializeSeed<'de> for RingSignatureSeed<'a, C> {
+    type Value = RingSignature<'a, ScalarBigInt<C>, C>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let fields = SignatureFields::<C>::deserialize(deserializer)?;
+        if fields.responses.len() != self.ring.size() {
+            return Err(de::Error::invalid_length(
+                fields.responses.len(),
+                &"one response per ring member",
+            ));
+        }
+        Ok(RingSignature {
+            ring: self.ring,
+            challenge: fields.challenge.0.into_bigint(),
+            ring_sig_vals: fields
+                .responses
+                .iter()
+                .map(|response| response.0.into_bigint())
+                .collect(),
+            image: fields.key_image,
+            hash_id: challenge::Sha512::HASH_ID,
+        })
+    }
+}
@@ -0,0 +1,219 @@
+use crate::challenge::{self, ChallengeHash};
+use crate::key_image::KeyImage;
+use crate::ring::{CurvePoint, Ring, RingSignature, Scalar, ScalarBigInt};
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+
+// arkworks serialization for rings, key images and signatures. Points use arkworks' own
+// compressed or uncompressed encodings and scalars their canonical little-endian form. A
+// signature is written without its ring, which the reader supplies when decoding it, and
+// without a challenge hash, so only SHA-512 signatures can be written.
+//
+// arkworks itself accepts more than one encoding of some points: it ignores the spare bits of
+// a short Weierstrass curve's flag byte, and the x coordinate whenever the infinity flag is set.
+// Every decoder in the crate checks points and scalars with `read_canonical` or `is_canonical`,
+// which only accept the bytes arkworks would write back, so a signature's encoding cannot be
+// malleated.
+
+impl<C: CurveGroup> CanonicalSerialize for Ring<C> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.keys().serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.keys().serialized_size(compress)
+    }
+}
+
+impl<C: CurveGroup> Valid for Ring<C> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.keys().is_empty() || self.keys().iter().any(|key| key.is_zero()) {
+            return Err(SerializationError::InvalidData);
+        }
+        CurvePoint::<C>::batch_check(self.keys().iter())
+    }
+}
+
+impl<C: CurveGroup> CanonicalDeserialize for Ring<C> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Ring<C>, SerializationError> {
+        let mut reader = reader;
+        let size = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+        let keys = (0..size)
+            .map(|_| read_canonical::<CurvePoint<C>, _>(&mut reader, compress, validate))
+            .collect::<Result<Vec<_>, _>>()?;
+        match validate {
+            Validate::Yes => {
+                Ring::from_fixed_pubkeys(keys).map_err(|_| SerializationError::InvalidData)
+            }
+            Validate::No => Ok(Ring::from_unchecked_pubkeys(keys)),
+        }
+    }
+}
+
+impl<C: CurveGroup> CanonicalSerialize for KeyImage<C> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.point().serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.point().serialized_size(compress)
+    }
+}
+
+impl<C: CurveGroup> Valid for KeyImage<C> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if !self.is_valid() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl<C: CurveGroup> CanonicalDeserialize for KeyImage<C> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<KeyImage<C>, SerializationError> {
+        let image = KeyImage::from_point(read_canonical::<CurvePoint<C>, _>(
+            reader, compress, validate,
+        )?);
+        if validate == Validate::Yes {
+            image.check()?;
+        }
+        Ok(image)
+    }
+}
+
+/// Writes the challenge, the responses and the optional key image; the ring is left out.
+/// Non-canonical scalars and signatures made with a challenge hash other than SHA-512 cannot be
+/// written.
+impl<'a, C: CurveGroup> CanonicalSerialize for RingSignature<'a, ScalarBigInt<C>, C> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        if self.hash_id != challenge::Sha512::HASH_ID {
+            return Err(SerializationError::InvalidData);
+        }
+        let scalar = |value: &ScalarBigInt<C>| {
+            Scalar::<C>::from_bigint(*value).ok_or(SerializationError::InvalidData)
+        };
+        scalar(&self.challenge)?.serialize_with_mode(&mut writer, compress)?;
+        self.ring_sig_vals
+            .iter()
+            .map(scalar)
+            .collect::<Result<Vec<_>, _>>()?
+            .serialize_with_mode(&mut writer, compress)?;
+        self.image.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        signature_size::<C>(self.ring_sig_vals.len(), self.image.is_some(), compress)
+    }
+}
+
+impl<C: CurveGroup> Ring<C> {
+    /// The number of bytes the ring's [`CanonicalSerialize`] impl writes.
+    pub fn serialized_size(&self, compress: Compress) -> usize {
+        CanonicalSerialize::serialized_size(self, compress)
+    }
+
+    /// The number of bytes a signature over this ring will take, ring excluded, so fees and size
+    /// limits can be checked before signing. `linkable` is set for bLSAG signatures.
+    pub fn signature_size(&self, linkable: bool, compress: Compress) -> usize {
+        signature_size::<C>(self.size(), linkable, compress)
+    }
+}
+
+impl<'a, C: CurveGroup> RingSignature<'a, ScalarBigInt<C>, C> {
+    /// The number of bytes the signature's [`CanonicalSerialize`] impl writes, without
+    /// serializing it.
+    pub fn serialized_size(&self, compress: Compress) -> usize {
+        CanonicalSerialize::serialized_size(self, compress)
+    }
+
+    /// Reads a signature written by its [`CanonicalSerialize`] impl, attaching it to `ring`.
+    /// With [`Validate::Yes`] the number of responses must match the ring size and a key image
+    /// must lie in the prime-order subgroup.
+    pub fn deserialize_with_ring<R: Read>(
+        ring: &'a Ring<C>,
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, SerializationError> {
+        let challenge = Scalar::<C>::deserialize_with_mode(&mut reader, compress, validate)?;
+        let responses = Vec::<Scalar<C>>::deserialize_with_mode(&mut reader, compress, validate)?;
+        let image = Option::<KeyImage<C>>::deserialize_with_mode(&mut reader, compress, validate)?;
+        if validate == Validate::Yes && responses.len() != ring.size() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(RingSignature {
+            ring,
+            challenge: challenge.into_bigint(),
+            ring_sig_vals: responses.iter().map(|r| r.into_bigint()).collect(),
+            image,
+            hash_id: challenge::Sha512::HASH_ID,
+        })
+    }
+}
+
+// the challenge, the length-prefixed responses and the optional key image
+fn signature_size<C: CurveGroup>(responses: usize, linkable: bool, compress: Compress) -> usize {
+    let scalar = Scalar::<C>::zero().serialized_size(compress);
+    let image = linkable.then(|| KeyImage::<C>::from_point(CurvePoint::<C>::zero()));
+    scalar
+        + Vec::<Scalar<C>>::new().serialized_size(compress)
+        + scalar * responses
+        + image.serialized_size(compress)
+}
+
+/// Reads a point or scalar, rejecting any encoding other than the one `serialize_with_mode`
+/// writes for the value.
+pub(crate) fn read_canonical<T, R>(
+    mut reader: R,
+    compress: Compress,
+    validate: Validate,
+) -> Result<T, SerializationError>
+where
+    T: CanonicalDeserialize + CanonicalSerialize + Default,
+    R: Read,
+{
+    let mut bytes = vec![0; T::default().serialized_size(compress)];
+    reader.read_exact(&mut bytes)?;
+    let value = T::deserialize_with_mode(&bytes[..], compress, validate)?;
+    let mut canonical = Vec::with_capacity(bytes.len());
+    value.serialize_with_mode(&mut canonical, compress)?;
+    if canonical != bytes {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(value)
+}
+
+/// Whether `bytes` are exactly the compressed encoding of `value`.
+#[cfg(feature = "serde")]
+pub(crate) fn is_canonical<T: CanonicalSerialize>(value: &T, bytes: &[u8]) -> bool {
+    let mut canonical = Vec::with_capacity(bytes.len());
+    value
+        .serialize_compressed(&mut canonical)
+        .is_ok_and(|()| canonical == bytes)
+}
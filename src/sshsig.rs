@@ -0,0 +1,317 @@
+use crate::base64;
+use crate::error::{DecodeError, VerifyError};
+use crate::ring::Ring;
+use crate::secret::SecretKey;
+use crate::wire::{self, DecodedSignature, WireCurve};
+use ark_ec::CurveGroup;
+use ark_std::rand::{self, CryptoRng, RngCore};
+use sha2::{Digest, Sha256, Sha512};
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+// Detached file signatures modelled on OpenSSH's SSHSIG (PROTOCOL.sshsig), with a ring in place
+// of the public key. The signed data is
+//
+//   "LNGSIG" | string namespace | string reserved | string hash_algorithm | string H(message)
+//
+// and the signature blob
+//
+//   "LNGSIG" | uint32 version | string namespace | string reserved | string hash_algorithm
+//   | string signature
+//
+// where `string` is a uint32 big-endian length followed by the bytes, `signature` holds the
+// `wire` encoding of the ring signature over the signed data, and `reserved` is empty. The
+// namespace keeps a signature made for one purpose (e.g. "file" or "git") from verifying for
+// another. The blob is armored like `ssh-keygen -Y sign` output, as base64 wrapped at 70
+// columns between "-----BEGIN LINGO SIGNATURE-----" and "-----END LINGO SIGNATURE-----".
+
+const MAGIC: &[u8; 6] = b"LNGSIG";
+const VERSION: u32 = 1;
+const BEGIN: &str = "-----BEGIN LINGO SIGNATURE-----";
+const END: &str = "-----END LINGO SIGNATURE-----";
+const LINE_WIDTH: usize = 70;
+
+/// Hash applied to the message before it is signed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha512 => "sha512",
+        }
+    }
+
+    fn from_name(name: &[u8]) -> Option<HashAlgorithm> {
+        match name {
+            b"sha256" => Some(HashAlgorithm::Sha256),
+            b"sha512" => Some(HashAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    /// Hashes everything `reader` yields.
+    pub fn digest_reader<R: Read>(&self, mut reader: R) -> io::Result<Vec<u8>> {
+        match self {
+            HashAlgorithm::Sha256 => digest_reader::<Sha256, R>(&mut reader),
+            HashAlgorithm::Sha512 => digest_reader::<Sha512, R>(&mut reader),
+        }
+    }
+}
+
+/// A detached signature over a message digest, bound to a namespace.
+pub struct FileSignature<C: CurveGroup> {
+    namespace: String,
+    hash: HashAlgorithm,
+    signature: DecodedSignature<C>,
+}
+
+impl<C: WireCurve> FileSignature<C> {
+    /// Signs everything `message` yields for the ring member at `secret_index`, with a bLSAG
+    /// signature if `linkable` and SAG otherwise. A signer [`Ring::sign`] would refuse is
+    /// reported as [`io::ErrorKind::InvalidInput`] wrapping the
+    /// [`RingError`](crate::error::RingError).
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign<R: Read>(
+        ring: &Ring<C>,
+        message: R,
+        namespace: &str,
+        hash: HashAlgorithm,
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        linkable: bool,
+    ) -> io::Result<FileSignature<C>> {
+        FileSignature::sign_with_rng(
+            ring,
+            message,
+            namespace,
+            hash,
+            private_key,
+            secret_index,
+            linkable,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`FileSignature::sign`], drawing the nonces from `rng`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign_with_rng<R: Read, G: RngCore + CryptoRng>(
+        ring: &Ring<C>,
+        message: R,
+        namespace: &str,
+        hash: HashAlgorithm,
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        linkable: bool,
+        rng: &mut G,
+    ) -> io::Result<FileSignature<C>> {
+        let signed = signed_data(namespace, hash, &hash.digest_reader(message)?);
+        let signature = if linkable {
+            ring.sign_linkable_with_rng(&signed, private_key, secret_index, rng)
+        } else {
+            ring.sign_with_rng(&signed, private_key, secret_index, rng)
+        }
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        Ok(FileSignature {
+            namespace: namespace.to_string(),
+            hash,
+            signature: signature.into_owned(),
+        })
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn hash_algorithm(&self) -> HashAlgorithm {
+        self.hash
+    }
+
+    /// The ring signature, whose ring the verifier must recognise before trusting it.
+    pub fn signature(&self) -> &DecodedSignature<C> {
+        &self.signature
+    }
+
+    /// Checks the signature against the `digest` of a message under `namespace`.
+    pub fn verify_digest(&self, digest: &[u8], namespace: &str) -> Result<(), VerifyError> {
+        if self.namespace != namespace {
+            return Err(VerifyError::WrongNamespace);
+        }
+        self.signature
+            .verify(&signed_data(namespace, self.hash, digest))
+    }
+
+    /// Checks the signature against everything `message` yields under `namespace`. Verification
+    /// failures are reported as [`io::ErrorKind::InvalidData`] wrapping the [`VerifyError`].
+    pub fn verify<R: Read>(&self, message: R, namespace: &str) -> io::Result<()> {
+        let digest = self.hash.digest_reader(message)?;
+        self.verify_digest(&digest, namespace)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend(VERSION.to_be_bytes());
+        write_string(&mut bytes, self.namespace.as_bytes());
+        write_string(&mut bytes, &[]);
+        write_string(&mut bytes, self.hash.name().as_bytes());
+        write_string(&mut bytes, &self.signature.to_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<FileSignature<C>, DecodeError> {
+        let mut reader = bytes;
+        if wire::take(&mut reader, MAGIC.len())? != MAGIC {
+            return Err(DecodeError::BadMagic);
+        }
+        let version = u32::from_be_bytes(wire::take(&mut reader, 4)?.try_into().unwrap());
+        if version != VERSION {
+            return Err(DecodeError::UnsupportedVersion(
+                version.min(u8::MAX as u32) as u8
+            ));
+        }
+        let namespace = std::str::from_utf8(read_string(&mut reader)?)
+            .map_err(|_| DecodeError::InvalidEncoding)?
+            .to_string();
+        read_string(&mut reader)?;
+        let hash = read_string(&mut reader)?;
+        let hash = HashAlgorithm::from_name(hash)
+            .ok_or_else(|| DecodeError::UnknownHash(String::from_utf8_lossy(hash).into_owned()))?;
+        let signature = DecodedSignature::from_bytes(read_string(&mut reader)?)?;
+        if !reader.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        Ok(FileSignature {
+            namespace,
+            hash,
+            signature,
+        })
+    }
+
+    /// The signature blob in `ssh-keygen -Y sign` style armor.
+    pub fn to_armor(&self) -> String {
+        let body = base64::encode(&self.to_bytes(), base64::STANDARD, true);
+        let mut armor = format!("{BEGIN}\n");
+        for line in body.as_bytes().chunks(LINE_WIDTH) {
+            armor.push_str(std::str::from_utf8(line).expect("base64 is ASCII"));
+            armor.push('\n');
+        }
+        armor.push_str(END);
+        armor.push('\n');
+        armor
+    }
+
+    /// Parses the first armored signature in `text`.
+    pub fn from_armor(text: &str) -> Result<FileSignature<C>, DecodeError> {
+        let start = text.find(BEGIN).ok_or(DecodeError::InvalidArmor)? + BEGIN.len();
+        let end = text[start..].find(END).ok_or(DecodeError::InvalidArmor)?;
+        let symbols: Vec<u8> = text[start..start + end]
+            .bytes()
+            .filter(|byte| !byte.is_ascii_whitespace())
+            .collect();
+        let bytes =
+            base64::decode(&symbols, base64::STANDARD, true).ok_or(DecodeError::InvalidArmor)?;
+        FileSignature::from_bytes(&bytes)
+    }
+}
+
+/// Signs the file at `path` under `namespace` with a SHA-512 digest, returning the armored
+/// signature, as `ssh-keygen -Y sign` does. The signature is linkable if `linkable` is set.
+pub fn sign_file<C: WireCurve, P: AsRef<Path>>(
+    ring: &Ring<C>,
+    path: P,
+    namespace: &str,
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    linkable: bool,
+) -> io::Result<String> {
+    sign_file_with_rng(
+        ring,
+        path,
+        namespace,
+        private_key,
+        secret_index,
+        linkable,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Like [`sign_file`], drawing the nonces from `rng`.
+pub fn sign_file_with_rng<C: WireCurve, P: AsRef<Path>, R: RngCore + CryptoRng>(
+    ring: &Ring<C>,
+    path: P,
+    namespace: &str,
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    linkable: bool,
+    rng: &mut R,
+) -> io::Result<String> {
+    let file = File::open(path)?;
+    let signature = FileSignature::sign_with_rng(
+        ring,
+        file,
+        namespace,
+        HashAlgorithm::Sha512,
+        private_key,
+        secret_index,
+        linkable,
+        rng,
+    )?;
+    Ok(signature.to_armor())
+}
+
+/// Checks an armored signature over the file at `path` under `namespace` and that it was made
+/// over `ring`, returning the signature so its key image can be inspected. Malformed or invalid
+/// signatures are reported as [`io::ErrorKind::InvalidData`].
+pub fn verify_file<C: WireCurve, P: AsRef<Path>>(
+    ring: &Ring<C>,
+    path: P,
+    armor: &str,
+    namespace: &str,
+) -> io::Result<FileSignature<C>> {
+    let invalid = |error| io::Error::new(io::ErrorKind::InvalidData, error);
+    let signature = FileSignature::<C>::from_armor(armor).map_err(invalid)?;
+    if signature.signature.ring() != ring {
+        return Err(invalid(DecodeError::RingMismatch));
+    }
+    signature.verify(File::open(path)?, namespace)?;
+    Ok(signature)
+}
+
+fn signed_data(namespace: &str, hash: HashAlgorithm, digest: &[u8]) -> Vec<u8> {
+    let mut data = MAGIC.to_vec();
+    write_string(&mut data, namespace.as_bytes());
+    write_string(&mut data, &[]);
+    write_string(&mut data, hash.name().as_bytes());
+    write_string(&mut data, digest);
+    data
+}
+
+fn digest_reader<D: Digest, R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let mut hasher = D::new();
+    let mut buffer = [0u8; 8192];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(hasher.finalize().to_vec()),
+            Ok(read) => hasher.update(&buffer[..read]),
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+fn write_string(bytes: &mut Vec<u8>, value: &[u8]) {
+    let length = u32::try_from(value.len()).expect("strings fit in a u32 length");
+    bytes.extend(length.to_be_bytes());
+    bytes.extend(value);
+}
+
+fn read_string<'a>(reader: &mut &'a [u8]) -> Result<&'a [u8], DecodeError> {
+    let length = u32::from_be_bytes(wire::take(reader, 4)?.try_into().unwrap());
+    wire::take(reader, length as usize)
+}
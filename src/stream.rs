@@ -0,0 +1,126 @@
+use crate::error::{RingError, VerifyError};
+use crate::ring::{Ring, RingSignature, ScalarBigInt};
+use crate::secret::SecretKey;
+use ark_ec::CurveGroup;
+use ark_std::rand::{self, CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+use std::io;
+
+const STREAM_DOMAIN: &[u8] = b"lingo-stream-v1";
+
+// Streamed messages are first reduced to a domain-separated Sha512 digest, which is then signed
+// as an ordinary message. A streamed signature therefore only verifies through [`Verifier`],
+// never against the raw message with `RingSignature::verify`.
+
+fn message_hasher() -> Sha512 {
+    let mut hasher = Sha512::new();
+    hasher.update((STREAM_DOMAIN.len() as u64).to_le_bytes());
+    hasher.update(STREAM_DOMAIN);
+    hasher
+}
+
+/// Incrementally hashes a message too large to hold in memory, then signs it for the ring
+/// member at `secret_index`. Implements [`io::Write`] so a reader can be `io::copy`-ed into it.
+pub struct Signer<'a, C: CurveGroup> {
+    ring: &'a Ring<C>,
+    private_key: &'a SecretKey<C>,
+    secret_index: usize,
+    hasher: Sha512,
+}
+
+impl<'a, C: CurveGroup> Signer<'a, C> {
+    /// Fails as [`Ring::sign`] would, before any data is hashed rather than at the end of a long
+    /// stream.
+    pub fn new(
+        ring: &'a Ring<C>,
+        private_key: &'a SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<Signer<'a, C>, RingError> {
+        ring.signer_secret(private_key, secret_index)?;
+        Ok(Signer {
+            ring,
+            private_key,
+            secret_index,
+            hasher: message_hasher(),
+        })
+    }
+
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    /// Produces a SAG signature over the streamed message.
+    pub fn finalize(self) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+        self.finalize_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Like [`Signer::finalize`], drawing the nonces from `rng`.
+    pub fn finalize_with_rng<R: RngCore + CryptoRng>(
+        self,
+        rng: &mut R,
+    ) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+        let digest = self.hasher.finalize();
+        self.ring
+            .sign_with_rng(&digest, self.private_key, self.secret_index, rng)
+    }
+
+    /// Produces a linkable bLSAG signature over the streamed message.
+    pub fn finalize_linkable(self) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+        self.finalize_linkable_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Like [`Signer::finalize_linkable`], drawing the nonces from `rng`.
+    pub fn finalize_linkable_with_rng<R: RngCore + CryptoRng>(
+        self,
+        rng: &mut R,
+    ) -> Result<RingSignature<'a, ScalarBigInt<C>, C>, RingError> {
+        let digest = self.hasher.finalize();
+        self.ring
+            .sign_linkable_with_rng(&digest, self.private_key, self.secret_index, rng)
+    }
+}
+
+impl<C: CurveGroup> io::Write for Signer<'_, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Incrementally hashes a message and checks it against a signature made with [`Signer`].
+pub struct Verifier<'s, 'a, C: CurveGroup> {
+    signature: &'s RingSignature<'a, ScalarBigInt<C>, C>,
+    hasher: Sha512,
+}
+
+impl<'s, 'a, C: CurveGroup> Verifier<'s, 'a, C> {
+    pub fn new(signature: &'s RingSignature<'a, ScalarBigInt<C>, C>) -> Verifier<'s, 'a, C> {
+        Verifier {
+            signature,
+            hasher: message_hasher(),
+        }
+    }
+
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    pub fn finalize(self) -> Result<(), VerifyError> {
+        self.signature.verify(&self.hasher.finalize())
+    }
+}
+
+impl<C: CurveGroup> io::Write for Verifier<'_, '_, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
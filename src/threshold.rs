@@ -0,0 +1,395 @@
+use crate::ct;
+use crate::error::{self, RingError, SessionError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::ring::{CurvePoint, Ring, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField, UniformRand};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::Digest;
+
+const THRESHOLD_DOMAIN: &[u8] = b"lingo-threshold-v1";
+
+// Threshold signatures follow the Cramer–Damgård–Schoenmakers construction: every member gets
+// the challenge `f(i)` of a polynomial of degree `n - t` with `f(0)` the Fiat–Shamir challenge.
+// The `n - t` non-signers are simulated up front, which pins the polynomial down once the hash is
+// known, so the `t` real signers must each answer the challenge they are dealt.
+
+/// Signer-side state between the commitment and response rounds. Dropping it without calling
+/// [`SignerNonce::respond`] aborts this signer's participation.
+pub struct SignerNonce<C: CurveGroup> {
+    index: usize,
+    secret: SecretScalar<C>,
+    nonce: SecretScalar<C>,
+}
+
+impl<C: CurveGroup> SignerNonce<C> {
+    /// Round one: the member at `index` samples a nonce and returns the commitment to send to
+    /// the session coordinator.
+    #[cfg(feature = "std")]
+    pub fn commit(
+        ring: &Ring<C>,
+        index: usize,
+        private_key: &SecretKey<C>,
+    ) -> Result<(SignerNonce<C>, CurvePoint<C>), RingError> {
+        SignerNonce::commit_with_rng(ring, index, private_key, &mut rand::thread_rng())
+    }
+
+    /// Like [`SignerNonce::commit`], drawing the nonce from `rng`.
+    pub fn commit_with_rng<R: RngCore + CryptoRng>(
+        ring: &Ring<C>,
+        index: usize,
+        private_key: &SecretKey<C>,
+        rng: &mut R,
+    ) -> Result<(SignerNonce<C>, CurvePoint<C>), RingError> {
+        let secret = ring.signer_secret(private_key, index)?;
+
+        let nonce = SecretScalar::<C>::random(rng);
+        let commitment = ct::generator_mul::<C>(&nonce).into_affine();
+        Ok((
+            SignerNonce {
+                index,
+                secret,
+                nonce,
+            },
+            commitment,
+        ))
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Round two: answers the challenge dealt by the coordinator for this signer. The nonce is
+    /// consumed either way, so it never answers two challenges.
+    pub fn respond(self, challenge: ScalarBigInt<C>) -> Result<ScalarBigInt<C>, SessionError> {
+        let challenge =
+            Scalar::<C>::from_bigint(challenge).ok_or(SessionError::NonCanonicalScalar)?;
+        Ok(self.nonce.response(&challenge, &self.secret).into_bigint())
+    }
+}
+
+/// Coordinator for a `t`-of-`n` signing session over `ring`. The coordinator holds no secrets:
+/// it simulates the non-signers, collects commitments, deals challenges and assembles the final
+/// signature from the signers' responses.
+pub struct ThresholdSession<'a, C: CurveGroup> {
+    ring: &'a Ring<C>,
+    message: Vec<u8>,
+    threshold: usize,
+    signers: Vec<usize>,
+    commitments: Vec<Option<CurvePoint<C>>>,
+    challenges: Vec<Scalar<C>>,
+    responses: Vec<Option<Scalar<C>>>,
+    polynomial: Option<Vec<Scalar<C>>>,
+}
+
+impl<'a, C: CurveGroup> ThresholdSession<'a, C> {
+    /// Opens a session for the members at `signers`. Fails with [`SessionError::InvalidSigners`]
+    /// if the set is empty, larger than the ring, repeats a member or names one outside it.
+    #[cfg(feature = "std")]
+    pub fn new(
+        ring: &'a Ring<C>,
+        message: &[u8],
+        signers: &[usize],
+    ) -> Result<ThresholdSession<'a, C>, SessionError> {
+        ThresholdSession::new_with_rng(ring, message, signers, &mut rand::thread_rng())
+    }
+
+    /// Like [`ThresholdSession::new`], drawing the simulated members from `rng`.
+    pub fn new_with_rng<R: RngCore + CryptoRng>(
+        ring: &'a Ring<C>,
+        message: &[u8],
+        signers: &[usize],
+        rng: &mut R,
+    ) -> Result<ThresholdSession<'a, C>, SessionError> {
+        let n = ring.size();
+        let threshold = signers.len();
+        let mut signers = signers.to_vec();
+        signers.sort_unstable();
+        signers.dedup();
+        if threshold == 0
+            || threshold > n
+            || signers.len() != threshold
+            || signers.iter().any(|&index| index >= n)
+        {
+            return Err(SessionError::InvalidSigners);
+        }
+
+        let mut commitments = vec![None; n];
+        let mut challenges = vec![Scalar::<C>::from(0u64); n];
+        let mut responses = vec![None; n];
+        for (i, key) in ring.keys().iter().enumerate() {
+            if signers.binary_search(&i).is_err() {
+                let challenge = Scalar::<C>::rand(rng);
+                let response = Scalar::<C>::rand(rng);
+                commitments[i] =
+                    Some((generator::mul::<C>(&response) + *key * challenge).into_affine());
+                challenges[i] = challenge;
+                responses[i] = Some(response);
+            }
+        }
+
+        Ok(ThresholdSession {
+            ring,
+            message: message.to_vec(),
+            threshold,
+            signers,
+            commitments,
+            challenges,
+            responses,
+            polynomial: None,
+        })
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    pub fn signers(&self) -> &[usize] {
+        &self.signers
+    }
+
+    /// Records a signer's commitment. Commitments are only taken until the challenges are dealt,
+    /// and only one from each signer.
+    pub fn add_commitment(
+        &mut self,
+        index: usize,
+        commitment: CurvePoint<C>,
+    ) -> Result<(), SessionError> {
+        if self.polynomial.is_some() {
+            return Err(SessionError::OutOfOrder);
+        }
+        self.check_signer(index)?;
+        if self.commitments[index].is_some() {
+            return Err(SessionError::AlreadyCommitted(index));
+        }
+        self.commitments[index] = Some(commitment);
+        Ok(())
+    }
+
+    /// Once every signer has committed, fixes the challenge polynomial and returns the
+    /// challenge dealt to each signer, in the order of [`ThresholdSession::signers`]. Fails with
+    /// the signers still to commit otherwise.
+    pub fn challenges(&mut self) -> Result<Vec<(usize, ScalarBigInt<C>)>, SessionError> {
+        if self.polynomial.is_none() {
+            let missing = missing(&self.commitments);
+            if !missing.is_empty() {
+                return Err(SessionError::MissingCommitments(missing));
+            }
+            let commitments: Vec<CurvePoint<C>> =
+                self.commitments.iter().flatten().copied().collect();
+            let root = challenge::<C>(self.ring, &self.message, self.threshold, &commitments);
+
+            let mut points = vec![(Scalar::<C>::from(0u64), root)];
+            for i in 0..self.ring.size() {
+                if self.signers.binary_search(&i).is_err() {
+                    points.push((position::<C>(i), self.challenges[i]));
+                }
+            }
+            let polynomial = interpolate(&points);
+            for &i in &self.signers {
+                self.challenges[i] = evaluate(&polynomial, position::<C>(i));
+            }
+            self.polynomial = Some(polynomial);
+        }
+
+        Ok(self
+            .signers
+            .iter()
+            .map(|&i| (i, self.challenges[i].into_bigint()))
+            .collect())
+    }
+
+    /// Records a signer's response once the challenges are dealt, discarding it with
+    /// [`SessionError::InvalidResponse`] if it does not answer the signer's commitment.
+    pub fn add_response(
+        &mut self,
+        index: usize,
+        response: ScalarBigInt<C>,
+    ) -> Result<(), SessionError> {
+        if self.polynomial.is_none() {
+            return Err(SessionError::OutOfOrder);
+        }
+        self.check_signer(index)?;
+        let response =
+            Scalar::<C>::from_bigint(response).ok_or(SessionError::NonCanonicalScalar)?;
+        let expected =
+            generator::mul::<C>(&response) + self.ring.keys()[index] * self.challenges[index];
+        if Some(expected.into_affine()) != self.commitments[index] {
+            return Err(SessionError::InvalidResponse(index));
+        }
+        self.responses[index] = Some(response);
+        Ok(())
+    }
+
+    /// The signature, once every signer has responded. Fails with the signers still to respond
+    /// otherwise.
+    pub fn finalize(self) -> Result<ThresholdSignature<'a, ScalarBigInt<C>, C>, SessionError> {
+        let polynomial = self.polynomial.ok_or(SessionError::OutOfOrder)?;
+        let missing = missing(&self.responses);
+        if !missing.is_empty() {
+            return Err(SessionError::MissingResponses(missing));
+        }
+        Ok(ThresholdSignature {
+            ring: self.ring,
+            threshold: self.threshold,
+            polynomial: polynomial.iter().map(|a| a.into_bigint()).collect(),
+            ring_sig_vals: self
+                .responses
+                .iter()
+                .flatten()
+                .map(|r| r.into_bigint())
+                .collect(),
+        })
+    }
+
+    fn check_signer(&self, index: usize) -> Result<(), SessionError> {
+        match self.signers.binary_search(&index) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SessionError::NotASigner(index)),
+        }
+    }
+}
+
+// the members a session is still waiting on
+fn missing<T>(received: &[Option<T>]) -> Vec<usize> {
+    received
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| value.is_none())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[derive(PartialEq, Eq)]
+pub struct ThresholdSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: &'a Ring<C>,
+    pub threshold: usize,
+    /// Coefficients of the challenge polynomial, constant term first.
+    pub polynomial: Vec<B>,
+    pub ring_sig_vals: Vec<B>,
+}
+
+impl<'a, B, C> ThresholdSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn ring(&self) -> &Ring<C> {
+        self.ring
+    }
+}
+
+impl<'a, C> ThresholdSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    /// Checks that at least `threshold` members of the ring took part in signing `message`.
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        let keys = self.ring.keys();
+        let n = keys.len();
+        if self.threshold == 0 || self.threshold > n {
+            return Err(VerifyError::RingTooSmall);
+        }
+        // the degree is what enforces the threshold: with `n - t + 1` free coefficients at most
+        // `n - t` members can be simulated
+        if self.polynomial.len() != n - self.threshold + 1 || self.ring_sig_vals.len() != n {
+            return Err(VerifyError::LengthMismatch);
+        }
+        if keys.iter().any(|key| key.is_zero()) {
+            return Err(VerifyError::InvalidPoint);
+        }
+
+        let polynomial = self
+            .polynomial
+            .iter()
+            .map(|a| Scalar::<C>::from_bigint(*a))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(VerifyError::NonCanonicalScalar)?;
+        let responses = self
+            .ring_sig_vals
+            .iter()
+            .map(|s| Scalar::<C>::from_bigint(*s))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(VerifyError::NonCanonicalScalar)?;
+
+        let commitments: Vec<C> = keys
+            .iter()
+            .zip(&responses)
+            .enumerate()
+            .map(|(i, (key, response))| {
+                generator::mul::<C>(response) + *key * evaluate(&polynomial, position::<C>(i))
+            })
+            .collect();
+        let commitments = C::normalize_batch(&commitments);
+
+        error::check_challenge(
+            challenge::<C>(self.ring, message, self.threshold, &commitments),
+            polynomial[0],
+        )
+    }
+}
+
+// members sit at x = 1..=n so that x = 0 is reserved for the Fiat–Shamir challenge
+fn position<C: CurveGroup>(index: usize) -> Scalar<C> {
+    Scalar::<C>::from((index + 1) as u64)
+}
+
+fn evaluate<F: Field>(polynomial: &[F], x: F) -> F {
+    polynomial
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+// Lagrange interpolation returning coefficients, constant term first
+fn interpolate<F: Field>(points: &[(F, F)]) -> Vec<F> {
+    let mut coefficients = vec![F::zero(); points.len()];
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        let mut basis = vec![F::one()];
+        let mut denominator = F::one();
+        for (j, (xj, _)) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let mut next = vec![F::zero(); basis.len() + 1];
+            for (k, b) in basis.iter().enumerate() {
+                next[k] -= *b * xj;
+                next[k + 1] += b;
+            }
+            basis = next;
+            denominator *= *xi - xj;
+        }
+        let scale = *yi
+            * denominator
+                .inverse()
+                .expect("interpolation points are distinct");
+        for (coefficient, b) in coefficients.iter_mut().zip(&basis) {
+            *coefficient += *b * scale;
+        }
+    }
+    coefficients
+}
+
+fn challenge<C: CurveGroup>(
+    ring: &Ring<C>,
+    message: &[u8],
+    threshold: usize,
+    commitments: &[CurvePoint<C>],
+) -> Scalar<C> {
+    let mut hasher = hash::challenge_hasher::<C>(THRESHOLD_DOMAIN, ring.keys(), message);
+    hasher.update((threshold as u64).to_le_bytes());
+    for commitment in commitments {
+        hash::append_point::<C>(&mut hasher, commitment);
+    }
+    hash::hash_to_scalar(hasher)
+}
@@ -0,0 +1,67 @@
+// Spans around ring construction, signing and verification, for services embedding the crate.
+// With the `tracing` feature each operation runs inside a debug-level `tracing` span carrying
+// the curve and the ring size; subscribers time spans themselves, e.g. `tracing-subscriber`'s
+// `FmtSpan::CLOSE`. Without it every guard is an empty struct and the calls compile away.
+// Nothing secret is recorded: not the signer's position, nor any key or scalar.
+
+#[cfg(feature = "tracing")]
+pub(crate) type Guard = tracing::span::EnteredSpan;
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct Guard;
+
+/// The name spans record for curve `C`.
+pub(crate) fn curve<C>() -> &'static str {
+    core::any::type_name::<C>()
+}
+
+/// Entered around building a ring of `size` members.
+pub(crate) fn ring(curve: &'static str, size: usize) -> Guard {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::debug_span!("lingo.ring", curve, size).entered()
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (curve, size);
+        Guard
+    }
+}
+
+/// Entered around signing over a ring of `size` members.
+pub(crate) fn sign(curve: &'static str, size: usize, linkable: bool, hash_id: u8) -> Guard {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::debug_span!("lingo.sign", curve, size, linkable, hash_id).entered()
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (curve, size, linkable, hash_id);
+        Guard
+    }
+}
+
+/// Entered around verifying a signature over a ring of `size` members.
+pub(crate) fn verify(curve: &'static str, size: usize, linkable: bool, hash_id: u8) -> Guard {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::debug_span!("lingo.verify", curve, size, linkable, hash_id).entered()
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (curve, size, linkable, hash_id);
+        Guard
+    }
+}
+
+/// Entered around verifying a batch of `count` signatures.
+pub(crate) fn batch_verify(curve: &'static str, count: usize) -> Guard {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::debug_span!("lingo.batch_verify", curve, count).entered()
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (curve, count);
+        Guard
+    }
+}
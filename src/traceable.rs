@@ -0,0 +1,269 @@
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::ring::{CurvePoint, Ring, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField, UniformRand};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+const TRACEABLE_DOMAIN: &[u8] = b"lingo-traceable-v1";
+const TAG_POINT_DOMAIN: &[u8] = b"lingo-traceable-tag-v1";
+const MESSAGE_POINT_DOMAIN: &[u8] = b"lingo-traceable-message-v1";
+
+/// Produces a Fujisaki–Suzuki traceable ring signature under the tag `(issue, ring)`. Each
+/// signature publishes a line `sigma_j = A_0 + j A_1` passing through the signer's tag point
+/// `x_i H(tag)`; two signatures by the same key under the same tag share that point, which
+/// [`trace`] uses to reveal the signer.
+#[cfg(feature = "std")]
+pub fn sign<'a, C>(
+    ring: &'a Ring<C>,
+    issue: &[u8],
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+) -> Result<TraceableSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+{
+    sign_with_rng(
+        ring,
+        issue,
+        message,
+        private_key,
+        secret_index,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Like [`sign`], drawing the nonce and decoy responses from `rng`.
+pub fn sign_with_rng<'a, C, R>(
+    ring: &'a Ring<C>,
+    issue: &[u8],
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    rng: &mut R,
+) -> Result<TraceableSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+    R: RngCore + CryptoRng,
+{
+    let n = ring.size();
+    let secret = ring.signer_secret(private_key, secret_index)?;
+
+    let tag_point = tag_point::<C>(ring, issue);
+    let a0 = message_point::<C>(ring, issue, message);
+    let signer_sigma = ct::secret_mul::<C>(&tag_point, &secret);
+    let position = Scalar::<C>::from((secret_index + 1) as u64);
+    let a1 = ((signer_sigma - a0) * position.inverse().expect("ring positions are non-zero"))
+        .into_affine();
+    let sigmas = sigmas::<C>(a0, a1, n);
+
+    let mut challenges = vec![Scalar::<C>::from(0u64); n];
+    let mut responses = vec![Scalar::<C>::from(0u64); n];
+
+    // the decoys are filled in over the ring rotated so the signer is at position 0, as in
+    // `Ring::sign`, and their commitments rotated back into ring order for the challenge
+    let keys = ct::rotate_left(ring.keys(), secret_index);
+    let sigmas = ct::rotate_left(&sigmas, secret_index);
+    let nonce = SecretScalar::<C>::random(rng);
+    let mut key_commitments = vec![ct::generator_mul::<C>(&nonce)];
+    let mut tag_commitments = vec![ct::secret_mul::<C>(&tag_point, &nonce)];
+    for k in 1..n {
+        challenges[k] = Scalar::<C>::rand(rng);
+        responses[k] = Scalar::<C>::rand(rng);
+        key_commitments.push(generator::mul::<C>(&responses[k]) + keys[k] * challenges[k]);
+        tag_commitments.push(tag_point * responses[k] + sigmas[k] * challenges[k]);
+    }
+    let commitments: Vec<C> = ct::rotate_right(&key_commitments, secret_index)
+        .into_iter()
+        .zip(ct::rotate_right(&tag_commitments, secret_index))
+        .flat_map(|(key, tag)| [key, tag])
+        .collect();
+
+    let total = challenge::<C>(ring, issue, message, &a0, &a1, &commitments);
+    challenges[0] = total - challenges.iter().sum::<Scalar<C>>();
+    responses[0] = nonce.response(&challenges[0], &secret);
+    let challenges = ct::rotate_right(&challenges, secret_index);
+    let responses = ct::rotate_right(&responses, secret_index);
+
+    Ok(TraceableSignature {
+        ring,
+        issue: issue.to_vec(),
+        a0,
+        a1,
+        challenges: challenges.iter().map(|c| c.into_bigint()).collect(),
+        ring_sig_vals: responses.iter().map(|z| z.into_bigint()).collect(),
+    })
+}
+
+/// Outcome of comparing two valid signatures issued under the same tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trace<C: CurveGroup> {
+    /// Signed by different keys (or under different tags).
+    Independent,
+    /// Signed by the same key over the same message.
+    Linked,
+    /// Signed by the same key over different messages; the signer is revealed.
+    Traced {
+        index: usize,
+        public_key: CurvePoint<C>,
+    },
+}
+
+/// Compares two signatures. Both must already have passed [`TraceableSignature::verify`].
+pub fn trace<B, C>(a: &TraceableSignature<'_, B, C>, b: &TraceableSignature<'_, B, C>) -> Trace<C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    if a.issue != b.issue || a.ring.keys() != b.ring.keys() {
+        return Trace::Independent;
+    }
+
+    let n = a.ring.size();
+    let a_sigmas = C::normalize_batch(&sigmas::<C>(a.a0, a.a1, n));
+    let b_sigmas = C::normalize_batch(&sigmas::<C>(b.a0, b.a1, n));
+    let matches: Vec<usize> = (0..n).filter(|&j| a_sigmas[j] == b_sigmas[j]).collect();
+
+    match matches.as_slice() {
+        [index] if n > 1 => Trace::Traced {
+            index: *index,
+            public_key: a.ring.keys()[*index],
+        },
+        [] => Trace::Independent,
+        _ if matches.len() == n => Trace::Linked,
+        _ => Trace::Independent,
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct TraceableSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: &'a Ring<C>,
+    pub issue: Vec<u8>,
+    pub a0: CurvePoint<C>,
+    pub a1: CurvePoint<C>,
+    pub challenges: Vec<B>,
+    pub ring_sig_vals: Vec<B>,
+}
+
+impl<'a, B, C> TraceableSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn ring(&self) -> &Ring<C> {
+        self.ring
+    }
+
+    pub fn issue(&self) -> &[u8] {
+        &self.issue
+    }
+}
+
+impl<'a, C> TraceableSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        let keys = self.ring.keys();
+        let n = keys.len();
+        if n == 0 {
+            return Err(VerifyError::RingTooSmall);
+        }
+        if self.challenges.len() != n || self.ring_sig_vals.len() != n {
+            return Err(VerifyError::LengthMismatch);
+        }
+        if keys.iter().any(|key| key.is_zero()) {
+            return Err(VerifyError::InvalidPoint);
+        }
+        // A_0 is pinned to the message, so a signature cannot be replayed over another message
+        if self.a0 != message_point::<C>(self.ring, &self.issue, message) {
+            return Err(VerifyError::ChallengeMismatch);
+        }
+
+        let challenges = self
+            .challenges
+            .iter()
+            .map(|c| Scalar::<C>::from_bigint(*c))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(VerifyError::NonCanonicalScalar)?;
+        let responses = self
+            .ring_sig_vals
+            .iter()
+            .map(|z| Scalar::<C>::from_bigint(*z))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(VerifyError::NonCanonicalScalar)?;
+
+        let tag_point = tag_point::<C>(self.ring, &self.issue);
+        let sigmas = sigmas::<C>(self.a0, self.a1, n);
+        let mut commitments = Vec::with_capacity(2 * n);
+        for j in 0..n {
+            commitments.push(generator::mul::<C>(&responses[j]) + keys[j] * challenges[j]);
+            commitments.push(tag_point * responses[j] + sigmas[j] * challenges[j]);
+        }
+
+        let total = challenge::<C>(
+            self.ring,
+            &self.issue,
+            message,
+            &self.a0,
+            &self.a1,
+            &commitments,
+        );
+        error::check_challenge(total, challenges.iter().sum::<Scalar<C>>())
+    }
+}
+
+// sigma_j = A_0 + j A_1 for the 1-based ring positions j
+fn sigmas<C: CurveGroup>(a0: CurvePoint<C>, a1: CurvePoint<C>, n: usize) -> Vec<C> {
+    (1..=n)
+        .map(|j| a1 * Scalar::<C>::from(j as u64) + a0)
+        .collect()
+}
+
+fn tag_hasher<C: CurveGroup>(ring: &Ring<C>, issue: &[u8]) -> Sha512 {
+    hash::challenge_hasher::<C>(TRACEABLE_DOMAIN, ring.keys(), issue)
+}
+
+fn tag_point<C: CurveGroup>(ring: &Ring<C>, issue: &[u8]) -> CurvePoint<C> {
+    let tag = tag_hasher::<C>(ring, issue).finalize();
+    hash::hash_to_point::<C>(TAG_POINT_DOMAIN, &tag)
+}
+
+fn message_point<C: CurveGroup>(ring: &Ring<C>, issue: &[u8], message: &[u8]) -> CurvePoint<C> {
+    let mut hasher = tag_hasher::<C>(ring, issue);
+    hasher.update((message.len() as u64).to_le_bytes());
+    hasher.update(message);
+    hash::hash_to_point::<C>(MESSAGE_POINT_DOMAIN, &hasher.finalize())
+}
+
+fn challenge<C: CurveGroup>(
+    ring: &Ring<C>,
+    issue: &[u8],
+    message: &[u8],
+    a0: &CurvePoint<C>,
+    a1: &CurvePoint<C>,
+    commitments: &[C],
+) -> Scalar<C> {
+    let mut hasher = tag_hasher::<C>(ring, issue);
+    hasher.update((message.len() as u64).to_le_bytes());
+    hasher.update(message);
+    hash::append_point::<C>(&mut hasher, a0);
+    hash::append_point::<C>(&mut hasher, a1);
+    for commitment in C::normalize_batch(commitments) {
+        hash::append_point::<C>(&mut hasher, &commitment);
+    }
+    hash::hash_to_scalar(hasher)
+}
@@ -0,0 +1,321 @@
+use crate::challenge::ChallengeHash;
+use crate::error::{RingError, VerifyError};
+use crate::hash;
+use crate::ring::{self, Ring, RingSignature, ScalarBigInt};
+use crate::secret::SecretKey;
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+
+// Merlin transcripts (https://merlin.cool): a Fiat–Shamir transcript built on the STROBE-128
+// framework over Keccak-f[1600], byte-for-byte compatible with the `merlin` crate.
+//
+// Every message is absorbed with its label and length as STROBE metadata, so no two sequences of
+// appends collide, and challenges are squeezed out under their own label. A ring signature
+// signed with `Merlin` as its challenge hash runs its challenges through a transcript: the
+// domain, ring size, every key and the message are appended under the labels `dom-sep`,
+// `ring-size`, `key` and `message`, then the key image under `key-image` and each commitment
+// under `commitment`, and the challenge is 64 bytes squeezed under `challenge`. Appending other
+// statements to a transcript first and signing with `Ring::sign_with_transcript` binds the
+// signature to them, so a ring signature composes with other proofs on the same transcript.
+
+const MERLIN_PROTOCOL_LABEL: &[u8] = b"Merlin v1.0";
+const TRANSCRIPT_LABEL: &[u8] = b"lingo-ring-signature";
+
+const STROBE_R: u8 = 166;
+
+const FLAG_I: u8 = 1;
+const FLAG_A: u8 = 1 << 1;
+const FLAG_C: u8 = 1 << 2;
+const FLAG_T: u8 = 1 << 3;
+const FLAG_M: u8 = 1 << 4;
+const FLAG_K: u8 = 1 << 5;
+
+// the subset of STROBE-128 merlin uses: meta-AD, AD and PRF
+#[derive(Clone)]
+struct Strobe128 {
+    state: [u8; 200],
+    pos: u8,
+    pos_begin: u8,
+    cur_flags: u8,
+}
+
+impl Strobe128 {
+    fn new(protocol_label: &[u8]) -> Strobe128 {
+        let mut state = [0u8; 200];
+        state[0..6].copy_from_slice(&[1, STROBE_R + 2, 1, 0, 1, 96]);
+        state[6..18].copy_from_slice(b"STROBEv1.0.2");
+        keccak_f1600(&mut state);
+        let mut strobe = Strobe128 {
+            state,
+            pos: 0,
+            pos_begin: 0,
+            cur_flags: 0,
+        };
+        strobe.meta_ad(protocol_label, false);
+        strobe
+    }
+
+    fn meta_ad(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_M | FLAG_A, more);
+        self.absorb(data);
+    }
+
+    fn ad(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_A, more);
+        self.absorb(data);
+    }
+
+    fn prf(&mut self, data: &mut [u8], more: bool) {
+        self.begin_op(FLAG_I | FLAG_A | FLAG_C, more);
+        self.squeeze(data);
+    }
+
+    fn run_f(&mut self) {
+        self.state[self.pos as usize] ^= self.pos_begin;
+        self.state[self.pos as usize + 1] ^= 0x04;
+        self.state[STROBE_R as usize + 1] ^= 0x80;
+        keccak_f1600(&mut self.state);
+        self.pos = 0;
+        self.pos_begin = 0;
+    }
+
+    fn absorb(&mut self, data: &[u8]) {
+        for byte in data {
+            self.state[self.pos as usize] ^= byte;
+            self.pos += 1;
+            if self.pos == STROBE_R {
+                self.run_f();
+            }
+        }
+    }
+
+    fn squeeze(&mut self, data: &mut [u8]) {
+        for byte in data {
+            *byte = self.state[self.pos as usize];
+            self.state[self.pos as usize] = 0;
+            self.pos += 1;
+            if self.pos == STROBE_R {
+                self.run_f();
+            }
+        }
+    }
+
+    fn begin_op(&mut self, flags: u8, more: bool) {
+        if more {
+            assert_eq!(
+                self.cur_flags, flags,
+                "continued a different STROBE operation"
+            );
+            return;
+        }
+        assert_eq!(flags & FLAG_T, 0, "transport operations are not supported");
+
+        let old_begin = self.pos_begin;
+        self.pos_begin = self.pos + 1;
+        self.cur_flags = flags;
+        self.absorb(&[old_begin, flags]);
+
+        let force_f = flags & (FLAG_C | FLAG_K) != 0;
+        if force_f && self.pos != 0 {
+            self.run_f();
+        }
+    }
+}
+
+fn keccak_f1600(bytes: &mut [u8; 200]) {
+    let mut lanes = [0u64; 25];
+    for (lane, chunk) in lanes.iter_mut().zip(bytes.chunks_exact(8)) {
+        *lane = u64::from_le_bytes(chunk.try_into().expect("8-byte chunk"));
+    }
+    keccak::f1600(&mut lanes);
+    for (chunk, lane) in bytes.chunks_exact_mut(8).zip(lanes) {
+        chunk.copy_from_slice(&lane.to_le_bytes());
+    }
+}
+
+/// A Merlin transcript: labelled messages in, labelled challenges out.
+#[derive(Clone)]
+pub struct Transcript {
+    strobe: Strobe128,
+}
+
+impl Transcript {
+    /// Starts a transcript for the protocol named `label`.
+    pub fn new(label: &'static [u8]) -> Transcript {
+        let mut transcript = Transcript {
+            strobe: Strobe128::new(MERLIN_PROTOCOL_LABEL),
+        };
+        transcript.append_message(b"dom-sep", label);
+        transcript
+    }
+
+    pub fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        let length = u32::try_from(message.len()).expect("message longer than 4 GiB");
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&length.to_le_bytes(), true);
+        self.strobe.ad(message, false);
+    }
+
+    pub fn append_u64(&mut self, label: &'static [u8], value: u64) {
+        self.append_message(label, &value.to_le_bytes());
+    }
+
+    /// Appends the compressed encoding of `point`.
+    pub fn append_point<C: CurveGroup>(&mut self, label: &'static [u8], point: &C::Affine) {
+        self.append_message(label, &hash::point_bytes::<C>(point));
+    }
+
+    /// Fills `dest` with challenge bytes bound to everything appended so far.
+    pub fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        let length = u32::try_from(dest.len()).expect("challenge longer than 4 GiB");
+        self.strobe.meta_ad(label, false);
+        self.strobe.meta_ad(&length.to_le_bytes(), true);
+        self.strobe.prf(dest, false);
+    }
+
+    /// A challenge scalar reduced from 64 challenge bytes, so it is uniform modulo the order.
+    pub fn challenge_scalar<F: PrimeField>(&mut self, label: &'static [u8]) -> F {
+        let mut bytes = [0u8; 64];
+        self.challenge_bytes(label, &mut bytes);
+        F::from_le_bytes_mod_order(&bytes)
+    }
+}
+
+impl core::fmt::Debug for Transcript {
+    // the state is not printed: it is as good as every message appended so far
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Transcript { .. }")
+    }
+}
+
+/// Ring signature challenges made with a Merlin [`Transcript`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Merlin;
+
+impl ChallengeHash for Merlin {
+    const HASH_ID: u8 = 0x08;
+    const HASH_NAME: &'static str = "merlin";
+
+    type State = Transcript;
+
+    fn new() -> Transcript {
+        Transcript::new(TRANSCRIPT_LABEL)
+    }
+
+    fn update(transcript: &mut Transcript, bytes: &[u8]) {
+        transcript.append_message(b"data", bytes);
+    }
+
+    fn finalize(transcript: Transcript) -> [u8; 64] {
+        Merlin::challenge(transcript, b"challenge")
+    }
+
+    fn append_message(transcript: &mut Transcript, label: &'static [u8], message: &[u8]) {
+        transcript.append_message(label, message);
+    }
+
+    fn append_sized(transcript: &mut Transcript, label: &'static [u8], message: &[u8]) {
+        transcript.append_message(label, message);
+    }
+
+    fn challenge(mut transcript: Transcript, label: &'static [u8]) -> [u8; 64] {
+        let mut output = [0u8; 64];
+        transcript.challenge_bytes(label, &mut output);
+        output
+    }
+}
+
+impl<C: CurveGroup> Ring<C> {
+    /// Signs `message` with [`Merlin`] challenges continuing from `transcript`, so the signature
+    /// is bound to every statement appended to it first. `transcript` itself is left as it was.
+    #[cfg(feature = "std")]
+    pub fn sign_with_transcript(
+        &self,
+        transcript: &Transcript,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_with_transcript_and_rng(
+            transcript,
+            message,
+            private_key,
+            secret_index,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`Ring::sign_with_transcript`], drawing the nonces from `rng`.
+    pub fn sign_with_transcript_and_rng<R: RngCore + CryptoRng>(
+        &self,
+        transcript: &Transcript,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_hashed::<Merlin, R>(transcript.clone(), message, private_key, secret_index, rng)
+    }
+
+    /// Linkable counterpart of [`Ring::sign_with_transcript`].
+    #[cfg(feature = "std")]
+    pub fn sign_linkable_with_transcript(
+        &self,
+        transcript: &Transcript,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_linkable_with_transcript_and_rng(
+            transcript,
+            message,
+            private_key,
+            secret_index,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`Ring::sign_linkable_with_transcript`], drawing the nonces from `rng`.
+    pub fn sign_linkable_with_transcript_and_rng<R: RngCore + CryptoRng>(
+        &self,
+        transcript: &Transcript,
+        message: &[u8],
+        private_key: &SecretKey<C>,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<RingSignature<'_, ScalarBigInt<C>, C>, RingError> {
+        self.sign_linkable_hashed::<Merlin, R>(
+            transcript.clone(),
+            message,
+            private_key,
+            secret_index,
+            rng,
+        )
+    }
+}
+
+impl<C: CurveGroup> RingSignature<'_, ScalarBigInt<C>, C> {
+    /// Checks a signature made by [`Ring::sign_with_transcript`] against `message` and a
+    /// transcript holding the same statements the signer's did. Fails with
+    /// [`VerifyError::UnsupportedHash`] for signatures not made with [`Merlin`].
+    pub fn verify_with_transcript(
+        &self,
+        transcript: &Transcript,
+        message: &[u8],
+    ) -> Result<(), VerifyError> {
+        if self.hash_id != Merlin::HASH_ID {
+            return Err(VerifyError::UnsupportedHash);
+        }
+        ring::verify_hashed::<C, Merlin>(
+            transcript.clone(),
+            self.public_keys(),
+            self.challenge,
+            &self.ring_sig_vals,
+            self.image.as_ref(),
+            message,
+        )
+    }
+}
@@ -0,0 +1,190 @@
+use crate::ct;
+use crate::error::{self, RingError, VerifyError};
+use crate::generator;
+use crate::hash;
+use crate::key_image::KeyImage;
+use crate::one_of_many::{self, DigitCommitments, Generators, proving_digit_count};
+use crate::ring::{CurvePoint, Ring, Scalar, ScalarBigInt};
+use crate::secret::{SecretKey, SecretScalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField, UniformRand};
+#[cfg(feature = "std")]
+use ark_std::rand;
+use ark_std::rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+const TRIPTYCH_DOMAIN: &[u8] = b"lingo-triptych-v1";
+const TRIPTYCH_TAG_DOMAIN: &[u8] = b"lingo-triptych-tag-v1";
+
+/// Produces a Triptych signature for the member at `secret_index`: a linkable extension of the
+/// [one-out-of-many](crate::one_of_many) proof, holding `O(log n)` group elements and scalars.
+/// Fails with [`RingError::UnsupportedSize`] unless the ring size is a power of two.
+#[cfg(feature = "std")]
+pub fn prove<'a, C>(
+    ring: &'a Ring<C>,
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+) -> Result<TriptychSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+{
+    prove_with_rng(
+        ring,
+        message,
+        private_key,
+        secret_index,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Like [`prove`], drawing the signature's randomness from `rng`.
+pub fn prove_with_rng<'a, C, R>(
+    ring: &'a Ring<C>,
+    message: &[u8],
+    private_key: &SecretKey<C>,
+    secret_index: usize,
+    rng: &mut R,
+) -> Result<TriptychSignature<'a, ScalarBigInt<C>, C>, RingError>
+where
+    C: CurveGroup,
+    R: RngCore + CryptoRng,
+{
+    let size = ring.size();
+    let m = proving_digit_count(size)?;
+    let secret = ring.signer_secret(private_key, secret_index)?;
+
+    let tag_base = tag_base::<C>();
+    let inverse = SecretScalar::<C>::new(secret.inverse().expect("private key is non-zero"));
+    let tag: KeyImage<C> =
+        KeyImage::from_point(ct::secret_mul::<C>(&tag_base, &inverse).into_affine());
+
+    let generators = Generators::<C>::new(m);
+    let digits = DigitCommitments::<C>::new(&generators, secret_index, m, rng);
+    let coefficients = Zeroizing::new(digits.member_coefficients(size));
+
+    let rho = Zeroizing::new((0..m).map(|_| Scalar::<C>::rand(rng)).collect::<Vec<_>>());
+    let x_points: Vec<C> = (0..m)
+        .map(|j| {
+            let scalars: Vec<Scalar<C>> = coefficients.iter().map(|poly| poly[j]).collect();
+            C::msm_unchecked(ring.keys(), &scalars) + ct::generator_mul::<C>(&rho[j])
+        })
+        .collect();
+    let y_points: Vec<C> = rho.iter().map(|rho| *tag.point() * rho).collect();
+
+    let mut points = vec![tag.point().into_group()];
+    points.extend(digits.points);
+    points.extend(&x_points);
+    points.extend(&y_points);
+    let points = C::normalize_batch(&points);
+    let xi = one_of_many::challenge::<C>(TRIPTYCH_DOMAIN, ring.keys(), message, &points);
+
+    let (f, z_a, z_c) = digits.respond(xi);
+    let z = one_of_many::mask_secret(*secret, &rho, xi);
+
+    Ok(TriptychSignature {
+        ring,
+        a: points[1],
+        b: points[2],
+        c: points[3],
+        d: points[4],
+        x: points[5..5 + m].to_vec(),
+        y: points[5 + m..].to_vec(),
+        f: f.iter()
+            .map(|row| row.iter().map(|s| s.into_bigint()).collect())
+            .collect(),
+        z_a: z_a.into_bigint(),
+        z_c: z_c.into_bigint(),
+        z: z.into_bigint(),
+        tag,
+    })
+}
+
+#[derive(PartialEq, Eq)]
+pub struct TriptychSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub ring: &'a Ring<C>,
+    pub a: CurvePoint<C>,
+    pub b: CurvePoint<C>,
+    pub c: CurvePoint<C>,
+    pub d: CurvePoint<C>,
+    pub x: Vec<CurvePoint<C>>,
+    pub y: Vec<CurvePoint<C>>,
+    pub f: Vec<Vec<B>>,
+    pub z_a: B,
+    pub z_c: B,
+    pub z: B,
+    /// Linking tag `k^-1 U`; equal across Triptych signatures made with the same key.
+    pub tag: KeyImage<C>,
+}
+
+impl<'a, B, C> TriptychSignature<'a, B, C>
+where
+    B: BigInteger,
+    C: CurveGroup,
+{
+    pub fn ring(&self) -> &Ring<C> {
+        self.ring
+    }
+
+    pub fn key_image(&self) -> &KeyImage<C> {
+        &self.tag
+    }
+}
+
+impl<'a, C> TriptychSignature<'a, ScalarBigInt<C>, C>
+where
+    C: CurveGroup,
+{
+    pub fn verify(&self, message: &[u8]) -> Result<(), VerifyError> {
+        let keys = self.ring.keys();
+        let m = one_of_many::verified_digit_count(keys.len())?;
+        if self.x.len() != m || self.y.len() != m || !one_of_many::matrix_fits(&self.f, m) {
+            return Err(VerifyError::LengthMismatch);
+        }
+        if !self.tag.is_valid() {
+            return Err(VerifyError::InvalidKeyImage);
+        }
+        if keys.iter().any(|key| key.is_zero()) {
+            return Err(VerifyError::InvalidPoint);
+        }
+        let (Some(f), Some(z_a), Some(z_c), Some(z)) = (
+            one_of_many::scalar_matrix::<C>(&self.f, m),
+            Scalar::<C>::from_bigint(self.z_a),
+            Scalar::<C>::from_bigint(self.z_c),
+            Scalar::<C>::from_bigint(self.z),
+        ) else {
+            return Err(VerifyError::NonCanonicalScalar);
+        };
+
+        let mut points = vec![*self.tag.point(), self.a, self.b, self.c, self.d];
+        points.extend(&self.x);
+        points.extend(&self.y);
+        let xi = one_of_many::challenge::<C>(TRIPTYCH_DOMAIN, keys, message, &points);
+
+        let generators = Generators::<C>::new(m);
+        let commitments = [self.a, self.b, self.c, self.d];
+        let full_f = one_of_many::verify_digits(&generators, &commitments, &f, z_a, z_c, xi)
+            .ok_or(VerifyError::ChallengeMismatch)?;
+
+        let powers = one_of_many::powers(xi, m + 1);
+        let key_sum = C::msm_unchecked(keys, &one_of_many::member_scalars(&full_f, keys.len()));
+        error::check_challenge(
+            key_sum - C::msm_unchecked(&self.x, &powers[..m]),
+            generator::mul::<C>(&z),
+        )?;
+
+        // sum_k p_k(xi) = xi^m, so the tag equation collapses to a single base point
+        let y_sum = C::msm_unchecked(&self.y, &powers[..m]);
+        error::check_challenge(tag_base::<C>() * powers[m] - y_sum, *self.tag.point() * z)
+    }
+}
+
+fn tag_base<C: CurveGroup>() -> CurvePoint<C> {
+    hash::hash_to_point::<C>(TRIPTYCH_TAG_DOMAIN, &[])
+}
@@ -0,0 +1,107 @@
+use crate::error::KeyError;
+use crate::keypair::KeyPair;
+use crate::ring::Ring;
+use crate::secp256k1;
+use crate::wire::DecodedSignature;
+use alloc::vec::Vec;
+use ark_secp256k1::Projective;
+use ark_std::rand;
+use wasm_bindgen::prelude::*;
+
+// A wasm-bindgen wrapper for browser wallets: key generation, signing and verification over
+// secp256k1, with every key, ring and signature passed as plain bytes. Secret keys are 32-byte
+// big-endian scalars, public keys and key images 33-byte compressed SEC1 points, rings the
+// concatenation of their members' public keys, and signatures the versioned wire format with the
+// ring included. Errors surface as JS exceptions carrying the crate's error messages.
+//
+// Randomness comes from the browser's `crypto.getRandomValues` through getrandom's `js` backend.
+// The library itself has no `cdylib` target, so a browser build goes through
+//
+//     cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm \
+//         --crate-type cdylib
+//
+// followed by `wasm-bindgen --target web` on the resulting `lingo.wasm`.
+
+const PUBLIC_KEY_SIZE: usize = 33;
+
+/// A fresh 32-byte secret key.
+#[wasm_bindgen(js_name = generateSecretKey)]
+pub fn generate_secret_key() -> Vec<u8> {
+    KeyPair::<Projective>::generate(&mut rand::thread_rng())
+        .secp256k1_secret()
+        .to_vec()
+}
+
+/// The compressed public key of a 32-byte secret key.
+#[wasm_bindgen(js_name = publicKey)]
+pub fn public_key(secret_key: &[u8]) -> Result<Vec<u8>, JsError> {
+    Ok(secp256k1::encode(keypair(secret_key)?.public_key()).to_vec())
+}
+
+/// The compressed key image linkable signatures made with a 32-byte secret key carry.
+#[wasm_bindgen(js_name = keyImage)]
+pub fn key_image(secret_key: &[u8]) -> Result<Vec<u8>, JsError> {
+    Ok(secp256k1::encode(keypair(secret_key)?.key_image().point()).to_vec())
+}
+
+/// Signs `message` over `ring`, the concatenated compressed public keys of its members, as the
+/// member at `index`, whose public key must belong to `secret_key`. `linkable` picks a bLSAG
+/// signature over a SAG one. Returns the signature in the wire format, ring included.
+#[wasm_bindgen]
+pub fn sign(
+    ring: &[u8],
+    message: &[u8],
+    secret_key: &[u8],
+    index: usize,
+    linkable: bool,
+) -> Result<Vec<u8>, JsError> {
+    let keypair = keypair(secret_key)?;
+    let ring = decode_ring(ring)?;
+    let signature = if linkable {
+        ring.sign_linkable(message, keypair.private_key(), index)?
+    } else {
+        ring.sign(message, keypair.private_key(), index)?
+    };
+    Ok(signature.to_bytes())
+}
+
+/// Whether `signature`, in the wire format, is a valid signature of `message` over the ring it
+/// carries. Throws if the signature cannot be decoded at all.
+#[wasm_bindgen]
+pub fn verify(message: &[u8], signature: &[u8]) -> Result<bool, JsError> {
+    let signature = DecodedSignature::<Projective>::from_bytes(signature)?;
+    Ok(signature.verify(message).is_ok())
+}
+
+/// The compressed key image `signature` carries, or `undefined` for an unlinkable signature.
+#[wasm_bindgen(js_name = signatureKeyImage)]
+pub fn signature_key_image(signature: &[u8]) -> Result<Option<Vec<u8>>, JsError> {
+    let signature = DecodedSignature::<Projective>::from_bytes(signature)?;
+    Ok(signature
+        .image
+        .map(|image| secp256k1::encode(image.point()).to_vec()))
+}
+
+/// The ring `signature` was made over, as its members' concatenated compressed public keys.
+#[wasm_bindgen(js_name = signatureRing)]
+pub fn signature_ring(signature: &[u8]) -> Result<Vec<u8>, JsError> {
+    let signature = DecodedSignature::<Projective>::from_bytes(signature)?;
+    Ok(signature.ring().sec1_keys().concat())
+}
+
+fn keypair(secret_key: &[u8]) -> Result<KeyPair<Projective>, KeyError> {
+    let secret_key: &[u8; 32] = secret_key
+        .try_into()
+        .map_err(|_| KeyError::InvalidSecretKey)?;
+    KeyPair::try_from(secret_key)
+}
+
+fn decode_ring(ring: &[u8]) -> Result<Ring<Projective>, JsError> {
+    if ring.is_empty() || !ring.len().is_multiple_of(PUBLIC_KEY_SIZE) {
+        return Err(JsError::new(
+            "a ring is a non-empty concatenation of 33-byte public keys",
+        ));
+    }
+    let keys: Vec<&[u8]> = ring.chunks_exact(PUBLIC_KEY_SIZE).collect();
+    Ok(Ring::from_sec1_keys(&keys)?)
+}
@@ -0,0 +1,352 @@
+use crate::challenge::{self, ChallengeHash};
+use crate::error::DecodeError;
+use crate::key_image::KeyImage;
+use crate::ring::{
+    CurvePoint, DetachedSignature, OwnedRingSignature, Ring, RingSignature, Scalar, ScalarBigInt,
+};
+use crate::serialize;
+use alloc::vec::Vec;
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+
+// Self-describing binary encoding of a signature together with its ring:
+//
+//   magic "LNGO" | version u8 | curve id u8 | scheme id u8 | ring size u32 (LE)
+//   | ring keys | challenge | responses, one per member | key image (bLSAG only)
+//
+// Points and scalars use their compressed arkworks encodings. Version 1 is frozen: a later
+// layout gets a new version number and its own arm in the decoders' `match` on the version, so
+// signatures stored today stay readable after the crate starts writing the new one. Versions
+// with no arm fail with `DecodeError::UnsupportedVersion`, and `wire_version` reports the
+// version of stored bytes without decoding them.
+//
+// Version 2 binds the challenge hash into the encoding: a hash id byte (`ChallengeHash::HASH_ID`)
+// follows the scheme id, and the rest is laid out as in version 1. SHA-512 signatures are still
+// written as version 1, so version 2 with the SHA-512 id is rejected and every signature has
+// exactly one encoding.
+//
+// Where rings are globally known, signatures can be stored without theirs:
+//
+//   magic "LNGR" | version u8 | curve id u8 | scheme id u8 | ring digest (32 bytes) | body
+//
+// with the hash id after the scheme id in version 2, as above.
+//
+// The verifier looks the ring up by its `Ring::digest` and supplies it when decoding; the body
+// is the same as above, with one response per member of that ring.
+
+const MAGIC: &[u8; 4] = b"LNGO";
+const RINGLESS_MAGIC: &[u8; 4] = b"LNGR";
+/// The newest wire format version, which [`RingSignature::to_bytes`] and
+/// [`RingSignature::to_ringless_bytes`] write for signatures made with a challenge hash other
+/// than SHA-512. SHA-512 signatures are written as version 1.
+pub const VERSION: u8 = 2;
+
+const SCHEME_SAG: u8 = 1;
+const SCHEME_BLSAG: u8 = 2;
+
+/// A curve with a fixed identifier in the wire format. Ids below `0x80` are reserved for the
+/// crate's curves; applications registering their own curves should pick ids from `0x80` up.
+pub trait WireCurve: CurveGroup {
+    const CURVE_ID: u8;
+    /// Name used by the text encodings, e.g. `"secp256k1"`.
+    const CURVE_NAME: &'static str;
+}
+
+impl WireCurve for ark_secp256k1::Projective {
+    const CURVE_ID: u8 = 0x01;
+    const CURVE_NAME: &'static str = "secp256k1";
+}
+
+impl WireCurve for ark_ed25519::EdwardsProjective {
+    const CURVE_ID: u8 = 0x02;
+    const CURVE_NAME: &'static str = "ed25519";
+}
+
+#[cfg(feature = "p256")]
+impl WireCurve for crate::curves::p256::Projective {
+    const CURVE_ID: u8 = 0x03;
+    const CURVE_NAME: &'static str = "p256";
+}
+
+#[cfg(feature = "bls12-381")]
+impl WireCurve for crate::curves::bls12_381::G1Projective {
+    const CURVE_ID: u8 = 0x04;
+    const CURVE_NAME: &'static str = "bls12-381-g1";
+}
+
+#[cfg(feature = "bn254")]
+impl WireCurve for crate::curves::bn254::G1Projective {
+    const CURVE_ID: u8 = 0x05;
+    const CURVE_NAME: &'static str = "bn254-g1";
+}
+
+#[cfg(feature = "pallas")]
+impl WireCurve for crate::curves::pallas::Projective {
+    const CURVE_ID: u8 = 0x06;
+    const CURVE_NAME: &'static str = "pallas";
+}
+
+#[cfg(feature = "vesta")]
+impl WireCurve for crate::curves::vesta::Projective {
+    const CURVE_ID: u8 = 0x07;
+    const CURVE_NAME: &'static str = "vesta";
+}
+
+#[cfg(feature = "jubjub")]
+impl WireCurve for crate::curves::jubjub::EdwardsProjective {
+    const CURVE_ID: u8 = 0x08;
+    const CURVE_NAME: &'static str = "jubjub";
+}
+
+impl<'a, C: WireCurve> RingSignature<'a, ScalarBigInt<C>, C> {
+    /// The signature and its ring in the versioned wire format, read back with
+    /// [`DecodedSignature::from_bytes`]. Panics if a scalar is not canonical.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let size = u32::try_from(self.ring.size()).expect("ring size fits in a u32");
+
+        let mut bytes = self.header(MAGIC);
+        bytes.extend(size.to_le_bytes());
+        for key in self.ring.keys() {
+            write(&mut bytes, key);
+        }
+        self.write_body(&mut bytes);
+        bytes
+    }
+
+    /// The signature without its ring, read back with [`DetachedSignature::from_ringless_bytes`]
+    /// given the same ring. Panics if a scalar is not canonical.
+    pub fn to_ringless_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.header(RINGLESS_MAGIC);
+        bytes.extend(self.ring.digest());
+        self.write_body(&mut bytes);
+        bytes
+    }
+
+    // magic, version, curve and scheme, and the hash id from version 2
+    fn header(&self, magic: &[u8; 4]) -> Vec<u8> {
+        let mut bytes = magic.to_vec();
+        let scheme = scheme(self.image.is_some());
+        if self.hash_id == challenge::Sha512::HASH_ID {
+            bytes.extend([1, C::CURVE_ID, scheme]);
+        } else {
+            bytes.extend([2, C::CURVE_ID, scheme, self.hash_id]);
+        }
+        bytes
+    }
+}
+
+impl<'a, C: CurveGroup> RingSignature<'a, ScalarBigInt<C>, C> {
+    // challenge, responses and key image, shared with the other binary encodings
+    pub(crate) fn write_body(&self, bytes: &mut Vec<u8>) {
+        for value in core::iter::once(&self.challenge).chain(&self.ring_sig_vals) {
+            let scalar = Scalar::<C>::from_bigint(*value).expect("signature scalars are canonical");
+            write(bytes, &scalar);
+        }
+        if let Some(image) = &self.image {
+            write(bytes, image.point());
+        }
+    }
+}
+
+/// A signature decoded from the wire format, owning the ring it was made over.
+pub type DecodedSignature<C> = OwnedRingSignature<ScalarBigInt<C>, C>;
+
+impl<C: WireCurve> DecodedSignature<C> {
+    /// Parses bytes written by [`RingSignature::to_bytes`]. Every point must be valid, every
+    /// scalar canonical, and nothing may follow the signature.
+    pub fn from_bytes(bytes: &[u8]) -> Result<DecodedSignature<C>, DecodeError> {
+        let mut reader = bytes;
+        let header = read_header::<C>(&mut reader, MAGIC)?;
+        let signature = match header.version {
+            1 => read_v1::<C>(&mut reader, header.linkable)?,
+            2 => {
+                let hash_id = read_hash_id(&mut reader)?;
+                DecodedSignature {
+                    hash_id,
+                    ..read_v1::<C>(&mut reader, header.linkable)?
+                }
+            }
+            version => return Err(DecodeError::UnsupportedVersion(version)),
+        };
+        if !reader.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        Ok(signature)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.signature().to_bytes()
+    }
+}
+
+// the ring and body of a version 1 signature, which version 2 shares
+fn read_v1<C: WireCurve>(
+    reader: &mut &[u8],
+    linkable: bool,
+) -> Result<DecodedSignature<C>, DecodeError> {
+    let size = u32::from_le_bytes(take(reader, 4)?.try_into().unwrap()) as usize;
+
+    // bound the allocation by what the input could possibly hold
+    let point_size = CurvePoint::<C>::default().compressed_size();
+    if size > reader.len() / point_size {
+        return Err(DecodeError::Truncated);
+    }
+    let keys = (0..size)
+        .map(|_| read::<CurvePoint<C>>(reader))
+        .collect::<Result<Vec<_>, _>>()?;
+    let ring = Ring::from_fixed_pubkeys(keys).map_err(DecodeError::InvalidRing)?;
+    Ok(read_body(reader, ring.size(), linkable)?.attach_owned(ring))
+}
+
+impl<C: WireCurve> DetachedSignature<ScalarBigInt<C>, C> {
+    /// Parses bytes written by [`RingSignature::to_ringless_bytes`], given the ring the
+    /// signature was made over. Fails with [`DecodeError::RingMismatch`] if `ring` is not that
+    /// ring; the result can then be checked with [`DetachedSignature::verify_with_ring`].
+    pub fn from_ringless_bytes(
+        bytes: &[u8],
+        ring: &Ring<C>,
+    ) -> Result<DetachedSignature<ScalarBigInt<C>, C>, DecodeError> {
+        let mut reader = bytes;
+        let header = read_header::<C>(&mut reader, RINGLESS_MAGIC)?;
+        let hash_id = match header.version {
+            1 => challenge::Sha512::HASH_ID,
+            2 => read_hash_id(&mut reader)?,
+            version => return Err(DecodeError::UnsupportedVersion(version)),
+        };
+        if take(&mut reader, 32)? != ring.digest() {
+            return Err(DecodeError::RingMismatch);
+        }
+        let signature = DetachedSignature {
+            hash_id,
+            ..read_body(&mut reader, ring.size(), header.linkable)?
+        };
+        if !reader.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        Ok(signature)
+    }
+}
+
+/// The digest of the ring a ring-less signature was made over, for finding the ring before
+/// calling [`DetachedSignature::from_ringless_bytes`].
+pub fn ringless_ring_digest<C: WireCurve>(bytes: &[u8]) -> Result<[u8; 32], DecodeError> {
+    let mut reader = bytes;
+    match read_header::<C>(&mut reader, RINGLESS_MAGIC)?.version {
+        1 => {}
+        2 => {
+            read_hash_id(&mut reader)?;
+        }
+        version => return Err(DecodeError::UnsupportedVersion(version)),
+    }
+    Ok(take(&mut reader, 32)?.try_into().expect("took 32 bytes"))
+}
+
+/// The format version of bytes in the wire format, with or without their ring, read without
+/// decoding the rest. Versions this crate cannot read are returned too, so stored signatures
+/// can be sorted by version before an upgrade; only a missing magic fails.
+pub fn wire_version(bytes: &[u8]) -> Result<u8, DecodeError> {
+    let mut reader = bytes;
+    let magic = take(&mut reader, MAGIC.len())?;
+    if magic != MAGIC && magic != RINGLESS_MAGIC {
+        return Err(DecodeError::BadMagic);
+    }
+    Ok(take(&mut reader, 1)?[0])
+}
+
+fn scheme(linkable: bool) -> u8 {
+    if linkable { SCHEME_BLSAG } else { SCHEME_SAG }
+}
+
+struct Header {
+    version: u8,
+    linkable: bool,
+}
+
+// magic, version, curve and scheme; the caller picks the layout that follows by the version
+fn read_header<C: WireCurve>(reader: &mut &[u8], magic: &[u8; 4]) -> Result<Header, DecodeError> {
+    if take(reader, magic.len())? != magic {
+        return Err(DecodeError::BadMagic);
+    }
+    let [version, curve, scheme] = take(reader, 3)? else {
+        unreachable!()
+    };
+    // a later version may lay out the curve and scheme differently, so stop before reading them
+    if *version == 0 || *version > VERSION {
+        return Err(DecodeError::UnsupportedVersion(*version));
+    }
+    if *curve != C::CURVE_ID {
+        return Err(DecodeError::WrongCurve(*curve));
+    }
+    let linkable = match *scheme {
+        SCHEME_SAG => false,
+        SCHEME_BLSAG => true,
+        scheme => return Err(DecodeError::UnknownScheme(scheme)),
+    };
+    Ok(Header {
+        version: *version,
+        linkable,
+    })
+}
+
+// the hash id of a version 2 signature, which SHA-512 signatures never carry
+fn read_hash_id(reader: &mut &[u8]) -> Result<u8, DecodeError> {
+    match take(reader, 1)?[0] {
+        challenge::Sha512::HASH_ID => Err(DecodeError::InvalidEncoding),
+        hash_id => Ok(hash_id),
+    }
+}
+
+// the body written by `RingSignature::write_body` for a signature over a ring of `size` members,
+// made with SHA-512 unless the caller's layout says otherwise
+pub(crate) fn read_body<C: CurveGroup>(
+    reader: &mut &[u8],
+    size: usize,
+    linkable: bool,
+) -> Result<DetachedSignature<ScalarBigInt<C>, C>, DecodeError> {
+    let challenge = read::<Scalar<C>>(reader)?.into_bigint();
+    let ring_sig_vals = (0..size)
+        .map(|_| read::<Scalar<C>>(reader).map(|r| r.into_bigint()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let image = if linkable {
+        let image = KeyImage::from_point(read::<CurvePoint<C>>(reader)?);
+        if !image.is_valid() {
+            return Err(DecodeError::InvalidKeyImage);
+        }
+        Some(image)
+    } else {
+        None
+    };
+    Ok(DetachedSignature {
+        challenge,
+        ring_sig_vals,
+        image,
+        hash_id: challenge::Sha512::HASH_ID,
+    })
+}
+
+pub(crate) fn write<T: CanonicalSerialize>(bytes: &mut Vec<u8>, value: &T) {
+    value
+        .serialize_compressed(bytes)
+        .expect("serializing into a Vec cannot fail");
+}
+
+pub(crate) fn take<'a>(reader: &mut &'a [u8], length: usize) -> Result<&'a [u8], DecodeError> {
+    if reader.len() < length {
+        return Err(DecodeError::Truncated);
+    }
+    let (head, rest) = reader.split_at(length);
+    *reader = rest;
+    Ok(head)
+}
+
+// points are checked for curve and subgroup membership, and both points and scalars for
+// canonical encoding
+pub(crate) fn read<T: CanonicalDeserialize + CanonicalSerialize + Default>(
+    reader: &mut &[u8],
+) -> Result<T, DecodeError> {
+    let size = T::default().compressed_size();
+    let bytes = take(reader, size)?;
+    serialize::read_canonical(bytes, Compress::Yes, Validate::Yes)
+        .map_err(|_| DecodeError::InvalidEncoding)
+}
@@ -0,0 +1,228 @@
+// Every component of a valid signature tampered with in turn — the message, the challenge, each
+// response, the key image, each ring key and the hash id — for SAG and bLSAG over several ring
+// sizes and curves. Single and batch verification must reject every one of them.
+
+use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
+use ark_ff::{One, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::challenge::{Blake2b, ChallengeHash};
+use lingo::error::VerifyError;
+use lingo::key_image::{self, KeyImage};
+use lingo::ring::{Ring, RingSignature};
+use lingo::secret::SecretKey;
+
+type Scalar<C> = <C as PrimeGroup>::ScalarField;
+type Signature<'a, C> = RingSignature<'a, <Scalar<C> as PrimeField>::BigInt, C>;
+
+const MESSAGE: &[u8] = b"lingo adversarial";
+
+fn copy<'a, C: CurveGroup>(signature: &Signature<'a, C>) -> Signature<'a, C> {
+    RingSignature {
+        ring: signature.ring,
+        challenge: signature.challenge,
+        ring_sig_vals: signature.ring_sig_vals.clone(),
+        image: signature.image,
+        hash_id: signature.hash_id,
+    }
+}
+
+// the next scalar: still canonical, so only the challenge chain can catch it
+fn bump<C: CurveGroup>(
+    value: <Scalar<C> as PrimeField>::BigInt,
+) -> <Scalar<C> as PrimeField>::BigInt {
+    (Scalar::<C>::from_bigint(value).unwrap() + Scalar::<C>::one()).into_bigint()
+}
+
+fn rejected<C: CurveGroup>(signature: &Signature<'_, C>, message: &[u8], what: &str) {
+    assert!(
+        signature.verify(message).is_err(),
+        "{what} verified on its own"
+    );
+    assert!(
+        Signature::<C>::batch_verify(&[(message, signature)]).is_err(),
+        "{what} verified in a batch"
+    );
+}
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn tamper_message<C: CurveGroup>(signature: &Signature<'_, C>) {
+    rejected(signature, b"lingo adversarian", "another message");
+    rejected(signature, b"", "the empty message");
+    let mut longer = MESSAGE.to_vec();
+    longer.push(0);
+    rejected(signature, &longer, "an extended message");
+}
+
+fn tamper_challenge<C: CurveGroup>(signature: &Signature<'_, C>) {
+    let mut tampered = copy(signature);
+    tampered.challenge = bump::<C>(signature.challenge);
+    rejected(&tampered, MESSAGE, "a bumped challenge");
+
+    tampered.challenge = Scalar::<C>::MODULUS;
+    assert_eq!(
+        tampered.verify(MESSAGE),
+        Err(VerifyError::NonCanonicalScalar)
+    );
+    rejected(&tampered, MESSAGE, "a non-canonical challenge");
+}
+
+fn tamper_responses<C: CurveGroup>(signature: &Signature<'_, C>) {
+    for i in 0..signature.ring_sig_vals.len() {
+        let mut tampered = copy(signature);
+        tampered.ring_sig_vals[i] = bump::<C>(signature.ring_sig_vals[i]);
+        rejected(&tampered, MESSAGE, &format!("bumped response {i}"));
+
+        tampered.ring_sig_vals[i] = Scalar::<C>::MODULUS;
+        assert_eq!(
+            tampered.verify(MESSAGE),
+            Err(VerifyError::NonCanonicalScalar)
+        );
+        rejected(&tampered, MESSAGE, &format!("non-canonical response {i}"));
+    }
+
+    let mut missing = copy(signature);
+    missing.ring_sig_vals.pop();
+    rejected(&missing, MESSAGE, "a missing response");
+    let mut extra = copy(signature);
+    extra.ring_sig_vals.push(signature.ring_sig_vals[0]);
+    assert_eq!(extra.verify(MESSAGE), Err(VerifyError::LengthMismatch));
+    rejected(&extra, MESSAGE, "an extra response");
+
+    if signature.ring_sig_vals.len() > 1 {
+        let mut swapped = copy(signature);
+        swapped.ring_sig_vals.swap(0, 1);
+        rejected(&swapped, MESSAGE, "swapped responses");
+    }
+}
+
+fn tamper_image<C: CurveGroup>(signature: &Signature<'_, C>, rng: &mut StdRng) {
+    let other = SecretKey::<C>::generate(rng);
+    let other_image = key_image::key_image(&other, &public_key(&other)).unwrap();
+
+    let mut tampered = copy(signature);
+    match signature.image {
+        None => {
+            tampered.image = Some(other_image);
+            rejected(&tampered, MESSAGE, "a key image added to a SAG signature");
+        }
+        Some(image) => {
+            tampered.image = Some(other_image);
+            rejected(&tampered, MESSAGE, "another key's image");
+
+            let mut bytes = Vec::new();
+            C::Affine::zero().serialize_compressed(&mut bytes).unwrap();
+            tampered.image = Some(KeyImage::deserialize_compressed_unchecked(&bytes[..]).unwrap());
+            assert_eq!(tampered.verify(MESSAGE), Err(VerifyError::InvalidKeyImage));
+            rejected(&tampered, MESSAGE, "the identity as key image");
+
+            let mut bytes = Vec::new();
+            (*image.point() + C::generator())
+                .into_affine()
+                .serialize_compressed(&mut bytes)
+                .unwrap();
+            tampered.image = Some(KeyImage::deserialize_compressed(&bytes[..]).unwrap());
+            rejected(&tampered, MESSAGE, "a shifted key image");
+
+            tampered.image = None;
+            rejected(&tampered, MESSAGE, "a stripped key image");
+        }
+    }
+}
+
+fn tamper_keys<C: CurveGroup>(signature: &Signature<'_, C>, rng: &mut StdRng) {
+    let keys = signature.ring.keys();
+    for i in 0..keys.len() {
+        let mut replaced = keys.to_vec();
+        replaced[i] = public_key(&SecretKey::<C>::generate(rng));
+        let ring = Ring::from_fixed_pubkeys(replaced).unwrap();
+        let tampered = RingSignature {
+            ring: &ring,
+            ..copy(signature)
+        };
+        rejected(&tampered, MESSAGE, &format!("replaced ring key {i}"));
+
+        let mut negated = keys.to_vec();
+        negated[i] = (-keys[i].into_group()).into_affine();
+        let ring = Ring::from_fixed_pubkeys(negated).unwrap();
+        let tampered = RingSignature {
+            ring: &ring,
+            ..copy(signature)
+        };
+        rejected(&tampered, MESSAGE, &format!("negated ring key {i}"));
+    }
+
+    if keys.len() > 1 {
+        let mut rotated = keys.to_vec();
+        rotated.rotate_left(1);
+        let ring = Ring::from_fixed_pubkeys(rotated).unwrap();
+        let tampered = RingSignature {
+            ring: &ring,
+            ..copy(signature)
+        };
+        rejected(&tampered, MESSAGE, "a reordered ring");
+
+        let ring = Ring::from_fixed_pubkeys(keys[1..].to_vec()).unwrap();
+        let tampered = RingSignature {
+            ring: &ring,
+            ..copy(signature)
+        };
+        assert_eq!(tampered.verify(MESSAGE), Err(VerifyError::LengthMismatch));
+        rejected(&tampered, MESSAGE, "a shrunk ring");
+    }
+}
+
+fn tamper_hash<C: CurveGroup>(signature: &Signature<'_, C>) {
+    let mut tampered = copy(signature);
+    tampered.hash_id = Blake2b::HASH_ID;
+    rejected(&tampered, MESSAGE, "another hash id");
+    tampered.hash_id = 0xff;
+    assert_eq!(tampered.verify(MESSAGE), Err(VerifyError::UnsupportedHash));
+    rejected(&tampered, MESSAGE, "an unknown hash id");
+}
+
+fn run<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for size in [1usize, 2, 5] {
+        let secret = SecretKey::<C>::generate(&mut rng);
+        let index = size / 2;
+        let ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+        for linkable in [false, true] {
+            let signature = if linkable {
+                ring.sign_linkable_with_rng(MESSAGE, &secret, index, &mut rng)
+                    .unwrap()
+            } else {
+                ring.sign_with_rng(MESSAGE, &secret, index, &mut rng)
+                    .unwrap()
+            };
+            signature.verify(MESSAGE).unwrap();
+            Signature::<C>::batch_verify(&[(MESSAGE, &signature)]).unwrap();
+
+            tamper_message(&signature);
+            tamper_challenge(&signature);
+            tamper_responses(&signature);
+            tamper_image(&signature, &mut rng);
+            tamper_keys(&signature, &mut rng);
+            tamper_hash(&signature);
+        }
+    }
+}
+
+#[test]
+fn secp256k1() {
+    run::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn ed25519() {
+    run::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[cfg(feature = "bls12-381")]
+#[test]
+fn bls12_381() {
+    run::<lingo::curves::bls12_381::G1Projective>(3);
+}
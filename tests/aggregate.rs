@@ -0,0 +1,44 @@
+// One signature over a batch of messages: it verifies against the same messages in the same
+// order, and not against a reordering, a regrouping of their bytes, or a subset.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::aggregate;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGES: [&[u8]; 3] = [b"lingo", b"aggregate", b""];
+
+fn aggregates<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 0, &mut rng).unwrap();
+
+    let plain = aggregate::sign_with_rng(&ring, &MESSAGES, &secret, 0, &mut rng).unwrap();
+    let linkable =
+        aggregate::sign_linkable_with_rng(&ring, &MESSAGES, &secret, 0, &mut rng).unwrap();
+    assert!(plain.key_image().is_none());
+    assert!(linkable.key_image().is_some());
+    for signature in [&plain, &linkable] {
+        assert!(aggregate::verify(signature, &MESSAGES).is_ok());
+        assert!(aggregate::verify(signature, &[b"aggregate", b"lingo", b""]).is_err());
+        assert!(aggregate::verify(signature, &[b"lingoaggregate", b""]).is_err());
+        assert!(aggregate::verify(signature, &[b"lingo", b"aggregate"]).is_err());
+        assert!(aggregate::verify(signature, &[]).is_err());
+        assert!(signature.verify(b"lingoaggregate").is_err());
+    }
+
+    let empty = aggregate::sign_with_rng(&ring, &[], &secret, 0, &mut rng).unwrap();
+    assert!(aggregate::verify(&empty, &[]).is_ok());
+    assert!(aggregate::verify(&empty, &[b""]).is_err());
+}
+
+#[test]
+fn aggregate_secp256k1() {
+    aggregates::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn aggregate_ed25519() {
+    aggregates::<ark_ed25519::EdwardsProjective>(2);
+}
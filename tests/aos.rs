@@ -0,0 +1,94 @@
+// AOS signing and verification over rings that mix secp256k1, ed25519 and BLS12-381 keys: a
+// signer in any group, at any position, must verify against its own message and no other. A
+// signer outside the ring, or whose key is not the member's, is refused.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::aos::{AosRing, DlogPublicKey};
+use lingo::error::RingError;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+type Secp256k1 = ark_secp256k1::Projective;
+type Ed25519 = ark_ed25519::EdwardsProjective;
+
+const MESSAGE: &[u8] = b"lingo aos";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn signs<C: CurveGroup>(ring: &AosRing, secret: &SecretKey<C>, index: usize, rng: &mut StdRng) {
+    let signature = ring
+        .sign_with_rng::<C, _>(MESSAGE, secret, index, rng)
+        .unwrap();
+    assert!(signature.verify(MESSAGE).is_ok());
+    assert!(signature.verify(b"lingo aor").is_err());
+}
+
+#[test]
+fn mixed_secp256k1_ed25519() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let secp = SecretKey::<Secp256k1>::generate(&mut rng);
+    let ed = SecretKey::<Ed25519>::generate(&mut rng);
+
+    let mut ring = AosRing::default();
+    ring.extend_from_ring(&Ring::<Secp256k1>::new_with_rng(3, &secp, 1, &mut rng).unwrap());
+    ring.extend_from_ring(&Ring::<Ed25519>::new_with_rng(2, &ed, 0, &mut rng).unwrap());
+    assert_eq!(ring.size(), 5);
+    signs(&ring, &secp, 1, &mut rng);
+    signs(&ring, &ed, 3, &mut rng);
+}
+
+#[test]
+fn single_member() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let secret = SecretKey::<Secp256k1>::generate(&mut rng);
+    let mut ring = AosRing::default();
+    ring.push(DlogPublicKey::<Secp256k1>(public_key(&secret)));
+    signs(&ring, &secret, 0, &mut rng);
+}
+
+#[test]
+fn refuses_wrong_signers() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let secp = SecretKey::<Secp256k1>::generate(&mut rng);
+    let ed = SecretKey::<Ed25519>::generate(&mut rng);
+    let mut ring = AosRing::default();
+    ring.extend_from_ring(&Ring::<Secp256k1>::new_with_rng(2, &secp, 1, &mut rng).unwrap());
+    ring.push(DlogPublicKey::<Ed25519>(public_key(&ed)));
+
+    assert_eq!(
+        ring.sign_with_rng(MESSAGE, &secp, 3, &mut rng).err(),
+        Some(RingError::IndexOutOfBounds { index: 3, size: 3 })
+    );
+    assert_eq!(
+        ring.sign_with_rng(MESSAGE, &secp, 0, &mut rng).err(),
+        Some(RingError::KeyMismatch)
+    );
+    // the right index with a key from another group
+    assert_eq!(
+        ring.sign_with_rng(MESSAGE, &secp, 2, &mut rng).err(),
+        Some(RingError::KeyMismatch)
+    );
+}
+
+#[cfg(feature = "bls12-381")]
+#[test]
+fn mixed_with_bls12_381() {
+    type Bls = lingo::curves::bls12_381::G1Projective;
+    let mut rng = StdRng::seed_from_u64(3);
+    let bls = SecretKey::<Bls>::generate(&mut rng);
+    let ed = SecretKey::<Ed25519>::generate(&mut rng);
+
+    let mut ring = AosRing::default();
+    ring.push(DlogPublicKey::<Ed25519>(public_key(&ed)));
+    ring.push(DlogPublicKey::<Secp256k1>(public_key(&SecretKey::<
+        Secp256k1,
+    >::generate(
+        &mut rng
+    ))));
+    ring.push(DlogPublicKey::<Bls>(public_key(&bls)));
+    signs(&ring, &ed, 0, &mut rng);
+    signs(&ring, &bls, 2, &mut rng);
+}
@@ -0,0 +1,112 @@
+// Blind signing sessions over several ring sizes, with the signer at every position: the
+// unblinded signature must verify against the user's message and no other, and a tampered
+// response must not unblind at all. A signer refuses a request whose challenge is not canonical,
+// and a user refuses commitments that do not match the ring or are not prime-order points.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{One, PrimeField, Zero};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::blind::{BlindRequest, BlindSigner, unblind};
+use lingo::error::SessionError;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo blind";
+
+fn signs_blindly_at_every_index<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 4] {
+        for index in 0..size {
+            let ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+            let (signer, commitment) =
+                BlindSigner::commit_with_rng(&ring, &secret, index, &mut rng).unwrap();
+            let (blinding, request) =
+                BlindRequest::new_with_rng(&ring, MESSAGE, &commitment, &mut rng).unwrap();
+            let response = signer.respond(&request).unwrap();
+
+            let signature = unblind(blinding, MESSAGE, &response).unwrap();
+            assert!(signature.verify(MESSAGE).is_ok());
+            assert!(signature.verify(b"lingo blinc").is_err());
+            // the signer never sees the challenges that end up in the signature
+            assert_ne!(signature.challenges, response.challenges);
+
+            let (signer, commitment) =
+                BlindSigner::commit_with_rng(&ring, &secret, index, &mut rng).unwrap();
+            let (blinding, request) =
+                BlindRequest::new_with_rng(&ring, MESSAGE, &commitment, &mut rng).unwrap();
+            let mut tampered = signer.respond(&request).unwrap();
+            tampered.responses[index] = (C::ScalarField::from_bigint(tampered.responses[index])
+                .unwrap()
+                + C::ScalarField::one())
+            .into_bigint();
+            assert!(unblind(blinding, MESSAGE, &tampered).is_none());
+
+            let (signer, _) =
+                BlindSigner::commit_with_rng(&ring, &secret, index, &mut rng).unwrap();
+            let request = BlindRequest::<C> {
+                challenge: C::ScalarField::MODULUS,
+            };
+            assert_eq!(
+                signer.respond(&request),
+                Err(SessionError::NonCanonicalScalar)
+            );
+        }
+    }
+}
+
+fn refuses_malformed_commitments<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+    let (_, commitment) = BlindSigner::commit_with_rng(&ring, &secret, 1, &mut rng).unwrap();
+
+    let mut short = commitment.clone();
+    short.commitments.pop();
+    assert!(matches!(
+        BlindRequest::new_with_rng(&ring, MESSAGE, &short, &mut rng),
+        Err(SessionError::LengthMismatch)
+    ));
+    let mut zero = commitment;
+    zero.commitments[2] = C::Affine::zero();
+    assert!(matches!(
+        BlindRequest::new_with_rng(&ring, MESSAGE, &zero, &mut rng),
+        Err(SessionError::InvalidPoints(indices)) if indices == [2]
+    ));
+}
+
+#[test]
+fn blind_secp256k1() {
+    signs_blindly_at_every_index::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn blind_ed25519() {
+    signs_blindly_at_every_index::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn malformed_secp256k1() {
+    refuses_malformed_commitments::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn malformed_ed25519() {
+    refuses_malformed_commitments::<ark_ed25519::EdwardsProjective>(4);
+}
+
+#[test]
+fn refuses_small_order_commitments() {
+    type C = ark_ed25519::EdwardsProjective;
+    let mut rng = StdRng::seed_from_u64(5);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(2, &secret, 0, &mut rng).unwrap();
+    let (_, mut commitment) = BlindSigner::commit_with_rng(&ring, &secret, 0, &mut rng).unwrap();
+    // (0, -1) is on the curve and has order 2
+    commitment.commitments[1] =
+        ark_ed25519::EdwardsAffine::new_unchecked(ark_ed25519::Fq::zero(), -ark_ed25519::Fq::one());
+    assert!(matches!(
+        BlindRequest::new_with_rng(&ring, MESSAGE, &commitment, &mut rng),
+        Err(SessionError::InvalidPoints(indices)) if indices == [1]
+    ));
+}
@@ -0,0 +1,96 @@
+// Borromean signing and verification over sets of rings of different sizes, with a signer at
+// every combination of positions: each signature must verify against its own message and no
+// other, and fail once any one of its rings is swapped out. Signing refuses no rings, keys or
+// indices that do not come one per ring, and a signer outside its ring or not holding its key.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::borromean;
+use lingo::error::RingError;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo borromean";
+
+fn signs_across_rings<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for sizes in [vec![1], vec![2, 3], vec![4, 1, 2]] {
+        let secrets: Vec<SecretKey<C>> = sizes
+            .iter()
+            .map(|_| SecretKey::generate(&mut rng))
+            .collect();
+        for shift in 0..4 {
+            let indices: Vec<usize> = sizes.iter().map(|size| shift % size).collect();
+            let rings: Vec<Ring<C>> = sizes
+                .iter()
+                .zip(&secrets)
+                .zip(&indices)
+                .map(|((size, secret), index)| {
+                    Ring::new_with_rng(*size, secret, *index, &mut rng).unwrap()
+                })
+                .collect();
+            let signature =
+                borromean::sign_with_rng(&rings, MESSAGE, &secrets, &indices, &mut rng).unwrap();
+            assert!(signature.verify(MESSAGE).is_ok());
+            assert!(signature.verify(b"lingo borromeam").is_err());
+
+            // the same responses over a first ring the signer holds no key in
+            let stranger = SecretKey::<C>::generate(&mut rng);
+            let mut swapped = rings.clone();
+            swapped[0] = Ring::new_with_rng(sizes[0], &stranger, indices[0], &mut rng).unwrap();
+            let forged = borromean::BorromeanSignature {
+                rings: &swapped,
+                challenge: signature.challenge,
+                ring_sig_vals: signature.ring_sig_vals.clone(),
+            };
+            assert!(forged.verify(MESSAGE).is_err());
+        }
+    }
+}
+
+fn refuses_wrong_signers<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secrets: Vec<SecretKey<C>> = (0..2).map(|_| SecretKey::generate(&mut rng)).collect();
+    let rings: Vec<Ring<C>> = secrets
+        .iter()
+        .map(|secret| Ring::new_with_rng(3, secret, 1, &mut rng).unwrap())
+        .collect();
+    let mut sign = |rings: &[Ring<C>], secrets: &[SecretKey<C>], indices: &[usize]| {
+        borromean::sign_with_rng(rings, MESSAGE, secrets, indices, &mut rng).err()
+    };
+
+    assert_eq!(sign(&[], &[], &[]), Some(RingError::EmptyRing));
+    assert_eq!(
+        sign(&rings, &secrets[..1], &[1, 1]),
+        Some(RingError::KeyMismatch)
+    );
+    assert_eq!(sign(&rings, &secrets, &[1]), Some(RingError::KeyMismatch));
+    assert_eq!(
+        sign(&rings, &secrets, &[1, 3]),
+        Some(RingError::IndexOutOfBounds { index: 3, size: 3 })
+    );
+    assert_eq!(
+        sign(&rings, &secrets, &[1, 2]),
+        Some(RingError::KeyMismatch)
+    );
+}
+
+#[test]
+fn borromean_secp256k1() {
+    signs_across_rings::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn borromean_ed25519() {
+    signs_across_rings::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn wrong_signers_secp256k1() {
+    refuses_wrong_signers::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn wrong_signers_ed25519() {
+    refuses_wrong_signers::<ark_ed25519::EdwardsProjective>(4);
+}
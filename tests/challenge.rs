@@ -0,0 +1,286 @@
+// Challenge hashes: BLAKE2b must match its RFC 7693 digests however its input is split, and a
+// signature made with any of the crate's hashes must verify with the hash it names and carry
+// that name through every encoding that can hold it. Relabelling a signature with another hash
+// breaks it, unknown hash ids are refused, and SHA-512 is only ever implied, so each signature
+// keeps exactly one encoding. Encodings with no room for a hash id take SHA-512 signatures only.
+// Poseidon over each supported field must permute, absorb split input as it does whole input,
+// and tell inputs of different lengths apart.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::challenge::{self, Blake2b, ChallengeHash, Keccak256, Sha512};
+use lingo::context::SigningContext;
+use lingo::error::{DecodeError, VerifyError};
+use lingo::ring::{DetachedSignature, Ring, RingSignature};
+use lingo::secret::SecretKey;
+use lingo::wire::{self, DecodedSignature, WireCurve};
+
+const MESSAGE: &[u8] = b"lingo challenge";
+
+fn blake2b(data: &[u8], split: usize) -> [u8; 64] {
+    let mut state = Blake2b::new();
+    Blake2b::update(&mut state, &data[..split]);
+    Blake2b::update(&mut state, &data[split..]);
+    Blake2b::finalize(state)
+}
+
+fn unhex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn round_trips<C: WireCurve, H: ChallengeHash>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 2, &mut rng).unwrap();
+    let implied = H::HASH_ID == Sha512::HASH_ID;
+    for signature in [
+        ring.sign_with_hash_and_rng::<H, _>(MESSAGE, &secret, 2, &mut rng)
+            .unwrap(),
+        ring.sign_linkable_with_hash_and_rng::<H, _>(MESSAGE, &secret, 2, &mut rng)
+            .unwrap(),
+    ] {
+        assert_eq!(signature.hash_id, H::HASH_ID);
+        assert!(signature.verify(MESSAGE).is_ok());
+        assert!(signature.verify(b"lingo challengf").is_err());
+        assert!(signature.verify_with_hash::<H>(MESSAGE).is_ok());
+        if !implied {
+            assert_eq!(
+                signature.verify_with_hash::<Sha512>(MESSAGE),
+                Err(VerifyError::UnsupportedHash)
+            );
+            let mut relabelled = signature.detach();
+            relabelled.hash_id = Sha512::HASH_ID;
+            assert!(relabelled.verify_with_ring(&ring, MESSAGE).is_err());
+        }
+        let mut unknown = signature.detach();
+        unknown.hash_id = 0x90;
+        assert_eq!(
+            unknown.verify_with_ring(&ring, MESSAGE),
+            Err(VerifyError::UnsupportedHash)
+        );
+
+        let bytes = signature.to_bytes();
+        assert_eq!(wire::wire_version(&bytes), Ok(if implied { 1 } else { 2 }));
+        let read = DecodedSignature::<C>::from_bytes(&bytes).unwrap();
+        assert_eq!(read.hash_id, H::HASH_ID);
+        assert!(read.verify(MESSAGE).is_ok());
+        let read =
+            DetachedSignature::from_ringless_bytes(&signature.to_ringless_bytes(), &ring).unwrap();
+        assert_eq!(read.hash_id, H::HASH_ID);
+        assert!(read.verify_with_ring(&ring, MESSAGE).is_ok());
+
+        let compact = signature.to_compact();
+        let read = DecodedSignature::<C>::from_compact(&compact).unwrap();
+        assert_eq!(read.hash_id, H::HASH_ID);
+        assert!(read.verify(MESSAGE).is_ok());
+        let read = DecodedSignature::<C>::from_cbor(&signature.to_cbor()).unwrap();
+        assert_eq!(read.hash_id, H::HASH_ID);
+        assert!(read.verify(MESSAGE).is_ok());
+
+        #[cfg(feature = "json")]
+        {
+            let json = signature.to_json();
+            assert_eq!(json.contains("\"hash\""), !implied);
+            let read = DecodedSignature::<C>::from_json(&json).unwrap();
+            assert_eq!(read.hash_id, H::HASH_ID);
+        }
+        #[cfg(feature = "proto")]
+        {
+            let message: lingo::proto::v1::RingSignature = (&signature).into();
+            let read = DecodedSignature::<C>::try_from(&message).unwrap();
+            assert_eq!(read.hash_id, H::HASH_ID);
+        }
+        #[cfg(feature = "borsh")]
+        assert_eq!(borsh::to_vec(&signature).is_ok(), implied);
+        #[cfg(feature = "serde")]
+        assert_eq!(serde_json::to_string(&signature.detach()).is_ok(), implied);
+        let mut canonical = Vec::new();
+        assert_eq!(
+            ark_serialize::CanonicalSerialize::serialize_compressed(&signature, &mut canonical)
+                .is_ok(),
+            implied
+        );
+
+        if !implied {
+            // an explicit SHA-512 id would give the same signature a second encoding
+            let mut explicit = bytes.clone();
+            explicit[7] = Sha512::HASH_ID;
+            assert_eq!(
+                DecodedSignature::<C>::from_bytes(&explicit).err(),
+                Some(DecodeError::InvalidEncoding)
+            );
+            let mut explicit = compact.clone();
+            explicit[2] = Sha512::HASH_ID;
+            assert_eq!(
+                DecodedSignature::<C>::from_compact(&explicit).err(),
+                Some(DecodeError::InvalidEncoding)
+            );
+        }
+    }
+}
+
+fn mixes_hashes<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 0, &mut rng).unwrap();
+    assert_eq!(
+        ring.sign_with_rng(MESSAGE, &secret, 0, &mut rng)
+            .unwrap()
+            .hash_id,
+        Sha512::HASH_ID
+    );
+
+    let context = SigningContext::new(b"lingo tests");
+    let keccak = context.with_hash::<Keccak256>();
+    let signature = keccak
+        .sign_linkable_with_rng(&ring, MESSAGE, &secret, 0, &mut rng)
+        .unwrap();
+    assert_eq!(signature.hash_id, Keccak256::HASH_ID);
+    assert!(keccak.verify(&signature, MESSAGE).is_ok());
+    assert!(context.verify(&signature, MESSAGE).is_err());
+
+    let first = ring
+        .sign_with_hash_and_rng::<Blake2b, _>(b"first", &secret, 0, &mut rng)
+        .unwrap();
+    let second = ring.sign_with_rng(b"second", &secret, 0, &mut rng).unwrap();
+    let third = ring
+        .sign_linkable_with_hash_and_rng::<Keccak256, _>(b"third", &secret, 0, &mut rng)
+        .unwrap();
+    let batch = [
+        (&b"first"[..], &first),
+        (&b"second"[..], &second),
+        (&b"third"[..], &third),
+    ];
+    assert!(RingSignature::batch_verify(&batch).is_ok());
+    let batch = [
+        (&b"first"[..], &first),
+        (&b"second"[..], &second),
+        (&b"fourth"[..], &third),
+    ];
+    assert!(RingSignature::batch_verify(&batch).is_err());
+}
+
+#[test]
+fn blake2b_rfc7693() {
+    assert_eq!(
+        blake2b(b"", 0)[..],
+        unhex(
+            "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419\
+             d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce"
+        )
+    );
+    assert_eq!(
+        blake2b(b"abc", 1)[..],
+        unhex(
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        )
+    );
+    // across block boundaries
+    let long: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+    let whole = blake2b(&long, 0);
+    for split in [1, 127, 128, 129, 256, 999, 1000] {
+        assert_eq!(blake2b(&long, split), whole);
+    }
+}
+
+#[test]
+fn hash_names() {
+    for (id, name) in [(1, "sha512"), (2, "keccak256"), (3, "blake2b")] {
+        assert_eq!(challenge::hash_name(id), Some(name));
+        assert_eq!(challenge::hash_id(name), Some(id));
+    }
+    assert_eq!(challenge::hash_name(0x80), None);
+    assert_eq!(challenge::hash_id("md5"), None);
+}
+
+#[test]
+fn hashes_secp256k1() {
+    round_trips::<ark_secp256k1::Projective, Sha512>(1);
+    round_trips::<ark_secp256k1::Projective, Keccak256>(2);
+    round_trips::<ark_secp256k1::Projective, Blake2b>(3);
+    mixes_hashes::<ark_secp256k1::Projective>(4);
+}
+
+#[test]
+fn hashes_ed25519() {
+    round_trips::<ark_ed25519::EdwardsProjective, Sha512>(5);
+    round_trips::<ark_ed25519::EdwardsProjective, Keccak256>(6);
+    round_trips::<ark_ed25519::EdwardsProjective, Blake2b>(7);
+    mixes_hashes::<ark_ed25519::EdwardsProjective>(8);
+}
+
+#[cfg(feature = "poseidon")]
+fn poseidon_field<F: lingo::poseidon::PoseidonField>() {
+    use ark_ff::BigInteger;
+    use lingo::poseidon::{Poseidon, PoseidonParameters};
+
+    // x^5 is only a permutation if 5 does not divide p - 1
+    let remainder = F::MODULUS
+        .to_bytes_be()
+        .iter()
+        .fold(0u32, |remainder, byte| (remainder * 256 + *byte as u32) % 5);
+    assert_ne!(remainder, 1, "{}", F::HASH_NAME);
+    let parameters = PoseidonParameters::<F>::new();
+    assert_eq!(parameters.alpha, 5);
+    assert_eq!(parameters.ark.len(), 65);
+    let mut state = [F::from(1u64), F::from(2u64), F::from(3u64)];
+    parameters.permute(&mut state);
+    assert_ne!(state, [F::from(1u64), F::from(2u64), F::from(3u64)]);
+
+    let digest = |parts: &[&[u8]]| {
+        let mut state = Poseidon::<F>::new();
+        for part in parts {
+            Poseidon::<F>::update(&mut state, part);
+        }
+        Poseidon::<F>::finalize(state)
+    };
+    let long: Vec<u8> = (0..200u8).collect();
+    let whole = digest(&[&long]);
+    for split in [1, 30, 31, 32, 62, 63, 199] {
+        assert_eq!(digest(&[&long[..split], &long[split..]]), whole);
+    }
+    assert_ne!(digest(&[b""]), digest(&[b"\0"]));
+    assert_ne!(digest(&[&[0; 30]]), digest(&[&[0; 31]]));
+    assert_ne!(digest(&[&[0; 31]]), digest(&[&[0; 32]]));
+}
+
+#[cfg(all(feature = "poseidon", feature = "bls12-381", feature = "jubjub"))]
+#[test]
+fn poseidon_bls12_381() {
+    use lingo::curves::{bls12_381, jubjub};
+    use lingo::poseidon::Poseidon;
+
+    poseidon_field::<bls12_381::Fr>();
+    assert_eq!(challenge::hash_name(4), Some("poseidon-bls12-381"));
+    // Jubjub's base field is the BLS12-381 scalar field, where circuits verify its signatures
+    round_trips::<jubjub::EdwardsProjective, Poseidon<bls12_381::Fr>>(9);
+}
+
+#[cfg(all(feature = "poseidon", feature = "bn254"))]
+#[test]
+fn poseidon_bn254() {
+    use lingo::curves::bn254;
+    use lingo::poseidon::Poseidon;
+
+    poseidon_field::<bn254::Fr>();
+    assert_eq!(challenge::hash_id("poseidon-bn254"), Some(5));
+    round_trips::<bn254::G1Projective, Poseidon<bn254::Fr>>(10);
+}
+
+#[cfg(all(feature = "poseidon", feature = "pallas", feature = "vesta"))]
+#[test]
+fn poseidon_pasta() {
+    use lingo::curves::{pallas, pasta, vesta};
+    use lingo::poseidon::Poseidon;
+
+    poseidon_field::<pasta::Fp>();
+    poseidon_field::<pasta::Fq>();
+    assert_eq!(challenge::hash_id("poseidon-pasta-fp"), Some(6));
+    assert_eq!(challenge::hash_id("poseidon-pasta-fq"), Some(7));
+    round_trips::<pallas::Projective, Poseidon<pasta::Fq>>(11);
+    round_trips::<vesta::Projective, Poseidon<pasta::Fp>>(12);
+}
@@ -0,0 +1,50 @@
+// Signer claims on bLSAG signatures, for the signer at every position: a claim opens only the
+// signature it was made for, names the right index, and only the signer can make one.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo claim";
+
+fn claims_at_every_index<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    for index in 0..4 {
+        let ring = Ring::<C>::new_with_rng(4, &secret, index, &mut rng).unwrap();
+        let signature = ring
+            .sign_linkable_with_rng(MESSAGE, &secret, index, &mut rng)
+            .unwrap();
+        let claim = signature.claim_with_rng(&secret, &mut rng).unwrap();
+        assert_eq!(claim.index, index);
+        assert!(signature.verify_claim(&claim).is_ok());
+
+        // bound to one signature, not to the signer's key
+        let again = ring
+            .sign_linkable_with_rng(MESSAGE, &secret, index, &mut rng)
+            .unwrap();
+        assert!(again.verify_claim(&claim).is_err());
+
+        let mut moved = claim;
+        moved.index = (index + 1) % 4;
+        assert!(signature.verify_claim(&moved).is_err());
+
+        assert!(signature.claim_with_rng(&other, &mut rng).is_none());
+        let plain = ring
+            .sign_with_rng(MESSAGE, &secret, index, &mut rng)
+            .unwrap();
+        assert!(plain.claim_with_rng(&secret, &mut rng).is_none());
+    }
+}
+
+#[test]
+fn claim_secp256k1() {
+    claims_at_every_index::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn claim_ed25519() {
+    claims_at_every_index::<ark_ed25519::EdwardsProjective>(2);
+}
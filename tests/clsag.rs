@@ -0,0 +1,94 @@
+// CLSAG signing and verification over matrix rings of several sizes and widths, with the signer
+// at every row: each signature must verify against its own message and no other, and its single
+// key image must be the one MLSAG takes over the first layer. A signer outside the matrix, or
+// with keys that are not its row's, is refused.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::RingError;
+use lingo::mlsag::MatrixRing;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo clsag";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn members<C: CurveGroup>(
+    size: usize,
+    width: usize,
+    rng: &mut StdRng,
+) -> (Vec<Vec<SecretKey<C>>>, MatrixRing<C>) {
+    let secrets: Vec<Vec<SecretKey<C>>> = (0..size)
+        .map(|_| (0..width).map(|_| SecretKey::generate(rng)).collect())
+        .collect();
+    let members: Vec<Vec<C::Affine>> = secrets
+        .iter()
+        .map(|member| member.iter().map(public_key).collect())
+        .collect();
+    (secrets, MatrixRing::from_members(&members).unwrap())
+}
+
+fn signs_at_every_row<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for size in [1, 3] {
+        for width in [1, 2, 3] {
+            let (secrets, ring) = members::<C>(size, width, &mut rng);
+            for (index, secret) in secrets.iter().enumerate() {
+                let signature = ring
+                    .sign_clsag_with_rng(MESSAGE, secret, index, &mut rng)
+                    .unwrap();
+                assert!(signature.verify(MESSAGE).is_ok());
+                assert!(signature.verify(b"lingo clsah").is_err());
+                assert_eq!(signature.ring_sig_vals.len(), size);
+                assert_eq!(signature.auxiliary_images.len(), width - 1);
+
+                let mlsag = ring
+                    .sign_with_rng(MESSAGE, secret, index, &mut rng)
+                    .unwrap();
+                assert_eq!(signature.key_image(), &mlsag.key_images()[0]);
+            }
+        }
+    }
+}
+
+fn refuses_wrong_signers<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (secrets, ring) = members::<C>(3, 2, &mut rng);
+    assert_eq!(
+        ring.sign_clsag_with_rng(MESSAGE, &secrets[0], 3, &mut rng)
+            .err(),
+        Some(RingError::IndexOutOfBounds { index: 3, size: 3 })
+    );
+    assert_eq!(
+        ring.sign_clsag_with_rng(MESSAGE, &secrets[0][1..], 0, &mut rng)
+            .err(),
+        Some(RingError::KeyMismatch)
+    );
+    assert_eq!(
+        ring.sign_clsag_with_rng(MESSAGE, &secrets[0], 2, &mut rng)
+            .err(),
+        Some(RingError::KeyMismatch)
+    );
+}
+
+#[test]
+fn clsag_secp256k1() {
+    signs_at_every_row::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn clsag_ed25519() {
+    signs_at_every_row::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn wrong_signers_secp256k1() {
+    refuses_wrong_signers::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn wrong_signers_ed25519() {
+    refuses_wrong_signers::<ark_ed25519::EdwardsProjective>(4);
+}
@@ -0,0 +1,127 @@
+// Constant-time signing: the signing chain walks a rotation of the ring chosen without branching
+// on the signer's position, so the position must come back out where the verifier expects it.
+// Signatures made at every position of rings of several sizes, by every signing path, must
+// verify and keep one response per member in ring order. Public keys and key images, which
+// multiply by the private key along the hardened path when the `constant-time` feature is on,
+// must come out exactly as arkworks' own multiplication has them, for edge scalars too.
+
+use ark_ec::CurveGroup;
+use ark_ff::{One, UniformRand};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::external::SoftwareSigner;
+use lingo::key_image::{self, hash_point};
+use lingo::keypair::KeyPair;
+use lingo::mlsag::MatrixRing;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo constant time";
+
+fn signs_at_every_position<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 2, 3, 5] {
+        for index in 0..size {
+            let ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+            let signatures = [
+                ring.sign_with_rng(MESSAGE, &secret, index, &mut rng)
+                    .unwrap(),
+                ring.sign_linkable_with_rng(MESSAGE, &secret, index, &mut rng)
+                    .unwrap(),
+                ring.sign_deterministic(MESSAGE, &secret, index).unwrap(),
+                ring.sign_linkable_deterministic(MESSAGE, &secret, index)
+                    .unwrap(),
+                ring.sign_external_with_rng(
+                    MESSAGE,
+                    &mut SoftwareSigner::<C, _>::with_rng(&secret, StdRng::seed_from_u64(seed)),
+                    index,
+                    &mut rng,
+                )
+                .unwrap(),
+            ];
+            for signature in signatures {
+                assert_eq!(signature.ring_sig_vals.len(), size);
+                assert!(signature.verify(MESSAGE).is_ok());
+                assert!(signature.verify(b"lingo constant-time").is_err());
+            }
+        }
+    }
+}
+
+fn multiplies_secrets<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let one = C::ScalarField::one();
+    let scalars = [
+        one,
+        one + one,
+        -one,
+        C::ScalarField::from(0xffu64),
+        C::ScalarField::from(1u64 << 32),
+        C::ScalarField::rand(&mut rng),
+    ];
+    for scalar in scalars {
+        let secret = SecretKey::<C>::new(scalar);
+        let public_key = *KeyPair::from_private_key(&secret).public_key();
+        assert_eq!(public_key, (C::generator() * scalar).into_affine());
+
+        let expected = (hash_point::<C>(&public_key) * scalar).into_affine();
+        assert_eq!(
+            *key_image::key_image(&secret, &public_key).unwrap().point(),
+            expected
+        );
+        let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+        let signature = ring
+            .sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+            .unwrap();
+        assert_eq!(*signature.image.unwrap().point(), expected);
+
+        // every layer of a matrix ring multiplies its own key the same way
+        let decoys: Vec<Vec<C::Affine>> = (0..2)
+            .map(|_| {
+                (0..2)
+                    .map(|_| (C::generator() * C::ScalarField::rand(&mut rng)).into_affine())
+                    .collect()
+            })
+            .collect();
+        let ring = MatrixRing::<C>::from_members(&[
+            decoys[0].clone(),
+            vec![public_key, public_key],
+            decoys[1].clone(),
+        ])
+        .unwrap();
+        let keys = [SecretKey::new(scalar), SecretKey::new(scalar)];
+        let mlsag = ring.sign_with_rng(MESSAGE, &keys, 1, &mut rng).unwrap();
+        assert!(mlsag.verify(MESSAGE).is_ok());
+        assert!(
+            mlsag
+                .key_images()
+                .iter()
+                .all(|image| *image.point() == expected)
+        );
+        let clsag = ring
+            .sign_clsag_with_rng(MESSAGE, &keys, 1, &mut rng)
+            .unwrap();
+        assert!(clsag.verify(MESSAGE).is_ok());
+        assert_eq!(*clsag.key_image().point(), expected);
+    }
+}
+
+#[test]
+fn positions_secp256k1() {
+    signs_at_every_position::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn positions_ed25519() {
+    signs_at_every_position::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn secrets_secp256k1() {
+    multiplies_secrets::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn secrets_ed25519() {
+    multiplies_secrets::<ark_ed25519::EdwardsProjective>(4);
+}
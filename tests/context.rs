@@ -0,0 +1,67 @@
+// Signing contexts: a signature made under one context verifies under that context alone, not
+// under another label, another challenge hash or no context at all, and labels cannot be shifted
+// into the message.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::challenge::Blake2b;
+use lingo::context::SigningContext;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo context";
+
+fn separates_contexts<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 0, &mut rng).unwrap();
+    let chat = SigningContext::new(b"chat");
+    let other = SigningContext::new(b"other");
+
+    for signature in [
+        chat.sign_with_rng(&ring, MESSAGE, &secret, 0, &mut rng)
+            .unwrap(),
+        chat.sign_linkable_with_rng(&ring, MESSAGE, &secret, 0, &mut rng)
+            .unwrap(),
+    ] {
+        assert!(chat.verify(&signature, MESSAGE).is_ok());
+        assert!(chat.verify(&signature, b"lingo contexu").is_err());
+        assert!(other.verify(&signature, MESSAGE).is_err());
+        assert!(
+            chat.with_hash::<Blake2b>()
+                .verify(&signature, MESSAGE)
+                .is_err()
+        );
+        assert!(signature.verify(MESSAGE).is_err());
+    }
+
+    // the label's end cannot move into the message
+    let short = SigningContext::new(b"cha");
+    let signature = chat
+        .sign_with_rng(&ring, b"t", &secret, 0, &mut rng)
+        .unwrap();
+    assert!(short.verify(&signature, b"tt").is_err());
+    assert!(short.verify(&signature, b"t").is_err());
+
+    // nor can any message stand in for the context
+    let prefixed = ring.sign_with_rng(b"chatt", &secret, 0, &mut rng).unwrap();
+    assert!(chat.verify(&prefixed, b"t").is_err());
+    assert!(chat.verify(&prefixed, b"chatt").is_err());
+
+    let hashed = chat.with_hash::<Blake2b>();
+    let signature = hashed
+        .sign_with_rng(&ring, MESSAGE, &secret, 0, &mut rng)
+        .unwrap();
+    assert!(hashed.verify(&signature, MESSAGE).is_ok());
+    assert!(chat.verify(&signature, MESSAGE).is_err());
+}
+
+#[test]
+fn context_secp256k1() {
+    separates_contexts::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn context_ed25519() {
+    separates_contexts::<ark_ed25519::EdwardsProjective>(2);
+}
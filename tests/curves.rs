@@ -0,0 +1,273 @@
+// Every signature scheme run over each curve through the same generic code, so a curve added to
+// the crate only needs a line here to be covered by all of them, plus a known-answer check of
+// each in-tree curve's group law against its published parameters. Configs registered with
+// `impl_ring_curve!` name ring types that sign like any other.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::RingError;
+use lingo::mlsag::MatrixRing;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::{borromean, designated, one_of_many, traceable, triptych};
+
+const MESSAGE: &[u8] = b"lingo curves";
+
+lingo::impl_ring_curve!(ark_secp256k1::Config => type Secp256k1Ring);
+lingo::impl_ring_curve!(te ark_ed25519::EdwardsConfig => pub type Ed25519Ring);
+lingo::impl_ring_curve!(ark_secp256k1::Config);
+lingo::impl_ring_curve!(te ark_ed25519::EdwardsConfig);
+#[cfg(feature = "p256")]
+lingo::impl_ring_curve!(lingo::curves::p256::Config => type P256Ring);
+#[cfg(feature = "jubjub")]
+lingo::impl_ring_curve!(te lingo::curves::jubjub::EdwardsConfig);
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+// the generator lies on the curve, in the subgroup of the order arkworks was given
+fn prime_order_generator<C: CurveGroup>() {
+    let generator = C::Affine::generator();
+    assert!(!generator.is_zero());
+    assert!(
+        generator
+            .mul_bigint(<C::ScalarField as PrimeField>::MODULUS)
+            .is_zero()
+    );
+}
+
+fn every_scheme<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 2, &mut rng).unwrap();
+
+    assert!(
+        ring.sign_with_rng(MESSAGE, &secret, 2, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+    let linkable = ring
+        .sign_linkable_with_rng(MESSAGE, &secret, 2, &mut rng)
+        .unwrap();
+    assert!(linkable.verify(MESSAGE).is_ok());
+    assert!(linkable.key_image().unwrap().is_valid());
+
+    let second = Ring::<C>::new_with_rng(4, &other, 2, &mut rng).unwrap();
+    let matrix = MatrixRing::from_layers(vec![ring.clone(), second.clone()]).unwrap();
+    let keys = [secret.clone(), other.clone()];
+    assert!(
+        matrix
+            .sign_with_rng(MESSAGE, &keys, 2, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+    assert!(
+        matrix
+            .sign_clsag_with_rng(MESSAGE, &keys, 2, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+
+    let rings = [ring.clone(), second];
+    assert!(
+        borromean::sign_with_rng(&rings, MESSAGE, &keys, &[2, 2], &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+    assert!(
+        triptych::prove_with_rng(&ring, MESSAGE, &secret, 2, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+    assert!(
+        one_of_many::prove_with_rng(&ring, MESSAGE, &secret, 2, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+    assert!(
+        traceable::sign_with_rng(&ring, b"issue", MESSAGE, &secret, 2, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+    assert!(
+        designated::sign_with_rng(&ring, MESSAGE, &secret, 2, public_key(&other), &mut rng)
+            .unwrap()
+            .verify(MESSAGE, &other)
+            .is_ok()
+    );
+}
+
+#[test]
+fn secp256k1() {
+    every_scheme::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn ed25519() {
+    every_scheme::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[cfg(feature = "p256")]
+#[test]
+fn p256() {
+    use lingo::curves::p256::{Affine, Projective};
+    prime_order_generator::<Projective>();
+    assert!(Affine::generator().is_on_curve());
+    // 2G from the SEC 2 / NIST P-256 test vectors
+    let double = (Affine::generator() + Affine::generator()).into_affine();
+    assert_eq!(
+        double.x.to_string(),
+        "56515219790691171413109057904011688695424810155802929973526481321309856242040"
+    );
+    every_scheme::<Projective>(4);
+}
+
+#[cfg(feature = "bls12-381")]
+#[test]
+fn bls12_381() {
+    use ark_ec::CurveConfig;
+    use ark_serialize::CanonicalSerialize;
+    use lingo::curves::bls12_381::{Config, Fr, G1Affine, G1Projective};
+    prime_order_generator::<G1Projective>();
+    assert!(G1Affine::generator().is_on_curve());
+    assert!(G1Affine::generator().is_in_correct_subgroup_assuming_on_curve());
+    // h = 0x396c8c005555e1568c00aaab0000aaab, inverted mod r for cofactor clearing
+    assert_eq!(
+        Config::COFACTOR_INV * Fr::from(76329603384216526031706109802092473003u128),
+        Fr::from(1u64)
+    );
+    assert_eq!(G1Affine::generator().compressed_size(), 48);
+    every_scheme::<G1Projective>(5);
+}
+
+#[cfg(feature = "bn254")]
+#[test]
+fn bn254() {
+    use lingo::curves::bn254::{Fr, G1Affine, G1Projective, from_evm_bytes, to_evm_bytes};
+    prime_order_generator::<G1Projective>();
+    // the generator (1, 2) as the precompiles take it: two 32-byte big-endian words
+    let generator = to_evm_bytes(&G1Affine::generator());
+    assert_eq!(generator[..31], [0; 31]);
+    assert_eq!((generator[31], generator[63]), (1, 2));
+    assert_eq!(from_evm_bytes(&generator), Some(G1Affine::generator()));
+
+    let point = (G1Affine::generator() * Fr::from(12345u64)).into_affine();
+    assert_eq!(from_evm_bytes(&to_evm_bytes(&point)), Some(point));
+    // the precompiles encode the point at infinity as all zeros
+    assert_eq!(from_evm_bytes(&[0; 64]), Some(G1Affine::zero()));
+    let mut off_curve = generator;
+    off_curve[63] = 3;
+    assert_eq!(from_evm_bytes(&off_curve), None);
+    every_scheme::<G1Projective>(6);
+}
+
+#[cfg(feature = "pallas")]
+#[test]
+fn pallas() {
+    prime_order_generator::<lingo::curves::pallas::Projective>();
+    every_scheme::<lingo::curves::pallas::Projective>(7);
+}
+
+#[cfg(feature = "vesta")]
+#[test]
+fn vesta() {
+    prime_order_generator::<lingo::curves::vesta::Projective>();
+    every_scheme::<lingo::curves::vesta::Projective>(8);
+}
+
+// each curve's scalar field is the other's base field
+#[cfg(all(feature = "pallas", feature = "vesta"))]
+#[test]
+fn pasta_cycle() {
+    use ark_ec::CurveConfig;
+    use lingo::curves::{pallas, vesta};
+    type Base<P> = <P as CurveConfig>::BaseField;
+    type Scalar<P> = <P as CurveConfig>::ScalarField;
+    assert_eq!(
+        Scalar::<pallas::Config>::MODULUS,
+        Base::<vesta::Config>::MODULUS
+    );
+    assert_eq!(
+        Scalar::<vesta::Config>::MODULUS,
+        Base::<pallas::Config>::MODULUS
+    );
+}
+
+#[cfg(feature = "jubjub")]
+#[test]
+fn jubjub() {
+    use ark_ec::CurveConfig;
+    use ark_ec::twisted_edwards::TECurveConfig;
+    use lingo::curves::jubjub::{EdwardsAffine, EdwardsConfig, EdwardsProjective, Fq, Fr};
+    prime_order_generator::<EdwardsProjective>();
+    assert!(EdwardsAffine::generator().is_on_curve());
+    // d = -(10240 / 10241), and the base field is BLS12-381's scalar field
+    assert_eq!(
+        EdwardsConfig::COEFF_D * Fq::from(10241u64),
+        -Fq::from(10240u64)
+    );
+    assert_eq!(Fq::MODULUS, lingo::curves::bls12_381::Fr::MODULUS);
+    assert_eq!(EdwardsConfig::COFACTOR_INV * Fr::from(8u64), Fr::from(1u64));
+    every_scheme::<EdwardsProjective>(9);
+}
+
+#[test]
+fn registered_rings() {
+    let mut rng = StdRng::seed_from_u64(10);
+    let secret = SecretKey::generate(&mut rng);
+    let ring = Secp256k1Ring::new_with_rng(2, &secret, 0, &mut rng).unwrap();
+    assert!(
+        ring.sign_with_rng(MESSAGE, &secret, 0, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+
+    let secret = SecretKey::generate(&mut rng);
+    let ring = Ed25519Ring::new_with_rng(2, &secret, 1, &mut rng).unwrap();
+    assert!(
+        ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+
+    #[cfg(feature = "p256")]
+    {
+        let secret = SecretKey::generate(&mut rng);
+        let ring = P256Ring::new_with_rng(3, &secret, 2, &mut rng).unwrap();
+        assert!(
+            ring.sign_with_rng(MESSAGE, &secret, 2, &mut rng)
+                .unwrap()
+                .verify(MESSAGE)
+                .is_ok()
+        );
+    }
+}
+
+#[test]
+fn wrong_signer_key() {
+    type C = ark_secp256k1::Projective;
+    let mut rng = StdRng::seed_from_u64(3);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+    assert_eq!(
+        ring.sign_with_rng(MESSAGE, &secret, 0, &mut rng).err(),
+        Some(RingError::KeyMismatch)
+    );
+    assert_eq!(
+        ring.sign_linkable_with_rng(MESSAGE, &secret, 3, &mut rng)
+            .err(),
+        Some(RingError::IndexOutOfBounds { index: 3, size: 3 })
+    );
+}
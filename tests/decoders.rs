@@ -0,0 +1,143 @@
+// Decoders under attacker-controlled input, the properties the fuzz targets in `fuzz/` check,
+// run as proptests so every `cargo test` covers them: arbitrary bytes into the wire, compact and
+// CBOR decoders, arbitrary text into the text decoders, and valid encodings with one byte
+// overwritten or cut short. No decoder may panic, anything that decodes must survive
+// verification and re-encode to the same signature, and nothing corrupted may still verify.
+
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::key_image::KeyImage;
+use lingo::pem::SpkiCurve;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::wire::{DecodedSignature, WireCurve};
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+const MESSAGE: &[u8] = b"lingo decoders";
+
+type Decoder<C> = fn(&[u8]) -> Result<DecodedSignature<C>, lingo::error::DecodeError>;
+type Encoder<C> = fn(&DecodedSignature<C>) -> Vec<u8>;
+
+fn codecs<C: WireCurve>() -> [(Decoder<C>, Encoder<C>); 3] {
+    [
+        (
+            DecodedSignature::<C>::from_bytes,
+            DecodedSignature::<C>::to_bytes,
+        ),
+        (
+            DecodedSignature::<C>::from_compact,
+            DecodedSignature::<C>::to_compact,
+        ),
+        (
+            DecodedSignature::<C>::from_cbor,
+            DecodedSignature::<C>::to_cbor,
+        ),
+    ]
+}
+
+fn signed<C: WireCurve>(seed: u64, linkable: bool) -> DecodedSignature<C> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+    let signature = if linkable {
+        ring.sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+            .unwrap()
+    } else {
+        ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng).unwrap()
+    };
+    DecodedSignature::from_bytes(&signature.to_bytes()).unwrap()
+}
+
+// decodes `bytes`, returning whether the result verifies
+fn decodes<C: WireCurve>(
+    decode: Decoder<C>,
+    encode: Encoder<C>,
+    bytes: &[u8],
+) -> Result<bool, TestCaseError> {
+    let Ok(signature) = decode(bytes) else {
+        return Ok(false);
+    };
+    let verifies = signature.verify(MESSAGE).is_ok();
+    let again = decode(&encode(&signature));
+    prop_assert!(again.as_ref() == Ok(&signature));
+    Ok(verifies)
+}
+
+fn garbage<C: WireCurve>(bytes: Vec<u8>) -> Result<(), TestCaseError> {
+    for (decode, encode) in codecs::<C>() {
+        prop_assert!(!decodes(decode, encode, &bytes)?);
+    }
+    Ok(())
+}
+
+fn text<C: WireCurve + SpkiCurve>(text: String) -> Result<(), TestCaseError> {
+    for signature in [
+        DecodedSignature::<C>::from_armor(&text),
+        DecodedSignature::<C>::from_hex(&text),
+        DecodedSignature::<C>::from_compact_base45(&text),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        prop_assert!(signature.verify(MESSAGE).is_err());
+    }
+    let _ = KeyImage::<C>::from_hex(&text);
+    let _ = Ring::<C>::from_hex_list(&text);
+    let _ = Ring::<C>::from_pem_bundle(&text);
+    Ok(())
+}
+
+fn corrupted<C: WireCurve>(
+    seed: u64,
+    linkable: bool,
+    at: Index,
+    byte: u8,
+) -> Result<(), TestCaseError> {
+    let signature = signed::<C>(seed, linkable);
+    for (decode, encode) in codecs::<C>() {
+        let bytes = encode(&signature);
+        let at = at.index(bytes.len());
+
+        let mut overwritten = bytes.clone();
+        overwritten[at] = byte;
+        let unchanged = overwritten == bytes;
+        prop_assert_eq!(decodes(decode, encode, &overwritten)?, unchanged);
+        prop_assert!(!decodes(decode, encode, &bytes[..at])?);
+    }
+    Ok(())
+}
+
+macro_rules! props {
+    ($name:ident, $curve:ty) => {
+        mod $name {
+            use super::*;
+
+            proptest! {
+                #![proptest_config(ProptestConfig::with_cases(32))]
+
+                #[test]
+                fn garbage(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+                    super::garbage::<$curve>(bytes)?;
+                }
+
+                #[test]
+                fn text(text in ".{0,256}") {
+                    super::text::<$curve>(text)?;
+                }
+
+                #[test]
+                fn corrupted(
+                    seed in any::<u64>(),
+                    linkable in any::<bool>(),
+                    at in any::<Index>(),
+                    byte in any::<u8>(),
+                ) {
+                    super::corrupted::<$curve>(seed, linkable, at, byte)?;
+                }
+            }
+        }
+    };
+}
+
+props!(secp256k1, ark_secp256k1::Projective);
+props!(ed25519, ark_ed25519::EdwardsProjective);
@@ -0,0 +1,115 @@
+// Designated-verifier signatures over several ring sizes, with the signer at every position: only
+// the designated verifier's key verifies them, and the verifier can simulate signatures that
+// verify just as well. A verifier key or ephemeral key that is the identity or of small order is
+// refused on both sides.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{One, Zero};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::designated;
+use lingo::error::{RingError, VerifyError};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo designated";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn verifies_for_the_designee_only<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let verifier = SecretKey::<C>::generate(&mut rng);
+    let bystander = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 3] {
+        for index in 0..size {
+            let ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+            let signature = designated::sign_with_rng(
+                &ring,
+                MESSAGE,
+                &secret,
+                index,
+                public_key(&verifier),
+                &mut rng,
+            )
+            .unwrap();
+            assert_eq!(signature.verifier(), &public_key(&verifier));
+            assert!(signature.verify(MESSAGE, &verifier).is_ok());
+            assert!(signature.verify(b"lingo designatec", &verifier).is_err());
+            assert_eq!(
+                signature.verify(MESSAGE, &bystander),
+                Err(VerifyError::WrongVerifier)
+            );
+
+            let simulated = designated::simulate_with_rng(&ring, MESSAGE, &verifier, &mut rng);
+            assert!(simulated.verify(MESSAGE, &verifier).is_ok());
+            assert!(simulated.verify(MESSAGE, &bystander).is_err());
+        }
+    }
+}
+
+fn refuses_malformed_points<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(2, &secret, 0, &mut rng).unwrap();
+    assert_eq!(
+        designated::sign_with_rng(&ring, MESSAGE, &secret, 0, C::Affine::zero(), &mut rng).err(),
+        Some(RingError::InvalidVerifier)
+    );
+
+    let verifier = SecretKey::<C>::generate(&mut rng);
+    let mut signature =
+        designated::sign_with_rng(&ring, MESSAGE, &secret, 0, public_key(&verifier), &mut rng)
+            .unwrap();
+    signature.ephemeral = C::Affine::zero();
+    assert_eq!(
+        signature.verify(MESSAGE, &verifier),
+        Err(VerifyError::InvalidPoint)
+    );
+}
+
+#[test]
+fn designated_secp256k1() {
+    verifies_for_the_designee_only::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn designated_ed25519() {
+    verifies_for_the_designee_only::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn malformed_secp256k1() {
+    refuses_malformed_points::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn malformed_ed25519() {
+    refuses_malformed_points::<ark_ed25519::EdwardsProjective>(4);
+}
+
+#[test]
+fn refuses_small_order_points() {
+    type C = ark_ed25519::EdwardsProjective;
+    let mut rng = StdRng::seed_from_u64(5);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let verifier = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(2, &secret, 1, &mut rng).unwrap();
+    // (0, -1) is on the curve and has order 2
+    let small =
+        ark_ed25519::EdwardsAffine::new_unchecked(ark_ed25519::Fq::zero(), -ark_ed25519::Fq::one());
+    assert_eq!(
+        designated::sign_with_rng(&ring, MESSAGE, &secret, 1, small, &mut rng).err(),
+        Some(RingError::InvalidVerifier)
+    );
+
+    let mut signature =
+        designated::sign_with_rng(&ring, MESSAGE, &secret, 1, public_key(&verifier), &mut rng)
+            .unwrap();
+    signature.ephemeral = small;
+    assert_eq!(
+        signature.verify(MESSAGE, &verifier),
+        Err(VerifyError::InvalidPoint)
+    );
+}
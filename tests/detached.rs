@@ -0,0 +1,139 @@
+// Signatures kept apart from their ring: a detached signature verifies against the ring it was
+// made over and no other, and re-attaches to it unchanged. An owned signature carries its ring
+// with it, so it can outlive the ring it was made over and be returned from where that was built.
+// Ring-less bytes name their ring by digest and decode only against that ring.
+
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::DecodeError;
+use lingo::ring::{DetachedSignature, OwnedRingSignature, Ring};
+use lingo::secret::SecretKey;
+use lingo::wire::{self, DecodedSignature, WireCurve};
+
+const MESSAGE: &[u8] = b"lingo detached";
+
+type ScalarBigInt<C> = <<C as PrimeGroup>::ScalarField as PrimeField>::BigInt;
+
+fn detaches<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 0, &mut rng).unwrap();
+    let shifted = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+
+    for linkable in [false, true] {
+        let signature = if linkable {
+            ring.sign_linkable_with_rng(MESSAGE, &secret, 0, &mut rng)
+                .unwrap()
+        } else {
+            ring.sign_with_rng(MESSAGE, &secret, 0, &mut rng).unwrap()
+        };
+        let detached = signature.detach();
+        assert_eq!(detached.key_image(), signature.key_image());
+        assert!(detached.verify_with_ring(&ring, MESSAGE).is_ok());
+        assert!(detached.verify_with_ring(&ring, b"lingo detachee").is_err());
+        assert!(detached.verify_with_ring(&shifted, MESSAGE).is_err());
+
+        let attached = detached.clone().attach(&ring);
+        assert!(attached == signature);
+        assert!(attached.verify(MESSAGE).is_ok());
+        assert!(detached.attach(&shifted).verify(MESSAGE).is_err());
+    }
+}
+
+// the ring is dropped on return
+fn sign_owned<C: CurveGroup>(
+    secret: &SecretKey<C>,
+    rng: &mut StdRng,
+) -> OwnedRingSignature<ScalarBigInt<C>, C> {
+    let ring = Ring::<C>::new_with_rng(4, secret, 2, rng).unwrap();
+    ring.sign_linkable_with_rng(MESSAGE, secret, 2, rng)
+        .unwrap()
+        .into_owned()
+}
+
+fn owns_its_ring<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let owned = sign_owned(&secret, &mut rng);
+    assert!(owned.verify(MESSAGE).is_ok());
+    assert!(owned.verify(b"lingo detachee").is_err());
+    assert!(owned.signature().verify(MESSAGE).is_ok());
+    assert!(owned.links_with(&sign_owned(&secret, &mut rng)));
+
+    let (ring, detached) = owned.clone().detach();
+    assert_eq!(ring.size(), 4);
+    let back = detached.attach_owned(ring);
+    assert!(back == owned);
+}
+
+#[test]
+fn owned_secp256k1() {
+    owns_its_ring::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn owned_ed25519() {
+    owns_its_ring::<ark_ed25519::EdwardsProjective>(4);
+}
+
+fn ringless<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(5, &secret, 1, &mut rng).unwrap();
+    let other = Ring::<C>::new_with_rng(5, &secret, 1, &mut rng).unwrap();
+    assert_ne!(ring.digest(), other.digest());
+
+    for signature in [
+        ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng).unwrap(),
+        ring.sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+            .unwrap(),
+    ] {
+        let bytes = signature.to_ringless_bytes();
+        let with_ring = signature.to_bytes();
+        assert_eq!(
+            with_ring.len() - bytes.len(),
+            ring.size() * C::Affine::default().compressed_size() + 4 - 32
+        );
+        assert_eq!(
+            wire::ringless_ring_digest::<C>(&bytes).unwrap(),
+            ring.digest()
+        );
+
+        let detached = DetachedSignature::from_ringless_bytes(&bytes, &ring).unwrap();
+        assert!(detached.verify_with_ring(&ring, MESSAGE).is_ok());
+        assert!(detached.attach(&ring) == signature);
+        assert_eq!(
+            DetachedSignature::from_ringless_bytes(&bytes, &other).err(),
+            Some(DecodeError::RingMismatch)
+        );
+        assert!(
+            DetachedSignature::<_, C>::from_ringless_bytes(&bytes[..bytes.len() - 1], &ring)
+                .is_err()
+        );
+        // the two layouts are told apart by their magic
+        assert!(DecodedSignature::<C>::from_bytes(&bytes).is_err());
+        assert!(DetachedSignature::<_, C>::from_ringless_bytes(&with_ring, &ring).is_err());
+    }
+}
+
+#[test]
+fn ringless_secp256k1() {
+    ringless::<ark_secp256k1::Projective>(5);
+}
+
+#[test]
+fn ringless_ed25519() {
+    ringless::<ark_ed25519::EdwardsProjective>(6);
+}
+
+#[test]
+fn detached_secp256k1() {
+    detaches::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn detached_ed25519() {
+    detaches::<ark_ed25519::EdwardsProjective>(2);
+}
@@ -0,0 +1,761 @@
+// Rings, key images and signatures through arkworks' canonical serialization, compressed and
+// not, with sizes known before signing, through serde (as JSON and bincode, on every curve),
+// borsh and protobuf, as hex and URL-safe base64, in ASCII armor, in the JSON envelope, as
+// COSE-style CBOR and in the compact QR format: everything must read back equal to what was
+// written, signatures must verify once reattached to the ring, and a signature must not attach
+// to a ring of another size. Malformed text must be refused, armor whose checksum does not
+// match its body among it, the envelope and CBOR parsed strictly, and compact bytes accepted in
+// their one minimal form only.
+
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::DecodeError;
+use lingo::hex;
+use lingo::key_image::{KeyImage, key_image};
+use lingo::keypair::KeyPair;
+use lingo::ring::{Ring, RingSignature};
+use lingo::secret::SecretKey;
+use lingo::wire::{DecodedSignature, WireCurve};
+
+const MESSAGE: &[u8] = b"lingo encoding";
+
+fn round_trips_canonically<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 2, &mut rng).unwrap();
+    let smaller = Ring::<C>::new_with_rng(3, &secret, 0, &mut rng).unwrap();
+    for compress in [Compress::Yes, Compress::No] {
+        let mut bytes = Vec::new();
+        ring.serialize_with_mode(&mut bytes, compress).unwrap();
+        assert_eq!(bytes.len(), ring.serialized_size(compress));
+        let read = Ring::<C>::deserialize_with_mode(&bytes[..], compress, Validate::Yes).unwrap();
+        assert!(read == ring);
+
+        for signature in [
+            ring.sign_with_rng(MESSAGE, &secret, 2, &mut rng).unwrap(),
+            ring.sign_linkable_with_rng(MESSAGE, &secret, 2, &mut rng)
+                .unwrap(),
+        ] {
+            let mut bytes = Vec::new();
+            signature.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(bytes.len(), signature.serialized_size(compress));
+            assert_eq!(
+                bytes.len(),
+                ring.signature_size(signature.image.is_some(), compress)
+            );
+            let attached =
+                RingSignature::deserialize_with_ring(&read, &bytes[..], compress, Validate::Yes)
+                    .unwrap();
+            assert!(attached.verify(MESSAGE).is_ok());
+            assert!(
+                attached
+                    == RingSignature {
+                        ring: &read,
+                        ..signature
+                    }
+            );
+            assert!(
+                RingSignature::deserialize_with_ring(&smaller, &bytes[..], compress, Validate::Yes)
+                    .is_err()
+            );
+        }
+
+        let image = key_image(&secret, &ring.keys()[2]).unwrap();
+        let mut bytes = Vec::new();
+        image.serialize_with_mode(&mut bytes, compress).unwrap();
+        assert_eq!(
+            KeyImage::<C>::deserialize_with_mode(&bytes[..], compress, Validate::Yes).unwrap(),
+            image
+        );
+    }
+
+    // an empty ring, and one holding the identity
+    let mut bytes = Vec::new();
+    Vec::<C::Affine>::new()
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+    assert!(Ring::<C>::deserialize_compressed(&bytes[..]).is_err());
+    let mut bytes = Vec::new();
+    vec![C::Affine::default()]
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+    assert!(Ring::<C>::deserialize_compressed(&bytes[..]).is_err());
+}
+
+#[test]
+fn canonical_secp256k1() {
+    round_trips_canonically::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn canonical_ed25519() {
+    round_trips_canonically::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[cfg(feature = "p256")]
+#[test]
+fn canonical_p256() {
+    round_trips_canonically::<lingo::curves::p256::Projective>(3);
+}
+
+fn sizes_before_signing<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 2, 7] {
+        let ring = Ring::<C>::new_with_rng(size, &secret, size - 1, &mut rng).unwrap();
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = Vec::new();
+            ring.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(ring.serialized_size(compress), bytes.len());
+            for linkable in [false, true] {
+                let expected = ring.signature_size(linkable, compress);
+                let signature = if linkable {
+                    ring.sign_linkable_with_rng(MESSAGE, &secret, size - 1, &mut rng)
+                        .unwrap()
+                } else {
+                    ring.sign_with_rng(MESSAGE, &secret, size - 1, &mut rng)
+                        .unwrap()
+                };
+                let mut bytes = Vec::new();
+                signature.serialize_with_mode(&mut bytes, compress).unwrap();
+                assert_eq!(bytes.len(), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn sizes_secp256k1() {
+    sizes_before_signing::<ark_secp256k1::Projective>(34);
+    // the challenge, a length-prefixed response per member, then a presence byte and the image
+    let mut rng = StdRng::seed_from_u64(35);
+    let secret = SecretKey::generate(&mut rng);
+    let ring = Ring::<ark_secp256k1::Projective>::new_with_rng(3, &secret, 0, &mut rng).unwrap();
+    assert_eq!(
+        ring.signature_size(false, Compress::Yes),
+        32 + 8 + 3 * 32 + 1
+    );
+    assert_eq!(
+        ring.signature_size(true, Compress::Yes),
+        32 + 8 + 3 * 32 + 1 + 33
+    );
+}
+
+#[test]
+fn sizes_ed25519() {
+    sizes_before_signing::<ark_ed25519::EdwardsProjective>(36);
+}
+
+#[cfg(feature = "serde")]
+fn round_trips_through_serde<C: CurveGroup>(seed: u64) {
+    use lingo::ring::RingSignatureSeed;
+    use serde::de::DeserializeSeed;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+    let smaller = Ring::<C>::new_with_rng(2, &secret, 1, &mut rng).unwrap();
+    let json = serde_json::to_string(&ring).unwrap();
+    assert!(serde_json::from_str::<Ring<C>>(&json).unwrap() == ring);
+
+    for signature in [
+        ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng).unwrap(),
+        ring.sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+            .unwrap(),
+    ] {
+        let json = serde_json::to_string(&signature).unwrap();
+        let read = RingSignatureSeed::new(&ring)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        assert!(read == signature);
+        assert!(read.verify(MESSAGE).is_ok());
+        assert!(
+            RingSignatureSeed::new(&smaller)
+                .deserialize(&mut serde_json::Deserializer::from_str(&json))
+                .is_err()
+        );
+    }
+
+    let image = key_image(&secret, &ring.keys()[1]).unwrap();
+    let json = serde_json::to_string(&image).unwrap();
+    assert_eq!(serde_json::from_str::<KeyImage<C>>(&json).unwrap(), image);
+    assert!(serde_json::from_str::<Ring<C>>("[]").is_err());
+    assert!(serde_json::from_str::<Ring<C>>("[\"+0\"]").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_secp256k1() {
+    round_trips_through_serde::<ark_secp256k1::Projective>(4);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_ed25519() {
+    round_trips_through_serde::<ark_ed25519::EdwardsProjective>(5);
+}
+
+#[cfg(feature = "serde")]
+fn round_trips_through_bincode<C: CurveGroup>(seed: u64) {
+    use bincode::Options;
+    use lingo::ring::{DetachedSignature, OwnedRingSignature, RingSignatureSeed};
+    use serde::de::DeserializeSeed;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(5, &secret, 3, &mut rng).unwrap();
+    let bytes = bincode::serialize(&ring).unwrap();
+    assert!(bincode::deserialize::<Ring<C>>(&bytes).unwrap() == ring);
+
+    for signature in [
+        ring.sign_with_rng(MESSAGE, &secret, 3, &mut rng).unwrap(),
+        ring.sign_linkable_with_rng(MESSAGE, &secret, 3, &mut rng)
+            .unwrap(),
+    ] {
+        let bytes = bincode::serialize(&signature).unwrap();
+        let read = RingSignatureSeed::new(&ring)
+            .deserialize(&mut bincode::Deserializer::from_slice(
+                &bytes,
+                bincode::DefaultOptions::new().with_fixint_encoding(),
+            ))
+            .unwrap();
+        assert!(read == signature);
+
+        let detached = signature.detach();
+        let bytes = bincode::serialize(&detached).unwrap();
+        assert_eq!(
+            bincode::deserialize::<DetachedSignature<_, C>>(&bytes).unwrap(),
+            detached
+        );
+
+        let owned = signature.into_owned();
+        let bytes = bincode::serialize(&owned).unwrap();
+        let read = bincode::deserialize::<OwnedRingSignature<_, C>>(&bytes).unwrap();
+        assert!(read == owned);
+        assert!(read.verify(MESSAGE).is_ok());
+        assert!(
+            bincode::deserialize::<OwnedRingSignature<_, C>>(&bytes[..bytes.len() - 1]).is_err()
+        );
+        if let Some(image) = owned.image {
+            let bytes = bincode::serialize(&image).unwrap();
+            assert_eq!(bincode::deserialize::<KeyImage<C>>(&bytes).unwrap(), image);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn bincode_every_curve() {
+    round_trips_through_bincode::<ark_secp256k1::Projective>(20);
+    round_trips_through_bincode::<ark_ed25519::EdwardsProjective>(21);
+    #[cfg(feature = "p256")]
+    round_trips_through_bincode::<lingo::curves::p256::Projective>(22);
+    #[cfg(feature = "bls12-381")]
+    round_trips_through_bincode::<lingo::curves::bls12_381::G1Projective>(23);
+    #[cfg(feature = "bn254")]
+    round_trips_through_bincode::<lingo::curves::bn254::G1Projective>(24);
+    #[cfg(feature = "pallas")]
+    round_trips_through_bincode::<lingo::curves::pallas::Projective>(25);
+    #[cfg(feature = "vesta")]
+    round_trips_through_bincode::<lingo::curves::vesta::Projective>(26);
+    #[cfg(feature = "jubjub")]
+    round_trips_through_bincode::<lingo::curves::jubjub::EdwardsProjective>(27);
+}
+
+fn round_trips_as_hex<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pair = KeyPair::<C>::generate(&mut rng);
+    let text = hex::public_key_to_hex::<C>(pair.public_key());
+    assert_eq!(text.len(), 2 * C::Affine::default().compressed_size());
+    assert_eq!(
+        hex::public_key_from_hex::<C>(&text).unwrap(),
+        *pair.public_key()
+    );
+    assert_eq!(
+        hex::public_key_from_hex::<C>(&text.to_uppercase()).unwrap(),
+        *pair.public_key()
+    );
+    assert!(hex::public_key_from_hex::<C>("zz").is_err());
+    assert!(hex::public_key_from_hex::<C>(&text[2..]).is_err());
+
+    let image = pair.key_image();
+    assert_eq!(KeyImage::<C>::from_hex(&image.to_hex()).unwrap(), image);
+
+    let ring = pair.ring_with_rng(3, 1, &mut rng).unwrap();
+    let signature = ring
+        .sign_linkable_with_rng(MESSAGE, pair.private_key(), 1, &mut rng)
+        .unwrap();
+    let text = signature.to_hex();
+    let decoded = DecodedSignature::<C>::from_hex(&text).unwrap();
+    assert!(decoded.verify(MESSAGE).is_ok());
+    assert_eq!(decoded.to_hex(), text);
+    assert_eq!(
+        DecodedSignature::<C>::from_hex(&text[1..]).err(),
+        Some(DecodeError::InvalidHex)
+    );
+    assert_eq!(
+        DecodedSignature::<C>::from_hex("0g").err(),
+        Some(DecodeError::InvalidHex)
+    );
+}
+
+#[test]
+fn hex_secp256k1() {
+    round_trips_as_hex::<ark_secp256k1::Projective>(6);
+}
+
+#[test]
+fn hex_ed25519() {
+    round_trips_as_hex::<ark_ed25519::EdwardsProjective>(7);
+}
+
+fn round_trips_as_base64<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pair = KeyPair::<C>::generate(&mut rng);
+    for size in 1..6 {
+        let ring = pair.ring_with_rng(size, 0, &mut rng).unwrap();
+        for signature in [
+            ring.sign_with_rng(MESSAGE, pair.private_key(), 0, &mut rng)
+                .unwrap(),
+            ring.sign_linkable_with_rng(MESSAGE, pair.private_key(), 0, &mut rng)
+                .unwrap(),
+        ] {
+            // unpadded, and safe in a URL
+            let text = signature.to_base64();
+            assert!(!text.contains(['=', '+', '/']));
+            let decoded = DecodedSignature::<C>::from_base64(&text).unwrap();
+            assert!(decoded.verify(MESSAGE).is_ok());
+            assert_eq!(decoded.to_base64(), text);
+            assert_eq!(
+                DecodedSignature::<C>::from_base64(&format!("{text}=")).err(),
+                Some(DecodeError::InvalidBase64)
+            );
+            assert!(DecodedSignature::<C>::from_base64(&text[1..]).is_err());
+        }
+    }
+}
+
+#[test]
+fn base64_secp256k1() {
+    round_trips_as_base64::<ark_secp256k1::Projective>(8);
+}
+
+#[test]
+fn base64_ed25519() {
+    round_trips_as_base64::<ark_ed25519::EdwardsProjective>(9);
+}
+
+fn round_trips_as_armor<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(6, &secret, 3, &mut rng).unwrap();
+    for (signature, scheme) in [
+        (
+            ring.sign_with_rng(MESSAGE, &secret, 3, &mut rng).unwrap(),
+            "sag",
+        ),
+        (
+            ring.sign_linkable_with_rng(MESSAGE, &secret, 3, &mut rng)
+                .unwrap(),
+            "blsag",
+        ),
+    ] {
+        let armor = signature.to_armor();
+        let lines: Vec<&str> = armor.lines().collect();
+        assert_eq!(lines[0], "-----BEGIN LINGO RING SIGNATURE-----");
+        assert_eq!(lines[1], format!("Curve: {}", C::CURVE_NAME));
+        assert_eq!(lines[2], format!("Scheme: {scheme}"));
+        assert_eq!(lines[lines.len() - 1], "-----END LINGO RING SIGNATURE-----");
+        assert!(
+            lines
+                .iter()
+                .all(|line| line.len() <= 64 || line.starts_with("-----"))
+        );
+        let read = DecodedSignature::<C>::from_armor(&armor).unwrap();
+        assert!(read.signature() == signature);
+        assert_eq!(read.to_armor(), armor);
+
+        // quoted in an email, with CRLF line ends
+        let quoted = format!("Hi,\r\n\r\n{}\r\nthanks\r\n", armor.replace('\n', "\r\n"));
+        assert!(
+            DecodedSignature::<C>::from_armor(&quoted)
+                .unwrap()
+                .signature()
+                == signature
+        );
+
+        let mut corrupted = lines.clone();
+        let body = if lines[4].starts_with('A') {
+            lines[4].replacen('A', "B", 1)
+        } else {
+            format!("A{}", &lines[4][1..])
+        };
+        corrupted[4] = &body;
+        assert_eq!(
+            DecodedSignature::<C>::from_armor(&corrupted.join("\n")).err(),
+            Some(DecodeError::BadChecksum)
+        );
+        let unchecked: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|line| !line.starts_with('='))
+            .collect();
+        assert_eq!(
+            DecodedSignature::<C>::from_armor(&unchecked.join("\n")).err(),
+            Some(DecodeError::InvalidArmor)
+        );
+        assert_eq!(
+            DecodedSignature::<C>::from_armor(&lines[..lines.len() - 1].join("\n")).err(),
+            Some(DecodeError::InvalidArmor)
+        );
+        let renamed = armor.replacen(C::CURVE_NAME, "another", 1);
+        assert_eq!(
+            DecodedSignature::<C>::from_armor(&renamed).err(),
+            Some(DecodeError::WrongCurveName("another".to_string()))
+        );
+    }
+}
+
+#[test]
+fn armor_secp256k1() {
+    round_trips_as_armor::<ark_secp256k1::Projective>(30);
+}
+
+#[test]
+fn armor_ed25519() {
+    round_trips_as_armor::<ark_ed25519::EdwardsProjective>(31);
+}
+
+fn round_trips_compactly<C: WireCurve>(seed: u64) {
+    use lingo::ring::DetachedSignature;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 3, 200] {
+        let ring = Ring::<C>::new_with_rng(size, &secret, 0, &mut rng).unwrap();
+        for (signature, linkable) in [
+            (
+                ring.sign_with_rng(MESSAGE, &secret, 0, &mut rng).unwrap(),
+                0,
+            ),
+            (
+                ring.sign_linkable_with_rng(MESSAGE, &secret, 0, &mut rng)
+                    .unwrap(),
+                1,
+            ),
+        ] {
+            let compact = signature.to_compact();
+            assert!(compact.len() < signature.to_bytes().len());
+            assert_eq!(compact[..2], [0x10 | linkable, C::CURVE_ID]);
+            let read = DecodedSignature::<C>::from_compact(&compact).unwrap();
+            assert!(read.signature() == signature);
+            assert_eq!(read.to_compact(), compact);
+
+            let text = signature.to_compact_base45();
+            assert!(
+                text.bytes()
+                    .all(|c| b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:".contains(&c))
+            );
+            let read = DecodedSignature::<C>::from_compact_base45(&text).unwrap();
+            assert!(read.signature() == signature);
+            assert_eq!(
+                DecodedSignature::<C>::from_compact_base45("a").err(),
+                Some(DecodeError::InvalidBase45)
+            );
+
+            let ringless = signature.to_compact_ringless();
+            assert_eq!(ringless[0], 0x12 | linkable);
+            let detached = DetachedSignature::from_compact_ringless(&ringless, &ring).unwrap();
+            assert!(detached.attach(&ring) == signature);
+            assert_eq!(
+                DecodedSignature::<C>::from_compact(&ringless).err(),
+                Some(DecodeError::RingMismatch)
+            );
+            assert_eq!(
+                DetachedSignature::from_compact_ringless(&compact, &ring).err(),
+                Some(DecodeError::RingMismatch)
+            );
+
+            assert!(DecodedSignature::<C>::from_compact(&compact[..compact.len() - 1]).is_err());
+            let mut extended = compact.clone();
+            extended.push(0);
+            assert_eq!(
+                DecodedSignature::<C>::from_compact(&extended).err(),
+                Some(DecodeError::TrailingBytes)
+            );
+            let mut versioned = compact.clone();
+            versioned[0] = 0x20 | linkable;
+            assert_eq!(
+                DecodedSignature::<C>::from_compact(&versioned).err(),
+                Some(DecodeError::UnsupportedVersion(2))
+            );
+            let mut flagged = compact.clone();
+            flagged[0] |= 0x08;
+            assert_eq!(
+                DecodedSignature::<C>::from_compact(&flagged).err(),
+                Some(DecodeError::InvalidEncoding)
+            );
+            let mut elsewhere = compact.clone();
+            elsewhere[1] ^= 0xff;
+            assert_eq!(
+                DecodedSignature::<C>::from_compact(&elsewhere).err(),
+                Some(DecodeError::WrongCurve(elsewhere[1]))
+            );
+        }
+    }
+
+    // a ring size with a redundant continuation byte is not minimal
+    let ring = Ring::<C>::new_with_rng(1, &secret, 0, &mut rng).unwrap();
+    let compact = ring
+        .sign_with_rng(MESSAGE, &secret, 0, &mut rng)
+        .unwrap()
+        .to_compact();
+    let padded = [&compact[..2], &[0x81, 0x00], &compact[3..]].concat();
+    assert_eq!(
+        DecodedSignature::<C>::from_compact(&padded).err(),
+        Some(DecodeError::InvalidEncoding)
+    );
+}
+
+#[test]
+fn compact_secp256k1() {
+    round_trips_compactly::<ark_secp256k1::Projective>(32);
+}
+
+#[test]
+fn compact_ed25519() {
+    round_trips_compactly::<ark_ed25519::EdwardsProjective>(33);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_envelope() {
+    type C = ark_secp256k1::Projective;
+    let mut rng = StdRng::seed_from_u64(10);
+    let pair = KeyPair::<C>::generate(&mut rng);
+    let ring = pair.ring_with_rng(3, 2, &mut rng).unwrap();
+    for (scheme, image, signature) in [
+        (
+            "sag",
+            false,
+            ring.sign_with_rng(MESSAGE, pair.private_key(), 2, &mut rng)
+                .unwrap(),
+        ),
+        (
+            "blsag",
+            true,
+            ring.sign_linkable_with_rng(MESSAGE, pair.private_key(), 2, &mut rng)
+                .unwrap(),
+        ),
+    ] {
+        let json = signature.to_json();
+        assert!(json.starts_with(&format!(
+            "{{\"version\":1,\"curve\":\"secp256k1\",\"scheme\":\"{scheme}\",\"ring\":[\"{}\",",
+            hex::public_key_to_hex::<C>(&ring.keys()[0])
+        )));
+        assert_eq!(json.ends_with("\"key_image\":null}"), !image);
+        let decoded = DecodedSignature::<C>::from_json(&json).unwrap();
+        assert!(decoded.verify(MESSAGE).is_ok());
+        assert_eq!(decoded.to_json(), json);
+
+        // unknown and repeated fields, a foreign curve and uppercase hex
+        for strict in [
+            json.replacen('{', "{\"extra\":1,", 1),
+            json.replacen('{', "{\"version\":1,", 1),
+        ] {
+            assert_eq!(
+                DecodedSignature::<C>::from_json(&strict).err(),
+                Some(DecodeError::InvalidJson)
+            );
+        }
+        assert_eq!(
+            DecodedSignature::<ark_ed25519::EdwardsProjective>::from_json(&json).err(),
+            Some(DecodeError::WrongCurveName("secp256k1".into()))
+        );
+        let upper = json.replace("\"challenge\":\"", "\"challenge\":\"A");
+        assert!(DecodedSignature::<C>::from_json(&upper).is_err());
+        assert!(DecodedSignature::<C>::from_json(&json[1..]).is_err());
+    }
+}
+
+fn round_trips_as_cbor<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pair = KeyPair::<C>::generate(&mut rng);
+    // 30 members take a two-byte array length
+    for size in [1, 3, 30] {
+        let ring = pair.ring_with_rng(size, 0, &mut rng).unwrap();
+        for signature in [
+            ring.sign_with_rng(MESSAGE, pair.private_key(), 0, &mut rng)
+                .unwrap(),
+            ring.sign_linkable_with_rng(MESSAGE, pair.private_key(), 0, &mut rng)
+                .unwrap(),
+        ] {
+            let bytes = signature.to_cbor();
+            // tag 18, then an array of four
+            assert_eq!(bytes[..2], [0xd2, 0x84]);
+            let decoded = DecodedSignature::<C>::from_cbor(&bytes).unwrap();
+            assert!(decoded.verify(MESSAGE).is_ok());
+            assert_eq!(decoded.to_cbor(), bytes);
+            // every prefix of a large ring would take minutes to try
+            if size < 30 {
+                for cut in 0..bytes.len() {
+                    assert!(DecodedSignature::<C>::from_cbor(&bytes[..cut]).is_err());
+                }
+            }
+            assert!(DecodedSignature::<C>::from_cbor(&bytes[..bytes.len() - 1]).is_err());
+            let mut trailing = bytes.clone();
+            trailing.push(0);
+            assert!(DecodedSignature::<C>::from_cbor(&trailing).is_err());
+        }
+    }
+}
+
+#[test]
+fn cbor_secp256k1() {
+    round_trips_as_cbor::<ark_secp256k1::Projective>(11);
+}
+
+#[test]
+fn cbor_ed25519() {
+    round_trips_as_cbor::<ark_ed25519::EdwardsProjective>(12);
+}
+
+#[test]
+fn cbor_rejects_other_curves() {
+    let mut rng = StdRng::seed_from_u64(13);
+    let pair = KeyPair::<ark_secp256k1::Projective>::generate(&mut rng);
+    let ring = pair.ring_with_rng(2, 1, &mut rng).unwrap();
+    let bytes = ring
+        .sign_with_rng(MESSAGE, pair.private_key(), 1, &mut rng)
+        .unwrap()
+        .to_cbor();
+    assert!(DecodedSignature::<ark_ed25519::EdwardsProjective>::from_cbor(&bytes).is_err());
+}
+
+#[cfg(feature = "borsh")]
+fn round_trips_through_borsh<C: WireCurve>(seed: u64) {
+    use borsh::BorshDeserialize;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pair = KeyPair::<C>::generate(&mut rng);
+    let ring = pair.ring_with_rng(4, 3, &mut rng).unwrap();
+    let bytes = borsh::to_vec(&ring).unwrap();
+    // a u32 length, then fixed-width keys
+    assert_eq!(bytes.len(), 4 + 4 * C::Affine::default().compressed_size());
+    assert!(Ring::<C>::try_from_slice(&bytes).unwrap() == ring);
+
+    let image = pair.key_image();
+    assert_eq!(
+        KeyImage::<C>::try_from_slice(&borsh::to_vec(&image).unwrap()).unwrap(),
+        image
+    );
+
+    for signature in [
+        ring.sign_with_rng(MESSAGE, pair.private_key(), 3, &mut rng)
+            .unwrap(),
+        ring.sign_linkable_with_rng(MESSAGE, pair.private_key(), 3, &mut rng)
+            .unwrap(),
+    ] {
+        let bytes = borsh::to_vec(&signature).unwrap();
+        let read = RingSignature::deserialize_borsh_with_ring(&ring, &mut &bytes[..]).unwrap();
+        assert!(read == signature);
+
+        let decoded = DecodedSignature::<C>::from_bytes(&signature.to_bytes()).unwrap();
+        let bytes = borsh::to_vec(&decoded).unwrap();
+        let read = DecodedSignature::<C>::try_from_slice(&bytes).unwrap();
+        assert!(read.verify(MESSAGE).is_ok());
+        assert!(DecodedSignature::<C>::try_from_slice(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn borsh_secp256k1() {
+    round_trips_through_borsh::<ark_secp256k1::Projective>(14);
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn borsh_ed25519() {
+    round_trips_through_borsh::<ark_ed25519::EdwardsProjective>(15);
+}
+
+#[cfg(feature = "proto")]
+fn round_trips_through_protobuf<C: WireCurve>(seed: u64) {
+    use lingo::proto::v1;
+    use prost::Message;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pair = KeyPair::<C>::generate(&mut rng);
+    let ring = pair.ring_with_rng(4, 1, &mut rng).unwrap();
+    assert!(Ring::<C>::try_from(&v1::Ring::from(&ring)).unwrap() == ring);
+    let image = pair.key_image();
+    assert_eq!(
+        KeyImage::<C>::try_from(&v1::KeyImage::from(&image)).unwrap(),
+        image
+    );
+
+    for (scheme, signature) in [
+        (
+            v1::Scheme::Sag,
+            ring.sign_with_rng(MESSAGE, pair.private_key(), 1, &mut rng)
+                .unwrap(),
+        ),
+        (
+            v1::Scheme::Blsag,
+            ring.sign_linkable_with_rng(MESSAGE, pair.private_key(), 1, &mut rng)
+                .unwrap(),
+        ),
+    ] {
+        let message = v1::RingSignature::from(&signature);
+        assert_eq!(message.curve, C::CURVE_NAME);
+        assert_eq!(message.scheme(), scheme);
+        let read = v1::RingSignature::decode(&message.encode_to_vec()[..]).unwrap();
+        let decoded = DecodedSignature::<C>::try_from(&read).unwrap();
+        assert!(decoded.verify(MESSAGE).is_ok());
+        assert_eq!(v1::RingSignature::from(&decoded), message);
+
+        let mut short = message.clone();
+        short.responses.pop();
+        assert!(DecodedSignature::<C>::try_from(&short).is_err());
+        let mut unknown = message.clone();
+        unknown.scheme = 7;
+        assert!(DecodedSignature::<C>::try_from(&unknown).is_err());
+        let mut imageless = message.clone();
+        imageless.key_image = None;
+        assert_eq!(
+            DecodedSignature::<C>::try_from(&imageless).is_err(),
+            scheme == v1::Scheme::Blsag
+        );
+    }
+}
+
+#[cfg(feature = "proto")]
+#[test]
+fn proto_secp256k1() {
+    round_trips_through_protobuf::<ark_secp256k1::Projective>(16);
+}
+
+#[cfg(feature = "proto")]
+#[test]
+fn proto_ed25519() {
+    round_trips_through_protobuf::<ark_ed25519::EdwardsProjective>(17);
+}
+
+#[cfg(feature = "proto")]
+#[test]
+fn proto_rejects_other_curves() {
+    let mut rng = StdRng::seed_from_u64(18);
+    let pair = KeyPair::<ark_secp256k1::Projective>::generate(&mut rng);
+    let ring = pair.ring_with_rng(2, 0, &mut rng).unwrap();
+    let signature = ring
+        .sign_with_rng(MESSAGE, pair.private_key(), 0, &mut rng)
+        .unwrap();
+    assert!(
+        DecodedSignature::<ark_ed25519::EdwardsProjective>::try_from(
+            &lingo::proto::v1::RingSignature::from(&signature)
+        )
+        .is_err()
+    );
+}
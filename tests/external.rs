@@ -0,0 +1,126 @@
+// Signing through `RingSigner`, with the key held by a stand-in device: the signatures must
+// verify like locally made ones and carry the same key image. A device that is not the member at
+// the signer's index, an index outside the ring, and a device that answers wrongly or fails must be
+// reported, not turned into a signature.
+
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::PrimeField;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::SignerError;
+use lingo::external::{RingSigner, SoftwareSigner};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+type Scalar<C> = <C as PrimeGroup>::ScalarField;
+type ScalarBigInt<C> = <Scalar<C> as PrimeField>::BigInt;
+
+const MESSAGE: &[u8] = b"lingo external";
+
+// answers one off from the right response
+struct Faulty<C: CurveGroup>(SoftwareSigner<C, StdRng>);
+
+impl<C: CurveGroup> RingSigner<C> for Faulty<C> {
+    type Error = &'static str;
+
+    fn public_key(&self) -> Result<C::Affine, &'static str> {
+        Ok(self.0.public_key().unwrap())
+    }
+
+    fn multiply(&mut self, base: &C::Affine) -> Result<C::Affine, &'static str> {
+        Ok(self.0.multiply(base).unwrap())
+    }
+
+    fn commit(&mut self, bases: &[C::Affine]) -> Result<Vec<C::Affine>, &'static str> {
+        Ok(self.0.commit(bases).unwrap())
+    }
+
+    fn respond(&mut self, challenge: ScalarBigInt<C>) -> Result<ScalarBigInt<C>, &'static str> {
+        let response = Scalar::<C>::from_bigint(self.0.respond(challenge).unwrap()).unwrap();
+        Ok((response + Scalar::<C>::from(1u64)).into_bigint())
+    }
+}
+
+// unplugged halfway through a session
+struct Unplugged<C: CurveGroup>(SoftwareSigner<C, StdRng>);
+
+impl<C: CurveGroup> RingSigner<C> for Unplugged<C> {
+    type Error = &'static str;
+
+    fn public_key(&self) -> Result<C::Affine, &'static str> {
+        Ok(self.0.public_key().unwrap())
+    }
+
+    fn multiply(&mut self, base: &C::Affine) -> Result<C::Affine, &'static str> {
+        Ok(self.0.multiply(base).unwrap())
+    }
+
+    fn commit(&mut self, _: &[C::Affine]) -> Result<Vec<C::Affine>, &'static str> {
+        Err("unplugged")
+    }
+
+    fn respond(&mut self, _: ScalarBigInt<C>) -> Result<ScalarBigInt<C>, &'static str> {
+        Err("unplugged")
+    }
+}
+
+fn signs_externally<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(5, &secret, 2, &mut rng).unwrap();
+    let mut signer = SoftwareSigner::with_rng(&secret, StdRng::seed_from_u64(seed + 100));
+
+    let signature = ring
+        .sign_external_with_rng(MESSAGE, &mut signer, 2, &mut rng)
+        .unwrap();
+    assert!(signature.verify(MESSAGE).is_ok());
+    assert!(signature.verify(b"lingo externam").is_err());
+
+    let linkable = ring
+        .sign_linkable_external_with_rng(MESSAGE, &mut signer, 2, &mut rng)
+        .unwrap();
+    assert!(linkable.verify(MESSAGE).is_ok());
+    let local = ring
+        .sign_linkable_with_rng(b"local", &secret, 2, &mut rng)
+        .unwrap();
+    assert_eq!(linkable.image, local.image);
+}
+
+fn reports_bad_devices<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 0, &mut rng).unwrap();
+    let device = || SoftwareSigner::with_rng(&secret, StdRng::seed_from_u64(seed + 100));
+
+    assert!(matches!(
+        ring.sign_external_with_rng(MESSAGE, &mut device(), 1, &mut rng),
+        Err(SignerError::NotRingMember)
+    ));
+    assert!(matches!(
+        ring.sign_linkable_external_with_rng(MESSAGE, &mut device(), 3, &mut rng),
+        Err(SignerError::IndexOutOfBounds { index: 3, size: 3 })
+    ));
+    assert!(matches!(
+        ring.sign_external_with_rng(MESSAGE, &mut Faulty(device()), 0, &mut rng),
+        Err(SignerError::InvalidResponse)
+    ));
+    assert!(matches!(
+        ring.sign_linkable_external_with_rng(MESSAGE, &mut Faulty(device()), 0, &mut rng),
+        Err(SignerError::InvalidResponse)
+    ));
+    assert!(matches!(
+        ring.sign_external_with_rng(MESSAGE, &mut Unplugged(device()), 0, &mut rng),
+        Err(SignerError::Device("unplugged"))
+    ));
+}
+
+#[test]
+fn external_secp256k1() {
+    signs_externally::<ark_secp256k1::Projective>(1);
+    reports_bad_devices::<ark_secp256k1::Projective>(2);
+}
+
+#[test]
+fn external_ed25519() {
+    signs_externally::<ark_ed25519::EdwardsProjective>(3);
+    reports_bad_devices::<ark_ed25519::EdwardsProjective>(4);
+}
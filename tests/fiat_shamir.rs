@@ -0,0 +1,91 @@
+// Strong Fiat–Shamir: every challenge in a SAG or bLSAG chain absorbs the whole ring, the
+// message, the key image and the scheme. A signature must verify only against the ring it was
+// made over, in the same order, the message it signed and the key image it carries; moving it to
+// a reordered ring, a ring with one member replaced, another message, or another key image, or
+// stripping or adding an image, must break it.
+
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::key_image::KeyImage;
+use lingo::ring::{Ring, RingSignature};
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo fiat-shamir";
+
+// the signature's challenge and responses, reattached to other statements
+fn verifies<C: CurveGroup>(
+    signature: &RingSignature<'_, <C::ScalarField as PrimeField>::BigInt, C>,
+    ring: &Ring<C>,
+    image: Option<KeyImage<C>>,
+    message: &[u8],
+) -> bool {
+    RingSignature {
+        ring,
+        challenge: signature.challenge,
+        ring_sig_vals: signature.ring_sig_vals.clone(),
+        image,
+        hash_id: signature.hash_id,
+    }
+    .verify(message)
+    .is_ok()
+}
+
+fn binds_everything<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 1, &mut rng).unwrap();
+    let theirs = Ring::<C>::new_with_rng(1, &other, 0, &mut rng).unwrap();
+    let foreign = theirs
+        .sign_linkable_with_rng(MESSAGE, &other, 0, &mut rng)
+        .unwrap()
+        .image;
+
+    let mut keys = ring.keys().to_vec();
+    keys.swap(0, 2);
+    let swapped = Ring::<C>::from_fixed_pubkeys(keys).unwrap();
+    let mut keys = ring.keys().to_vec();
+    keys[3] = theirs.keys()[0];
+    let replaced = Ring::<C>::from_fixed_pubkeys(keys).unwrap();
+
+    for linkable in [false, true] {
+        let signature = if linkable {
+            ring.sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+                .unwrap()
+        } else {
+            ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng).unwrap()
+        };
+        assert!(verifies(&signature, &ring, signature.image, MESSAGE));
+        assert!(!verifies(
+            &signature,
+            &ring,
+            signature.image,
+            b"lingo fiat-shamiR"
+        ));
+        assert!(!verifies(&signature, &ring, signature.image, b""));
+        assert!(!verifies(&signature, &swapped, signature.image, MESSAGE));
+        assert!(!verifies(&signature, &replaced, signature.image, MESSAGE));
+        assert!(!verifies(&signature, &ring, foreign, MESSAGE));
+
+        // the scheme tag keeps SAG and bLSAG chains apart
+        let image = if linkable {
+            None
+        } else {
+            ring.sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+                .unwrap()
+                .image
+        };
+        assert!(!verifies(&signature, &ring, image, MESSAGE));
+    }
+}
+
+#[test]
+fn binding_secp256k1() {
+    binds_everything::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn binding_ed25519() {
+    binds_everything::<ark_ed25519::EdwardsProjective>(2);
+}
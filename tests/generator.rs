@@ -0,0 +1,94 @@
+// Generator multiplication: the per-curve precomputed tables are built lazily on first use, from
+// whichever thread gets there first. On every curve, public keys derived from edge-case scalars
+// must match arkworks' own multiplication, and signatures, whose verification multiplies the
+// generator by every response, must verify, including when many threads sign at once.
+
+use ark_ec::CurveGroup;
+use ark_ff::{One, UniformRand};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::keypair::KeyPair;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use std::thread;
+
+const MESSAGE: &[u8] = b"lingo generator";
+
+fn matches_arkworks<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let one = C::ScalarField::one();
+    // one table window's worth
+    let byte = C::ScalarField::from(256u64);
+    let scalars = [
+        one,
+        one + one,
+        -one,
+        byte - one,
+        byte,
+        byte * byte,
+        -byte,
+        C::ScalarField::rand(&mut rng),
+    ];
+    for scalar in scalars {
+        let secret = SecretKey::<C>::new(scalar);
+        let key = *KeyPair::from_private_key(&secret).public_key();
+        assert_eq!(key, (C::generator() * scalar).into_affine());
+
+        let ring = Ring::<C>::new_with_rng(3, &secret, 2, &mut rng).unwrap();
+        assert!(ring.keys().contains(&key));
+        let signature = ring.sign_with_rng(MESSAGE, &secret, 2, &mut rng).unwrap();
+        assert!(signature.verify(MESSAGE).is_ok());
+    }
+}
+
+fn signs_concurrently<C: CurveGroup>(seed: u64) {
+    let handles: Vec<_> = (0..4)
+        .map(|t| {
+            thread::spawn(move || {
+                let mut rng = StdRng::seed_from_u64(seed + t);
+                let secret = SecretKey::<C>::generate(&mut rng);
+                let ring = Ring::<C>::new_with_rng(4, &secret, 0, &mut rng).unwrap();
+                let signature = ring
+                    .sign_linkable_with_rng(MESSAGE, &secret, 0, &mut rng)
+                    .unwrap();
+                signature.verify(MESSAGE).is_ok()
+            })
+        })
+        .collect();
+    assert!(handles.into_iter().all(|handle| handle.join().unwrap()));
+}
+
+#[test]
+fn generator_secp256k1() {
+    matches_arkworks::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn generator_ed25519() {
+    matches_arkworks::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn generator_other_curves() {
+    #[cfg(feature = "p256")]
+    matches_arkworks::<lingo::curves::p256::Projective>(3);
+    #[cfg(feature = "bls12-381")]
+    matches_arkworks::<lingo::curves::bls12_381::G1Projective>(4);
+    #[cfg(feature = "bn254")]
+    matches_arkworks::<lingo::curves::bn254::G1Projective>(5);
+    #[cfg(feature = "pallas")]
+    matches_arkworks::<lingo::curves::pallas::Projective>(6);
+    #[cfg(feature = "vesta")]
+    matches_arkworks::<lingo::curves::vesta::Projective>(7);
+    #[cfg(feature = "jubjub")]
+    matches_arkworks::<lingo::curves::jubjub::EdwardsProjective>(8);
+}
+
+#[test]
+fn concurrent_secp256k1() {
+    signs_concurrently::<ark_secp256k1::Projective>(9);
+}
+
+#[test]
+fn concurrent_ed25519() {
+    signs_concurrently::<ark_ed25519::EdwardsProjective>(13);
+}
@@ -0,0 +1,214 @@
+// RFC 9380 hash-to-curve: the suites the RFC defines must reproduce its test vectors, and on every
+// curve the key image base a signer's key hashes to must lie in the prime-order subgroup and not
+// depend on the message, so bLSAG signatures by one key link wherever they were made. Points
+// hashed under different domains must differ, each curve must name its suite and the DST it
+// builds from a domain, and key image bases must be the public mapping under their own domain.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::hash_to_curve::{dst, hash_to_point, suite_id};
+use lingo::key_image::{KEY_IMAGE_DOMAIN, hash_point};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo hash to curve";
+
+// the RFC's test vectors all hash under this domain
+const DOMAIN: &[u8] = b"QUUX-V01-CS02";
+
+fn field<F: PrimeField>(hex: &str) -> F {
+    let bytes: Vec<u8> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect();
+    F::from_be_bytes_mod_order(&bytes)
+}
+
+fn key_images_in_subgroup<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for _ in 0..4 {
+        let secret = SecretKey::<C>::generate(&mut rng);
+        let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+        let signature = ring
+            .sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+            .unwrap();
+        assert!(signature.verify(MESSAGE).is_ok());
+        let image = *signature.key_image().unwrap();
+        assert!(image.is_valid());
+        let again = ring
+            .sign_linkable_with_rng(b"elsewhere", &secret, 1, &mut rng)
+            .unwrap();
+        assert_eq!(*again.key_image().unwrap(), image);
+    }
+}
+
+#[test]
+fn rfc9380_secp256k1() {
+    let point = hash_to_point::<ark_secp256k1::Projective>(DOMAIN, b"");
+    assert_eq!(
+        point.x,
+        field("c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346")
+    );
+    assert_eq!(
+        point.y,
+        field("64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067")
+    );
+}
+
+#[test]
+fn rfc9380_ed25519() {
+    let point = hash_to_point::<ark_ed25519::EdwardsProjective>(DOMAIN, b"");
+    assert_eq!(
+        point.x,
+        field("3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6")
+    );
+    assert_eq!(
+        point.y,
+        field("09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21")
+    );
+}
+
+#[cfg(feature = "p256")]
+#[test]
+fn rfc9380_p256() {
+    let point = hash_to_point::<lingo::curves::p256::Projective>(DOMAIN, b"");
+    assert_eq!(
+        point.x,
+        field("2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4")
+    );
+    assert_eq!(
+        point.y,
+        field("8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415")
+    );
+}
+
+#[cfg(feature = "bls12-381")]
+#[test]
+fn rfc9380_bls12_381() {
+    type C = lingo::curves::bls12_381::G1Projective;
+    for (message, x, y) in [
+        (
+            &b""[..],
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        ),
+        (
+            &b"abc"[..],
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        ),
+        (
+            &b"abcdef0123456789"[..],
+            "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+            "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+        ),
+    ] {
+        let point = hash_to_point::<C>(DOMAIN, message);
+        assert_eq!((point.x, point.y), (field(x), field(y)));
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+    }
+}
+
+#[test]
+fn key_images_secp256k1() {
+    key_images_in_subgroup::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn key_images_ed25519() {
+    key_images_in_subgroup::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn key_images_other_curves() {
+    #[cfg(feature = "p256")]
+    key_images_in_subgroup::<lingo::curves::p256::Projective>(3);
+    #[cfg(feature = "bls12-381")]
+    key_images_in_subgroup::<lingo::curves::bls12_381::G1Projective>(4);
+    #[cfg(feature = "bn254")]
+    key_images_in_subgroup::<lingo::curves::bn254::G1Projective>(5);
+    #[cfg(feature = "pallas")]
+    key_images_in_subgroup::<lingo::curves::pallas::Projective>(6);
+    #[cfg(feature = "vesta")]
+    key_images_in_subgroup::<lingo::curves::vesta::Projective>(7);
+    #[cfg(feature = "jubjub")]
+    key_images_in_subgroup::<lingo::curves::jubjub::EdwardsProjective>(8);
+}
+
+fn separates_domains<C: CurveGroup>(seed: u64) {
+    let first = hash_to_point::<C>(b"lingo tests", MESSAGE);
+    assert_eq!(hash_to_point::<C>(b"lingo tests", MESSAGE), first);
+    assert_ne!(hash_to_point::<C>(b"lingo tests 2", MESSAGE), first);
+    assert_ne!(hash_to_point::<C>(b"lingo tests", b"lingo"), first);
+    assert!(!first.is_zero());
+    assert!(first.mul_bigint(C::ScalarField::MODULUS).is_zero());
+
+    // key image bases hash the compressed key under their own domain
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let key = (C::generator() * secret.expose_secret()).into_affine();
+    let mut bytes = Vec::new();
+    key.serialize_compressed(&mut bytes).unwrap();
+    assert_eq!(
+        hash_point::<C>(&key).into_affine(),
+        hash_to_point::<C>(KEY_IMAGE_DOMAIN, &bytes)
+    );
+}
+
+#[test]
+fn domains_secp256k1() {
+    separates_domains::<ark_secp256k1::Projective>(9);
+}
+
+#[test]
+fn domains_ed25519() {
+    separates_domains::<ark_ed25519::EdwardsProjective>(10);
+}
+
+#[test]
+fn suites() {
+    assert_eq!(
+        suite_id::<ark_secp256k1::Projective>(),
+        Some("secp256k1_XMD:SHA-256_SSWU_RO_")
+    );
+    assert_eq!(
+        suite_id::<ark_ed25519::EdwardsProjective>(),
+        Some("edwards25519_XMD:SHA-512_ELL2_RO_")
+    );
+    assert_eq!(
+        dst::<ark_secp256k1::Projective>(b"app").unwrap(),
+        b"app-with-secp256k1_XMD:SHA-256_SSWU_RO_"
+    );
+    #[cfg(feature = "p256")]
+    assert_eq!(
+        suite_id::<lingo::curves::p256::Projective>(),
+        Some("P256_XMD:SHA-256_SSWU_RO_")
+    );
+    #[cfg(feature = "bls12-381")]
+    assert_eq!(
+        suite_id::<lingo::curves::bls12_381::G1Projective>(),
+        Some("BLS12381G1_XMD:SHA-256_SSWU_RO_")
+    );
+    #[cfg(feature = "bn254")]
+    assert_eq!(
+        dst::<lingo::curves::bn254::G1Projective>(b"app").unwrap(),
+        b"app-with-BN254G1_XMD:SHA-256_SVDW_RO_"
+    );
+    #[cfg(feature = "pallas")]
+    assert_eq!(
+        suite_id::<lingo::curves::pallas::Projective>(),
+        Some("pallas_XMD:SHA-256_SVDW_RO_")
+    );
+    #[cfg(feature = "vesta")]
+    assert_eq!(
+        suite_id::<lingo::curves::vesta::Projective>(),
+        Some("vesta_XMD:SHA-256_SVDW_RO_")
+    );
+    #[cfg(feature = "jubjub")]
+    assert_eq!(
+        suite_id::<lingo::curves::jubjub::EdwardsProjective>(),
+        Some("jubjub_XMD:SHA-256_ELL2_RO_")
+    );
+}
@@ -0,0 +1,109 @@
+// SLIP-10 derivation against the published test vector 1 for ed25519 and secp256k1 (the latter
+// also BIP32 test vector 1), path parsing, and signing with derived keys.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::KeyError;
+use lingo::hd::{ExtendedKey, HARDENED, Slip10Curve};
+
+type Ed25519 = ark_ed25519::EdwardsProjective;
+type Secp256k1 = ark_secp256k1::Projective;
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn seed() -> Vec<u8> {
+    (0u8..16).collect()
+}
+
+fn node<C: Slip10Curve>(node: &ExtendedKey<C>, private_key: &str, chain_code: &str) {
+    assert_eq!(hex(node.private_key()), private_key);
+    assert_eq!(hex(node.chain_code()), chain_code);
+}
+
+fn signs_with_derived_keys<C: Slip10Curve + CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let master = ExtendedKey::<C>::master(&self::seed());
+    let pair = master.derive_path("m/44'/1'/7'").unwrap().keypair();
+    let ring = pair.ring_with_rng(3, 1, &mut rng).unwrap();
+    assert!(
+        ring.sign_with_rng(b"lingo hd", pair.private_key(), 1, &mut rng)
+            .unwrap()
+            .verify(b"lingo hd")
+            .is_ok()
+    );
+    assert!(master.derive_path("m/44'/1'/8'").unwrap().keypair() != pair);
+}
+
+#[test]
+fn ed25519_vector_1() {
+    let master = ExtendedKey::<Ed25519>::master(&seed());
+    node(
+        &master,
+        "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+        "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+    );
+    assert_eq!(
+        hex(&lingo::ed25519::encode(master.keypair().public_key())),
+        "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed"
+    );
+    let child = master.child(HARDENED).unwrap();
+    node(
+        &child,
+        "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+        "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+    );
+    node(
+        &master.derive_path("m/0'/1'").unwrap(),
+        "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+        "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14",
+    );
+    assert!(master.derive_path("m/0'").unwrap() == child);
+}
+
+#[test]
+fn secp256k1_vector_1() {
+    let master = ExtendedKey::<Secp256k1>::master(&seed());
+    node(
+        &master,
+        "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+        "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508",
+    );
+    node(
+        &master.derive_path("m/0h").unwrap(),
+        "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+        "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+    );
+}
+
+#[test]
+fn parses_paths() {
+    let master = ExtendedKey::<Ed25519>::master(&seed());
+    assert!(master.derive_path("m").unwrap() == master);
+    assert!(master.derive_path("m/0h").unwrap() == master.derive_path("m/0'").unwrap());
+    // only hardened components, below the hardened offset, under a leading `m`
+    for path in ["m/0", "x/0'", "0'", "m/2147483648'", "m/0'/", ""] {
+        assert_eq!(master.derive_path(path).err(), Some(KeyError::InvalidPath));
+    }
+}
+
+#[test]
+fn rejects_normal_children() {
+    let master = ExtendedKey::<Secp256k1>::master(&seed());
+    assert_eq!(master.child(0).err(), Some(KeyError::InvalidPath));
+    assert_eq!(
+        master.child(HARDENED - 1).err(),
+        Some(KeyError::InvalidPath)
+    );
+}
+
+#[test]
+fn derived_secp256k1() {
+    signs_with_derived_keys::<Secp256k1>(1);
+}
+
+#[test]
+fn derived_ed25519() {
+    signs_with_derived_keys::<Ed25519>(2);
+}
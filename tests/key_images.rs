@@ -0,0 +1,148 @@
+// Key images computed apart from signing: `key_image` must give the image every linkable
+// signature by the key carries, whatever the ring or message, and the per-curve byte helpers the
+// encoding of that same image. A public key that is not the private key's, or a zero private key,
+// must be refused with an error.
+// Salted images must link signatures under the same context only, never with unsalted ones, and
+// salted signatures must verify only against the context they were made under.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::KeyError;
+use lingo::key_image::{self, key_image, salted_key_image};
+use lingo::keypair::KeyPair;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::wire::{DecodedSignature, WireCurve};
+use lingo::{ed25519, secp256k1};
+
+const MESSAGE: &[u8] = b"lingo key images";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn matches_signing<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let image = key_image(&secret, &public_key(&secret)).unwrap();
+    assert!(image.is_valid());
+    assert_eq!(
+        *image.point(),
+        (key_image::hash_point::<C>(&public_key(&secret)) * secret.expose_secret()).into_affine()
+    );
+
+    for size in [1, 4] {
+        let ring = Ring::<C>::new_with_rng(size, &secret, size - 1, &mut rng).unwrap();
+        let signature = ring
+            .sign_linkable_with_rng(MESSAGE, &secret, size - 1, &mut rng)
+            .unwrap();
+        assert_eq!(signature.image, Some(image));
+    }
+    let pair = KeyPair::from_private_key(&secret);
+    assert_eq!(pair.key_image(), image);
+
+    let other = SecretKey::<C>::generate(&mut rng);
+    assert_ne!(key_image(&other, &public_key(&other)).unwrap(), image);
+}
+
+fn salts_by_context<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 3, &mut rng).unwrap();
+
+    let first = ring
+        .sign_linkable_salted_with_rng(MESSAGE, &secret, 3, b"app-a", &mut rng)
+        .unwrap();
+    let second = ring
+        .sign_linkable_salted_with_rng(b"again", &secret, 3, b"app-a", &mut rng)
+        .unwrap();
+    let elsewhere = ring
+        .sign_linkable_salted_with_rng(MESSAGE, &secret, 3, b"app-b", &mut rng)
+        .unwrap();
+    let empty = ring
+        .sign_linkable_salted_with_rng(MESSAGE, &secret, 3, b"", &mut rng)
+        .unwrap();
+    let plain = ring
+        .sign_linkable_with_rng(MESSAGE, &secret, 3, &mut rng)
+        .unwrap();
+    assert!(first.verify_salted(MESSAGE, b"app-a").is_ok());
+    assert!(second.verify_salted(b"again", b"app-a").is_ok());
+    assert!(elsewhere.verify_salted(MESSAGE, b"app-b").is_ok());
+    assert!(empty.verify_salted(MESSAGE, b"").is_ok());
+
+    assert_eq!(
+        first.image,
+        Some(salted_key_image(&secret, &public_key(&secret), b"app-a").unwrap())
+    );
+    assert!(first.links_with(&second));
+    assert!(!first.links_with(&elsewhere));
+    // an empty context is still a salt
+    assert!(!first.links_with(&plain) && !empty.links_with(&plain));
+
+    assert!(first.verify_salted(MESSAGE, b"app-b").is_err());
+    assert!(first.verify_salted(b"lingo key imagez", b"app-a").is_err());
+    assert!(first.verify(MESSAGE).is_err());
+    assert!(empty.verify(MESSAGE).is_err());
+    assert!(plain.verify_salted(MESSAGE, b"").is_err());
+
+    let decoded = DecodedSignature::<C>::from_bytes(&first.to_bytes()).unwrap();
+    assert!(decoded.signature().verify_salted(MESSAGE, b"app-a").is_ok());
+}
+
+#[test]
+fn key_image_secp256k1() {
+    matches_signing::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn key_image_ed25519() {
+    matches_signing::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn salted_secp256k1() {
+    salts_by_context::<ark_secp256k1::Projective>(5);
+}
+
+#[test]
+fn salted_ed25519() {
+    salts_by_context::<ark_ed25519::EdwardsProjective>(6);
+}
+
+#[test]
+fn key_image_bytes() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let pair = KeyPair::<ark_secp256k1::Projective>::generate(&mut rng);
+    assert_eq!(
+        secp256k1::key_image(&pair.secp256k1_secret()).unwrap(),
+        secp256k1::encode(pair.key_image().point())
+    );
+    assert!(secp256k1::key_image(&[0; 32]).is_err());
+
+    let seed = [7; 32];
+    let pair = KeyPair::<ark_ed25519::EdwardsProjective>::from_ed25519_secret(&seed);
+    assert_eq!(
+        ed25519::key_image(&seed),
+        ed25519::encode(pair.key_image().point())
+    );
+}
+
+#[test]
+fn key_image_rejects_foreign_public_key() {
+    type C = ark_secp256k1::Projective;
+    let mut rng = StdRng::seed_from_u64(4);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    assert_eq!(
+        key_image(&secret, &public_key(&other)).err(),
+        Some(KeyError::InvalidPublicKey)
+    );
+    assert_eq!(
+        salted_key_image(&secret, &public_key(&other), b"app-a").err(),
+        Some(KeyError::InvalidPublicKey)
+    );
+    assert_eq!(
+        key_image(&SecretKey::<C>::new(0u64.into()), &public_key(&other)).err(),
+        Some(KeyError::InvalidSecretKey)
+    );
+}
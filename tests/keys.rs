@@ -0,0 +1,175 @@
+// Key pairs: generated keys open the public key they carry, sign in the rings they build, and
+// report the key image their linkable signatures carry. Seeded keys come out of HKDF-SHA512 the
+// same every time. Secret keys read from bytes reduce modulo the group order, sign as the key
+// they reduce to and compare in constant time, and they and secret scalars hold what they were
+// given and are zeroed on drop.
+
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_std::rand::{RngCore, SeedableRng, rngs::StdRng};
+use lingo::keypair::KeyPair;
+use lingo::ring::Ring;
+use lingo::secret::{SecretKey, SecretScalar};
+use subtle::ConstantTimeEq;
+use zeroize::ZeroizeOnDrop;
+
+const MESSAGE: &[u8] = b"lingo keys";
+
+fn generates<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pair = KeyPair::<C>::generate(&mut rng);
+    assert!(!pair.private_key().expose_secret().is_zero());
+    assert_eq!(
+        *pair.public_key(),
+        (C::generator() * pair.private_key().expose_secret()).into_affine()
+    );
+    assert!(KeyPair::<C>::from_private_key(pair.private_key()) == pair);
+    assert!(KeyPair::<C>::generate(&mut rng) != pair);
+
+    let ring = pair.ring_with_rng(4, 2, &mut rng).unwrap();
+    assert_eq!(&ring.keys()[2], pair.public_key());
+    let signature = ring
+        .sign_linkable_with_rng(MESSAGE, pair.private_key(), 2, &mut rng)
+        .unwrap();
+    assert!(signature.verify(MESSAGE).is_ok());
+    assert_eq!(signature.key_image(), Some(&pair.key_image()));
+    assert!(pair.ring_with_rng(4, 4, &mut rng).is_err());
+}
+
+fn derives_from_seed<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pair = KeyPair::<C>::from_seed(&[7; 32]);
+    assert!(KeyPair::<C>::from_seed(&[7; 32]) == pair);
+    assert!(KeyPair::<C>::from_seed(&[8; 32]) != pair);
+    let ring = pair.ring_with_rng(2, 0, &mut rng).unwrap();
+    assert!(
+        ring.sign_with_rng(MESSAGE, pair.private_key(), 0, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+}
+
+#[test]
+fn seed_known_answer() {
+    // HKDF-SHA512(salt "lingo-keypair-seed-v1", ikm [7; 32], info 0u64 LE), 64 bytes read as a
+    // little-endian integer and reduced modulo the secp256k1 group order
+    let pair = KeyPair::<ark_secp256k1::Projective>::from_seed(&[7; 32]);
+    assert_eq!(
+        pair.private_key().expose_secret().to_string(),
+        "54725550243467731627398318437834209222925069282604249495113931339263153404577"
+    );
+}
+
+fn zeroized_on_drop<T: ZeroizeOnDrop>() {}
+
+fn reduces_mod_order<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let small = rng.next_u64();
+    let mut above = <C as PrimeGroup>::ScalarField::MODULUS;
+    assert!(!above.add_with_carry(&small.into()));
+
+    let reduced = SecretKey::<C>::from_le_bytes_mod_order(&above.to_bytes_le());
+    assert!(reduced == SecretKey::new(small.into()));
+    assert!(SecretKey::<C>::from_be_bytes_mod_order(&above.to_bytes_be()) == reduced);
+
+    // a ring holding `small * G` takes the unreduced key's signatures
+    let ring = Ring::<C>::new_with_rng(3, &reduced, 1, &mut rng).unwrap();
+    assert_eq!(
+        ring.keys()[1],
+        (C::generator() * <C as PrimeGroup>::ScalarField::from(small)).into_affine()
+    );
+    assert!(
+        ring.sign_linkable_with_rng(MESSAGE, &reduced, 1, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+}
+
+#[test]
+fn scalars_secp256k1() {
+    reduces_mod_order::<ark_secp256k1::Projective>(7);
+}
+
+#[test]
+fn scalars_ed25519() {
+    reduces_mod_order::<ark_ed25519::EdwardsProjective>(8);
+}
+
+#[test]
+fn secret_key_from_bytes() {
+    type C = ark_secp256k1::Projective;
+    let order = ark_secp256k1::Fr::MODULUS;
+
+    // the order plus one, either way round, is one
+    let mut little = order.to_bytes_le();
+    little[0] += 1;
+    assert_eq!(
+        *SecretKey::<C>::from_le_bytes_mod_order(&little).expose_secret(),
+        ark_secp256k1::Fr::from(1u64)
+    );
+    let mut big = order.to_bytes_be();
+    big[31] += 1;
+    assert!(SecretKey::<C>::from_be_bytes_mod_order(&big) == SecretKey::new(1u64.into()));
+
+    let mut one = [0; 32];
+    one[0] = 1;
+    assert!(
+        SecretKey::<C>::from_le_bytes_mod_order(&one) != SecretKey::from_be_bytes_mod_order(&one)
+    );
+
+    // equality goes through the constant-time comparison
+    let key = SecretKey::<C>::from_le_bytes_mod_order(&one);
+    assert!(bool::from(key.ct_eq(&SecretKey::new(1u64.into()))));
+    assert!(!bool::from(key.ct_eq(&SecretKey::new(2u64.into()))));
+}
+
+#[test]
+fn secret_scalar() {
+    type C = ark_secp256k1::Projective;
+    zeroized_on_drop::<SecretKey<C>>();
+    zeroized_on_drop::<SecretScalar<C>>();
+
+    let five = ark_secp256k1::Fr::from(5u64);
+    let scalar = SecretScalar::<C>::from_private_key(&SecretKey::new(five));
+    assert_eq!(*scalar, five);
+    assert_eq!(*SecretScalar::<C>::new(five), five);
+    let mut rng = StdRng::seed_from_u64(6);
+    assert_ne!(
+        *SecretScalar::<C>::random(&mut rng),
+        *SecretScalar::<C>::random(&mut rng)
+    );
+}
+
+#[test]
+#[should_panic]
+fn secret_scalar_rejects_zero() {
+    SecretScalar::from_private_key(&SecretKey::<ark_secp256k1::Projective>::new(Zero::zero()));
+}
+
+#[test]
+fn keypair_secp256k1() {
+    generates::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn keypair_ed25519() {
+    generates::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn seed_secp256k1() {
+    derives_from_seed::<ark_secp256k1::Projective>(4);
+}
+
+#[test]
+fn seed_ed25519() {
+    derives_from_seed::<ark_ed25519::EdwardsProjective>(5);
+}
+
+#[cfg(feature = "bls12-381")]
+#[test]
+fn keypair_bls12_381() {
+    generates::<lingo::curves::bls12_381::G1Projective>(3);
+}
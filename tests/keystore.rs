@@ -0,0 +1,104 @@
+// Encrypted keystores: a key saved under a password opens with that password alone, survives a
+// trip through JSON and through a file, and can be re-encrypted or rotated. Editing the stored
+// public key must break decryption, since it is bound in as associated data, and a scrypt cost
+// that scrypt itself refuses is an error rather than a panic.
+
+#![cfg(feature = "keystore")]
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::KeyError;
+use lingo::keypair::KeyPair;
+use lingo::keystore::{Keystore, ScryptParams};
+use std::fs;
+
+// far cheaper than the default, which is sized for real keys
+const PARAMS: ScryptParams = ScryptParams {
+    log_n: 10,
+    r: 8,
+    p: 1,
+};
+
+fn stores<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pair = KeyPair::<C>::generate(&mut rng);
+    let keystore = pair
+        .to_keystore_with_params(b"password", PARAMS, &mut rng)
+        .unwrap();
+    assert!(KeyPair::<C>::from_keystore(&keystore, b"password").unwrap() == pair);
+    assert_eq!(
+        KeyPair::<C>::from_keystore(&keystore, b"passwore").err(),
+        Some(KeyError::DecryptionFailed)
+    );
+    assert_eq!(keystore.public_key::<C>().unwrap(), *pair.public_key());
+
+    let json = keystore.to_json();
+    assert!(Keystore::from_json(&json).unwrap() == keystore);
+    assert!(Keystore::from_json(&json[1..]).is_err());
+
+    // the same key under another password, and a fresh key in its place
+    let changed = keystore
+        .change_password(b"password", b"changed", &mut rng)
+        .unwrap();
+    assert!(KeyPair::<C>::from_keystore(&changed, b"changed").unwrap() == pair);
+    assert!(KeyPair::<C>::from_keystore(&changed, b"password").is_err());
+    assert!(
+        keystore
+            .change_password(b"passwore", b"changed", &mut rng)
+            .is_err()
+    );
+    let (rotated, replaced) =
+        KeyPair::<C>::rotate_keystore(&changed, b"changed", &mut rng).unwrap();
+    assert!(rotated != pair);
+    assert!(KeyPair::<C>::from_keystore(&replaced, b"changed").unwrap() == rotated);
+
+    let path = std::env::temp_dir().join(format!("lingo-keystore-{seed}-{}", std::process::id()));
+    replaced.save(&path).unwrap();
+    assert!(Keystore::load(&path).unwrap() == replaced);
+    fs::remove_file(&path).unwrap();
+
+    let mut swapped: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let other: serde_json::Value = serde_json::from_str(&replaced.to_json()).unwrap();
+    swapped["public_key"] = other["public_key"].clone();
+    let swapped = Keystore::from_json(&swapped.to_string()).unwrap();
+    assert_eq!(
+        KeyPair::<C>::from_keystore(&swapped, b"password").err(),
+        Some(KeyError::DecryptionFailed)
+    );
+}
+
+fn refuses_bad_params<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pair = KeyPair::<C>::generate(&mut rng);
+    for params in [
+        ScryptParams { log_n: 0, ..PARAMS },
+        ScryptParams { r: 0, ..PARAMS },
+        ScryptParams { p: 0, ..PARAMS },
+    ] {
+        assert_eq!(
+            pair.to_keystore_with_params(b"password", params, &mut rng)
+                .err(),
+            Some(KeyError::UnsupportedAlgorithm)
+        );
+    }
+}
+
+#[test]
+fn keystore_secp256k1() {
+    stores::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn keystore_ed25519() {
+    stores::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn bad_params_secp256k1() {
+    refuses_bad_params::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn bad_params_ed25519() {
+    refuses_bad_params::<ark_ed25519::EdwardsProjective>(4);
+}
@@ -0,0 +1,144 @@
+// Malleated encodings: no bit flipped anywhere in a signature's wire bytes (the lowest, a middle
+// and the highest of each byte) may leave something that still verifies, and a scalar written
+// as itself plus the group order, or as any value at or above the order, must be refused rather
+// than reduced. The arkworks encodings of rings, key images and signatures take only the bytes
+// `serialize_compressed` writes.
+
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::DecodeError;
+use lingo::key_image::{KeyImage, key_image};
+use lingo::ring::{Ring, RingSignature};
+use lingo::secret::SecretKey;
+use lingo::wire::{DecodedSignature, WireCurve};
+
+const MESSAGE: &[u8] = b"lingo malleability";
+
+// magic, version, curve id, scheme id and ring size
+const HEADER: usize = 11;
+
+fn no_bit_flip_verifies<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(2, &secret, 1, &mut rng).unwrap();
+    for signature in [
+        ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng).unwrap(),
+        ring.sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+            .unwrap(),
+    ] {
+        let bytes = signature.to_bytes();
+        for index in 0..bytes.len() {
+            for bit in [0, 3, 7] {
+                let mut flipped = bytes.clone();
+                flipped[index] ^= 1 << bit;
+                let decoded = DecodedSignature::<C>::from_bytes(&flipped);
+                assert!(
+                    !decoded.is_ok_and(|decoded| decoded.verify(MESSAGE).is_ok()),
+                    "bit {bit} of byte {index} flipped still verifies"
+                );
+            }
+        }
+    }
+}
+
+fn rejects_unreduced_scalars<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+    let signature = ring
+        .sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+        .unwrap();
+    let bytes = signature.to_bytes();
+    let point = C::Affine::default().compressed_size();
+    let scalar = C::ScalarField::default().compressed_size();
+    let challenge = HEADER + ring.size() * point;
+
+    // every scalar in the body: the challenge, then one response per member
+    for offset in (0..=ring.size()).map(|i| challenge + i * scalar) {
+        let mut saturated = bytes.clone();
+        saturated[offset..offset + scalar].fill(0xff);
+        assert_eq!(
+            DecodedSignature::<C>::from_bytes(&saturated).err(),
+            Some(DecodeError::InvalidEncoding)
+        );
+
+        let value = C::ScalarField::deserialize_compressed(&bytes[offset..offset + scalar])
+            .unwrap()
+            .into_bigint();
+        let mut unreduced = value;
+        // scalars are written little-endian, so the sum fits unless it carries out of the top
+        if !unreduced.add_with_carry(&C::ScalarField::MODULUS)
+            && unreduced.to_bytes_le()[scalar..]
+                .iter()
+                .all(|byte| *byte == 0)
+        {
+            let mut malleated = bytes.clone();
+            malleated[offset..offset + scalar].copy_from_slice(&unreduced.to_bytes_le()[..scalar]);
+            assert_eq!(
+                DecodedSignature::<C>::from_bytes(&malleated).err(),
+                Some(DecodeError::InvalidEncoding)
+            );
+        }
+    }
+
+    let mut canonical = Vec::new();
+    signature.serialize_compressed(&mut canonical).unwrap();
+    let mut saturated = canonical.clone();
+    saturated[..scalar].fill(0xff);
+    assert!(
+        RingSignature::deserialize_with_ring(
+            &ring,
+            &saturated[..],
+            ark_serialize::Compress::Yes,
+            ark_serialize::Validate::Yes
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn flips_secp256k1() {
+    no_bit_flip_verifies::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn flips_ed25519() {
+    no_bit_flip_verifies::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn scalars_secp256k1() {
+    rejects_unreduced_scalars::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn scalars_ed25519() {
+    rejects_unreduced_scalars::<ark_ed25519::EdwardsProjective>(4);
+}
+
+#[test]
+fn points_secp256k1() {
+    type C = ark_secp256k1::Projective;
+    let mut rng = StdRng::seed_from_u64(5);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(2, &secret, 0, &mut rng).unwrap();
+
+    // the flags byte follows the 32 bytes of the first key's x coordinate, after the length
+    let mut bytes = Vec::new();
+    ring.serialize_compressed(&mut bytes).unwrap();
+    bytes[8 + 32] ^= 0x01;
+    assert!(Ring::<C>::deserialize_compressed(&bytes[..]).is_err());
+
+    let image = key_image(&secret, &ring.keys()[0]).unwrap();
+    let mut bytes = Vec::new();
+    image.serialize_compressed(&mut bytes).unwrap();
+    bytes[32] ^= 0x02;
+    assert!(KeyImage::<C>::deserialize_compressed(&bytes[..]).is_err());
+
+    // the same key with an unused flag bit set
+    let hex = lingo::hex::public_key_to_hex::<C>(&ring.keys()[0]);
+    let flagged = format!("{}1", &hex[..hex.len() - 1]);
+    assert_ne!(flagged, hex);
+    assert!(lingo::hex::public_key_from_hex::<C>(&flagged).is_err());
+}
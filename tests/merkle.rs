@@ -0,0 +1,95 @@
+// Signing against a Merkle commitment of a larger key set: a ring drawn from the tree must carry
+// one path per member to the tree's root, and its signatures verify against that root and
+// message only. A verifier holding just the root can rebuild the signature from its parts, and
+// paths handed over in the wrong order no longer vouch for their keys.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::{RingError, VerifyError};
+use lingo::merkle::{KeyTree, MerkleSignature};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo merkle";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn signs_against_root<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for count in [1usize, 2, 7, 33] {
+        let mut keys: Vec<C::Affine> = (0..count)
+            .map(|_| public_key(&SecretKey::<C>::generate(&mut rng)))
+            .collect();
+        let mine = count / 2;
+        keys[mine] = public_key(&secret);
+        let tree = KeyTree::<C>::new(keys).unwrap();
+        assert_eq!(tree.len(), count);
+        let root = tree.root();
+
+        let indices = if count > 3 {
+            vec![0, mine, count - 1]
+        } else {
+            vec![mine]
+        };
+        let position = indices.iter().position(|&i| i == mine).unwrap();
+        let ring = tree.ring(&indices).unwrap();
+        assert_eq!(ring.root(), root);
+        assert_eq!(ring.paths().len(), indices.len());
+
+        for signature in [
+            ring.sign_with_rng(MESSAGE, &secret, position, &mut rng)
+                .unwrap(),
+            ring.sign_linkable_with_rng(MESSAGE, &secret, position, &mut rng)
+                .unwrap(),
+        ] {
+            assert!(signature.verify(&root, MESSAGE).is_ok());
+            assert!(signature.verify(&root, b"lingo merklf").is_err());
+            assert!(signature.verify(&[1; 32], MESSAGE).is_err());
+
+            // the verifier's copy, rebuilt from the ring's keys and the paths
+            let keys =
+                Ring::<C>::from_fixed_pubkeys(signature.signature.public_keys().to_vec()).unwrap();
+            let paths = ring.paths().to_vec();
+            let rebuilt = MerkleSignature {
+                signature: signature.signature.detach().attach(&keys),
+                paths: &paths,
+            };
+            assert!(rebuilt.verify(&root, MESSAGE).is_ok());
+
+            if paths.len() > 1 {
+                let mut swapped = paths.clone();
+                swapped.swap(0, 1);
+                let swapped = MerkleSignature {
+                    signature: signature.signature.detach().attach(&keys),
+                    paths: &swapped,
+                };
+                assert_eq!(swapped.verify(&root, MESSAGE), Err(VerifyError::NotAMember));
+                let short = MerkleSignature {
+                    signature: signature.signature.detach().attach(&keys),
+                    paths: &paths[1..],
+                };
+                assert_eq!(
+                    short.verify(&root, MESSAGE),
+                    Err(VerifyError::LengthMismatch)
+                );
+            }
+        }
+    }
+    assert_eq!(
+        KeyTree::<C>::new(Vec::new()).err(),
+        Some(RingError::EmptyRing)
+    );
+}
+
+#[test]
+fn merkle_secp256k1() {
+    signs_against_root::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn merkle_ed25519() {
+    signs_against_root::<ark_ed25519::EdwardsProjective>(2);
+}
@@ -0,0 +1,126 @@
+// MLSAG signing and verification over matrix rings of several sizes and widths, with the signer
+// at every row: each signature must verify against its own message and no other, and carry one
+// key image per layer. A matrix with no members, uneven rows or layers, or an invalid key is
+// refused, naming the offending rows or layers, and so is a signer outside the matrix or with
+// keys that are not its row's.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::RingError;
+use lingo::mlsag::MatrixRing;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo mlsag";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn members<C: CurveGroup>(
+    size: usize,
+    width: usize,
+    rng: &mut StdRng,
+) -> (Vec<Vec<SecretKey<C>>>, MatrixRing<C>) {
+    let secrets: Vec<Vec<SecretKey<C>>> = (0..size)
+        .map(|_| (0..width).map(|_| SecretKey::generate(rng)).collect())
+        .collect();
+    let members: Vec<Vec<C::Affine>> = secrets
+        .iter()
+        .map(|member| member.iter().map(public_key).collect())
+        .collect();
+    (secrets, MatrixRing::from_members(&members).unwrap())
+}
+
+fn signs_at_every_row<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for size in [1, 3] {
+        for width in [1, 2, 3] {
+            let (secrets, ring) = members::<C>(size, width, &mut rng);
+            assert_eq!(ring.size(), size);
+            assert_eq!(ring.width(), width);
+            for (index, secret) in secrets.iter().enumerate() {
+                let signature = ring
+                    .sign_with_rng(MESSAGE, secret, index, &mut rng)
+                    .unwrap();
+                assert!(signature.verify(MESSAGE).is_ok());
+                assert!(signature.verify(b"lingo mlsah").is_err());
+                assert_eq!(signature.key_images().len(), width);
+
+                // the images are the member's own, whatever the message
+                let again = ring
+                    .sign_with_rng(b"again", secret, index, &mut rng)
+                    .unwrap();
+                assert_eq!(signature.key_images(), again.key_images());
+            }
+        }
+    }
+}
+
+fn refuses_malformed<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (secrets, ring) = members::<C>(3, 2, &mut rng);
+    let mut members: Vec<Vec<C::Affine>> = (0..3).map(|index| ring.member(index)).collect();
+
+    assert_eq!(
+        ring.sign_with_rng(MESSAGE, &secrets[1], 3, &mut rng).err(),
+        Some(RingError::IndexOutOfBounds { index: 3, size: 3 })
+    );
+    assert_eq!(
+        ring.sign_with_rng(MESSAGE, &secrets[1][..1], 1, &mut rng)
+            .err(),
+        Some(RingError::KeyMismatch)
+    );
+    assert_eq!(
+        ring.sign_with_rng(MESSAGE, &secrets[1], 0, &mut rng).err(),
+        Some(RingError::KeyMismatch)
+    );
+
+    assert_eq!(
+        MatrixRing::<C>::from_members(&[]).err(),
+        Some(RingError::EmptyRing)
+    );
+    assert_eq!(
+        MatrixRing::<C>::from_layers(Vec::new()).err(),
+        Some(RingError::EmptyRing)
+    );
+
+    members[2].pop();
+    assert_eq!(
+        MatrixRing::<C>::from_members(&members).err(),
+        Some(RingError::MemberWidthMismatch(vec![2]))
+    );
+    members[2].push(C::Affine::zero());
+    members[0][0] = C::Affine::zero();
+    assert_eq!(
+        MatrixRing::<C>::from_members(&members).err(),
+        Some(RingError::InvalidPublicKeys(vec![0, 2]))
+    );
+
+    let shorter = Ring::<C>::new_with_rng(2, &secrets[0][1], 0, &mut rng).unwrap();
+    let layers = vec![ring.layers()[0].clone(), shorter.clone(), shorter];
+    assert_eq!(
+        MatrixRing::from_layers(layers).err(),
+        Some(RingError::LayerSizeMismatch(vec![1, 2]))
+    );
+}
+
+#[test]
+fn mlsag_secp256k1() {
+    signs_at_every_row::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn mlsag_ed25519() {
+    signs_at_every_row::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn malformed_secp256k1() {
+    refuses_malformed::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn malformed_ed25519() {
+    refuses_malformed::<ark_ed25519::EdwardsProjective>(4);
+}
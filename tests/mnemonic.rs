@@ -0,0 +1,88 @@
+// BIP39 recovery phrases: seeds against the reference vectors, bad checksums refused, and key
+// pairs derived under a phrase the same every time and only for the same phrase, passphrase and
+// path.
+
+#![cfg(feature = "mnemonic")]
+
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::KeyError;
+use lingo::hd::{ExtendedKey, Slip10Curve};
+use lingo::keypair::KeyPair;
+use lingo::mnemonic::{self, WordCount};
+
+const MESSAGE: &[u8] = b"lingo mnemonic";
+
+const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+                      abandon abandon about";
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn derives<C: Slip10Curve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let phrase = mnemonic::generate(WordCount::TwentyFour, &mut rng);
+    let pair = KeyPair::<C>::from_mnemonic(&phrase, "", "m/44'/0'").unwrap();
+    assert!(KeyPair::<C>::from_mnemonic(&phrase, "", "m/44'/0'").unwrap() == pair);
+    assert!(KeyPair::<C>::from_mnemonic(&phrase, "x", "m/44'/0'").unwrap() != pair);
+    assert!(KeyPair::<C>::from_mnemonic(&phrase, "", "m/44'/1'").unwrap() != pair);
+    assert!(
+        ExtendedKey::<C>::from_mnemonic(&phrase, "")
+            .unwrap()
+            .derive_path("m/44'/0'")
+            .unwrap()
+            .keypair()
+            == pair
+    );
+    assert_eq!(
+        KeyPair::<C>::from_mnemonic(&phrase, "", "m/0").err(),
+        Some(KeyError::InvalidPath)
+    );
+
+    let ring = pair.ring_with_rng(3, 2, &mut rng).unwrap();
+    assert!(
+        ring.sign_with_rng(MESSAGE, pair.private_key(), 2, &mut rng)
+            .unwrap()
+            .verify(MESSAGE)
+            .is_ok()
+    );
+}
+
+#[test]
+fn reference_seed() {
+    // the first vector of the BIP39 reference implementation, passphrase "TREZOR"
+    assert_eq!(
+        hex(&mnemonic::to_seed(PHRASE, "TREZOR").unwrap()),
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f\
+         2cf141630c7a3c4ab7c81b2f001698e7463b04"
+    );
+    // twelve valid words with the wrong checksum
+    assert_eq!(
+        mnemonic::to_seed(&PHRASE.replace("about", "abandon"), "").err(),
+        Some(KeyError::InvalidMnemonic)
+    );
+    assert_eq!(
+        mnemonic::to_seed("lingo", "").err(),
+        Some(KeyError::InvalidMnemonic)
+    );
+}
+
+#[test]
+fn generates_phrases() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for (words, count) in [(WordCount::Twelve, 12), (WordCount::TwentyFour, 24)] {
+        let phrase = mnemonic::generate(words, &mut rng);
+        assert_eq!(phrase.split(' ').count(), count);
+        assert!(mnemonic::to_seed(&phrase, "").is_ok());
+    }
+}
+
+#[test]
+fn mnemonic_secp256k1() {
+    derives::<ark_secp256k1::Projective>(2);
+}
+
+#[test]
+fn mnemonic_ed25519() {
+    derives::<ark_ed25519::EdwardsProjective>(3);
+}
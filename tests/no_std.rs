@@ -0,0 +1,69 @@
+// The `alloc`-only API, which is all that is left with `--no-default-features`: rings built from
+// an injected generator, signatures made and verified with it, deterministic signatures made
+// with no entropy source at all, and the wire encoding, must all work the same either way.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::builder::RingBuilder;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::wire::{self, DecodedSignature, WireCurve};
+
+const MESSAGE: &[u8] = b"lingo no_std";
+
+fn signs_without_std<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+
+    let plain = ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng).unwrap();
+    let linkable = ring
+        .sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+        .unwrap();
+    let deterministic = ring.sign_deterministic(MESSAGE, &secret, 1).unwrap();
+    for signature in [&plain, &linkable, &deterministic] {
+        assert!(signature.verify(MESSAGE).is_ok());
+        assert!(signature.verify(b"lingo no-std").is_err());
+
+        let bytes = signature.to_bytes();
+        let decoded = DecodedSignature::<C>::from_bytes(&bytes).unwrap();
+        assert!(decoded.verify(MESSAGE).is_ok());
+        assert_eq!(wire::wire_version(&bytes), Ok(1));
+    }
+
+    let (built, index) = RingBuilder::<C>::new()
+        .add_decoys(2, &mut rng)
+        .with_signer(&secret)
+        .shuffle_with_rng(&mut rng)
+        .build()
+        .unwrap();
+    let signature = built
+        .sign_with_rng(MESSAGE, &secret, index, &mut rng)
+        .unwrap();
+    assert!(signature.verify(MESSAGE).is_ok());
+}
+
+fn signs_deterministically<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 0, &mut rng).unwrap();
+    assert!(
+        ring.sign_linkable_deterministic(MESSAGE, &secret, 0)
+            .unwrap()
+            == ring
+                .sign_linkable_deterministic(MESSAGE, &secret, 0)
+                .unwrap()
+    );
+}
+
+#[test]
+fn no_std_secp256k1() {
+    signs_without_std::<ark_secp256k1::Projective>(1);
+    signs_deterministically::<ark_secp256k1::Projective>(2);
+}
+
+#[test]
+fn no_std_ed25519() {
+    signs_without_std::<ark_ed25519::EdwardsProjective>(3);
+    signs_deterministically::<ark_ed25519::EdwardsProjective>(4);
+}
@@ -0,0 +1,70 @@
+// Groth–Kohlweiss one-out-of-many proofs over power-of-two rings, with the prover at every
+// position: each proof must verify against its own message and no other, and only over the ring
+// it was made for. A ring of any other size is refused rather than proven over.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::RingError;
+use lingo::one_of_many;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo one-of-many";
+
+fn proves_at_every_index<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for size in [2, 4, 8] {
+        for index in 0..size {
+            let ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+            let proof =
+                one_of_many::prove_with_rng(&ring, MESSAGE, &secret, index, &mut rng).unwrap();
+            assert!(proof.verify(MESSAGE).is_ok());
+            assert!(proof.verify(b"lingo one-of-many?").is_err());
+
+            // plain membership, bound to no message
+            let membership =
+                one_of_many::prove_with_rng(&ring, b"", &secret, index, &mut rng).unwrap();
+            assert!(membership.verify(b"").is_ok());
+
+            let elsewhere = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+            let moved = one_of_many::OneOfManyProof {
+                ring: &elsewhere,
+                ..proof
+            };
+            assert!(moved.verify(MESSAGE).is_err());
+        }
+    }
+}
+
+fn refuses_other_sizes<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 3, 6] {
+        let ring = Ring::<C>::new_with_rng(size, &secret, 0, &mut rng).unwrap();
+        assert_eq!(
+            one_of_many::prove_with_rng(&ring, MESSAGE, &secret, 0, &mut rng).err(),
+            Some(RingError::UnsupportedSize(size))
+        );
+    }
+}
+
+#[test]
+fn one_of_many_secp256k1() {
+    proves_at_every_index::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn one_of_many_ed25519() {
+    proves_at_every_index::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn sizes_secp256k1() {
+    refuses_other_sizes::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn sizes_ed25519() {
+    refuses_other_sizes::<ark_ed25519::EdwardsProjective>(4);
+}
@@ -0,0 +1,73 @@
+// Prepared rings: a detached signature checked against a `PreparedRing` must verify exactly when
+// it verifies against the ring itself, whatever the challenge hash and whether or not it carries a
+// key image. Another message, another ring or an unknown hash id must all fail.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::challenge::{Blake2b, Keccak256};
+use lingo::error::VerifyError;
+use lingo::prepared::PreparedRing;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo prepared";
+
+fn verifies_prepared<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(6, &secret, 4, &mut rng).unwrap();
+    let prepared = PreparedRing::new(ring.clone()).unwrap();
+    assert!(*prepared.ring() == ring);
+
+    for message in [MESSAGE, b""] {
+        let signatures = [
+            ring.sign_with_rng(message, &secret, 4, &mut rng).unwrap(),
+            ring.sign_linkable_with_rng(message, &secret, 4, &mut rng)
+                .unwrap(),
+            ring.sign_with_hash_and_rng::<Blake2b, _>(message, &secret, 4, &mut rng)
+                .unwrap(),
+            ring.sign_linkable_with_hash_and_rng::<Keccak256, _>(message, &secret, 4, &mut rng)
+                .unwrap(),
+        ];
+        for signature in signatures {
+            let detached = signature.detach();
+            assert!(detached.verify_with_prepared(&prepared, message).is_ok());
+            assert!(
+                detached
+                    .verify_with_prepared(&prepared, b"lingo prepares")
+                    .is_err()
+            );
+
+            let mut unknown = detached.clone();
+            unknown.hash_id = 0xee;
+            assert_eq!(
+                unknown.verify_with_prepared(&prepared, message),
+                Err(VerifyError::UnsupportedHash)
+            );
+        }
+    }
+
+    // a signature over another ring of the same size
+    let other = Ring::<C>::new_with_rng(6, &secret, 1, &mut rng).unwrap();
+    let foreign = other
+        .sign_linkable_with_rng(MESSAGE, &secret, 1, &mut rng)
+        .unwrap();
+    assert!(foreign.verify(MESSAGE).is_ok());
+    assert!(
+        foreign
+            .detach()
+            .verify_with_prepared(&prepared, MESSAGE)
+            .is_err()
+    );
+    assert!(prepared.into_ring() == ring);
+}
+
+#[test]
+fn prepared_secp256k1() {
+    verifies_prepared::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn prepared_ed25519() {
+    verifies_prepared::<ark_ed25519::EdwardsProjective>(2);
+}
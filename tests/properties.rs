@@ -0,0 +1,140 @@
+// sign→verify, single-bit mutations and encoding round trips over random ring sizes, signer
+// positions and messages, for one curve of each family
+
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::wire::{DecodedSignature, WireCurve};
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+#[derive(Debug, Clone)]
+struct Case {
+    size: usize,
+    index: usize,
+    seed: u64,
+    linkable: bool,
+    message: Vec<u8>,
+}
+
+fn case() -> impl Strategy<Value = Case> {
+    (1usize..=6)
+        .prop_flat_map(|size| (Just(size), 0..size))
+        .prop_flat_map(|(size, index)| {
+            (
+                Just(size),
+                Just(index),
+                any::<u64>(),
+                any::<bool>(),
+                prop::collection::vec(any::<u8>(), 1..64),
+            )
+        })
+        .prop_map(|(size, index, seed, linkable, message)| Case {
+            size,
+            index,
+            seed,
+            linkable,
+            message,
+        })
+}
+
+fn setup<C: WireCurve>(case: &Case) -> (Ring<C>, SecretKey<C>, StdRng) {
+    let mut rng = StdRng::seed_from_u64(case.seed);
+    let sk = SecretKey::generate(&mut rng);
+    let ring = Ring::new_with_rng(case.size, &sk, case.index, &mut rng).unwrap();
+    (ring, sk, rng)
+}
+
+fn signed<C: WireCurve>(case: &Case) -> DecodedSignature<C> {
+    let (ring, sk, mut rng) = setup::<C>(case);
+    let sig = if case.linkable {
+        ring.sign_linkable_with_rng(&case.message, &sk, case.index, &mut rng)
+            .unwrap()
+    } else {
+        ring.sign_with_rng(&case.message, &sk, case.index, &mut rng)
+            .unwrap()
+    };
+    DecodedSignature::from_bytes(&sig.to_bytes()).unwrap()
+}
+
+fn flip(bytes: &[u8], bit: Index) -> Vec<u8> {
+    let bit = bit.index(bytes.len() * 8);
+    let mut flipped = bytes.to_vec();
+    flipped[bit / 8] ^= 1 << (bit % 8);
+    flipped
+}
+
+fn sign_verify<C: WireCurve>(case: Case) -> Result<(), TestCaseError> {
+    let sig = signed::<C>(&case);
+    prop_assert!(sig.verify(&case.message).is_ok());
+    prop_assert_eq!(sig.signature().image.is_some(), case.linkable);
+    Ok(())
+}
+
+fn message_mutation<C: WireCurve>(case: Case, bit: Index) -> Result<(), TestCaseError> {
+    let sig = signed::<C>(&case);
+    prop_assert!(sig.verify(&flip(&case.message, bit)).is_err());
+    Ok(())
+}
+
+fn signature_mutation<C: WireCurve>(case: Case, bit: Index) -> Result<(), TestCaseError> {
+    let bytes = flip(&signed::<C>(&case).to_bytes(), bit);
+    prop_assert!(
+        DecodedSignature::<C>::from_bytes(&bytes)
+            .map_or(true, |sig| sig.verify(&case.message).is_err())
+    );
+    Ok(())
+}
+
+fn roundtrips<C: WireCurve>(case: Case) -> Result<(), TestCaseError> {
+    let sig = signed::<C>(&case);
+    let sig = sig.signature();
+    let wire = DecodedSignature::<C>::from_bytes(&sig.to_bytes()).unwrap();
+    prop_assert!(wire.signature() == sig);
+    let compact = DecodedSignature::<C>::from_compact(&sig.to_compact()).unwrap();
+    prop_assert!(compact.signature() == sig);
+    let cbor = DecodedSignature::<C>::from_cbor(&sig.to_cbor()).unwrap();
+    prop_assert!(cbor.signature() == sig);
+    let hex = DecodedSignature::<C>::from_hex(&sig.to_hex()).unwrap();
+    prop_assert!(hex.signature() == sig);
+    let armor = DecodedSignature::<C>::from_armor(&sig.to_armor()).unwrap();
+    prop_assert!(armor.signature() == sig);
+    Ok(())
+}
+
+macro_rules! props {
+    ($name:ident, $curve:ty) => {
+        mod $name {
+            use super::*;
+
+            proptest! {
+                #![proptest_config(ProptestConfig::with_cases(24))]
+
+                #[test]
+                fn sign_verify(case in case()) {
+                    super::sign_verify::<$curve>(case)?;
+                }
+
+                #[test]
+                fn message_mutation(case in case(), bit in any::<Index>()) {
+                    super::message_mutation::<$curve>(case, bit)?;
+                }
+
+                #[test]
+                fn signature_mutation(case in case(), bit in any::<Index>()) {
+                    super::signature_mutation::<$curve>(case, bit)?;
+                }
+
+                #[test]
+                fn roundtrips(case in case()) {
+                    super::roundtrips::<$curve>(case)?;
+                }
+            }
+        }
+    };
+}
+
+props!(secp256k1, ark_secp256k1::Projective);
+props!(ed25519, ark_ed25519::EdwardsProjective);
+#[cfg(feature = "bls12-381")]
+props!(bls12_381, lingo::curves::bls12_381::G1Projective);
@@ -0,0 +1,78 @@
+// Key image registries over the in-memory and file backends: a key image is new exactly once,
+// and whatever the file store persisted is there again after reopening it.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::key_image::KeyImage;
+use lingo::registry::{FileStore, KeyImageSet, MemoryStore};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use std::fs;
+
+fn image<C: CurveGroup>(rng: &mut StdRng) -> KeyImage<C> {
+    let secret = SecretKey::<C>::generate(rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 0, rng).unwrap();
+    *ring
+        .sign_linkable_with_rng(b"lingo registry", &secret, 0, rng)
+        .unwrap()
+        .key_image()
+        .unwrap()
+}
+
+fn in_memory<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (spent, unspent) = (image::<C>(&mut rng), image::<C>(&mut rng));
+    let mut set = KeyImageSet::<C, _>::new(MemoryStore::new());
+    assert_eq!(set.insert(&spent), Ok(true));
+    assert_eq!(set.insert(&spent), Ok(false));
+    assert_eq!(set.contains(&spent), Ok(true));
+    assert_eq!(set.contains(&unspent), Ok(false));
+    assert_eq!(set.into_store().len(), 1);
+}
+
+fn on_disk<C: CurveGroup>(seed: u64, name: &str) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (first, second, unspent) = (
+        image::<C>(&mut rng),
+        image::<C>(&mut rng),
+        image::<C>(&mut rng),
+    );
+    let path = std::env::temp_dir().join(format!("lingo-{name}-{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut set = KeyImageSet::<C, _>::new(FileStore::open(&path).unwrap());
+    assert!(set.store().is_empty());
+    assert!(set.insert(&first).unwrap());
+    set.persist().unwrap();
+    assert!(set.insert(&second).unwrap());
+    assert!(!set.insert(&first).unwrap());
+    set.persist().unwrap();
+    drop(set);
+
+    let mut set = KeyImageSet::<C, _>::new(FileStore::open(&path).unwrap());
+    assert_eq!(set.store().len(), 2);
+    assert!(set.contains(&first).unwrap() && set.contains(&second).unwrap());
+    assert!(!set.contains(&unspent).unwrap());
+    assert!(!set.insert(&second).unwrap());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn memory_secp256k1() {
+    in_memory::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn memory_ed25519() {
+    in_memory::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn file_secp256k1() {
+    on_disk::<ark_secp256k1::Projective>(3, "registry-secp256k1");
+}
+
+#[test]
+fn file_ed25519() {
+    on_disk::<ark_ed25519::EdwardsProjective>(4, "registry-ed25519");
+}
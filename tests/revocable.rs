@@ -0,0 +1,57 @@
+// Link-and-revoke signatures: two signatures under one event from one key link across rings, and
+// over different messages give up the signer's public key; across events, signers or a repeated
+// message they give up nothing.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::revocable::{revoke, sign_with_rng};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const EVENT: &[u8] = b"lingo drop";
+
+fn revokes_double_signers<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    for index in 0..4 {
+        let ring = Ring::<C>::new_with_rng(4, &secret, index, &mut rng).unwrap();
+        let elsewhere = Ring::<C>::new_with_rng(3, &secret, index % 3, &mut rng).unwrap();
+
+        let first = sign_with_rng(&ring, EVENT, b"first", &secret, index, &mut rng).unwrap();
+        let second =
+            sign_with_rng(&elsewhere, EVENT, b"second", &secret, index % 3, &mut rng).unwrap();
+        assert!(first.verify(b"first").is_ok());
+        assert!(second.verify(b"second").is_ok());
+        assert!(first.verify(b"second").is_err());
+        assert!(first.links_with(&second));
+        assert_eq!(
+            revoke(&first, b"first", &second, b"second"),
+            Some(ring.keys()[index])
+        );
+
+        let repeated = sign_with_rng(&ring, EVENT, b"first", &secret, index, &mut rng).unwrap();
+        assert!(first.links_with(&repeated));
+        assert_eq!(revoke(&first, b"first", &repeated, b"first"), None);
+
+        let next_event =
+            sign_with_rng(&ring, b"lingo drop 2", b"second", &secret, index, &mut rng).unwrap();
+        assert!(!first.links_with(&next_event));
+        assert_eq!(revoke(&first, b"first", &next_event, b"second"), None);
+
+        let theirs = Ring::<C>::new_with_rng(4, &other, index, &mut rng).unwrap();
+        let stranger = sign_with_rng(&theirs, EVENT, b"second", &other, index, &mut rng).unwrap();
+        assert!(!first.links_with(&stranger));
+        assert_eq!(revoke(&first, b"first", &stranger, b"second"), None);
+    }
+}
+
+#[test]
+fn revocable_secp256k1() {
+    revokes_double_signers::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn revocable_ed25519() {
+    revokes_double_signers::<ark_ed25519::EdwardsProjective>(2);
+}
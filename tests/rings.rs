@@ -0,0 +1,474 @@
+// Ring construction from caller-supplied keys, directly and through `RingBuilder`: the signer's
+// key must land where the caller asked or the builder moved it, every other key keep its place,
+// and the returned index must be the one signing needs. Keys off the curve, outside the
+// prime-order subgroup or at the identity are refused, listed by their index, and so are zero
+// private keys, empty rings and signer indices past the end. Rings of Ethereum addresses take
+// their keys from whichever candidates hash to them. Canonical ordering must give the same ring
+// from the same keys in any order, following the signer, and refuse keys listed twice. Lists of
+// hex keys and bundles of PEM blocks import whole, or report every entry that does not decode.
+// Rings built at a random position must put the signer's key at the index they return, and over
+// many draws at every index.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::builder::RingBuilder;
+use lingo::error::RingError;
+use lingo::hex::public_key_to_hex;
+use lingo::keypair::KeyPair;
+use lingo::pem::{SpkiCurve, public_key_to_pem};
+use lingo::ring::Ring;
+use lingo::secp256k1;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo rings";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn decoys<C: CurveGroup>(count: usize, rng: &mut StdRng) -> Vec<C::Affine> {
+    (0..count)
+        .map(|_| public_key(&SecretKey::<C>::generate(rng)))
+        .collect()
+}
+
+fn splices_at_every_index<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for count in 0..5 {
+        let pubs = decoys::<C>(count, &mut rng);
+        let secret = SecretKey::<C>::generate(&mut rng);
+        for index in 0..=count {
+            let (ring, signer) = Ring::from_pubkeys(&pubs, &secret, index).unwrap();
+            assert_eq!(signer, index);
+            assert_eq!(ring.size(), count + 1);
+
+            let mut expected = pubs.clone();
+            expected.insert(index, public_key(&secret));
+            assert_eq!(ring.keys(), &expected[..]);
+
+            ring.sign_with_rng(MESSAGE, &secret, signer, &mut rng)
+                .unwrap()
+                .verify(MESSAGE)
+                .unwrap();
+            ring.sign_linkable_with_rng(MESSAGE, &secret, signer, &mut rng)
+                .unwrap()
+                .verify(MESSAGE)
+                .unwrap();
+        }
+    }
+}
+
+fn rejects_bad_input<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pubs = decoys::<C>(3, &mut rng);
+    let secret = SecretKey::<C>::generate(&mut rng);
+
+    assert_eq!(
+        Ring::from_pubkeys(&pubs, &secret, 4).err(),
+        Some(RingError::IndexOutOfBounds { index: 4, size: 4 })
+    );
+    assert_eq!(
+        Ring::from_pubkeys(&[], &secret, 1).err(),
+        Some(RingError::IndexOutOfBounds { index: 1, size: 1 })
+    );
+
+    // the identity among the decoys, reported at its position in the ring
+    let mut with_identity = pubs.clone();
+    with_identity[1] = C::Affine::zero();
+    assert_eq!(
+        Ring::from_pubkeys(&with_identity, &secret, 0).err(),
+        Some(RingError::InvalidPublicKeys(vec![2]))
+    );
+    assert_eq!(
+        Ring::from_pubkeys(&with_identity, &secret, 3).err(),
+        Some(RingError::InvalidPublicKeys(vec![1]))
+    );
+}
+
+fn builds_with_signer_tracked<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let known = decoys::<C>(2, &mut rng);
+    for count in 0..5 {
+        let secret = SecretKey::<C>::generate(&mut rng);
+        let (ring, index) = Ring::builder()
+            .add_key(known[0])
+            .add_decoys(count, &mut rng)
+            .with_signer(&secret)
+            .add_key(known[1])
+            .build()
+            .unwrap();
+        assert_eq!(ring.size(), count + 3);
+        assert_eq!(index, count + 1);
+        assert_eq!(ring.keys()[0], known[0]);
+        assert_eq!(ring.keys()[index], public_key(&secret));
+        assert_eq!(ring.keys()[count + 2], known[1]);
+
+        // shuffled over and over, the index must follow the signer's key wherever it goes
+        let mut positions = Vec::new();
+        for _ in 0..16 {
+            let (shuffled, index) = RingBuilder::new()
+                .add_key(known[0])
+                .add_decoys(count, &mut rng)
+                .with_signer(&secret)
+                .add_key(known[1])
+                .shuffle_with_rng(&mut rng)
+                .build()
+                .unwrap();
+            assert_eq!(shuffled.size(), count + 3);
+            assert_eq!(shuffled.keys()[index], public_key(&secret));
+            assert!(shuffled.keys().contains(&known[0]));
+            assert!(shuffled.keys().contains(&known[1]));
+            shuffled
+                .sign_with_rng(MESSAGE, &secret, index, &mut rng)
+                .unwrap()
+                .verify(MESSAGE)
+                .unwrap();
+            positions.push(index);
+        }
+        assert!(positions.iter().any(|index| *index != positions[0]));
+    }
+}
+
+fn builder_rejects_bad_input<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    let zero = SecretKey::<C>::new(Default::default());
+
+    assert_eq!(
+        RingBuilder::<C>::new()
+            .add_decoys(3, &mut rng)
+            .build()
+            .err(),
+        Some(RingError::NoSigner)
+    );
+    assert_eq!(
+        RingBuilder::<C>::new().build().err(),
+        Some(RingError::NoSigner)
+    );
+    assert_eq!(
+        RingBuilder::new()
+            .with_signer(&secret)
+            .with_signer(&other)
+            .build()
+            .err(),
+        Some(RingError::MultipleSigners)
+    );
+    assert_eq!(
+        RingBuilder::new().with_signer(&zero).build().err(),
+        Some(RingError::ZeroPrivateKey)
+    );
+    assert_eq!(
+        RingBuilder::new()
+            .with_signer(&secret)
+            .add_key(C::Affine::zero())
+            .build()
+            .err(),
+        Some(RingError::InvalidPublicKeys(vec![1]))
+    );
+    assert_eq!(
+        RingBuilder::new()
+            .add_key(public_key(&secret))
+            .add_decoys(2, &mut rng)
+            .with_signer(&secret)
+            .build()
+            .err(),
+        Some(RingError::DuplicatePublicKeys(vec![3]))
+    );
+}
+
+fn new_rejects_bad_input<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let zero = SecretKey::<C>::new(Default::default());
+
+    assert_eq!(
+        Ring::new_with_rng(3, &zero, 0, &mut rng).err(),
+        Some(RingError::ZeroPrivateKey)
+    );
+    assert_eq!(
+        Ring::new_with_rng(3, &secret, 3, &mut rng).err(),
+        Some(RingError::IndexOutOfBounds { index: 3, size: 3 })
+    );
+    assert_eq!(
+        Ring::new_with_rng(0, &secret, 0, &mut rng).err(),
+        Some(RingError::IndexOutOfBounds { index: 0, size: 0 })
+    );
+    assert_eq!(
+        Ring::from_pubkeys(&decoys::<C>(2, &mut rng), &zero, 0).err(),
+        Some(RingError::ZeroPrivateKey)
+    );
+    assert_eq!(
+        Ring::<C>::from_fixed_pubkeys(Vec::new()).err(),
+        Some(RingError::EmptyRing)
+    );
+}
+
+fn places_at_random<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 2, 5] {
+        let mut seen = vec![false; size];
+        for _ in 0..40 {
+            let (ring, index) =
+                Ring::<C>::new_at_random_position_with_rng(size, &secret, &mut rng).unwrap();
+            assert_eq!(ring.size(), size);
+            assert_eq!(ring.keys()[index], public_key(&secret));
+            seen[index] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+    }
+    assert_eq!(
+        Ring::<C>::new_at_random_position_with_rng(0, &secret, &mut rng).err(),
+        Some(RingError::EmptyRing)
+    );
+}
+
+#[test]
+fn new_secp256k1() {
+    new_rejects_bad_input::<ark_secp256k1::Projective>(9);
+}
+
+#[test]
+fn new_ed25519() {
+    new_rejects_bad_input::<ark_ed25519::EdwardsProjective>(10);
+}
+
+#[test]
+fn random_position_secp256k1() {
+    places_at_random::<ark_secp256k1::Projective>(16);
+}
+
+#[test]
+fn random_position_ed25519() {
+    places_at_random::<ark_ed25519::EdwardsProjective>(17);
+}
+
+#[test]
+fn from_pubkeys_secp256k1() {
+    splices_at_every_index::<ark_secp256k1::Projective>(1);
+    rejects_bad_input::<ark_secp256k1::Projective>(2);
+}
+
+#[test]
+fn from_pubkeys_ed25519() {
+    splices_at_every_index::<ark_ed25519::EdwardsProjective>(3);
+    rejects_bad_input::<ark_ed25519::EdwardsProjective>(4);
+}
+
+#[test]
+fn builder_secp256k1() {
+    builds_with_signer_tracked::<ark_secp256k1::Projective>(5);
+    builder_rejects_bad_input::<ark_secp256k1::Projective>(6);
+}
+
+#[test]
+fn builder_ed25519() {
+    builds_with_signer_tracked::<ark_ed25519::EdwardsProjective>(7);
+    builder_rejects_bad_input::<ark_ed25519::EdwardsProjective>(8);
+}
+
+#[test]
+fn validation_secp256k1() {
+    let generator = ark_secp256k1::Affine::generator();
+    let off_curve = ark_secp256k1::Affine::new_unchecked(
+        generator.x,
+        generator.y + ark_secp256k1::Fq::from(1u64),
+    );
+    let error = Ring::<ark_secp256k1::Projective>::from_fixed_pubkeys(vec![
+        generator,
+        ark_secp256k1::Affine::zero(),
+        generator,
+        off_curve,
+    ])
+    .err()
+    .unwrap();
+    assert_eq!(error, RingError::InvalidPublicKeys(vec![1, 3]));
+    assert_eq!(error.to_string(), "invalid public keys at indices 1, 3");
+    assert_eq!(
+        Ring::<ark_secp256k1::Projective>::from_fixed_pubkeys(vec![]).err(),
+        Some(RingError::EmptyRing)
+    );
+}
+
+#[test]
+fn validation_ed25519() {
+    let generator = ark_ed25519::EdwardsAffine::generator();
+    // (0, -1) is on the curve but of order 2
+    let small_order = ark_ed25519::EdwardsAffine::new_unchecked(
+        ark_ed25519::Fq::from(0u64),
+        -ark_ed25519::Fq::from(1u64),
+    );
+    let torsioned = (generator + small_order).into_affine();
+    assert!(torsioned.is_on_curve());
+    assert_eq!(
+        Ring::<ark_ed25519::EdwardsProjective>::from_fixed_pubkeys(vec![
+            small_order,
+            generator,
+            torsioned,
+            ark_ed25519::EdwardsAffine::zero(),
+        ])
+        .err(),
+        Some(RingError::InvalidPublicKeys(vec![0, 2, 3]))
+    );
+}
+
+#[test]
+fn eth_addresses() {
+    let mut rng = StdRng::seed_from_u64(11);
+    // the address of private key 1
+    let mut one = [0; 32];
+    one[31] = 1;
+    let pair = KeyPair::<ark_secp256k1::Projective>::try_from(&one).unwrap();
+    assert_eq!(
+        secp256k1::eth_address(pair.public_key()),
+        [
+            0x7e, 0x5f, 0x45, 0x52, 0x09, 0x1a, 0x69, 0x12, 0x5d, 0x5d, 0xfc, 0xb7, 0xb8, 0xc2,
+            0x65, 0x90, 0x29, 0x39, 0x5b, 0xdf
+        ]
+    );
+
+    let others = decoys::<ark_secp256k1::Projective>(3, &mut rng);
+    let addresses: Vec<[u8; 20]> = others
+        .iter()
+        .chain([pair.public_key()])
+        .map(secp256k1::eth_address)
+        .collect();
+    // candidates in any order, with strangers among them
+    let stranger = decoys::<ark_secp256k1::Projective>(1, &mut rng)[0];
+    let ring = Ring::from_eth_addresses(
+        &addresses,
+        [
+            *pair.public_key(),
+            others[2],
+            stranger,
+            others[0],
+            others[1],
+        ],
+    )
+    .unwrap();
+    assert_eq!(ring.eth_addresses(), addresses);
+    assert_eq!(&ring.keys()[3], pair.public_key());
+    ring.sign_with_rng(MESSAGE, pair.private_key(), 3, &mut rng)
+        .unwrap()
+        .verify(MESSAGE)
+        .unwrap();
+
+    let error = Ring::from_eth_addresses(&addresses, [*pair.public_key(), others[0]])
+        .err()
+        .unwrap();
+    assert_eq!(error, RingError::UnresolvedAddresses(vec![1, 2]));
+    assert_eq!(
+        error.to_string(),
+        "no public key for addresses at indices 1, 2"
+    );
+}
+
+fn canonicalizes<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(9, &secret, 6, &mut rng).unwrap();
+    let (canonical, index) = ring.canonicalize(6).unwrap();
+    assert!(canonical.is_canonical());
+    assert_eq!(canonical.keys()[index], public_key(&secret));
+    assert_eq!(canonical.canonicalize(index).unwrap().1, index);
+    canonical
+        .sign_with_rng(MESSAGE, &secret, index, &mut rng)
+        .unwrap()
+        .verify(MESSAGE)
+        .unwrap();
+
+    let mut reversed = ring.keys().to_vec();
+    reversed.reverse();
+    let (again, moved) = Ring::<C>::from_fixed_pubkeys(reversed)
+        .unwrap()
+        .canonicalize(2)
+        .unwrap();
+    assert!(again == canonical);
+    assert_eq!(moved, index);
+
+    let mut repeated = ring.keys().to_vec();
+    repeated.push(repeated[1]);
+    repeated.push(repeated[3]);
+    assert_eq!(
+        Ring::<C>::from_fixed_pubkeys(repeated)
+            .unwrap()
+            .canonicalize(0)
+            .err(),
+        Some(RingError::DuplicatePublicKeys(vec![9, 10]))
+    );
+    assert_eq!(
+        ring.canonicalize(9).err(),
+        Some(RingError::IndexOutOfBounds { index: 9, size: 9 })
+    );
+}
+
+#[test]
+fn canonical_secp256k1() {
+    canonicalizes::<ark_secp256k1::Projective>(12);
+}
+
+#[test]
+fn canonical_ed25519() {
+    canonicalizes::<ark_ed25519::EdwardsProjective>(13);
+}
+
+fn imports_in_bulk<C: SpkiCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 1, &mut rng).unwrap();
+
+    let hexes: Vec<String> = ring.keys().iter().map(public_key_to_hex::<C>).collect();
+    assert!(Ring::<C>::from_hex_keys(&hexes).unwrap() == ring);
+    let list = format!(
+        "0x{},{}\n {} ; {}\n\n",
+        hexes[0], hexes[1], hexes[2], hexes[3]
+    );
+    assert!(Ring::<C>::from_hex_list(&list).unwrap() == ring);
+    let broken = [&hexes[0], "zz", &hexes[2], "00"];
+    assert_eq!(
+        Ring::<C>::from_hex_keys(&broken).err(),
+        Some(RingError::InvalidPublicKeys(vec![1, 3]))
+    );
+    assert_eq!(
+        Ring::<C>::from_hex_list(" ,\n").err(),
+        Some(RingError::EmptyRing)
+    );
+
+    let bundle: String = ring
+        .keys()
+        .iter()
+        .map(|key| format!("# member\n{}", public_key_to_pem::<C>(key)))
+        .collect();
+    assert!(Ring::<C>::from_pem_bundle(&bundle).unwrap() == ring);
+    assert_eq!(
+        Ring::<C>::from_pem_bundle(&bundle)
+            .unwrap()
+            .public_key_pems()
+            .concat(),
+        bundle.replace("# member\n", "")
+    );
+    let broken = bundle.replacen("KEY-----\nM", "KEY-----\n!", 1);
+    assert_eq!(
+        Ring::<C>::from_pem_bundle(&broken).err(),
+        Some(RingError::InvalidPublicKeys(vec![0]))
+    );
+    assert_eq!(
+        Ring::<C>::from_pem_bundle(&bundle[..bundle.len() - 10]).err(),
+        Some(RingError::InvalidPublicKeys(vec![3]))
+    );
+    assert_eq!(
+        Ring::<C>::from_pem_bundle("no blocks here").err(),
+        Some(RingError::EmptyRing)
+    );
+}
+
+#[test]
+fn bulk_secp256k1() {
+    imports_in_bulk::<ark_secp256k1::Projective>(14);
+}
+
+#[test]
+fn bulk_ed25519() {
+    imports_in_bulk::<ark_ed25519::EdwardsProjective>(15);
+}
@@ -0,0 +1,72 @@
+// Ristretto255 encoding of ed25519 points: the RFC 9496 generator multiples, torsion-shifted
+// points encoding like the clean ones, invalid encodings rejected, and rings carried through
+// their Ristretto keys.
+
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ed25519::{EdwardsAffine, EdwardsProjective, Fq, Fr};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::ring::Ring;
+use lingo::ristretto::{RistrettoRing, decode, encode};
+use lingo::secret::SecretKey;
+
+// RFC 9496 appendix A.1: the encodings of 0, B, 2B and 3B
+const MULTIPLES: [&str; 4] = [
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+    "6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919",
+    "94741f5d5d52755ece4f23f044ee27d5d1ea1e2bd196b462166b16152a9d0259",
+];
+
+fn unhex(hex: &str) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+#[test]
+fn generator_multiples() {
+    for (k, expected) in MULTIPLES.iter().enumerate() {
+        let point = (EdwardsProjective::generator() * Fr::from(k as u64)).into_affine();
+        assert_eq!(encode(&point), unhex(expected));
+        assert_eq!(decode(&unhex(expected)), Some(point));
+    }
+}
+
+#[test]
+fn torsion_is_invisible() {
+    // (0, -1) has order two
+    let torsion = EdwardsAffine::new_unchecked(Fq::from(0u64), -Fq::from(1u64));
+    let point = (EdwardsProjective::generator() * Fr::from(7u64)).into_affine();
+    let shifted = (point + torsion).into_affine();
+    assert_ne!(shifted, point);
+    assert_eq!(encode(&shifted), encode(&point));
+    assert_eq!(decode(&encode(&shifted)), Some(point));
+}
+
+#[test]
+fn rejects_invalid_encodings() {
+    assert_eq!(decode(&[0xff; 32]), None);
+    // negative (odd) field elements are never canonical
+    let mut odd = unhex(MULTIPLES[1]);
+    odd[0] |= 1;
+    assert_eq!(decode(&odd), None);
+}
+
+#[test]
+fn rings_through_ristretto_keys() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let secret = SecretKey::generate(&mut rng);
+    let ring = Ring::<EdwardsProjective>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+    let decoded = RistrettoRing::from_ristretto_keys(&ring.ristretto_keys()).unwrap();
+    assert!(decoded == ring);
+    assert!(
+        decoded
+            .sign_linkable_with_rng(b"lingo ristretto", &secret, 1, &mut rng)
+            .unwrap()
+            .verify(b"lingo ristretto")
+            .is_ok()
+    );
+    assert!(RistrettoRing::from_ristretto_keys(&[[0xff; 32]]).is_none());
+}
@@ -0,0 +1,192 @@
+// Injected randomness: ring construction, signing and proving with `*_with_rng` must draw every
+// random choice from the generator they are given, so two runs from the same seed produce the
+// same ring or signature, runs from different seeds do not, and either way the result verifies.
+// Streamed and SSHSIG-style file signatures take their nonces from an injected generator too.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::challenge::Blake2b;
+use lingo::external::SoftwareSigner;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::sshsig::{FileSignature, HashAlgorithm};
+use lingo::stream;
+use lingo::wire::WireCurve;
+use lingo::{one_of_many, triptych};
+use std::io::Write;
+
+const MESSAGE: &[u8] = b"lingo rng";
+
+fn seeded(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+fn repeats_rings<C: CurveGroup>(seed: u64) {
+    let secret = SecretKey::<C>::generate(&mut seeded(seed));
+    let ring = Ring::<C>::new_with_rng(4, &secret, 1, &mut seeded(seed)).unwrap();
+    assert!(ring == Ring::<C>::new_with_rng(4, &secret, 1, &mut seeded(seed)).unwrap());
+    assert!(ring != Ring::<C>::new_with_rng(4, &secret, 1, &mut seeded(seed + 1)).unwrap());
+
+    let (ring, index) =
+        Ring::<C>::new_at_random_position_with_rng(4, &secret, &mut seeded(seed)).unwrap();
+    let (again, same) =
+        Ring::<C>::new_at_random_position_with_rng(4, &secret, &mut seeded(seed)).unwrap();
+    assert!(ring == again);
+    assert_eq!(index, same);
+}
+
+fn repeats_signatures<C: CurveGroup>(seed: u64) {
+    let secret = SecretKey::<C>::generate(&mut seeded(seed));
+    let ring = Ring::<C>::new_with_rng(4, &secret, 1, &mut seeded(seed)).unwrap();
+
+    let plain = ring
+        .sign_with_rng(MESSAGE, &secret, 1, &mut seeded(seed))
+        .unwrap();
+    assert!(plain.verify(MESSAGE).is_ok());
+    assert!(
+        plain
+            == ring
+                .sign_with_rng(MESSAGE, &secret, 1, &mut seeded(seed))
+                .unwrap()
+    );
+    assert!(
+        plain
+            != ring
+                .sign_with_rng(MESSAGE, &secret, 1, &mut seeded(seed + 1))
+                .unwrap()
+    );
+
+    let linkable = ring
+        .sign_linkable_with_rng(MESSAGE, &secret, 1, &mut seeded(seed))
+        .unwrap();
+    assert!(linkable.verify(MESSAGE).is_ok());
+    assert!(
+        linkable
+            == ring
+                .sign_linkable_with_rng(MESSAGE, &secret, 1, &mut seeded(seed))
+                .unwrap()
+    );
+
+    let blake2b = ring
+        .sign_with_hash_and_rng::<Blake2b, _>(MESSAGE, &secret, 1, &mut seeded(seed))
+        .unwrap();
+    assert!(blake2b.verify(MESSAGE).is_ok());
+    assert!(
+        blake2b
+            == ring
+                .sign_with_hash_and_rng::<Blake2b, _>(MESSAGE, &secret, 1, &mut seeded(seed))
+                .unwrap()
+    );
+
+    let mut signer = SoftwareSigner::<C, _>::with_rng(&secret, seeded(seed));
+    let external = ring
+        .sign_external_with_rng(MESSAGE, &mut signer, 1, &mut seeded(seed))
+        .unwrap();
+    assert!(external.verify(MESSAGE).is_ok());
+    let mut signer = SoftwareSigner::<C, _>::with_rng(&secret, seeded(seed));
+    let again = ring
+        .sign_external_with_rng(MESSAGE, &mut signer, 1, &mut seeded(seed))
+        .unwrap();
+    assert!(external == again);
+}
+
+fn repeats_streams<C: WireCurve>(seed: u64) {
+    let secret = SecretKey::<C>::generate(&mut seeded(seed));
+    let ring = Ring::<C>::new_with_rng(4, &secret, 2, &mut seeded(seed)).unwrap();
+
+    let streamed = |linkable: bool, seed: u64| {
+        let mut signer = stream::Signer::new(&ring, &secret, 2).unwrap();
+        signer.write_all(MESSAGE).unwrap();
+        if linkable {
+            signer
+                .finalize_linkable_with_rng(&mut seeded(seed))
+                .unwrap()
+        } else {
+            signer.finalize_with_rng(&mut seeded(seed)).unwrap()
+        }
+    };
+    for linkable in [false, true] {
+        let signature = streamed(linkable, seed);
+        let mut verifier = stream::Verifier::new(&signature);
+        verifier.update(MESSAGE);
+        assert!(verifier.finalize().is_ok());
+        assert!(signature == streamed(linkable, seed));
+        assert!(signature != streamed(linkable, seed + 1));
+
+        let signed = |seed: u64| {
+            FileSignature::sign_with_rng(
+                &ring,
+                MESSAGE,
+                "file",
+                HashAlgorithm::Sha512,
+                &secret,
+                2,
+                linkable,
+                &mut seeded(seed),
+            )
+            .unwrap()
+        };
+        let file = signed(seed);
+        assert!(file.verify(MESSAGE, "file").is_ok());
+        assert_eq!(file.to_bytes(), signed(seed).to_bytes());
+        assert_ne!(file.to_bytes(), signed(seed + 1).to_bytes());
+    }
+}
+
+fn repeats_proofs<C: CurveGroup>(seed: u64) {
+    let secret = SecretKey::<C>::generate(&mut seeded(seed));
+    let ring = Ring::<C>::new_with_rng(8, &secret, 3, &mut seeded(seed)).unwrap();
+
+    let proof = one_of_many::prove_with_rng(&ring, MESSAGE, &secret, 3, &mut seeded(seed)).unwrap();
+    assert!(proof.verify(MESSAGE).is_ok());
+    assert!(
+        proof
+            == one_of_many::prove_with_rng(&ring, MESSAGE, &secret, 3, &mut seeded(seed)).unwrap()
+    );
+
+    let proof = triptych::prove_with_rng(&ring, MESSAGE, &secret, 3, &mut seeded(seed)).unwrap();
+    assert!(proof.verify(MESSAGE).is_ok());
+    assert!(
+        proof == triptych::prove_with_rng(&ring, MESSAGE, &secret, 3, &mut seeded(seed)).unwrap()
+    );
+}
+
+#[test]
+fn rings_secp256k1() {
+    repeats_rings::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn rings_ed25519() {
+    repeats_rings::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn signatures_secp256k1() {
+    repeats_signatures::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn signatures_ed25519() {
+    repeats_signatures::<ark_ed25519::EdwardsProjective>(4);
+}
+
+#[test]
+fn streams_secp256k1() {
+    repeats_streams::<ark_secp256k1::Projective>(7);
+}
+
+#[test]
+fn streams_ed25519() {
+    repeats_streams::<ark_ed25519::EdwardsProjective>(8);
+}
+
+#[test]
+fn proofs_secp256k1() {
+    repeats_proofs::<ark_secp256k1::Projective>(5);
+}
+
+#[test]
+fn proofs_ed25519() {
+    repeats_proofs::<ark_ed25519::EdwardsProjective>(6);
+}
@@ -0,0 +1,163 @@
+// SAG and bLSAG signing and verification over several ring sizes, with the signer at every
+// position: each signature must verify against its own message and no other, and a bLSAG key
+// image must depend on the signer's key alone, so that `links_with` pairs up exactly the
+// signatures one key made. Deterministic signing must repeat itself exactly for the same inputs
+// and only for them.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo sag";
+
+fn signs_at_every_index<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 2, 5] {
+        for index in 0..size {
+            let ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+            let signature = ring
+                .sign_with_rng(MESSAGE, &secret, index, &mut rng)
+                .unwrap();
+            assert!(signature.verify(MESSAGE).is_ok());
+            assert!(signature.verify(b"lingo sah").is_err());
+            assert!(signature.verify(b"").is_err());
+        }
+    }
+}
+
+fn links_by_key_image<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 2, 5] {
+        for index in 0..size {
+            let ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+            let signature = ring
+                .sign_linkable_with_rng(MESSAGE, &secret, index, &mut rng)
+                .unwrap();
+            assert!(signature.verify(MESSAGE).is_ok());
+            assert!(signature.verify(b"lingo sah").is_err());
+
+            // the same key in another ring, over another message, links
+            let elsewhere = Ring::<C>::new_with_rng(size + 1, &secret, 0, &mut rng).unwrap();
+            let again = elsewhere
+                .sign_linkable_with_rng(b"elsewhere", &secret, 0, &mut rng)
+                .unwrap();
+            assert!(again.verify(b"elsewhere").is_ok());
+            assert_eq!(signature.image, again.image);
+
+            let stranger = Ring::<C>::new_with_rng(size, &other, index, &mut rng).unwrap();
+            let unlinked = stranger
+                .sign_linkable_with_rng(MESSAGE, &other, index, &mut rng)
+                .unwrap();
+            assert_ne!(signature.image, unlinked.image);
+
+            // a SAG signature carries no image at all
+            let plain = ring
+                .sign_with_rng(MESSAGE, &secret, index, &mut rng)
+                .unwrap();
+            assert!(plain.image.is_none());
+        }
+    }
+}
+
+fn links_with<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 0, &mut rng).unwrap();
+    let elsewhere = Ring::<C>::new_with_rng(2, &secret, 1, &mut rng).unwrap();
+    let theirs = Ring::<C>::new_with_rng(3, &other, 0, &mut rng).unwrap();
+
+    let first = ring
+        .sign_linkable_with_rng(b"first", &secret, 0, &mut rng)
+        .unwrap();
+    let second = elsewhere
+        .sign_linkable_with_rng(b"second", &secret, 1, &mut rng)
+        .unwrap();
+    let stranger = theirs
+        .sign_linkable_with_rng(b"first", &other, 0, &mut rng)
+        .unwrap();
+    let plain = ring.sign_with_rng(b"first", &secret, 0, &mut rng).unwrap();
+
+    assert!(first.links_with(&second) && second.links_with(&first));
+    assert!(first.detach().links_with(&second.detach()));
+    assert!(!first.links_with(&stranger));
+    // without a key image there is nothing to link on, not even to itself
+    assert!(!first.links_with(&plain));
+    assert!(!plain.links_with(&plain));
+}
+
+fn signs_deterministically<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 3, &mut rng).unwrap();
+    let reordered = Ring::<C>::new_with_rng(4, &secret, 3, &mut rng).unwrap();
+
+    let plain = ring.sign_deterministic(MESSAGE, &secret, 3).unwrap();
+    assert!(plain.verify(MESSAGE).is_ok());
+    assert!(plain == ring.sign_deterministic(MESSAGE, &secret, 3).unwrap());
+    assert!(plain != ring.sign_deterministic(b"lingo sah", &secret, 3).unwrap());
+    assert!(
+        plain.ring_sig_vals
+            != reordered
+                .sign_deterministic(MESSAGE, &secret, 3)
+                .unwrap()
+                .ring_sig_vals
+    );
+
+    let linkable = ring
+        .sign_linkable_deterministic(MESSAGE, &secret, 3)
+        .unwrap();
+    assert!(linkable.verify(MESSAGE).is_ok());
+    assert!(
+        linkable
+            == ring
+                .sign_linkable_deterministic(MESSAGE, &secret, 3)
+                .unwrap()
+    );
+    // the SAG and bLSAG nonces come from different domains
+    assert_ne!(linkable.ring_sig_vals, plain.ring_sig_vals);
+}
+
+#[test]
+fn sag_secp256k1() {
+    signs_at_every_index::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn sag_ed25519() {
+    signs_at_every_index::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn blsag_secp256k1() {
+    links_by_key_image::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn blsag_ed25519() {
+    links_by_key_image::<ark_ed25519::EdwardsProjective>(4);
+}
+
+#[test]
+fn links_secp256k1() {
+    links_with::<ark_secp256k1::Projective>(5);
+}
+
+#[test]
+fn links_ed25519() {
+    links_with::<ark_ed25519::EdwardsProjective>(6);
+}
+
+#[test]
+fn deterministic_secp256k1() {
+    signs_deterministically::<ark_secp256k1::Projective>(7);
+}
+
+#[test]
+fn deterministic_ed25519() {
+    signs_deterministically::<ark_ed25519::EdwardsProjective>(8);
+}
@@ -0,0 +1,59 @@
+// SAG, bLSAG and CLSAG driven through `RingScheme` alone, the way generic downstream code uses
+// them: sign, verify and read the key image without naming the scheme.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::VerifyError;
+use lingo::mlsag::MatrixRing;
+use lingo::ring::Ring;
+use lingo::scheme::{Blsag, Clsag, RingScheme, Sag};
+use lingo::secret::SecretKey;
+
+const MESSAGE: &[u8] = b"lingo scheme";
+
+fn round_trip<C: CurveGroup, S: RingScheme<C>>(
+    ring: &S::Ring,
+    private_key: &S::PrivateKey,
+    index: usize,
+    rng: &mut StdRng,
+) -> S::KeyImage {
+    let signature = S::sign_with_rng(ring, MESSAGE, private_key, index, rng).unwrap();
+    assert!(S::verify(&signature, MESSAGE).is_ok());
+    assert!(S::verify(&signature, b"lingo schemf").is_err());
+    S::key_image(&signature)
+}
+
+fn every_scheme<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 0, &mut rng).unwrap();
+
+    round_trip::<C, Sag>(&ring, &secret, 0, &mut rng);
+    let first = round_trip::<C, Blsag>(&ring, &secret, 0, &mut rng);
+    let second = round_trip::<C, Blsag>(&ring, &secret, 0, &mut rng);
+    assert_eq!(first, second);
+
+    // a SAG signature is no bLSAG signature
+    let plain =
+        <Sag as RingScheme<C>>::sign_with_rng(&ring, MESSAGE, &secret, 0, &mut rng).unwrap();
+    assert_eq!(
+        <Blsag as RingScheme<C>>::verify(&plain, MESSAGE),
+        Err(VerifyError::InvalidKeyImage)
+    );
+
+    let layer = Ring::<C>::new_with_rng(3, &other, 0, &mut rng).unwrap();
+    let matrix = MatrixRing::from_layers(vec![ring, layer]).unwrap();
+    let image = round_trip::<C, Clsag>(&matrix, &[secret, other][..], 0, &mut rng);
+    assert_eq!(image, first);
+}
+
+#[test]
+fn scheme_secp256k1() {
+    every_scheme::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn scheme_ed25519() {
+    every_scheme::<ark_ed25519::EdwardsProjective>(2);
+}
@@ -0,0 +1,113 @@
+// Tracing spans, captured by a minimal subscriber: ring construction, signing, verification and
+// batch verification must each open a span under its own name, carrying the curve, the ring size
+// or batch length and, for signatures, whether they are linkable and their hash id. No span may
+// record anything else, in particular not the signer's position.
+
+#![cfg(feature = "tracing")]
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::ring::{Ring, RingSignature};
+use lingo::secret::SecretKey;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+const MESSAGE: &[u8] = b"lingo spans";
+
+// every span opened, as its name and its fields in order
+type Spans = Arc<Mutex<Vec<(&'static str, Vec<(&'static str, String)>)>>>;
+
+struct Recorder {
+    spans: Spans,
+}
+
+struct Fields(Vec<(&'static str, String)>);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push((field.name(), format!("{value:?}")));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields(Vec::new());
+        span.record(&mut fields);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push((span.metadata().name(), fields.0));
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn records_spans<C: CurveGroup>(seed: u64) {
+    let spans = Spans::default();
+    let recorder = Recorder {
+        spans: spans.clone(),
+    };
+    tracing::subscriber::with_default(recorder, || {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let secret = SecretKey::<C>::generate(&mut rng);
+        let ring = Ring::<C>::new_with_rng(3, &secret, 2, &mut rng).unwrap();
+        let signature = ring
+            .sign_linkable_with_rng(MESSAGE, &secret, 2, &mut rng)
+            .unwrap();
+        assert!(signature.verify(MESSAGE).is_ok());
+        assert!(RingSignature::batch_verify(&[(MESSAGE, &signature)]).is_ok());
+    });
+
+    let spans = spans.lock().unwrap();
+    let curve = format!("{:?}", core::any::type_name::<C>());
+    let named = |name| spans.iter().filter(move |(span, _)| *span == name);
+    assert!(named("lingo.ring").count() >= 1);
+    for (_, fields) in named("lingo.ring") {
+        assert_eq!(fields, &[("curve", curve.clone()), ("size", "3".into())]);
+    }
+    for name in ["lingo.sign", "lingo.verify"] {
+        let fields: Vec<_> = named(name).map(|(_, fields)| fields).collect();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(
+            fields[0],
+            &[
+                ("curve", curve.clone()),
+                ("size", "3".into()),
+                ("linkable", "true".into()),
+                ("hash_id", signature_hash_id()),
+            ]
+        );
+    }
+    let batches: Vec<_> = named("lingo.batch_verify").collect();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].1, [("curve", curve), ("count", "1".into())]);
+}
+
+// SHA-512, the default challenge hash
+fn signature_hash_id() -> String {
+    lingo::challenge::hash_id("sha512").unwrap().to_string()
+}
+
+#[test]
+fn spans_secp256k1() {
+    records_spans::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn spans_ed25519() {
+    records_spans::<ark_ed25519::EdwardsProjective>(2);
+}
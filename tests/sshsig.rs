@@ -0,0 +1,151 @@
+// SSHSIG-style file signatures: a file signed under a namespace must verify over the same bytes,
+// namespace and ring only, with its armor wrapped at 70 columns like `ssh-keygen -Y sign`
+// output. The blob must read back equal to what was written and name its curve, hash and
+// version, refusing any it does not know.
+
+use ark_ec::CurveGroup;
+use lingo::error::{DecodeError, VerifyError};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::sshsig::{self, FileSignature, HashAlgorithm};
+use lingo::wire::WireCurve;
+use std::io::ErrorKind;
+
+const MESSAGE: &[u8] = b"lingo sshsig";
+
+fn signs_files<C: WireCurve>(name: &str) {
+    let mut rng = ark_std::rand::thread_rng();
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new(5, &secret, 2).unwrap();
+    let other = Ring::<C>::new(5, &secret, 2).unwrap();
+    let path = std::env::temp_dir().join(format!("lingo-sshsig-{name}-{}", std::process::id()));
+    std::fs::write(&path, MESSAGE).unwrap();
+
+    for linkable in [false, true] {
+        let armor = sshsig::sign_file(&ring, &path, "file", &secret, 2, linkable).unwrap();
+        let lines: Vec<&str> = armor.lines().collect();
+        assert_eq!(lines[0], "-----BEGIN LINGO SIGNATURE-----");
+        assert_eq!(lines[lines.len() - 1], "-----END LINGO SIGNATURE-----");
+        assert!(lines.iter().all(|line| line.len() <= 70));
+
+        let signature = sshsig::verify_file(&ring, &path, &armor, "file").unwrap();
+        assert_eq!(signature.namespace(), "file");
+        assert_eq!(signature.hash_algorithm(), HashAlgorithm::Sha512);
+        assert_eq!(signature.signature().image.is_some(), linkable);
+
+        let error = sshsig::verify_file(&ring, &path, &armor, "git")
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        let error = sshsig::verify_file(&other, &path, &armor, "file")
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    let armor = sshsig::sign_file(&ring, &path, "file", &secret, 2, false).unwrap();
+    std::fs::write(&path, b"lingo sshsih").unwrap();
+    let error = sshsig::verify_file(&ring, &path, &armor, "file")
+        .err()
+        .unwrap();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    std::fs::remove_file(&path).unwrap();
+    let error = sshsig::verify_file(&ring, &path, &armor, "file")
+        .err()
+        .unwrap();
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+}
+
+fn round_trips<C: WireCurve, Other: WireCurve>() {
+    let mut rng = ark_std::rand::thread_rng();
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new(3, &secret, 0).unwrap();
+    for hash in [HashAlgorithm::Sha256, HashAlgorithm::Sha512] {
+        let signature = FileSignature::sign(&ring, MESSAGE, "ns", hash, &secret, 0, true).unwrap();
+        let bytes = signature.to_bytes();
+        assert_eq!(&bytes[..6], b"LNGSIG");
+        let read = FileSignature::<C>::from_bytes(&bytes).unwrap();
+        assert_eq!(read.to_bytes(), bytes);
+        assert_eq!(read.hash_algorithm(), hash);
+        assert!(read.verify(MESSAGE, "ns").is_ok());
+        assert!(read.verify(&b"lingo sshsih"[..], "ns").is_err());
+        assert_eq!(
+            read.verify_digest(&hash.digest_reader(MESSAGE).unwrap(), "other"),
+            Err(VerifyError::WrongNamespace)
+        );
+        let read = FileSignature::<C>::from_armor(&signature.to_armor()).unwrap();
+        assert_eq!(read.to_bytes(), bytes);
+
+        assert!(FileSignature::<Other>::from_bytes(&bytes).is_err());
+        assert_eq!(
+            FileSignature::<C>::from_bytes(&bytes[..bytes.len() - 1]).err(),
+            Some(DecodeError::Truncated)
+        );
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert_eq!(
+            FileSignature::<C>::from_bytes(&extended).err(),
+            Some(DecodeError::TrailingBytes)
+        );
+        let mut versioned = bytes.clone();
+        versioned[9] = 2;
+        assert_eq!(
+            FileSignature::<C>::from_bytes(&versioned).err(),
+            Some(DecodeError::UnsupportedVersion(2))
+        );
+        let mut renamed = bytes.clone();
+        renamed[0] = b'S';
+        assert_eq!(
+            FileSignature::<C>::from_bytes(&renamed).err(),
+            Some(DecodeError::BadMagic)
+        );
+    }
+}
+
+// the magic, version and namespace, then the empty reserved string and the hash name
+fn rejects_unknown_hashes<C: CurveGroup + WireCurve>() {
+    let mut rng = ark_std::rand::thread_rng();
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new(2, &secret, 1).unwrap();
+    let signature = FileSignature::sign(
+        &ring,
+        MESSAGE,
+        "ns",
+        HashAlgorithm::Sha256,
+        &secret,
+        1,
+        false,
+    )
+    .unwrap();
+    let bytes = signature.to_bytes();
+    let name = 6 + 4 + 4 + 2 + 4 + 4;
+    assert_eq!(&bytes[name..name + 6], b"sha256");
+    let mut unknown = bytes.clone();
+    unknown[name + 3..name + 6].copy_from_slice(b"384");
+    assert_eq!(
+        FileSignature::<C>::from_bytes(&unknown).err(),
+        Some(DecodeError::UnknownHash("sha384".to_string()))
+    );
+}
+
+#[test]
+fn files_secp256k1() {
+    signs_files::<ark_secp256k1::Projective>("secp256k1");
+}
+
+#[test]
+fn files_ed25519() {
+    signs_files::<ark_ed25519::EdwardsProjective>("ed25519");
+}
+
+#[test]
+fn blobs_secp256k1() {
+    round_trips::<ark_secp256k1::Projective, ark_ed25519::EdwardsProjective>();
+    rejects_unknown_hashes::<ark_secp256k1::Projective>();
+}
+
+#[test]
+fn blobs_ed25519() {
+    round_trips::<ark_ed25519::EdwardsProjective, ark_secp256k1::Projective>();
+    rejects_unknown_hashes::<ark_ed25519::EdwardsProjective>();
+}
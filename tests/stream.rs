@@ -0,0 +1,67 @@
+// Streamed signing and verification: a message fed in chunks of any size, through `update` or
+// `io::Write`, must verify however the verifier chunks it, and only through the streaming
+// verifier.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::RingError;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::stream::{Signer, Verifier};
+use std::io::{self, Write};
+
+const MESSAGE: &[u8] = b"lingo stream, long enough to be cut into a few uneven chunks";
+
+fn streams<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 2, &mut rng).unwrap();
+
+    let mut signer = Signer::new(&ring, &secret, 2).unwrap();
+    signer.update(&MESSAGE[..7]);
+    signer.write_all(&MESSAGE[7..]).unwrap();
+    let linkable = signer.finalize_linkable().unwrap();
+    assert!(linkable.key_image().is_some());
+
+    let mut signer = Signer::new(&ring, &secret, 2).unwrap();
+    io::copy(&mut &MESSAGE[..], &mut signer).unwrap();
+    let plain = signer.finalize().unwrap();
+    assert!(plain.key_image().is_none());
+
+    for signature in [&linkable, &plain] {
+        for chunk in [1, 5, MESSAGE.len()] {
+            let mut verifier = Verifier::new(signature);
+            for piece in MESSAGE.chunks(chunk) {
+                verifier.write_all(piece).unwrap();
+            }
+            assert!(verifier.finalize().is_ok());
+        }
+
+        let mut truncated = Verifier::new(signature);
+        truncated.update(&MESSAGE[..MESSAGE.len() - 1]);
+        assert!(truncated.finalize().is_err());
+        assert!(signature.verify(MESSAGE).is_err());
+    }
+}
+
+#[test]
+fn stream_secp256k1() {
+    streams::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn stream_ed25519() {
+    streams::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn rejects_wrong_signer_up_front() {
+    type C = ark_secp256k1::Projective;
+    let mut rng = StdRng::seed_from_u64(3);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 2, &mut rng).unwrap();
+    assert_eq!(
+        Signer::new(&ring, &secret, 1).err(),
+        Some(RingError::KeyMismatch)
+    );
+}
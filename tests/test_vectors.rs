@@ -0,0 +1,106 @@
+// Known-answer test files: generating the same cases from the same seed must give the same
+// JSON byte for byte, and a parsed file must check against the curve it was made on and no
+// other. A vector whose seed no longer reproduces its signature is reported by its index. The
+// `lingo-vectors` binary must write the same files on every run, each checking on its curve.
+
+#![cfg(feature = "json")]
+
+use lingo::error::VectorError;
+use lingo::test_vectors::{VectorCase, VectorFile};
+use lingo::wire::WireCurve;
+
+const CASES: &[VectorCase<'static>] = &[
+    VectorCase {
+        message: b"",
+        ring_size: 1,
+        secret_index: 0,
+    },
+    VectorCase {
+        message: b"lingo test vectors",
+        ring_size: 4,
+        secret_index: 3,
+    },
+    VectorCase {
+        message: b"abc",
+        ring_size: 11,
+        secret_index: 5,
+    },
+];
+
+fn round_trips<C: WireCurve, Other: WireCurve>() {
+    for (linkable, scheme) in [(false, "sag"), (true, "blsag")] {
+        let file = VectorFile::generate::<C>(linkable, CASES, b"seed");
+        assert_eq!(file.curve(), C::CURVE_NAME);
+        assert_eq!(file.scheme(), scheme);
+        assert_eq!(file.len(), CASES.len());
+        let json = file.to_json();
+        assert_eq!(
+            json,
+            VectorFile::generate::<C>(linkable, CASES, b"seed").to_json()
+        );
+        assert_ne!(
+            json,
+            VectorFile::generate::<C>(linkable, CASES, b"seed2").to_json()
+        );
+
+        let parsed = VectorFile::from_json(&json).unwrap();
+        assert!(parsed.check::<C>().is_ok());
+        assert!(parsed.check::<Other>().is_err());
+
+        // a different seed for the first vector signs differently
+        let tampered = json.replacen("\"seed\": \"", "\"seed\": \"00", 1);
+        assert!(matches!(
+            VectorFile::from_json(&tampered).unwrap().check::<C>(),
+            Err(VectorError::SignatureMismatch(0))
+        ));
+    }
+    assert!(matches!(
+        VectorFile::from_json("{}"),
+        Err(VectorError::InvalidFile)
+    ));
+}
+
+#[test]
+fn vectors_secp256k1() {
+    round_trips::<ark_secp256k1::Projective, ark_ed25519::EdwardsProjective>();
+}
+
+#[test]
+fn vectors_ed25519() {
+    round_trips::<ark_ed25519::EdwardsProjective, ark_secp256k1::Projective>();
+}
+
+#[cfg(feature = "interop")]
+#[test]
+fn lingo_vectors() {
+    let dir = std::env::temp_dir().join(format!("lingo-vectors-{}", std::process::id()));
+    let run = |dir: &std::path::Path| {
+        let status = std::process::Command::new(env!("CARGO_BIN_EXE_lingo-vectors"))
+            .arg(dir)
+            .stdout(std::process::Stdio::null())
+            .status()
+            .unwrap();
+        assert!(status.success());
+    };
+    run(&dir.join("first"));
+    run(&dir.join("second"));
+
+    for scheme in ["sag", "blsag"] {
+        let name = format!(
+            "{}-{scheme}.json",
+            ark_ed25519::EdwardsProjective::CURVE_NAME
+        );
+        let first = std::fs::read_to_string(dir.join("first").join(&name)).unwrap();
+        let second = std::fs::read_to_string(dir.join("second").join(&name)).unwrap();
+        assert_eq!(first, second);
+        let file = VectorFile::from_json(&first).unwrap();
+        assert_eq!(file.scheme(), scheme);
+        assert!(file.check::<ark_ed25519::EdwardsProjective>().is_ok());
+
+        let name = format!("{}-{scheme}.json", ark_secp256k1::Projective::CURVE_NAME);
+        let file = std::fs::read_to_string(dir.join("first").join(&name)).unwrap();
+        let file = VectorFile::from_json(&file).unwrap();
+        assert!(file.check::<ark_secp256k1::Projective>().is_ok());
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
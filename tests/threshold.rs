@@ -0,0 +1,145 @@
+// Threshold sessions over one ring with every size of signer set: the coordinator's signature
+// must verify against its own message and no other, a bad response must be turned away, and the
+// signature must not pass for a smaller threshold than the one it was made for. A session refuses
+// a bad signer set, and every message from a non-signer or out of turn, without panicking.
+
+use ark_ec::CurveGroup;
+use ark_ff::{One, PrimeField};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::SessionError;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::threshold::{SignerNonce, ThresholdSession, ThresholdSignature};
+
+const MESSAGE: &[u8] = b"lingo threshold";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn signs_with_every_threshold<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secrets: Vec<SecretKey<C>> = (0..5).map(|_| SecretKey::generate(&mut rng)).collect();
+    let ring = Ring::<C>::from_fixed_pubkeys(secrets.iter().map(public_key).collect()).unwrap();
+
+    for signers in [vec![0], vec![1, 3], vec![0, 2, 4], vec![0, 1, 2, 3, 4]] {
+        let mut session =
+            ThresholdSession::new_with_rng(&ring, MESSAGE, &signers, &mut rng).unwrap();
+        assert_eq!(session.threshold(), signers.len());
+        let mut nonces = Vec::new();
+        for &index in &signers {
+            let (nonce, commitment) =
+                SignerNonce::commit_with_rng(&ring, index, &secrets[index], &mut rng).unwrap();
+            session.add_commitment(index, commitment).unwrap();
+            nonces.push(nonce);
+        }
+
+        for (nonce, (index, challenge)) in nonces.into_iter().zip(session.challenges().unwrap()) {
+            assert_eq!(nonce.index(), index);
+            let response = nonce.respond(challenge).unwrap();
+            let wrong = (<C::ScalarField as PrimeField>::from_bigint(response).unwrap()
+                + C::ScalarField::one())
+            .into_bigint();
+            assert_eq!(
+                session.add_response(index, wrong),
+                Err(SessionError::InvalidResponse(index))
+            );
+            assert!(session.add_response(index, response).is_ok());
+        }
+
+        let signature = session.finalize().unwrap();
+        assert!(signature.verify(MESSAGE).is_ok());
+        assert!(signature.verify(b"lingo thresholc").is_err());
+
+        if signers.len() > 1 {
+            let lowered = ThresholdSignature {
+                ring: &ring,
+                threshold: signers.len() - 1,
+                polynomial: signature.polynomial.clone(),
+                ring_sig_vals: signature.ring_sig_vals.clone(),
+            };
+            assert!(lowered.verify(MESSAGE).is_err());
+        }
+    }
+}
+
+fn refuses_out_of_turn<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secrets: Vec<SecretKey<C>> = (0..4).map(|_| SecretKey::generate(&mut rng)).collect();
+    let ring = Ring::<C>::from_fixed_pubkeys(secrets.iter().map(public_key).collect()).unwrap();
+
+    for signers in [&[][..], &[0, 0], &[4], &[0, 1, 2, 3, 0]] {
+        assert_eq!(
+            ThresholdSession::new_with_rng(&ring, MESSAGE, signers, &mut rng).err(),
+            Some(SessionError::InvalidSigners)
+        );
+    }
+
+    let mut session = ThresholdSession::new_with_rng(&ring, MESSAGE, &[1, 2], &mut rng).unwrap();
+    let (first, commitment) =
+        SignerNonce::commit_with_rng(&ring, 1, &secrets[1], &mut rng).unwrap();
+    assert_eq!(
+        session.add_commitment(0, commitment),
+        Err(SessionError::NotASigner(0))
+    );
+    session.add_commitment(1, commitment).unwrap();
+    assert_eq!(
+        session.add_commitment(1, commitment),
+        Err(SessionError::AlreadyCommitted(1))
+    );
+    assert_eq!(
+        session.add_response(1, C::ScalarField::one().into_bigint()),
+        Err(SessionError::OutOfOrder)
+    );
+    assert_eq!(
+        session.challenges(),
+        Err(SessionError::MissingCommitments(vec![2]))
+    );
+
+    let (second, commitment) =
+        SignerNonce::commit_with_rng(&ring, 2, &secrets[2], &mut rng).unwrap();
+    session.add_commitment(2, commitment).unwrap();
+    let challenges = session.challenges().unwrap();
+    assert_eq!(
+        session.add_commitment(2, commitment),
+        Err(SessionError::OutOfOrder)
+    );
+    assert_eq!(
+        second.respond(C::ScalarField::MODULUS),
+        Err(SessionError::NonCanonicalScalar)
+    );
+    assert_eq!(
+        session.add_response(1, C::ScalarField::MODULUS),
+        Err(SessionError::NonCanonicalScalar)
+    );
+    let response = first.respond(challenges[0].1).unwrap();
+    assert_eq!(
+        session.add_response(3, response),
+        Err(SessionError::NotASigner(3))
+    );
+    session.add_response(1, response).unwrap();
+    assert_eq!(
+        session.finalize().err(),
+        Some(SessionError::MissingResponses(vec![2]))
+    );
+}
+
+#[test]
+fn threshold_secp256k1() {
+    signs_with_every_threshold::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn threshold_ed25519() {
+    signs_with_every_threshold::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn out_of_turn_secp256k1() {
+    refuses_out_of_turn::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn out_of_turn_ed25519() {
+    refuses_out_of_turn::<ark_ed25519::EdwardsProjective>(4);
+}
@@ -0,0 +1,69 @@
+// Traceable ring signatures over several ring sizes, with the signer at every position: two
+// signatures on one issue link when the messages match, expose the signer when they differ, and
+// stay independent across issues or signers.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::traceable::{Trace, sign_with_rng, trace};
+
+const ISSUE: &[u8] = b"lingo vote";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn traces_at_every_index<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 2, 5] {
+        for index in 0..size {
+            let mut ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+            // a second real signer next to the first, where there is room for one
+            let neighbour = (index + 1) % size;
+            if size > 1 {
+                let mut keys = ring.keys().to_vec();
+                keys[neighbour] = public_key(&other);
+                ring = Ring::from_fixed_pubkeys(keys).unwrap();
+            }
+
+            let yes = sign_with_rng(&ring, ISSUE, b"yes", &secret, index, &mut rng).unwrap();
+            assert!(yes.verify(b"yes").is_ok());
+            assert!(yes.verify(b"no").is_err());
+            let no = sign_with_rng(&ring, ISSUE, b"no", &secret, index, &mut rng).unwrap();
+            assert!(no.verify(b"no").is_ok());
+
+            let repeat = sign_with_rng(&ring, ISSUE, b"yes", &secret, index, &mut rng).unwrap();
+            assert_eq!(trace(&yes, &repeat), Trace::Linked);
+            if size > 1 {
+                assert_eq!(
+                    trace(&yes, &no),
+                    Trace::Traced {
+                        index,
+                        public_key: ring.keys()[index]
+                    }
+                );
+                let theirs =
+                    sign_with_rng(&ring, ISSUE, b"no", &other, neighbour, &mut rng).unwrap();
+                assert!(theirs.verify(b"no").is_ok());
+                assert_eq!(trace(&yes, &theirs), Trace::Independent);
+
+                let next_issue =
+                    sign_with_rng(&ring, b"lingo vote 2", b"no", &secret, index, &mut rng).unwrap();
+                assert_eq!(trace(&no, &next_issue), Trace::Independent);
+            }
+        }
+    }
+}
+
+#[test]
+fn traceable_secp256k1() {
+    traces_at_every_index::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn traceable_ed25519() {
+    traces_at_every_index::<ark_ed25519::EdwardsProjective>(2);
+}
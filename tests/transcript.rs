@@ -0,0 +1,97 @@
+// Merlin transcripts: the STROBE-based transcript must reproduce the `merlin` crate's own test
+// vector, and ring signatures with Merlin challenges must verify under the hash id they carry.
+// Signing on a transcript binds the signature to every statement appended to it first: the
+// same statements verify, different ones or none at all do not.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::challenge::{self, ChallengeHash};
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::transcript::{Merlin, Transcript};
+
+const MESSAGE: &[u8] = b"lingo transcript";
+
+#[test]
+fn merlin_test_vector() {
+    let mut transcript = Transcript::new(b"test protocol");
+    transcript.append_message(b"some label", b"some data");
+    let mut challenge = [0u8; 32];
+    transcript.challenge_bytes(b"challenge", &mut challenge);
+    assert_eq!(
+        challenge
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>(),
+        "d5a21972d0d5fe320c0d263fac7fffb8145aa640af6e9bca177c03c7efcf0615"
+    );
+    assert_eq!(challenge::hash_id("merlin"), Some(Merlin::HASH_ID));
+}
+
+fn signs_with_merlin<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 2, &mut rng).unwrap();
+
+    let signature = ring
+        .sign_with_hash_and_rng::<Merlin, _>(MESSAGE, &secret, 2, &mut rng)
+        .unwrap();
+    assert_eq!(signature.hash_id, Merlin::HASH_ID);
+    assert!(signature.verify(MESSAGE).is_ok());
+    assert!(signature.verify(b"lingo transcripts").is_err());
+
+    let linkable = ring
+        .sign_linkable_with_hash_and_rng::<Merlin, _>(MESSAGE, &secret, 2, &mut rng)
+        .unwrap();
+    assert!(linkable.verify(MESSAGE).is_ok());
+    assert!(linkable.verify(b"lingo transcripts").is_err());
+}
+
+fn binds_statements<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 1, &mut rng).unwrap();
+
+    let mut proof = Transcript::new(b"outer");
+    proof.append_message(b"statement", b"first");
+    let mut other = Transcript::new(b"outer");
+    other.append_message(b"statement", b"second");
+
+    let plain = ring
+        .sign_with_transcript_and_rng(&proof, MESSAGE, &secret, 1, &mut rng)
+        .unwrap();
+    let linkable = ring
+        .sign_linkable_with_transcript_and_rng(&proof, MESSAGE, &secret, 1, &mut rng)
+        .unwrap();
+    for signature in [&plain, &linkable] {
+        assert!(signature.verify_with_transcript(&proof, MESSAGE).is_ok());
+        assert!(signature.verify_with_transcript(&proof, b"").is_err());
+        assert!(signature.verify_with_transcript(&other, MESSAGE).is_err());
+        // without the outer statements the challenges come out differently
+        assert!(signature.verify(MESSAGE).is_err());
+    }
+
+    // only Merlin signatures can be checked against a transcript
+    let sha512 = ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng).unwrap();
+    assert!(sha512.verify_with_transcript(&proof, MESSAGE).is_err());
+}
+
+#[test]
+fn merlin_secp256k1() {
+    signs_with_merlin::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn merlin_ed25519() {
+    signs_with_merlin::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn statements_secp256k1() {
+    binds_statements::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn statements_ed25519() {
+    binds_statements::<ark_ed25519::EdwardsProjective>(4);
+}
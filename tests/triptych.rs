@@ -0,0 +1,69 @@
+// Triptych signing and verification over power-of-two rings, with the signer at every position:
+// each signature must verify against its own message and no other, and its linking tag must
+// depend on the signer's key alone. A ring of any other size is refused rather than signed over.
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::RingError;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::triptych;
+
+const MESSAGE: &[u8] = b"lingo triptych";
+
+fn signs_at_every_index<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    for size in [2, 4, 8] {
+        for index in 0..size {
+            let ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+            let signature =
+                triptych::prove_with_rng(&ring, MESSAGE, &secret, index, &mut rng).unwrap();
+            assert!(signature.verify(MESSAGE).is_ok());
+            assert!(signature.verify(b"lingo triptyck").is_err());
+            assert_eq!(signature.x.len(), size.trailing_zeros() as usize);
+
+            let again =
+                triptych::prove_with_rng(&ring, b"again", &secret, index, &mut rng).unwrap();
+            assert_eq!(signature.key_image(), again.key_image());
+
+            let stranger = Ring::<C>::new_with_rng(size, &other, index, &mut rng).unwrap();
+            let unlinked =
+                triptych::prove_with_rng(&stranger, MESSAGE, &other, index, &mut rng).unwrap();
+            assert_ne!(signature.key_image(), unlinked.key_image());
+        }
+    }
+}
+
+fn refuses_other_sizes<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    for size in [1, 3, 6] {
+        let ring = Ring::<C>::new_with_rng(size, &secret, 0, &mut rng).unwrap();
+        assert_eq!(
+            triptych::prove_with_rng(&ring, MESSAGE, &secret, 0, &mut rng).err(),
+            Some(RingError::UnsupportedSize(size))
+        );
+    }
+}
+
+#[test]
+fn triptych_secp256k1() {
+    signs_at_every_index::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn triptych_ed25519() {
+    signs_at_every_index::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn sizes_secp256k1() {
+    refuses_other_sizes::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn sizes_ed25519() {
+    refuses_other_sizes::<ark_ed25519::EdwardsProjective>(4);
+}
@@ -0,0 +1,325 @@
+// Batch verification over mixed SAG and bLSAG signatures on rings of different sizes, where a
+// batch passes exactly when every signature in it verifies on its own, and the `VerifyError`
+// each of the other schemes reports for a misshapen, non-canonical or mismatched signature.
+// Salted and signing-context signatures are not batchable and must fail a batch, as must an
+// unknown challenge hash.
+// Verification folds each step's multiplications into one multi-scalar multiplication, so a
+// change to any one member's response, or to the key image, must still break the chain. Large
+// rings, whose per-member work the `parallel` feature spreads over threads, must verify alone
+// and in batches just as small ones do, and fail on a change to their last member.
+
+use ark_ec::{CurveGroup, PrimeGroup};
+use ark_ff::PrimeField;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::borromean::{self, BorromeanSignature};
+use lingo::context::SigningContext;
+use lingo::error::VerifyError;
+use lingo::mlsag::{MatrixRing, MlsagSignature};
+use lingo::ring::{Ring, RingSignature};
+use lingo::secret::SecretKey;
+use lingo::triptych::{self, TriptychSignature};
+
+type Scalar<C> = <C as PrimeGroup>::ScalarField;
+type Signature<'a, C> = RingSignature<'a, <Scalar<C> as PrimeField>::BigInt, C>;
+
+const MESSAGE: &[u8] = b"lingo verification";
+
+fn public_key<C: CurveGroup>(secret: &SecretKey<C>) -> C::Affine {
+    (C::generator() * secret.expose_secret()).into_affine()
+}
+
+fn verifies_batches<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let large = Ring::<C>::new_with_rng(4, &secret, 1, &mut rng).unwrap();
+    let small = Ring::<C>::new_with_rng(2, &secret, 0, &mut rng).unwrap();
+    let single = Ring::<C>::new_with_rng(1, &secret, 0, &mut rng).unwrap();
+
+    let a = large.sign_with_rng(b"a", &secret, 1, &mut rng).unwrap();
+    let b = small
+        .sign_linkable_with_rng(b"b", &secret, 0, &mut rng)
+        .unwrap();
+    let c = large
+        .sign_linkable_with_rng(b"c", &secret, 1, &mut rng)
+        .unwrap();
+    let d = single.sign_with_rng(b"", &secret, 0, &mut rng).unwrap();
+    let batch: [(&[u8], &Signature<C>); 4] = [(b"a", &a), (b"b", &b), (b"c", &c), (b"", &d)];
+    assert!(Signature::<C>::batch_verify(&batch).is_ok());
+    assert!(Signature::<C>::batch_verify(&[]).is_ok());
+
+    // any one signature over the wrong message sinks the batch
+    for wrong in 0..batch.len() {
+        let mut tampered = batch;
+        tampered[wrong].0 = b"x";
+        assert!(Signature::<C>::batch_verify(&tampered).is_err());
+    }
+}
+
+fn refuses_other_kinds<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+    let context = SigningContext::new(b"lingo verification context");
+
+    // salted and context signatures start their chains from state the batch does not have
+    let salted = ring
+        .sign_linkable_salted_with_rng(MESSAGE, &secret, 1, b"salt", &mut rng)
+        .unwrap();
+    let bound = context
+        .sign_with_rng(&ring, MESSAGE, &secret, 1, &mut rng)
+        .unwrap();
+    assert!(salted.verify_salted(MESSAGE, b"salt").is_ok());
+    assert!(context.verify(&bound, MESSAGE).is_ok());
+    for signature in [&salted, &bound] {
+        assert_eq!(
+            Signature::<C>::batch_verify(&[(MESSAGE, signature)]),
+            Err(VerifyError::ChallengeMismatch)
+        );
+    }
+
+    let mut unknown = ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng).unwrap();
+    unknown.hash_id = 0xff;
+    assert_eq!(
+        Signature::<C>::batch_verify(&[(MESSAGE, &unknown)]),
+        Err(VerifyError::UnsupportedHash)
+    );
+}
+
+fn rejects_tampering<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    let theirs = Ring::<C>::new_with_rng(1, &other, 0, &mut rng).unwrap();
+    let foreign = theirs
+        .sign_linkable_with_rng(MESSAGE, &other, 0, &mut rng)
+        .unwrap()
+        .image;
+    for size in [1, 2, 3, 17] {
+        let index = size / 2;
+        let ring = Ring::<C>::new_with_rng(size, &secret, index, &mut rng).unwrap();
+        for signature in [
+            ring.sign_with_rng(MESSAGE, &secret, index, &mut rng)
+                .unwrap(),
+            ring.sign_linkable_with_rng(MESSAGE, &secret, index, &mut rng)
+                .unwrap(),
+        ] {
+            assert!(signature.verify(MESSAGE).is_ok());
+            let altered = |ring_sig_vals, image| RingSignature {
+                ring: &ring,
+                challenge: signature.challenge,
+                ring_sig_vals,
+                image,
+                hash_id: signature.hash_id,
+            };
+            for member in 0..size {
+                let mut responses = signature.ring_sig_vals.clone();
+                let response = Scalar::<C>::from_bigint(responses[member]).unwrap();
+                responses[member] = (response + Scalar::<C>::from(1u64)).into_bigint();
+                assert_eq!(
+                    altered(responses, signature.image).verify(MESSAGE),
+                    Err(VerifyError::ChallengeMismatch)
+                );
+            }
+            if signature.image.is_some() {
+                assert!(
+                    altered(signature.ring_sig_vals.clone(), foreign)
+                        .verify(MESSAGE)
+                        .is_err()
+                );
+            }
+        }
+    }
+}
+
+fn verifies_large_rings<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let first = Ring::<C>::new_with_rng(64, &secret, 63, &mut rng).unwrap();
+    let second = Ring::<C>::new_with_rng(33, &secret, 0, &mut rng).unwrap();
+
+    let a = first.sign_with_rng(b"a", &secret, 63, &mut rng).unwrap();
+    let b = first
+        .sign_linkable_with_rng(b"b", &secret, 63, &mut rng)
+        .unwrap();
+    let c = second
+        .sign_linkable_with_rng(b"c", &secret, 0, &mut rng)
+        .unwrap();
+    assert!(a.verify(b"a").is_ok());
+    assert!(b.verify(b"b").is_ok());
+    assert_eq!(b.image, c.image);
+    let batch: [(&[u8], &Signature<C>); 3] = [(b"a", &a), (b"b", &b), (b"c", &c)];
+    assert!(Signature::<C>::batch_verify(&batch).is_ok());
+
+    let mut keys = second.keys().to_vec();
+    keys[32] = first.keys()[0];
+    let moved = Ring::<C>::from_fixed_pubkeys(keys).unwrap();
+    let tampered = RingSignature {
+        ring: &moved,
+        challenge: c.challenge,
+        ring_sig_vals: c.ring_sig_vals.clone(),
+        image: c.image,
+        hash_id: c.hash_id,
+    };
+    assert_eq!(tampered.verify(b"c"), Err(VerifyError::ChallengeMismatch));
+    let batch: [(&[u8], &Signature<C>); 3] = [(b"a", &a), (b"b", &b), (b"c", &tampered)];
+    assert!(Signature::<C>::batch_verify(&batch).is_err());
+}
+
+fn mlsag_errors<C: CurveGroup>(rng: &mut StdRng) {
+    let secrets: Vec<Vec<SecretKey<C>>> = (0..3)
+        .map(|_| (0..2).map(|_| SecretKey::generate(rng)).collect())
+        .collect();
+    let members: Vec<Vec<C::Affine>> = secrets
+        .iter()
+        .map(|member| member.iter().map(public_key).collect())
+        .collect();
+    let ring = MatrixRing::from_members(&members).unwrap();
+    let signature = ring.sign_with_rng(MESSAGE, &secrets[1], 1, rng).unwrap();
+    let altered = |challenge, ring_sig_vals| MlsagSignature {
+        ring: &ring,
+        challenge,
+        ring_sig_vals,
+        images: signature.images.clone(),
+    };
+
+    assert_eq!(
+        signature.verify(b"lingo verificatiom"),
+        Err(VerifyError::ChallengeMismatch)
+    );
+    let mut short = signature.ring_sig_vals.clone();
+    short.pop();
+    assert_eq!(
+        altered(signature.challenge, short).verify(MESSAGE),
+        Err(VerifyError::LengthMismatch)
+    );
+    let mut narrow = signature.ring_sig_vals.clone();
+    narrow[2].pop();
+    assert_eq!(
+        altered(signature.challenge, narrow).verify(MESSAGE),
+        Err(VerifyError::LengthMismatch)
+    );
+    assert_eq!(
+        altered(Scalar::<C>::MODULUS, signature.ring_sig_vals.clone()).verify(MESSAGE),
+        Err(VerifyError::NonCanonicalScalar)
+    );
+
+    let clsag = ring
+        .sign_clsag_with_rng(MESSAGE, &secrets[1], 1, rng)
+        .unwrap();
+    assert_eq!(
+        clsag.verify(b"lingo verificatiom"),
+        Err(VerifyError::ChallengeMismatch)
+    );
+}
+
+fn borromean_errors<C: CurveGroup>(rng: &mut StdRng) {
+    let secrets: Vec<SecretKey<C>> = (0..2).map(|_| SecretKey::generate(rng)).collect();
+    let rings: Vec<Ring<C>> = secrets
+        .iter()
+        .map(|secret| Ring::new_with_rng(3, secret, 2, rng).unwrap())
+        .collect();
+    let signature = borromean::sign_with_rng(&rings, MESSAGE, &secrets, &[2, 2], rng).unwrap();
+    assert_eq!(
+        signature.verify(b"lingo verificatiom"),
+        Err(VerifyError::ChallengeMismatch)
+    );
+
+    let mut short = signature.ring_sig_vals.clone();
+    short[1].pop();
+    let misshapen = BorromeanSignature {
+        rings: &rings,
+        challenge: signature.challenge,
+        ring_sig_vals: short,
+    };
+    assert_eq!(misshapen.verify(MESSAGE), Err(VerifyError::LengthMismatch));
+}
+
+fn triptych_errors<C: CurveGroup>(rng: &mut StdRng) {
+    let secret = SecretKey::<C>::generate(rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 3, rng).unwrap();
+    let three = Ring::<C>::new_with_rng(3, &secret, 2, rng).unwrap();
+    let one = Ring::<C>::new_with_rng(1, &secret, 0, rng).unwrap();
+    let signature = triptych::prove_with_rng(&ring, MESSAGE, &secret, 3, rng).unwrap();
+    assert_eq!(
+        signature.verify(b"lingo verificatiom"),
+        Err(VerifyError::ChallengeMismatch)
+    );
+
+    let moved = |ring| TriptychSignature {
+        ring,
+        x: signature.x.clone(),
+        y: signature.y.clone(),
+        f: signature.f.clone(),
+        ..signature
+    };
+    assert_eq!(
+        moved(&three).verify(MESSAGE),
+        Err(VerifyError::LengthMismatch)
+    );
+    assert_eq!(moved(&one).verify(MESSAGE), Err(VerifyError::RingTooSmall));
+    let noncanonical = TriptychSignature {
+        z: Scalar::<C>::MODULUS,
+        ..moved(&ring)
+    };
+    assert_eq!(
+        noncanonical.verify(MESSAGE),
+        Err(VerifyError::NonCanonicalScalar)
+    );
+}
+
+fn reports_structured_errors<C: CurveGroup>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    mlsag_errors::<C>(&mut rng);
+    borromean_errors::<C>(&mut rng);
+    triptych_errors::<C>(&mut rng);
+}
+
+#[test]
+fn batch_secp256k1() {
+    verifies_batches::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn batch_ed25519() {
+    verifies_batches::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[test]
+fn other_kinds_secp256k1() {
+    refuses_other_kinds::<ark_secp256k1::Projective>(9);
+}
+
+#[test]
+fn other_kinds_ed25519() {
+    refuses_other_kinds::<ark_ed25519::EdwardsProjective>(10);
+}
+
+#[test]
+fn tampering_secp256k1() {
+    rejects_tampering::<ark_secp256k1::Projective>(5);
+}
+
+#[test]
+fn tampering_ed25519() {
+    rejects_tampering::<ark_ed25519::EdwardsProjective>(6);
+}
+
+#[test]
+fn large_secp256k1() {
+    verifies_large_rings::<ark_secp256k1::Projective>(7);
+}
+
+#[test]
+fn large_ed25519() {
+    verifies_large_rings::<ark_ed25519::EdwardsProjective>(8);
+}
+
+#[test]
+fn errors_secp256k1() {
+    reports_structured_errors::<ark_secp256k1::Projective>(3);
+}
+
+#[test]
+fn errors_ed25519() {
+    reports_structured_errors::<ark_ed25519::EdwardsProjective>(4);
+}
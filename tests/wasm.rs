@@ -0,0 +1,36 @@
+// The wasm-bindgen wrapper, called natively: secret keys it generates must derive public keys
+// that form a ring, and a SAG or bLSAG signature made through it must verify against its own
+// message only, carry the ring it was made over, and carry the signer's key image exactly when
+// it is linkable. Only the success paths are exercised, since `JsError` needs a JS host.
+
+#![cfg(feature = "wasm")]
+
+use lingo::wasm::{
+    generate_secret_key, key_image, public_key, sign, signature_key_image, signature_ring, verify,
+};
+
+const MESSAGE: &[u8] = b"lingo wasm";
+
+#[test]
+fn wasm_round_trip() {
+    let secret_keys: Vec<Vec<u8>> = (0..4).map(|_| generate_secret_key()).collect();
+    let ring: Vec<u8> = secret_keys
+        .iter()
+        .flat_map(|secret_key| public_key(secret_key).unwrap())
+        .collect();
+    assert_eq!(ring.len(), 4 * 33);
+
+    for linkable in [false, true] {
+        let signature = sign(&ring, MESSAGE, &secret_keys[2], 2, linkable).unwrap();
+        assert!(verify(MESSAGE, &signature).unwrap());
+        assert!(!verify(b"lingo wasn", &signature).unwrap());
+        assert_eq!(signature_ring(&signature).unwrap(), ring);
+
+        let image = signature_key_image(&signature).unwrap();
+        if linkable {
+            assert_eq!(image, Some(key_image(&secret_keys[2]).unwrap()));
+        } else {
+            assert_eq!(image, None);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// The versioned wire format: signatures must come back from their bytes verifying and encoding
+// the same, behind a header naming the format, version, curve and scheme. Every truncation,
+// trailing byte, unknown version, foreign curve and missing magic must be refused. The version
+// of stored bytes, with or without their ring, must be readable without decoding them, even
+// for versions this build cannot decode.
+
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::error::DecodeError;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::wire::{self, DecodedSignature, WireCurve};
+
+const MESSAGE: &[u8] = b"lingo wire";
+
+fn round_trips<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &secret, 2, &mut rng).unwrap();
+    for (scheme, signature) in [
+        (
+            1,
+            ring.sign_with_rng(MESSAGE, &secret, 2, &mut rng).unwrap(),
+        ),
+        (
+            2,
+            ring.sign_linkable_with_rng(MESSAGE, &secret, 2, &mut rng)
+                .unwrap(),
+        ),
+    ] {
+        let bytes = signature.to_bytes();
+        assert_eq!(&bytes[..4], b"LNGO");
+        assert_eq!(bytes[4..7], [1, C::CURVE_ID, scheme]);
+        assert_eq!(bytes[7..11], 4u32.to_le_bytes());
+
+        let decoded = DecodedSignature::<C>::from_bytes(&bytes).unwrap();
+        assert!(decoded.verify(MESSAGE).is_ok());
+        assert!(decoded.verify(b"lingo wirf").is_err());
+        assert_eq!(decoded.to_bytes(), bytes);
+        assert_eq!(decoded.signature().image, signature.image);
+
+        for cut in 0..bytes.len() {
+            assert!(DecodedSignature::<C>::from_bytes(&bytes[..cut]).is_err());
+        }
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            DecodedSignature::<C>::from_bytes(&trailing).err(),
+            Some(DecodeError::TrailingBytes)
+        );
+        let mut version = bytes.clone();
+        version[4] = 9;
+        assert_eq!(
+            DecodedSignature::<C>::from_bytes(&version).err(),
+            Some(DecodeError::UnsupportedVersion(9))
+        );
+        let mut magic = bytes.clone();
+        magic[0] = b'X';
+        assert_eq!(
+            DecodedSignature::<C>::from_bytes(&magic).err(),
+            Some(DecodeError::BadMagic)
+        );
+        let mut scheme = bytes.clone();
+        scheme[6] = 9;
+        assert_eq!(
+            DecodedSignature::<C>::from_bytes(&scheme).err(),
+            Some(DecodeError::UnknownScheme(9))
+        );
+    }
+}
+
+#[test]
+fn wire_secp256k1() {
+    round_trips::<ark_secp256k1::Projective>(1);
+}
+
+#[test]
+fn wire_ed25519() {
+    round_trips::<ark_ed25519::EdwardsProjective>(2);
+}
+
+#[cfg(feature = "bls12-381")]
+#[test]
+fn wire_bls12_381() {
+    round_trips::<lingo::curves::bls12_381::G1Projective>(3);
+}
+
+#[test]
+fn wire_rejects_other_curves() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let secret = SecretKey::generate(&mut rng);
+    let ring = Ring::<ark_secp256k1::Projective>::new_with_rng(2, &secret, 0, &mut rng).unwrap();
+    let bytes = ring
+        .sign_with_rng(MESSAGE, &secret, 0, &mut rng)
+        .unwrap()
+        .to_bytes();
+    assert_eq!(
+        DecodedSignature::<ark_ed25519::EdwardsProjective>::from_bytes(&bytes).err(),
+        Some(DecodeError::WrongCurve(1))
+    );
+}
+
+fn reads_versions<C: WireCurve>(seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(3, &secret, 1, &mut rng).unwrap();
+    let signature = ring.sign_with_rng(MESSAGE, &secret, 1, &mut rng).unwrap();
+    let bytes = signature.to_bytes();
+    assert_eq!(wire::wire_version(&bytes), Ok(1));
+    assert_eq!(wire::wire_version(&signature.to_ringless_bytes()), Ok(1));
+
+    let mut later = bytes.clone();
+    later[4] = 3;
+    assert_eq!(wire::wire_version(&later), Ok(3));
+    assert_eq!(
+        DecodedSignature::<C>::from_bytes(&later).err(),
+        Some(DecodeError::UnsupportedVersion(3))
+    );
+    assert_eq!(wire::wire_version(b"LNGO"), Err(DecodeError::Truncated));
+    assert_eq!(wire::wire_version(b"XXXX\x01"), Err(DecodeError::BadMagic));
+}
+
+#[test]
+fn versions_secp256k1() {
+    reads_versions::<ark_secp256k1::Projective>(5);
+}
+
+#[test]
+fn versions_ed25519() {
+    reads_versions::<ark_ed25519::EdwardsProjective>(6);
+}
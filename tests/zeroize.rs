@@ -0,0 +1,224 @@
+// Signing must not hand heap memory back to the allocator with the private key still in it, in
+// any scheme: SAG and bLSAG, MLSAG and CLSAG, the one-out-of-many proofs, the signature variants
+// and the interactive sessions. This binary's allocator scans every block it frees, while armed,
+// for the keys' little-endian bytes and for a run of their bits as `constant-time`'s `secret_mul`
+// lays them out. The tests take turns, since the allocator is shared.
+
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, BitIteratorBE, PrimeField};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use lingo::aos::AosRing;
+use lingo::blind::{BlindRequest, BlindSigner, unblind};
+use lingo::keypair::KeyPair;
+use lingo::mlsag::MatrixRing;
+use lingo::ring::Ring;
+use lingo::secret::SecretKey;
+use lingo::threshold::{SignerNonce, ThresholdSession};
+use lingo::{borromean, designated, one_of_many, revocable, traceable, triptych};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+struct Scanning;
+
+static ARMED: AtomicBool = AtomicBool::new(false);
+static LEAKS: AtomicUsize = AtomicUsize::new(0);
+// only written while disarmed, so `dealloc` never waits on a lock its own thread holds
+static NEEDLES: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+
+unsafe impl GlobalAlloc for Scanning {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ARMED.load(Ordering::SeqCst)
+            && let Ok(needles) = NEEDLES.try_lock()
+        {
+            let block = unsafe { std::slice::from_raw_parts(ptr, layout.size()) };
+            if needles
+                .iter()
+                .any(|needle| block.windows(needle.len()).any(|window| window == needle))
+            {
+                LEAKS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Scanning = Scanning;
+
+type C = ark_secp256k1::Projective;
+
+static SERIAL: Mutex<()> = Mutex::new(());
+
+// through the crate, whose multiplication wipes the key's bits: arkworks' own would leave them in
+// freed blocks for a later allocation to pick up
+fn public_key(secret: &SecretKey<C>) -> <C as CurveGroup>::Affine {
+    *KeyPair::from_private_key(secret).public_key()
+}
+
+// runs `sign` with the allocator armed, returning how many freed blocks held one of `secrets`
+fn leaks(secrets: &[&SecretKey<C>], sign: impl FnOnce()) -> usize {
+    let _turn = SERIAL
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut needles = Vec::new();
+    for secret in secrets {
+        let secret = secret.expose_secret().into_bigint();
+        needles.push(secret.to_bytes_le());
+        // straight off the iterator: `to_bits_be` would free the whole key's bits right here
+        needles.push(
+            BitIteratorBE::new(secret)
+                .skip(64)
+                .take(64)
+                .map(u8::from)
+                .collect(),
+        );
+    }
+    *NEEDLES.lock().unwrap() = needles;
+    LEAKS.store(0, Ordering::SeqCst);
+
+    ARMED.store(true, Ordering::SeqCst);
+    sign();
+    ARMED.store(false, Ordering::SeqCst);
+    LEAKS.load(Ordering::SeqCst)
+}
+
+#[test]
+fn signing_frees_no_secrets() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let sk = SecretKey::<C>::generate(&mut rng);
+    let mut signatures = Vec::new();
+    let leaked = leaks(&[&sk], || {
+        let ring = Ring::<C>::new_with_rng(4, &sk, 2, &mut rng).unwrap();
+        signatures = vec![
+            ring.sign_with_rng(b"m", &sk, 2, &mut rng)
+                .unwrap()
+                .into_owned(),
+            ring.sign_linkable_with_rng(b"m", &sk, 2, &mut rng)
+                .unwrap()
+                .into_owned(),
+            ring.sign_deterministic(b"m", &sk, 2).unwrap().into_owned(),
+            ring.sign_linkable_deterministic(b"m", &sk, 2)
+                .unwrap()
+                .into_owned(),
+        ];
+    });
+
+    for signature in &signatures {
+        signature.verify(b"m").unwrap();
+    }
+    assert_eq!(leaked, 0);
+}
+
+#[test]
+fn matrix_signing_frees_no_secrets() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let keys = [
+        SecretKey::<C>::generate(&mut rng),
+        SecretKey::<C>::generate(&mut rng),
+    ];
+    let members: Vec<Vec<_>> = (0..3)
+        .map(|_| {
+            let decoy = SecretKey::<C>::generate(&mut rng);
+            vec![public_key(&decoy), public_key(&decoy)]
+        })
+        .chain([keys.iter().map(public_key).collect()])
+        .collect();
+    let ring = MatrixRing::from_members(&members).unwrap();
+
+    let leaked = leaks(&[&keys[0], &keys[1]], || {
+        let mlsag = ring.sign_with_rng(b"m", &keys, 3, &mut rng).unwrap();
+        let clsag = ring.sign_clsag_with_rng(b"m", &keys, 3, &mut rng).unwrap();
+        assert!(mlsag.verify(b"m").is_ok() && clsag.verify(b"m").is_ok());
+    });
+    assert_eq!(leaked, 0);
+}
+
+#[test]
+fn proving_frees_no_secrets() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let sk = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(8, &sk, 5, &mut rng).unwrap();
+
+    let leaked = leaks(&[&sk], || {
+        let proof = one_of_many::prove_with_rng(&ring, b"m", &sk, 5, &mut rng).unwrap();
+        let signature = triptych::prove_with_rng(&ring, b"m", &sk, 5, &mut rng).unwrap();
+        assert!(proof.verify(b"m").is_ok() && signature.verify(b"m").is_ok());
+    });
+    assert_eq!(leaked, 0);
+}
+
+#[test]
+fn variants_free_no_secrets() {
+    let mut rng = StdRng::seed_from_u64(6);
+    let sk = SecretKey::<C>::generate(&mut rng);
+    let other = SecretKey::<C>::generate(&mut rng);
+    let verifier = SecretKey::<C>::generate(&mut rng);
+    let ring = Ring::<C>::new_with_rng(4, &sk, 1, &mut rng).unwrap();
+    let second = Ring::<C>::new_with_rng(3, &other, 2, &mut rng).unwrap();
+    let mut mixed = AosRing::new(Vec::new());
+    mixed.extend_from_ring(&ring);
+
+    let leaked = leaks(&[&sk, &other], || {
+        let aos = mixed.sign_with_rng::<C, _>(b"m", &sk, 1, &mut rng).unwrap();
+        let rings = [ring.clone(), second.clone()];
+        let borromean = borromean::sign_with_rng(
+            &rings,
+            b"m",
+            &[sk.clone(), other.clone()],
+            &[1, 2],
+            &mut rng,
+        )
+        .unwrap();
+        let designated =
+            designated::sign_with_rng(&ring, b"m", &sk, 1, public_key(&verifier), &mut rng)
+                .unwrap();
+        let revocable = revocable::sign_with_rng(&ring, b"event", b"m", &sk, 1, &mut rng).unwrap();
+        let traceable = traceable::sign_with_rng(&ring, b"issue", b"m", &sk, 1, &mut rng).unwrap();
+        let linkable = ring.sign_linkable_with_rng(b"m", &sk, 1, &mut rng).unwrap();
+        let claim = linkable.claim_with_rng(&sk, &mut rng).unwrap();
+
+        assert!(aos.verify(b"m").is_ok() && borromean.verify(b"m").is_ok());
+        assert!(designated.verify(b"m", &verifier).is_ok());
+        assert!(revocable.verify(b"m").is_ok() && traceable.verify(b"m").is_ok());
+        assert!(linkable.verify_claim(&claim).is_ok());
+    });
+    assert_eq!(leaked, 0);
+}
+
+#[test]
+fn sessions_free_no_secrets() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let keys: Vec<SecretKey<C>> = (0..3).map(|_| SecretKey::generate(&mut rng)).collect();
+    let ring = Ring::<C>::from_fixed_pubkeys(keys.iter().map(public_key).collect()).unwrap();
+
+    let leaked = leaks(&keys.iter().collect::<Vec<_>>(), || {
+        let (signer, commitment) =
+            BlindSigner::commit_with_rng(&ring, &keys[1], 1, &mut rng).unwrap();
+        let (blinding, request) =
+            BlindRequest::new_with_rng(&ring, b"m", &commitment, &mut rng).unwrap();
+        let response = signer.respond(&request).unwrap();
+        let blind = unblind(blinding, b"m", &response).unwrap();
+        assert!(blind.verify(b"m").is_ok());
+
+        let signers = [0, 2];
+        let mut session = ThresholdSession::new_with_rng(&ring, b"m", &signers, &mut rng).unwrap();
+        let mut nonces = Vec::new();
+        for index in signers {
+            let (nonce, commitment) =
+                SignerNonce::commit_with_rng(&ring, index, &keys[index], &mut rng).unwrap();
+            session.add_commitment(index, commitment).unwrap();
+            nonces.push(nonce);
+        }
+        for (nonce, (index, challenge)) in nonces.into_iter().zip(session.challenges().unwrap()) {
+            let response = nonce.respond(challenge).unwrap();
+            assert!(session.add_response(index, response).is_ok());
+        }
+        assert!(session.finalize().unwrap().verify(b"m").is_ok());
+    });
+    assert_eq!(leaked, 0);
+}